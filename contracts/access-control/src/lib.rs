@@ -0,0 +1,42 @@
+#![no_std]
+//! # Access Control
+//!
+//! Ownership and role primitives shared by the grainlify-core,
+//! program-escrow and bounty_escrow contracts.
+//!
+//! - [`ownable`]: a single owner (the contract admin) with two-step transfer
+//! - [`roles`]: named roles granted to addresses
+//!
+//! Every check authorizes the acting address with `require_auth`; nothing
+//! relies on the invoker. The owner stays under each contract's existing
+//! admin key so adopting this crate does not move stored state; the pending
+//! owner and role grants live under [`AccessKey`].
+
+use soroban_sdk::{contracttype, Address, Symbol};
+
+pub mod ownable;
+pub mod roles;
+
+#[cfg(test)]
+mod test;
+
+/// Storage keys owned by this crate (instance storage).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AccessKey {
+    PendingOwner,          // Address proposed by the owner, awaiting acceptance
+    Role(Symbol, Address), // (role, account) -> granted
+}
+
+/// Failure of an access check. Contracts map this into their own error type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AccessError {
+    /// No owner has been stored yet.
+    NotInitialized,
+    /// An owner is already stored.
+    AlreadyInitialized,
+    /// The account lacks the required role.
+    Unauthorized,
+    /// `accept_ownership` was called with no transfer proposed.
+    NoPendingOwner,
+}
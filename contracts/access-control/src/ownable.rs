@@ -0,0 +1,80 @@
+//! Single-owner access control with two-step ownership transfer.
+//!
+//! The owner is read from and written to `owner_key` in instance storage,
+//! which lets each contract keep its own admin key. A transfer only takes
+//! effect once the proposed owner accepts it, so a typo cannot hand the
+//! contract to an address nobody controls.
+
+use crate::{AccessError, AccessKey};
+use soroban_sdk::{Address, Env, IntoVal, Val};
+
+/// Store the first owner.
+pub fn init_owner<K>(env: &Env, owner_key: &K, owner: &Address) -> Result<(), AccessError>
+where
+    K: IntoVal<Env, Val>,
+{
+    if env.storage().instance().has(owner_key) {
+        return Err(AccessError::AlreadyInitialized);
+    }
+    env.storage().instance().set(owner_key, owner);
+    Ok(())
+}
+
+/// The current owner, if one is stored.
+pub fn owner<K>(env: &Env, owner_key: &K) -> Option<Address>
+where
+    K: IntoVal<Env, Val>,
+{
+    env.storage().instance().get(owner_key)
+}
+
+/// Load the owner and require its authorization. Returns the owner.
+pub fn require_owner<K>(env: &Env, owner_key: &K) -> Result<Address, AccessError>
+where
+    K: IntoVal<Env, Val>,
+{
+    let owner = owner(env, owner_key).ok_or(AccessError::NotInitialized)?;
+    owner.require_auth();
+    Ok(owner)
+}
+
+/// Propose `new_owner` (owner only). Replaces any earlier proposal.
+pub fn propose_owner<K>(env: &Env, owner_key: &K, new_owner: &Address) -> Result<(), AccessError>
+where
+    K: IntoVal<Env, Val>,
+{
+    require_owner(env, owner_key)?;
+    env.storage()
+        .instance()
+        .set(&AccessKey::PendingOwner, new_owner);
+    Ok(())
+}
+
+/// The proposed owner, if a transfer is pending.
+pub fn pending_owner(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&AccessKey::PendingOwner)
+}
+
+/// Complete a pending transfer (proposed owner only). Returns the previous
+/// owner.
+pub fn accept_ownership<K>(env: &Env, owner_key: &K) -> Result<Address, AccessError>
+where
+    K: IntoVal<Env, Val>,
+{
+    let previous = owner(env, owner_key).ok_or(AccessError::NotInitialized)?;
+    let pending = pending_owner(env).ok_or(AccessError::NoPendingOwner)?;
+    pending.require_auth();
+    env.storage().instance().set(owner_key, &pending);
+    env.storage().instance().remove(&AccessKey::PendingOwner);
+    Ok(previous)
+}
+
+/// Withdraw a pending transfer (owner only).
+pub fn cancel_ownership_transfer<K>(env: &Env, owner_key: &K) -> Result<(), AccessError>
+where
+    K: IntoVal<Env, Val>,
+{
+    require_owner(env, owner_key)?;
+    env.storage().instance().remove(&AccessKey::PendingOwner);
+    Ok(())
+}
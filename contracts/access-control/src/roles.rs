@@ -0,0 +1,38 @@
+//! Named roles granted to addresses.
+//!
+//! Roles are plain `Symbol`s chosen by each contract (e.g. `"pauser"`).
+//! Granting and revoking do not check authorization themselves; callers gate
+//! them, typically behind [`crate::ownable::require_owner`].
+
+use crate::{AccessError, AccessKey};
+use soroban_sdk::{Address, Env, Symbol};
+
+/// Whether `account` holds `role`.
+pub fn has_role(env: &Env, role: &Symbol, account: &Address) -> bool {
+    env.storage()
+        .instance()
+        .has(&AccessKey::Role(role.clone(), account.clone()))
+}
+
+/// Give `role` to `account`. Granting a held role is a no-op.
+pub fn grant_role(env: &Env, role: &Symbol, account: &Address) {
+    env.storage()
+        .instance()
+        .set(&AccessKey::Role(role.clone(), account.clone()), &true);
+}
+
+/// Take `role` from `account`. Revoking a role not held is a no-op.
+pub fn revoke_role(env: &Env, role: &Symbol, account: &Address) {
+    env.storage()
+        .instance()
+        .remove(&AccessKey::Role(role.clone(), account.clone()));
+}
+
+/// Fail unless `account` holds `role`, then require its authorization.
+pub fn require_role(env: &Env, role: &Symbol, account: &Address) -> Result<(), AccessError> {
+    if !has_role(env, role, account) {
+        return Err(AccessError::Unauthorized);
+    }
+    account.require_auth();
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use crate::{ownable, roles, AccessError};
+use soroban_sdk::{contract, symbol_short, testutils::Address as _, Address, Env, Symbol};
+
+#[contract]
+struct Host;
+
+const OWNER: Symbol = symbol_short!("owner");
+
+fn setup() -> (Env, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let host = env.register_contract(None, Host);
+    (env, host)
+}
+
+#[test]
+fn test_two_step_ownership_transfer() {
+    let (env, host) = setup();
+    let owner = Address::generate(&env);
+    let next = Address::generate(&env);
+
+    env.as_contract(&host, || {
+        assert_eq!(
+            ownable::require_owner(&env, &OWNER),
+            Err(AccessError::NotInitialized)
+        );
+        ownable::init_owner(&env, &OWNER, &owner).unwrap();
+        assert_eq!(
+            ownable::init_owner(&env, &OWNER, &next),
+            Err(AccessError::AlreadyInitialized)
+        );
+        assert_eq!(
+            ownable::accept_ownership(&env, &OWNER),
+            Err(AccessError::NoPendingOwner)
+        );
+
+        ownable::propose_owner(&env, &OWNER, &next).unwrap();
+        assert_eq!(ownable::pending_owner(&env), Some(next.clone()));
+        // Nothing changes until the proposed owner accepts
+        assert_eq!(ownable::owner(&env, &OWNER), Some(owner.clone()));
+
+        assert_eq!(ownable::accept_ownership(&env, &OWNER), Ok(owner.clone()));
+        assert_eq!(ownable::owner(&env, &OWNER), Some(next.clone()));
+        assert_eq!(ownable::pending_owner(&env), None);
+    });
+    assert!(env.auths().iter().any(|(address, _)| *address == next));
+}
+
+#[test]
+fn test_cancel_ownership_transfer() {
+    let (env, host) = setup();
+    let owner = Address::generate(&env);
+    let next = Address::generate(&env);
+
+    env.as_contract(&host, || {
+        ownable::init_owner(&env, &OWNER, &owner).unwrap();
+        ownable::propose_owner(&env, &OWNER, &next).unwrap();
+    });
+    env.as_contract(&host, || {
+        ownable::cancel_ownership_transfer(&env, &OWNER).unwrap();
+        assert_eq!(ownable::pending_owner(&env), None);
+        assert_eq!(
+            ownable::accept_ownership(&env, &OWNER),
+            Err(AccessError::NoPendingOwner)
+        );
+    });
+}
+
+#[test]
+fn test_roles() {
+    let (env, host) = setup();
+    let pauser = Address::generate(&env);
+    let role = symbol_short!("pauser");
+
+    env.as_contract(&host, || {
+        assert!(!roles::has_role(&env, &role, &pauser));
+        assert_eq!(
+            roles::require_role(&env, &role, &pauser),
+            Err(AccessError::Unauthorized)
+        );
+
+        roles::grant_role(&env, &role, &pauser);
+        assert!(roles::has_role(&env, &role, &pauser));
+        assert_eq!(roles::require_role(&env, &role, &pauser), Ok(()));
+
+        roles::revoke_role(&env, &role, &pauser);
+        assert!(!roles::has_role(&env, &role, &pauser));
+    });
+}
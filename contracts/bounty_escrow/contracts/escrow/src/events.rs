@@ -1,7 +1,7 @@
-use crate::CapabilityAction;
-use soroban_sdk::{contracttype, symbol_short, Address, Env};
+use crate::{CapabilityAction, EscrowStatus, RateLimitRole, RefundMode};
+use soroban_sdk::{contracttype, symbol_short, Address, Bytes, BytesN, Env, Symbol, Vec};
 
-pub const EVENT_VERSION_V2: u32 = 2;
+pub use grainlify_common::events::EVENT_VERSION_V2;
 
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -84,6 +84,26 @@ pub fn emit_fee_collected(env: &Env, event: FeeCollected) {
     env.events().publish(topics, event.clone());
 }
 
+/// Emitted for every release payout, splitting what the recipient received
+/// from the release fee routed to the treasury. `FundsReleased.amount` is
+/// the gross amount.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ReleasePaid {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub recipient: Address,
+    pub gross_amount: i128,
+    pub fee: i128,
+    pub net_amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_release_paid(env: &Env, event: ReleasePaid) {
+    let topics = (symbol_short!("rel_paid"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct BatchFundsLocked {
@@ -235,3 +255,303 @@ pub fn emit_capability_revoked(env: &Env, event: CapabilityRevoked) {
     let topics = (symbol_short!("cap_rev"), event.capability_id);
     env.events().publish(topics, event);
 }
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestoneReleased {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub index: u32,
+    pub amount: i128,
+    pub recipient: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_milestone_released(env: &Env, event: MilestoneReleased) {
+    let topics = (symbol_short!("ms_rel"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeRaised {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub raised_by: Address,
+    pub bond: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_dispute_raised(env: &Env, event: DisputeRaised) {
+    let topics = (symbol_short!("disp_new"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeResolved {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub arbiter: Address,
+    pub award_to: Vec<Address>,
+    pub amounts: Vec<i128>,
+    pub bond_forfeited: bool,
+    pub timestamp: u64,
+}
+
+pub fn emit_dispute_resolved(env: &Env, event: DisputeResolved) {
+    let topics = (symbol_short!("disp_res"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContributorAssigned {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_contributor_assigned(env: &Env, event: ContributorAssigned) {
+    let topics = (symbol_short!("assign"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WorkSubmitted {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub submission_hash: BytesN<32>,
+    pub timestamp: u64,
+}
+
+pub fn emit_work_submitted(env: &Env, event: WorkSubmitted) {
+    let topics = (symbol_short!("submit"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubmissionApproved {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub submission_hash: BytesN<32>,
+    pub approved_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_submission_approved(env: &Env, event: SubmissionApproved) {
+    let topics = (symbol_short!("sub_ok"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowStatusChanged {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub from: EscrowStatus,
+    pub to: EscrowStatus,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrow_status_changed(env: &Env, event: EscrowStatusChanged) {
+    let topics = (symbol_short!("status"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExpiredSwept {
+    pub version: u32,
+    pub keeper: Address,
+    pub count: u32,
+    pub total_incentive: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_expired_swept(env: &Env, event: ExpiredSwept) {
+    let topics = (symbol_short!("sweep"),);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyBoosted {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub booster: Address,
+    pub amount: i128,
+    pub new_amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_bounty_boosted(env: &Env, event: BountyBoosted) {
+    let topics = (symbol_short!("boost"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingStarted {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub total: i128,
+    pub start: u64,
+    pub duration: u64,
+}
+
+pub fn emit_vesting_started(env: &Env, event: VestingStarted) {
+    let topics = (symbol_short!("vest_new"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingClaimed {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub amount: i128,
+    pub total_claimed: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_vesting_claimed(env: &Env, event: VestingClaimed) {
+    let topics = (symbol_short!("vest_clm"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HtlcLocked {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub hashlock: BytesN<32>,
+    pub timelock: u64,
+}
+
+pub fn emit_htlc_locked(env: &Env, event: HtlcLocked) {
+    let topics = (symbol_short!("htlc_new"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+/// Carries the revealed preimage so counterparties on other platforms can
+/// settle their side of the swap.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HtlcClaimed {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub preimage: Bytes,
+    pub timestamp: u64,
+}
+
+pub fn emit_htlc_claimed(env: &Env, event: HtlcClaimed) {
+    let topics = (symbol_short!("htlc_clm"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyArchived {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub status: EscrowStatus,
+    pub timestamp: u64,
+}
+
+pub fn emit_bounty_archived(env: &Env, event: BountyArchived) {
+    let topics = (symbol_short!("archive"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundApproved {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub approval_id: u64,
+    pub amount: i128,
+    pub recipient: Address,
+    pub mode: RefundMode,
+    pub approved_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_refund_approved(env: &Env, event: RefundApproved) {
+    let topics = (symbol_short!("ref_appr"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WhitelistUpdated {
+    pub version: u32,
+    pub address: Address,
+    pub whitelisted: bool,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_whitelist_updated(env: &Env, event: WhitelistUpdated) {
+    let topics = (symbol_short!("wl_upd"),);
+    env.events().publish(topics, event);
+}
+
+/// Effective limit for `role` after the change; `enabled` is false (and the
+/// limits zero) when the role is exempt.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RateLimitConfigUpdated {
+    pub version: u32,
+    pub role: RateLimitRole,
+    pub enabled: bool,
+    pub window_size: u64,
+    pub max_operations: u32,
+    pub cooldown_period: u64,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_rate_limit_config_updated(env: &Env, event: RateLimitConfigUpdated) {
+    let topics = (symbol_short!("rl_cfg"),);
+    env.events().publish(topics, event);
+}
+
+/// Generic event for admin settings without a dedicated event; `setting`
+/// names the changed setting and is also the second topic.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminConfigUpdated {
+    pub version: u32,
+    pub setting: Symbol,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_admin_config_updated(env: &Env, event: AdminConfigUpdated) {
+    let topics = (symbol_short!("adm_cfg"), event.setting.clone());
+    env.events().publish(topics, event);
+}
+
+/// Emitted by `upgrade` before the new WASM takes effect.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractUpgraded {
+    pub version: u32,
+    pub new_wasm_hash: BytesN<32>,
+    pub previous_version: u32,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_contract_upgraded(env: &Env, event: ContractUpgraded) {
+    let topics = (symbol_short!("upgrade"),);
+    env.events().publish(topics, event);
+}
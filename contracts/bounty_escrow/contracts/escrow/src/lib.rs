@@ -10,15 +10,19 @@ mod test_cross_contract_interface;
 mod test_rbac;
 mod traits;
 
+use access_control::{ownable, roles, AccessError};
 use events::{
     emit_batch_funds_locked, emit_batch_funds_released, emit_bounty_initialized, emit_funds_locked,
     emit_funds_refunded, emit_funds_released, BatchFundsLocked, BatchFundsReleased,
     BountyEscrowInitialized, ClaimCancelled, ClaimCreated, ClaimExecuted, FundsLocked,
     FundsRefunded, FundsReleased, EVENT_VERSION_V2,
 };
+use grainlify_common::errors::CommonError;
+use grainlify_common::math;
+use grainlify_common::storage::LEDGERS_PER_DAY;
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, Address, Env,
-    Symbol, Vec,
+    contract, contractclient, contracterror, contractimpl, contracttype, symbol_short, token, vec,
+    xdr::ToXdr, Address, Bytes, BytesN, Env, Symbol, Vec,
 };
 
 mod monitoring {
@@ -218,6 +222,8 @@ mod monitoring {
 }
 
 mod anti_abuse {
+    use crate::{Error, RateLimitOp, RateLimitRole, RateLimitStatus};
+    use grainlify_common::storage::LEDGERS_PER_DAY;
     use soroban_sdk::{contracttype, symbol_short, Address, Env};
 
     #[contracttype]
@@ -243,6 +249,11 @@ mod anti_abuse {
         State(Address),
         Whitelist(Address),
         Admin,
+        RoleConfig(RateLimitRole),
+        RoleState(RateLimitRole, Address),
+        AdminOverride,
+        OpConfig(RateLimitOp),
+        OpState(RateLimitOp, Address),
     }
 
     pub fn get_config(env: &Env) -> AntiAbuseConfig {
@@ -261,6 +272,49 @@ mod anti_abuse {
         env.storage().instance().set(&AntiAbuseKey::Config, &config);
     }
 
+    /// Config for `role`. Depositors use the global config; admin/approver
+    /// operations are exempt (None) until a config is set for them.
+    pub fn get_role_config(env: &Env, role: RateLimitRole) -> Option<AntiAbuseConfig> {
+        match role {
+            RateLimitRole::Depositor => Some(get_config(env)),
+            RateLimitRole::Admin => env
+                .storage()
+                .instance()
+                .get(&AntiAbuseKey::RoleConfig(role)),
+        }
+    }
+
+    pub fn set_role_config(env: &Env, role: RateLimitRole, config: Option<AntiAbuseConfig>) {
+        let key = match role {
+            RateLimitRole::Depositor => AntiAbuseKey::Config,
+            RateLimitRole::Admin => AntiAbuseKey::RoleConfig(role),
+        };
+        match config {
+            Some(config) => env.storage().instance().set(&key, &config),
+            None => env.storage().instance().remove(&key),
+        }
+    }
+
+    /// Config for one operation type; None means it shares its role's limit.
+    pub fn get_op_config(env: &Env, op: RateLimitOp) -> Option<AntiAbuseConfig> {
+        env.storage().instance().get(&AntiAbuseKey::OpConfig(op))
+    }
+
+    pub fn set_op_config(env: &Env, op: RateLimitOp, config: Option<AntiAbuseConfig>) {
+        let key = AntiAbuseKey::OpConfig(op);
+        match config {
+            Some(config) => env.storage().instance().set(&key, &config),
+            None => env.storage().instance().remove(&key),
+        }
+    }
+
+    fn state_key(role: RateLimitRole, address: Address) -> AntiAbuseKey {
+        match role {
+            RateLimitRole::Depositor => AntiAbuseKey::State(address),
+            RateLimitRole::Admin => AntiAbuseKey::RoleState(role, address),
+        }
+    }
+
     pub fn is_whitelisted(env: &Env, address: Address) -> bool {
         env.storage()
             .instance()
@@ -283,41 +337,237 @@ mod anti_abuse {
         env.storage().instance().get(&AntiAbuseKey::Admin)
     }
 
+    /// While set, `RateLimitRole::Admin` operations skip the limiter even
+    /// if a config is set for the role.
+    pub fn is_admin_override(env: &Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&AntiAbuseKey::AdminOverride)
+            .unwrap_or(false)
+    }
+
+    pub fn set_admin_override(env: &Env, enabled: bool) {
+        if enabled {
+            env.storage()
+                .instance()
+                .set(&AntiAbuseKey::AdminOverride, &true);
+        } else {
+            env.storage()
+                .instance()
+                .remove(&AntiAbuseKey::AdminOverride);
+        }
+    }
+
+    fn is_exempt(env: &Env, role: &RateLimitRole, address: Address) -> bool {
+        is_whitelisted(env, address) || (*role == RateLimitRole::Admin && is_admin_override(env))
+    }
+
     pub fn set_admin(env: &Env, admin: Address) {
         env.storage().instance().set(&AntiAbuseKey::Admin, &admin);
     }
 
-    pub fn check_rate_limit(env: &Env, address: Address) {
-        if is_whitelisted(env, address.clone()) {
-            return;
+    fn load_state(env: &Env, key: &AntiAbuseKey, now: u64) -> AddressState {
+        env.storage().persistent().get(key).unwrap_or(AddressState {
+            last_operation_timestamp: 0,
+            window_start_timestamp: now,
+            operation_count: 0,
+        })
+    }
+
+    /// Seconds left in the cooldown period for `state` (0 once it has elapsed).
+    fn cooldown_remaining(config: &AntiAbuseConfig, state: &AddressState, now: u64) -> u64 {
+        if state.last_operation_timestamp == 0 {
+            return 0;
+        }
+        state
+            .last_operation_timestamp
+            .saturating_add(config.cooldown_period)
+            .saturating_sub(now)
+    }
+
+    /// Seconds until the current window resets if it is already full, else 0.
+    fn window_remaining(config: &AntiAbuseConfig, state: &AddressState, now: u64) -> u64 {
+        let window_end = state
+            .window_start_timestamp
+            .saturating_add(config.window_size);
+        if now < window_end && state.operation_count >= config.max_operations {
+            window_end - now
+        } else {
+            0
+        }
+    }
+
+    /// True once `state` can no longer affect a check: its window has ended
+    /// and its cooldown has elapsed, or there is no limit to apply.
+    fn is_stale(config: Option<AntiAbuseConfig>, state: &AddressState, now: u64) -> bool {
+        let Some(config) = config else {
+            return true;
+        };
+        now >= state
+            .window_start_timestamp
+            .saturating_add(config.window_size)
+            && cooldown_remaining(&config, state, now) == 0
+    }
+
+    /// Remove the stale limiter entries of `address` across roles and
+    /// operation types. Returns how many entries were removed.
+    pub fn prune(env: &Env, address: Address) -> u32 {
+        let now = env.ledger().timestamp();
+        let entries = [
+            (
+                state_key(RateLimitRole::Depositor, address.clone()),
+                get_role_config(env, RateLimitRole::Depositor),
+            ),
+            (
+                state_key(RateLimitRole::Admin, address.clone()),
+                get_role_config(env, RateLimitRole::Admin),
+            ),
+            (
+                AntiAbuseKey::OpState(RateLimitOp::Lock, address.clone()),
+                get_op_config(env, RateLimitOp::Lock),
+            ),
+            (
+                AntiAbuseKey::OpState(RateLimitOp::Release, address.clone()),
+                get_op_config(env, RateLimitOp::Release),
+            ),
+            (
+                AntiAbuseKey::OpState(RateLimitOp::Refund, address),
+                get_op_config(env, RateLimitOp::Refund),
+            ),
+        ];
+        let mut pruned = 0;
+        for (key, config) in entries {
+            let Some(state) = env.storage().persistent().get::<_, AddressState>(&key) else {
+                continue;
+            };
+            if is_stale(config, &state, now) {
+                env.storage().persistent().remove(&key);
+                pruned += 1;
+            }
+        }
+        pruned
+    }
+
+    /// The limit `check_rate_limit` applies to `op` performed by `address`
+    /// in `role`, with the key its state lives under. None when exempt or
+    /// when neither the operation nor the role has a limit.
+    fn effective_limit(
+        env: &Env,
+        role: RateLimitRole,
+        op: RateLimitOp,
+        address: Address,
+    ) -> Option<(AntiAbuseConfig, AntiAbuseKey)> {
+        if is_exempt(env, &role, address.clone()) {
+            return None;
+        }
+        match get_op_config(env, op.clone()) {
+            Some(config) => Some((config, AntiAbuseKey::OpState(op, address))),
+            None => {
+                get_role_config(env, role.clone()).map(|config| (config, state_key(role, address)))
+            }
         }
+    }
 
-        let config = get_config(env);
+    /// Seconds `address` must wait before its next `op` operation in `role`,
+    /// under whichever limit `check_rate_limit` would apply.
+    pub fn op_retry_after(
+        env: &Env,
+        role: RateLimitRole,
+        op: RateLimitOp,
+        address: Address,
+    ) -> u64 {
+        let Some((config, key)) = effective_limit(env, role, op, address) else {
+            return 0;
+        };
         let now = env.ledger().timestamp();
-        let key = AntiAbuseKey::State(address.clone());
+        let state = load_state(env, &key, now);
+        cooldown_remaining(&config, &state, now).max(window_remaining(&config, &state, now))
+    }
 
-        let mut state: AddressState =
-            env.storage()
-                .persistent()
-                .get(&key)
-                .unwrap_or(AddressState {
-                    last_operation_timestamp: 0,
-                    window_start_timestamp: now,
-                    operation_count: 0,
-                });
+    /// Seconds `address` must wait before its next `role` operation.
+    pub fn retry_after(env: &Env, role: RateLimitRole, address: Address) -> u64 {
+        if is_exempt(env, &role, address.clone()) {
+            return 0;
+        }
+        let Some(config) = get_role_config(env, role.clone()) else {
+            return 0;
+        };
+        let now = env.ledger().timestamp();
+        let state = load_state(env, &state_key(role, address), now);
+        cooldown_remaining(&config, &state, now).max(window_remaining(&config, &state, now))
+    }
+
+    pub fn status(env: &Env, role: RateLimitRole, address: Address) -> RateLimitStatus {
+        let limit = if is_exempt(env, &role, address.clone()) {
+            None
+        } else {
+            get_role_config(env, role.clone()).map(|config| (config, state_key(role, address)))
+        };
+        status_for(env, limit)
+    }
+
+    /// Status of `address` for `op` in `role`, under whichever limit
+    /// `check_rate_limit` would apply.
+    pub fn op_status(
+        env: &Env,
+        role: RateLimitRole,
+        op: RateLimitOp,
+        address: Address,
+    ) -> RateLimitStatus {
+        status_for(env, effective_limit(env, role, op, address))
+    }
+
+    fn status_for(env: &Env, limit: Option<(AntiAbuseConfig, AntiAbuseKey)>) -> RateLimitStatus {
+        let Some((config, key)) = limit else {
+            return RateLimitStatus {
+                exempt: true,
+                operations_used: 0,
+                max_operations: 0,
+                window_resets_at: 0,
+                cooldown_remaining: 0,
+            };
+        };
+        let now = env.ledger().timestamp();
+        let state = load_state(env, &key, now);
+        let window_end = state
+            .window_start_timestamp
+            .saturating_add(config.window_size);
+        let window_open = state.operation_count > 0 && now < window_end;
+        RateLimitStatus {
+            exempt: false,
+            operations_used: if window_open {
+                state.operation_count
+            } else {
+                0
+            },
+            max_operations: config.max_operations,
+            window_resets_at: if window_open { window_end } else { 0 },
+            cooldown_remaining: cooldown_remaining(&config, &state, now),
+        }
+    }
+
+    /// Applies the limit configured for `op` if there is one, otherwise the
+    /// limit shared by every operation of `role`.
+    pub fn check_rate_limit(
+        env: &Env,
+        role: RateLimitRole,
+        op: RateLimitOp,
+        address: Address,
+    ) -> Result<(), Error> {
+        let Some((config, key)) = effective_limit(env, role, op, address.clone()) else {
+            return Ok(());
+        };
+        let now = env.ledger().timestamp();
+        let mut state = load_state(env, &key, now);
 
         // 1. Cooldown check
-        if state.last_operation_timestamp > 0
-            && now
-                < state
-                    .last_operation_timestamp
-                    .saturating_add(config.cooldown_period)
-        {
+        let retry_after = cooldown_remaining(&config, &state, now);
+        if retry_after > 0 {
             env.events().publish(
                 (symbol_short!("abuse"), symbol_short!("cooldown")),
-                (address.clone(), now),
+                (address.clone(), now, retry_after),
             );
-            panic!("Operation in cooldown period");
+            return Err(Error::CooldownActive);
         }
 
         // 2. Window check
@@ -331,12 +581,13 @@ mod anti_abuse {
             state.operation_count = 1;
         } else {
             // Same window
-            if state.operation_count >= config.max_operations {
+            let retry_after = window_remaining(&config, &state, now);
+            if retry_after > 0 {
                 env.events().publish(
                     (symbol_short!("abuse"), symbol_short!("limit")),
-                    (address.clone(), now),
+                    (address.clone(), now, retry_after),
                 );
-                panic!("Rate limit exceeded");
+                return Err(Error::RateLimited);
             }
             state.operation_count += 1;
         }
@@ -345,14 +596,27 @@ mod anti_abuse {
         env.storage().persistent().set(&key, &state);
 
         // Extend TTL for state (approx 1 day)
-        env.storage().persistent().extend_ttl(&key, 17280, 17280);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, LEDGERS_PER_DAY, LEDGERS_PER_DAY);
+        Ok(())
     }
 }
 
-#[allow(dead_code)]
-const BASIS_POINTS: i128 = 10_000;
 const MAX_FEE_RATE: i128 = 5_000; // 50% max fee
 const MAX_BATCH_SIZE: u32 = 20;
+const MAX_TAGS_PER_BOUNTY: u32 = 5;
+const MAX_BOUNTIES_PER_TAG: u32 = 50; // bounds each TagIndex entry; archiving frees slots
+const MAX_RACE_ENTRANTS: u32 = 5;
+const MAX_KEEPER_INCENTIVE: i128 = 100; // 1% max keeper incentive
+const APPROVER_ROLE: Symbol = symbol_short!("approver"); // may approve refunds/releases
+
+// Persistent TTL for bounty records, in ledgers (~5s each)
+const BOUNTY_TTL_THRESHOLD: u32 = LEDGERS_PER_DAY * 30; // extend when under ~30 days
+const BOUNTY_TTL_EXTEND_TO: u32 = LEDGERS_PER_DAY * 120; // ~120 days
+
+/// Version recorded by `init`; bump alongside releases that need migration.
+pub const CONTRACT_VERSION: u32 = 1;
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -366,7 +630,6 @@ pub enum Error {
     DeadlineNotPassed = 6,
     Unauthorized = 7,
     InvalidFeeRate = 8,
-    FeeRecipientNotSet = 9,
     InvalidBatchSize = 10,
     BatchSizeMismatch = 11,
     DuplicateBountyId = 12,
@@ -376,8 +639,6 @@ pub enum Error {
     InvalidDeadline = 14,
     /// Returned when contract has insufficient funds for the operation
     InsufficientFunds = 16,
-    /// Returned when refund is attempted without admin approval
-    RefundNotApproved = 17,
     FundsPaused = 18,
     /// Returned when lock amount is below the configured policy minimum (Issue #62)
     AmountBelowMinimum = 19,
@@ -393,6 +654,69 @@ pub enum Error {
     CapabilityAmountExceeded = 27,
     CapabilityUsesExhausted = 28,
     CapabilityExceedsAuthority = 29,
+    /// Returned when a milestone operation targets a bounty without milestones
+    MilestonesNotDefined = 30,
+    /// Returned when the milestone index is out of range
+    InvalidMilestone = 31,
+    MilestoneAlreadyReleased = 32,
+    /// Returned when split shares do not sum to 10000 basis points
+    InvalidShares = 33,
+    /// Returned when release or refund is attempted on a disputed bounty
+    BountyDisputed = 34,
+    DisputeNotFound = 35,
+    ArbiterNotSet = 36,
+    /// Returned when a bounty already has a contributor assigned or paid
+    BountyAssigned = 37,
+    /// Returned when an assigned bounty is released before its submission is approved
+    SubmissionNotApproved = 38,
+    SubmissionNotFound = 39,
+    VestingNotFound = 40,
+    /// Returned when a vesting claim finds nothing newly vested
+    NothingToClaim = 41,
+    /// Returned when an address acts again within the cooldown period;
+    /// `get_rate_limit_retry_after` gives the seconds to wait
+    CooldownActive = 42,
+    /// Returned when an address has used up its operations for the window;
+    /// `get_rate_limit_retry_after` gives the seconds to wait
+    RateLimited = 43,
+    /// Returned when archiving a bounty that is not Released or Refunded, or
+    /// releasing one whose prerequisite bounty has not been released
+    BountyNotSettled = 44,
+    RefundApprovalNotFound = 45,
+    HtlcNotFound = 46,
+    /// Returned when the revealed preimage does not hash to the hashlock
+    InvalidPreimage = 47,
+    /// Returned when an HTLC claim is attempted at or after the timelock, or
+    /// a release is clawed back after its clawback window
+    TimelockExpired = 48,
+    ReleaseOracleNotSet = 49,
+    /// Returned when an attestation names another bounty or contract
+    InvalidAttestation = 50,
+    AttestationExpired = 51,
+    /// Returned when a bounty dependency would form a cycle
+    DependencyCycle = 52,
+    /// Returned when another unsettled bounty is linked to the same
+    /// external reference
+    ExternalRefInUse = 53,
+}
+
+impl From<AccessError> for Error {
+    fn from(err: AccessError) -> Self {
+        match err {
+            AccessError::NotInitialized | AccessError::NoPendingOwner => Error::NotInitialized,
+            AccessError::AlreadyInitialized => Error::AlreadyInitialized,
+            AccessError::Unauthorized => Error::Unauthorized,
+        }
+    }
+}
+
+impl From<CommonError> for Error {
+    fn from(err: CommonError) -> Self {
+        match err {
+            CommonError::Overflow => Error::InvalidAmount,
+            CommonError::InvalidBasisPoints | CommonError::InvalidShares => Error::InvalidShares,
+        }
+    }
 }
 
 #[contracttype]
@@ -410,6 +734,31 @@ pub enum EscrowStatus {
     Released,
     Refunded,
     PartiallyRefunded,
+    /// Paid out to the contributor over time by `claim_vested`.
+    Vesting,
+    /// A contributor has been assigned by `assign_contributor`.
+    Assigned,
+    /// The assignee has recorded a deliverable with `submit_work`.
+    Submitted,
+    /// The depositor has started reviewing the submission.
+    UnderReview,
+    /// A dispute is open; only the arbiter can settle the bounty.
+    Disputed,
+}
+
+impl EscrowStatus {
+    /// True while the bounty is unsettled and has not been partially refunded
+    /// or moved to vesting, whatever stage of the work lifecycle it is in.
+    pub fn is_locked(&self) -> bool {
+        matches!(
+            self,
+            EscrowStatus::Locked
+                | EscrowStatus::Assigned
+                | EscrowStatus::Submitted
+                | EscrowStatus::UnderReview
+                | EscrowStatus::Disputed
+        )
+    }
 }
 
 #[contracttype]
@@ -432,10 +781,12 @@ pub enum DataKey {
     Token,
     Escrow(u64), // bounty_id
     Metadata(u64),
-    EscrowIndex,             // Vec<u64> of all bounty_ids
-    DepositorIndex(Address), // Vec<u64> of bounty_ids by depositor
-    FeeConfig,               // Fee configuration
-    RefundApproval(u64),     // bounty_id -> RefundApproval
+    EscrowIndex,              // Vec<u64> of all bounty_ids
+    DepositorIndex(Address),  // Vec<u64> of bounty_ids by depositor
+    FeeConfig,                // Fee configuration
+    RefundApproval(u64, u64), // (bounty_id, approval_id) -> RefundApproval
+    RefundApprovalIds(u64),   // bounty_id -> Vec<u64> of queued approval ids, oldest first
+    RefundApprovalNonce,      // monotonically increasing refund approval id
     ReentrancyGuard,
     MultisigConfig,
     ReleaseApproval(u64), // bounty_id -> ReleaseApproval
@@ -445,6 +796,137 @@ pub enum DataKey {
     AmountPolicy, // Option<(i128, i128)> — (min_amount, max_amount) set by set_amount_policy
     CapabilityNonce, // monotonically increasing capability id
     Capability(u64), // capability_id -> Capability
+    Milestones(u64), // bounty_id -> Vec<Milestone>
+    ReleaseHistory(u64), // bounty_id -> Vec<ReleaseRecord>
+    Arbiter,
+    Dispute(u64),                 // bounty_id -> DisputeRecord
+    Assignee(u64),                // bounty_id -> Address
+    Submission(u64),              // bounty_id -> Submission
+    KeeperIncentive,              // i128 basis points paid to sweep_expired callers
+    FeeAccounting,                // FeeAccounting totals for release fees
+    Vesting(u64),                 // bounty_id -> VestingSchedule
+    Archived(u64),                // bounty_id -> ArchivedBounty
+    Version,                      // u32 contract version
+    PreviousVersion,              // u32 version recorded before the last upgrade
+    Stats,                        // EscrowStats running totals
+    DisputeBond,                  // i128 bond posted by whoever raises a dispute
+    Htlc(u64),                    // bounty_id -> HtlcLock
+    ReleaseOracle(u64),           // bounty_id -> BytesN<32> ed25519 key that attests completion
+    ReviewWindow(u64),            // bounty_id -> u64 seconds before a submission auto-releases
+    TemplateNonce,                // monotonically increasing template id
+    Template(u64),                // template_id -> BountyTemplate
+    ExternalRef(u64),             // bounty_id -> BytesN<32> normalized external reference hash
+    RefIndex(BytesN<32>),         // external reference hash -> bounty_id
+    MaxDeadlineDuration,          // u64 seconds a lock deadline may sit beyond the current time
+    Tags(u64),                    // bounty_id -> Vec<Symbol> category tags
+    TagIndex(Symbol),             // tag -> Vec<u64> of tagged bounty_ids
+    BountyArbiter(u64), // bounty_id -> Address resolving its disputes instead of the global arbiter
+    RaceEntrants(u64),  // bounty_id -> Vec<RaceEntrant> competing on a race bounty
+    RaceSubmission(u64, Address), // (bounty_id, entrant) -> Submission
+    DependsOn(u64),     // bounty_id -> prerequisite bounty_id that must be Released first
+    ReputationStore,    // Address of the ReputationStore contract
+    MinReputation(u64), // bounty_id -> u32 minimum score to be assigned
+}
+
+/// Storage keys of the post-release clawback window, kept out of `DataKey`
+/// which is at the contract type variant limit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ClawbackKey {
+    Window,                // u64 seconds a release stays reversible (global config)
+    Pending(u64, Address), // (bounty_id, recipient) -> PendingRelease held during the window
+    Held(u64),             // bounty_id -> Vec<Address> recipients with a PendingRelease
+}
+
+/// Storage keys of the solvency accounting, kept out of `DataKey` which is
+/// at the contract type variant limit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SolvencyKey {
+    Liabilities, // i128 running total of funds owed (bounties, held releases, bonds)
+}
+
+/// Per-bounty fee overrides, kept out of `DataKey` which is at the contract
+/// type variant limit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FeePolicyKey {
+    ReleaseRate(u64), // bounty_id -> i128 release fee rate replacing FeeConfig's
+}
+
+/// One tranche of a milestone-based bounty.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Milestone {
+    pub amount: i128,
+    pub released: bool,
+}
+
+/// Interface expected from the external reputation store contract.
+#[contractclient(name = "ReputationStoreClient")]
+pub trait ReputationStore {
+    fn get_score(env: Env, account: Address) -> u32;
+}
+
+/// A contributor competing on a race bounty and their submission deadline.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RaceEntrant {
+    pub contributor: Address,
+    pub deadline: u64,
+}
+
+/// Linear vesting schedule for a bounty released with `release_vested`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingSchedule {
+    pub contributor: Address,
+    pub total: i128,
+    pub start: u64,
+    pub duration: u64,
+    pub claimed: i128,
+}
+
+/// Hash-time-lock on a bounty locked with `lock_funds_htlc`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HtlcLock {
+    pub contributor: Address,
+    /// sha256 of the secret the contributor reveals to claim.
+    pub hashlock: BytesN<32>,
+    /// Claims must happen before this timestamp; afterwards the depositor
+    /// can refund as with any expired bounty.
+    pub timelock: u64,
+}
+
+/// Completion attestation for `release_with_attestation`.
+///
+/// The bounty's release oracle signs the XDR encoding of this struct.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseAttestation {
+    /// This escrow contract, so attestations cannot be replayed elsewhere.
+    pub contract: Address,
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub expires_at: u64,
+}
+
+/// Reusable bounty settings for `lock_from_template`. The owner funds every
+/// bounty locked from it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyTemplate {
+    pub owner: Address,
+    /// Deadline of each bounty, in seconds after it is locked.
+    pub deadline_offset: u64,
+    /// Auto-release review window (see `set_review_window`); 0 disables it.
+    pub review_window: u64,
+    /// Arbiter named on each bounty; None falls back to the global arbiter.
+    pub arbiter: Option<Address>,
+    /// Release fee rate (basis points) charged on each bounty instead of the
+    /// global rate. Setting one needs the admin's signature.
+    pub release_fee_rate: Option<i128>,
 }
 
 #[contracttype]
@@ -454,6 +936,26 @@ pub struct EscrowWithId {
     pub escrow: Escrow,
 }
 
+/// Terminal record kept for a bounty after `archive_bounty`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArchivedBounty {
+    pub depositor: Address,
+    pub amount: i128,
+    pub status: EscrowStatus,
+    pub archived_at: u64,
+}
+
+/// Compact listing entry returned by `list_bounties`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountySummary {
+    pub bounty_id: u64,
+    pub amount: i128,
+    pub deadline: u64,
+    pub status: EscrowStatus,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PauseFlags {
@@ -475,6 +977,37 @@ pub struct AggregateStats {
     pub count_refunded: u32,
 }
 
+/// Running totals kept up to date on every lock, payout and refund.
+///
+/// `total_released` is gross of release fees, so
+/// `total_locked - total_released - total_refunded` is the amount still held.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowStats {
+    pub total_locked: i128,
+    pub total_released: i128,
+    pub total_refunded: i128,
+    pub total_fees: i128,
+    /// Bounties that are neither fully released nor fully refunded.
+    pub active_count: u32,
+}
+
+/// Result of `assert_solvency`: what the contract owes against what it holds.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SolvencyReport {
+    pub token: Address,
+    /// Running total of every bounty's remaining amount, open dispute bonds
+    /// and releases still inside the clawback window.
+    pub liabilities: i128,
+    /// The contract's balance of `token`.
+    pub balance: i128,
+    /// Bounties that are neither fully released nor fully refunded.
+    pub active_bounties: u32,
+    /// `liabilities <= balance`.
+    pub solvent: bool,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PauseStateChanged {
@@ -485,6 +1018,30 @@ pub struct PauseStateChanged {
     pub timestamp: u64,
 }
 
+/// Which rate limit config an operation draws from.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RateLimitRole {
+    /// Depositor operations such as `lock_funds`.
+    Depositor,
+    /// Admin and approver operations; exempt unless configured.
+    Admin,
+}
+
+/// Operation types that can be given their own rate limit with
+/// `set_op_rate_limit`, independent of the role's shared limit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RateLimitOp {
+    /// `lock_funds` and `lock_funds_batch`.
+    Lock,
+    /// `release_funds`, `partial_release`, batch releases and large release
+    /// approvals.
+    Release,
+    /// `approve_refund`.
+    Refund,
+}
+
 /// Public view of anti-abuse config (rate limit and cooldown).
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -494,6 +1051,20 @@ pub struct AntiAbuseConfigView {
     pub cooldown_period: u64,
 }
 
+/// Where an address stands against its rate limit, from `get_rate_limit_status`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RateLimitStatus {
+    /// True when the limiter does not apply (whitelisted, override or no config).
+    pub exempt: bool,
+    /// Operations used in the current window (0 once it has ended).
+    pub operations_used: u32,
+    pub max_operations: u32,
+    /// When the current window ends, or 0 if no window is open.
+    pub window_resets_at: u64,
+    pub cooldown_remaining: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct FeeConfig {
@@ -503,6 +1074,13 @@ pub struct FeeConfig {
     pub fee_enabled: bool,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeAccounting {
+    pub total_release_fees: i128,
+    pub release_fee_count: u32,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct MultisigConfig {
@@ -529,6 +1107,38 @@ pub struct ClaimRecord {
     pub claimed: bool,
 }
 
+/// A release held by the contract until `final_at`, during which the
+/// depositor and arbiter can jointly claw it back.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingRelease {
+    pub recipient: Address,
+    pub amount: i128,
+    pub final_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Submission {
+    pub contributor: Address,
+    pub submission_hash: BytesN<32>,
+    pub submitted_at: u64,
+    pub approved: bool,
+    pub approved_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeRecord {
+    pub bounty_id: u64,
+    pub raised_by: Address,
+    pub raised_at: u64,
+    /// Bond held while the dispute is open; 0 when none was required.
+    pub bond: i128,
+    /// Party the bond is forfeited to if the arbiter rules against `raised_by`.
+    pub counterparty: Option<Address>,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum CapabilityAction {
@@ -562,6 +1172,7 @@ pub enum RefundMode {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct RefundApproval {
     pub bounty_id: u64,
+    pub approval_id: u64,
     pub amount: i128,
     pub recipient: Address,
     pub mode: RefundMode,
@@ -578,6 +1189,14 @@ pub struct RefundRecord {
     pub mode: RefundMode,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseRecord {
+    pub amount: i128,
+    pub recipient: Address,
+    pub timestamp: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct LockFundsItem {
@@ -587,6 +1206,25 @@ pub struct LockFundsItem {
     pub deadline: u64,
 }
 
+/// One bounty created by `lock_funds_batch`; the depositor is shared by the batch.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyParams {
+    pub bounty_id: u64,
+    pub amount: i128,
+    pub deadline: u64,
+}
+
+/// Per-item outcome of a lenient `release_batch`; `error_code` is the
+/// `Error` discriminant when `released` is false and 0 otherwise.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseItemResult {
+    pub bounty_id: u64,
+    pub released: bool,
+    pub error_code: u32,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ReleaseFundsItem {
@@ -601,11 +1239,14 @@ pub struct BountyEscrowContract;
 impl BountyEscrowContract {
     /// Initialize the contract with the admin address and the token address (XLM).
     pub fn init(env: Env, admin: Address, token: Address) -> Result<(), Error> {
-        if env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::AlreadyInitialized);
-        }
-        env.storage().instance().set(&DataKey::Admin, &admin);
+        ownable::init_owner(&env, &DataKey::Admin, &admin)?;
         env.storage().instance().set(&DataKey::Token, &token);
+        env.storage()
+            .instance()
+            .set(&DataKey::Version, &CONTRACT_VERSION);
+        env.storage()
+            .instance()
+            .set(&SolvencyKey::Liabilities, &0i128);
 
         emit_bounty_initialized(
             &env,
@@ -620,33 +1261,229 @@ impl BountyEscrowContract {
         Ok(())
     }
 
-    /// Calculate fee amount based on rate (in basis points)
-    #[allow(dead_code)]
-    fn calculate_fee(amount: i128, fee_rate: i128) -> i128 {
-        if fee_rate == 0 {
-            return 0;
-        }
-        // Fee = (amount * fee_rate) / BASIS_POINTS
-        // Using checked arithmetic to prevent overflow
-        amount
-            .checked_mul(fee_rate)
-            .and_then(|x| x.checked_div(BASIS_POINTS))
-            .unwrap_or(0)
+    /// View: get the contract admin.
+    pub fn get_admin(env: Env) -> Option<Address> {
+        ownable::owner(&env, &DataKey::Admin)
     }
 
-    /// Get fee configuration (internal helper)
-    fn get_fee_config_internal(env: &Env) -> FeeConfig {
-        env.storage()
-            .instance()
-            .get(&DataKey::FeeConfig)
-            .unwrap_or_else(|| FeeConfig {
-                lock_fee_rate: 0,
+    /// Propose `new_admin` as the next admin (admin only). The transfer takes
+    /// effect once `new_admin` calls `accept_admin`; proposing again replaces
+    /// the earlier proposal.
+    pub fn propose_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        ownable::propose_owner(&env, &DataKey::Admin, &new_admin)?;
+        Self::emit_admin_config_updated(&env, symbol_short!("adm_prop"), new_admin);
+        Ok(())
+    }
+
+    /// Become admin (proposed admin only).
+    /// Returns NotInitialized if no transfer is pending.
+    pub fn accept_admin(env: Env) -> Result<(), Error> {
+        ownable::accept_ownership(&env, &DataKey::Admin)?;
+        let admin = ownable::owner(&env, &DataKey::Admin).ok_or(Error::NotInitialized)?;
+        Self::emit_admin_config_updated(&env, symbol_short!("admin"), admin);
+        Ok(())
+    }
+
+    /// Withdraw a pending admin transfer (admin only).
+    pub fn cancel_admin_transfer(env: Env) -> Result<(), Error> {
+        ownable::cancel_ownership_transfer(&env, &DataKey::Admin)?;
+        let admin = ownable::owner(&env, &DataKey::Admin).ok_or(Error::NotInitialized)?;
+        Self::emit_admin_config_updated(&env, symbol_short!("adm_canc"), admin);
+        Ok(())
+    }
+
+    /// View: get the proposed admin, if a transfer is pending.
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        ownable::pending_owner(&env)
+    }
+
+    /// Calculate fee amount based on rate (in basis points)
+    fn calculate_fee(amount: i128, fee_rate: i128) -> i128 {
+        math::fee_of(amount, fee_rate)
+    }
+
+    /// Get fee configuration (internal helper)
+    fn get_fee_config_internal(env: &Env) -> FeeConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::FeeConfig)
+            .unwrap_or_else(|| FeeConfig {
+                lock_fee_rate: 0,
                 release_fee_rate: 0,
                 fee_recipient: env.storage().instance().get(&DataKey::Admin).unwrap(),
                 fee_enabled: false,
             })
     }
 
+    /// Transfer `amount` released from a bounty to `recipient`, routing the
+    /// configured release fee to the fee recipient (treasury).
+    ///
+    /// Returns the net amount the recipient received.
+    fn transfer_release(
+        env: &Env,
+        client: &token::Client,
+        bounty_id: u64,
+        recipient: &Address,
+        amount: i128,
+    ) -> i128 {
+        let contract_address = env.current_contract_address();
+        let mut fee_config = Self::get_fee_config_internal(env);
+        if let Some(rate) = env
+            .storage()
+            .persistent()
+            .get(&FeePolicyKey::ReleaseRate(bounty_id))
+        {
+            fee_config.release_fee_rate = rate;
+        }
+        let fee = if fee_config.fee_enabled {
+            Self::calculate_fee(amount, fee_config.release_fee_rate)
+        } else {
+            0
+        };
+        if fee > 0 {
+            client.transfer(&contract_address, &fee_config.fee_recipient, &fee);
+            let mut accounting = Self::get_fee_accounting(env.clone());
+            accounting.total_release_fees += fee;
+            accounting.release_fee_count += 1;
+            env.storage()
+                .instance()
+                .set(&DataKey::FeeAccounting, &accounting);
+            events::emit_fee_collected(
+                env,
+                events::FeeCollected {
+                    operation_type: events::FeeOperationType::Release,
+                    amount: fee,
+                    fee_rate: fee_config.release_fee_rate,
+                    recipient: fee_config.fee_recipient,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+        let net = amount - fee;
+        client.transfer(&contract_address, recipient, &net);
+        Self::record_released(env, amount, fee);
+        events::emit_release_paid(
+            env,
+            events::ReleasePaid {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                recipient: recipient.clone(),
+                gross_amount: amount,
+                fee,
+                net_amount: net,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        net
+    }
+
+    /// View: running totals across all bounties, maintained in storage so
+    /// they survive event history pruning.
+    pub fn get_stats(env: Env) -> EscrowStats {
+        env.storage()
+            .instance()
+            .get(&DataKey::Stats)
+            .unwrap_or(EscrowStats {
+                total_locked: 0,
+                total_released: 0,
+                total_refunded: 0,
+                total_fees: 0,
+                active_count: 0,
+            })
+    }
+
+    fn save_stats(env: &Env, stats: &EscrowStats) {
+        env.storage().instance().set(&DataKey::Stats, stats);
+    }
+
+    /// Count funds entering escrow; `new_bounty` is false for top-ups.
+    fn record_locked(env: &Env, amount: i128, new_bounty: bool) {
+        let mut stats = Self::get_stats(env.clone());
+        stats.total_locked += amount;
+        if new_bounty {
+            stats.active_count += 1;
+        }
+        Self::save_stats(env, &stats);
+        Self::adjust_liabilities(env, amount);
+    }
+
+    fn record_released(env: &Env, amount: i128, fee: i128) {
+        let mut stats = Self::get_stats(env.clone());
+        stats.total_released += amount;
+        stats.total_fees += fee;
+        Self::save_stats(env, &stats);
+        Self::adjust_liabilities(env, -amount);
+    }
+
+    fn record_refunded(env: &Env, amount: i128) {
+        let mut stats = Self::get_stats(env.clone());
+        stats.total_refunded += amount;
+        Self::save_stats(env, &stats);
+        Self::adjust_liabilities(env, -amount);
+    }
+
+    /// Move the running liabilities total read by `assert_solvency`. Held
+    /// releases stay counted until `transfer_release` pays them out.
+    /// Deployments upgraded from before the total existed leave it unset
+    /// until `migrate_liabilities` seeds it.
+    fn adjust_liabilities(env: &Env, delta: i128) {
+        let Some(liabilities) = env
+            .storage()
+            .instance()
+            .get::<SolvencyKey, i128>(&SolvencyKey::Liabilities)
+        else {
+            return;
+        };
+        env.storage().instance().set(
+            &SolvencyKey::Liabilities,
+            &liabilities.saturating_add(delta),
+        );
+    }
+
+    /// Sum what the escrow owes from the per-bounty records: every indexed
+    /// bounty's remaining amount, its held releases and its open dispute bond.
+    fn sum_liabilities(env: &Env) -> i128 {
+        let storage = env.storage().persistent();
+        let index: Vec<u64> = storage.get(&DataKey::EscrowIndex).unwrap_or(Vec::new(env));
+        let mut liabilities: i128 = 0;
+        for bounty_id in index.iter() {
+            if let Some(escrow) = storage.get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id)) {
+                liabilities = liabilities.saturating_add(escrow.remaining_amount);
+            }
+            for recipient in Self::get_held_recipients(env.clone(), bounty_id).iter() {
+                if let Some(held) = storage
+                    .get::<ClawbackKey, PendingRelease>(&ClawbackKey::Pending(bounty_id, recipient))
+                {
+                    liabilities = liabilities.saturating_add(held.amount);
+                }
+            }
+            if let Some(dispute) =
+                storage.get::<DataKey, DisputeRecord>(&DataKey::Dispute(bounty_id))
+            {
+                liabilities = liabilities.saturating_add(dispute.bond);
+            }
+        }
+        liabilities
+    }
+
+    /// Called when a bounty reaches `Released` or `Refunded`.
+    fn record_settled(env: &Env) {
+        let mut stats = Self::get_stats(env.clone());
+        stats.active_count = stats.active_count.saturating_sub(1);
+        Self::save_stats(env, &stats);
+    }
+
+    /// View: release fees collected so far
+    pub fn get_fee_accounting(env: Env) -> FeeAccounting {
+        env.storage()
+            .instance()
+            .get(&DataKey::FeeAccounting)
+            .unwrap_or(FeeAccounting {
+                total_release_fees: 0,
+                release_fee_count: 0,
+            })
+    }
+
     /// Update fee configuration (admin only)
     pub fn update_fee_config(
         env: Env,
@@ -783,14 +1620,81 @@ impl BountyEscrowContract {
         Ok(())
     }
 
-    /// Emergency withdraw all funds (admin only, must have lock_paused = true)
-    pub fn emergency_withdraw(env: Env, target: Address) -> Result<(), Error> {
+    /// Grant the approver role to `approver` (admin only).
+    ///
+    /// Approvers can approve refunds and releases but hold none of the
+    /// admin's configuration powers.
+    pub fn grant_approver(env: Env, approver: Address) -> Result<(), Error> {
+        let admin = Self::require_admin(&env)?;
+        roles::grant_role(&env, &APPROVER_ROLE, &approver);
+        Self::emit_admin_config_updated(&env, APPROVER_ROLE, admin);
+        Ok(())
+    }
+
+    /// Revoke the approver role from `approver` (admin only).
+    pub fn revoke_approver(env: Env, approver: Address) -> Result<(), Error> {
+        let admin = Self::require_admin(&env)?;
+        roles::revoke_role(&env, &APPROVER_ROLE, &approver);
+        Self::emit_admin_config_updated(&env, APPROVER_ROLE, admin);
+        Ok(())
+    }
+
+    /// View: whether `account` holds the approver role.
+    pub fn is_approver(env: Env, account: Address) -> bool {
+        roles::has_role(&env, &APPROVER_ROLE, &account)
+    }
+
+    /// Require `caller` to be the admin or an approver and to have signed.
+    fn require_approver(env: &Env, caller: &Address) -> Result<(), Error> {
         let admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
             .ok_or(Error::NotInitialized)?;
-        admin.require_auth();
+        if *caller == admin {
+            caller.require_auth();
+            return Ok(());
+        }
+        Ok(roles::require_role(env, &APPROVER_ROLE, caller)?)
+    }
+
+    /// Load the admin and require its authorization.
+    fn require_admin(env: &Env) -> Result<Address, Error> {
+        Ok(ownable::require_owner(env, &DataKey::Admin)?)
+    }
+
+    /// Emit AdminConfigUpdated for a setting without a dedicated event.
+    fn emit_admin_config_updated(env: &Env, setting: Symbol, admin: Address) {
+        events::emit_admin_config_updated(
+            env,
+            events::AdminConfigUpdated {
+                version: EVENT_VERSION_V2,
+                setting,
+                admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+
+    /// Emergency stop: pause lock, release and refund at once (admin only).
+    pub fn pause(env: Env, reason: Option<soroban_sdk::String>) -> Result<(), Error> {
+        Self::set_paused(env, Some(true), Some(true), Some(true), reason)
+    }
+
+    /// Lift an emergency stop, resuming every operation (admin only).
+    pub fn unpause(env: Env) -> Result<(), Error> {
+        Self::set_paused(env, Some(false), Some(false), Some(false), None)
+    }
+
+    /// View: true if any operation is currently paused
+    pub fn is_paused(env: Env) -> bool {
+        let flags = Self::get_pause_flags(&env);
+        flags.lock_paused || flags.release_paused || flags.refund_paused
+    }
+
+    /// Emergency withdraw all funds (admin only, must have lock_paused = true)
+    pub fn emergency_withdraw(env: Env, target: Address) -> Result<(), Error> {
+        let admin = Self::require_admin(&env)?;
 
         let flags = Self::get_pause_flags(&env);
         if !flags.lock_paused {
@@ -846,6 +1750,36 @@ impl BountyEscrowContract {
         false
     }
 
+    /// Enforce the admin-configured amount policy and maximum deadline
+    /// duration. Both are skipped when unset, preserving the behaviour of
+    /// deployments that never configure them.
+    fn check_lock_limits(env: &Env, amount: i128, deadline: u64) -> Result<(), Error> {
+        if let Some((min_amount, max_amount)) = env
+            .storage()
+            .instance()
+            .get::<DataKey, (i128, i128)>(&DataKey::AmountPolicy)
+        {
+            if amount < min_amount {
+                return Err(Error::AmountBelowMinimum);
+            }
+            if amount > max_amount {
+                return Err(Error::AmountAboveMaximum);
+            }
+        }
+
+        if let Some(max_duration) = env
+            .storage()
+            .instance()
+            .get::<DataKey, u64>(&DataKey::MaxDeadlineDuration)
+        {
+            if deadline > env.ledger().timestamp().saturating_add(max_duration) {
+                return Err(Error::InvalidDeadline);
+            }
+        }
+
+        Ok(())
+    }
+
     fn next_capability_id(env: &Env) -> u64 {
         let last_id: u64 = env
             .storage()
@@ -911,7 +1845,7 @@ impl BountyEscrowContract {
                     .persistent()
                     .get(&DataKey::Escrow(bounty_id))
                     .ok_or(Error::BountyNotFound)?;
-                if escrow.status != EscrowStatus::Locked {
+                if !escrow.status.is_locked() {
                     return Err(Error::FundsNotLocked);
                 }
                 if amount_limit > escrow.remaining_amount {
@@ -932,9 +1866,7 @@ impl BountyEscrowContract {
                     .persistent()
                     .get(&DataKey::Escrow(bounty_id))
                     .ok_or(Error::BountyNotFound)?;
-                if escrow.status != EscrowStatus::Locked
-                    && escrow.status != EscrowStatus::PartiallyRefunded
-                {
+                if !escrow.status.is_locked() && escrow.status != EscrowStatus::PartiallyRefunded {
                     return Err(Error::FundsNotLocked);
                 }
                 if amount_limit > escrow.remaining_amount {
@@ -989,7 +1921,7 @@ impl BountyEscrowContract {
                     .persistent()
                     .get(&DataKey::Escrow(capability.bounty_id))
                     .ok_or(Error::BountyNotFound)?;
-                if escrow.status != EscrowStatus::Locked {
+                if !escrow.status.is_locked() {
                     return Err(Error::FundsNotLocked);
                 }
                 if requested_amount > escrow.remaining_amount {
@@ -1010,9 +1942,7 @@ impl BountyEscrowContract {
                     .persistent()
                     .get(&DataKey::Escrow(capability.bounty_id))
                     .ok_or(Error::BountyNotFound)?;
-                if escrow.status != EscrowStatus::Locked
-                    && escrow.status != EscrowStatus::PartiallyRefunded
-                {
+                if !escrow.status.is_locked() && escrow.status != EscrowStatus::PartiallyRefunded {
                     return Err(Error::FundsNotLocked);
                 }
                 if requested_amount > escrow.remaining_amount {
@@ -1205,6 +2135,7 @@ impl BountyEscrowContract {
         env.storage()
             .instance()
             .set(&DataKey::MultisigConfig, &config);
+        Self::emit_admin_config_updated(&env, symbol_short!("multisig"), admin);
 
         Ok(())
     }
@@ -1247,12 +2178,20 @@ impl BountyEscrowContract {
         }
 
         approver.require_auth();
+        anti_abuse::check_rate_limit(
+            &env,
+            RateLimitRole::Admin,
+            RateLimitOp::Release,
+            approver.clone(),
+        )?;
 
         let approval_key = DataKey::ReleaseApproval(bounty_id);
+        // Approvals for a different contributor do not carry over.
         let mut approval: ReleaseApproval = env
             .storage()
             .persistent()
             .get(&approval_key)
+            .filter(|approval: &ReleaseApproval| approval.contributor == contributor)
             .unwrap_or(ReleaseApproval {
                 bounty_id,
                 contributor: contributor.clone(),
@@ -1281,6 +2220,43 @@ impl BountyEscrowContract {
         Ok(())
     }
 
+    /// Releases from a bounty whose amount is above the multisig threshold
+    /// need `required_signatures` approvals from current signers for
+    /// `contributor`, otherwise the release fails with `Unauthorized`. Each
+    /// release consumes the approvals.
+    fn consume_multisig_approval(
+        env: &Env,
+        bounty_id: u64,
+        contributor: &Address,
+        escrow: &Escrow,
+    ) -> Result<(), Error> {
+        let config = Self::get_multisig_config(env.clone());
+        if config.required_signatures == 0 || escrow.amount <= config.threshold_amount {
+            return Ok(());
+        }
+
+        let approval_key = DataKey::ReleaseApproval(bounty_id);
+        let approval: ReleaseApproval = env
+            .storage()
+            .persistent()
+            .get(&approval_key)
+            .ok_or(Error::Unauthorized)?;
+        if *contributor != approval.contributor {
+            return Err(Error::Unauthorized);
+        }
+        let valid = approval
+            .approvals
+            .iter()
+            .filter(|approver| config.signers.contains(approver))
+            .count() as u32;
+        if valid < config.required_signatures {
+            return Err(Error::Unauthorized);
+        }
+
+        env.storage().persistent().remove(&approval_key);
+        Ok(())
+    }
+
     /// Lock funds for a specific bounty.
     pub fn lock_funds(
         env: Env,
@@ -1290,7 +2266,12 @@ impl BountyEscrowContract {
         deadline: u64,
     ) -> Result<(), Error> {
         // Apply rate limiting
-        anti_abuse::check_rate_limit(&env, depositor.clone());
+        let role = if env.storage().instance().get(&DataKey::Admin) == Some(depositor.clone()) {
+            RateLimitRole::Admin
+        } else {
+            RateLimitRole::Depositor
+        };
+        anti_abuse::check_rate_limit(&env, role, RateLimitOp::Lock, depositor.clone())?;
 
         if Self::check_paused(&env, symbol_short!("lock")) {
             return Err(Error::FundsPaused);
@@ -1306,31 +2287,23 @@ impl BountyEscrowContract {
             return Err(Error::NotInitialized);
         }
 
-        if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+        if env.storage().persistent().has(&DataKey::Escrow(bounty_id))
+            || env
+                .storage()
+                .persistent()
+                .has(&DataKey::Archived(bounty_id))
+        {
             return Err(Error::BountyExists);
         }
 
-        // Enforce min/max amount policy if one has been configured (Issue #62).
-        // When no policy is set this block is skipped entirely, preserving
-        // backward-compatible behaviour for callers that never call set_amount_policy.
-        if let Some((min_amount, max_amount)) = env
-            .storage()
-            .instance()
-            .get::<DataKey, (i128, i128)>(&DataKey::AmountPolicy)
-        {
-            if amount < min_amount {
-                return Err(Error::AmountBelowMinimum);
-            }
-            if amount > max_amount {
-                return Err(Error::AmountAboveMaximum);
-            }
-        }
+        Self::check_lock_limits(&env, amount, deadline)?;
 
         let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
         let client = token::Client::new(&env, &token_addr);
 
         // Transfer funds from depositor to contract
         client.transfer(&depositor, &env.current_contract_address(), &amount);
+        Self::record_locked(&env, amount, true);
 
         let escrow = Escrow {
             depositor: depositor.clone(),
@@ -1348,26 +2321,7 @@ impl BountyEscrowContract {
             .set(&DataKey::Escrow(bounty_id), &escrow);
 
         // Update indexes
-        let mut index: Vec<u64> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::EscrowIndex)
-            .unwrap_or(Vec::new(&env));
-        index.push_back(bounty_id);
-        env.storage()
-            .persistent()
-            .set(&DataKey::EscrowIndex, &index);
-
-        let mut depositor_index: Vec<u64> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::DepositorIndex(depositor.clone()))
-            .unwrap_or(Vec::new(&env));
-        depositor_index.push_back(bounty_id);
-        env.storage().persistent().set(
-            &DataKey::DepositorIndex(depositor.clone()),
-            &depositor_index,
-        );
+        Self::index_escrow(&env, bounty_id, &depositor);
 
         // Emit value allows for off-chain indexing
         emit_funds_locked(
@@ -1384,69 +2338,225 @@ impl BountyEscrowContract {
         Ok(())
     }
 
-    /// Release funds to the contributor.
-    /// Only the admin (backend) can authorize this.
-    pub fn release_funds(env: Env, bounty_id: u64, contributor: Address) -> Result<(), Error> {
-        if Self::check_paused(&env, symbol_short!("release")) {
-            return Err(Error::FundsPaused);
-        }
-        let _start = env.ledger().timestamp();
-
-        // Ensure contract is initialized
-        if env.storage().instance().has(&DataKey::ReentrancyGuard) {
-            panic!("Reentrancy detected");
+    /// Store a bounty template (its owner must sign). Returns the template id.
+    pub fn create_template(env: Env, params: BountyTemplate) -> Result<u64, Error> {
+        params.owner.require_auth();
+        if params.deadline_offset == 0 {
+            return Err(Error::InvalidDeadline);
         }
-        env.storage()
-            .instance()
-            .set(&DataKey::ReentrancyGuard, &true);
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+        if params.arbiter.as_ref() == Some(&params.owner) {
+            return Err(Error::Unauthorized);
         }
-
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
+        if let Some(rate) = params.release_fee_rate {
+            if !(0..=MAX_FEE_RATE).contains(&rate) {
+                return Err(Error::InvalidFeeRate);
+            }
+            // Fee policy is the platform's call, not the depositor's
+            Self::require_admin(&env)?;
         }
 
-        let mut escrow: Escrow = env
+        let template_id: u64 = env
             .storage()
+            .instance()
+            .get(&DataKey::TemplateNonce)
+            .unwrap_or(0)
+            + 1;
+        env.storage()
+            .instance()
+            .set(&DataKey::TemplateNonce, &template_id);
+        env.storage()
             .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-
-        if escrow.status != EscrowStatus::Locked {
-            return Err(Error::FundsNotLocked);
-        }
-
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-
-        // Transfer funds to contributor
-        client.transfer(
-            &env.current_contract_address(),
-            &contributor,
-            &escrow.amount,
-        );
+            .set(&DataKey::Template(template_id), &params);
+        env.events()
+            .publish((symbol_short!("tmpl_new"), template_id), params.owner);
+        Ok(template_id)
+    }
 
-        escrow.status = EscrowStatus::Released;
-        escrow.remaining_amount = 0;
-        invariants::assert_escrow(&env, &escrow);
+    /// View: get a bounty template. Fails with BountyNotFound if unknown.
+    pub fn get_template(env: Env, template_id: u64) -> Result<BountyTemplate, Error> {
         env.storage()
             .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+            .get(&DataKey::Template(template_id))
+            .ok_or(Error::BountyNotFound)
+    }
 
-        emit_funds_released(
+    /// Lock a bounty funded by the template owner, applying the template's
+    /// deadline offset, review window, arbiter and release fee rate.
+    pub fn lock_from_template(
+        env: Env,
+        template_id: u64,
+        bounty_id: u64,
+        amount: i128,
+    ) -> Result<(), Error> {
+        let template = Self::get_template(env.clone(), template_id)?;
+        let deadline = env
+            .ledger()
+            .timestamp()
+            .saturating_add(template.deadline_offset);
+        Self::lock_funds(env.clone(), template.owner, bounty_id, amount, deadline)?;
+
+        if template.review_window > 0 {
+            env.storage()
+                .persistent()
+                .set(&DataKey::ReviewWindow(bounty_id), &template.review_window);
+        }
+        if let Some(arbiter) = template.arbiter {
+            Self::store_bounty_arbiter(&env, bounty_id, arbiter);
+        }
+        if let Some(rate) = template.release_fee_rate {
+            env.storage()
+                .persistent()
+                .set(&FeePolicyKey::ReleaseRate(bounty_id), &rate);
+        }
+        Ok(())
+    }
+
+    /// Add `amount` from `booster` to a locked bounty's reward.
+    ///
+    /// Anyone can boost; the tokens are pulled from the booster. Boosted funds
+    /// belong to the bounty, so a later refund returns them to the depositor.
+    /// If milestones are defined, the boost is added to the last unreleased one.
+    /// Fails with `ClaimPending` while a claim is waiting to be collected.
+    pub fn boost_bounty(
+        env: Env,
+        bounty_id: u64,
+        booster: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("lock")) {
+            return Err(Error::FundsPaused);
+        }
+        Self::ensure_not_disputed(&env, bounty_id)?;
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if Self::has_pending_claim(&env, bounty_id) {
+            return Err(Error::ClaimPending);
+        }
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if !escrow.status.is_locked() {
+            return Err(Error::FundsNotLocked);
+        }
+
+        booster.require_auth();
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&booster, &env.current_contract_address(), &amount);
+        Self::record_locked(&env, amount, false);
+
+        escrow.amount = escrow
+            .amount
+            .checked_add(amount)
+            .ok_or(Error::InvalidAmount)?;
+        escrow.remaining_amount += amount;
+        invariants::assert_escrow(&env, &escrow);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        let mut milestones = Self::get_milestones(env.clone(), bounty_id);
+        if let Some(idx) = (0..milestones.len())
+            .rev()
+            .find(|&i| !milestones.get(i).unwrap().released)
+        {
+            let mut milestone = milestones.get(idx).unwrap();
+            milestone.amount += amount;
+            milestones.set(idx, milestone);
+            env.storage()
+                .persistent()
+                .set(&DataKey::Milestones(bounty_id), &milestones);
+        }
+
+        events::emit_bounty_boosted(
             &env,
-            FundsReleased {
+            events::BountyBoosted {
                 version: EVENT_VERSION_V2,
                 bounty_id,
-                amount: escrow.amount,
-                recipient: contributor.clone(),
+                booster,
+                amount,
+                new_amount: escrow.amount,
                 timestamp: env.ledger().timestamp(),
             },
         );
+        Ok(())
+    }
+
+    /// Release funds to the contributor.
+    /// Only the admin (backend) can authorize this.
+    pub fn release_funds(env: Env, bounty_id: u64, contributor: Address) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+        Self::ensure_release_allowed(&env, bounty_id)?;
+        Self::ensure_submission_approved(&env, bounty_id, &contributor)?;
+        let _start = env.ledger().timestamp();
+
+        // Ensure contract is initialized
+        if env.storage().instance().has(&DataKey::ReentrancyGuard) {
+            panic!("Reentrancy detected");
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::ReentrancyGuard, &true);
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        anti_abuse::check_rate_limit(
+            &env,
+            RateLimitRole::Admin,
+            RateLimitOp::Release,
+            admin.clone(),
+        )?;
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+
+        if !escrow.status.is_locked() {
+            return Err(Error::FundsNotLocked);
+        }
+        Self::consume_multisig_approval(&env, bounty_id, &contributor, &escrow)?;
+
+        // Earlier partial releases have already been paid out
+        let amount = escrow.remaining_amount;
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        let transferred = Self::release_or_hold(&env, &client, bounty_id, &contributor, amount)?;
+
+        escrow.status = EscrowStatus::Released;
+        Self::record_settled(&env);
+        escrow.remaining_amount = 0;
+        invariants::assert_escrow(&env, &escrow);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        if transferred {
+            emit_funds_released(
+                &env,
+                FundsReleased {
+                    version: EVENT_VERSION_V2,
+                    bounty_id,
+                    amount,
+                    recipient: contributor.clone(),
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
 
         // Clear reentrancy guard
         env.storage().instance().remove(&DataKey::ReentrancyGuard);
@@ -1454,6 +2564,185 @@ impl BountyEscrowContract {
         Ok(())
     }
 
+    /// Set how long releases stay reversible before they are transferred
+    /// (admin only). 0 disables the clawback window.
+    pub fn set_clawback_window(env: Env, clawback_window: u64) -> Result<(), Error> {
+        let admin = Self::require_admin(&env)?;
+        env.storage()
+            .instance()
+            .set(&ClawbackKey::Window, &clawback_window);
+        Self::emit_admin_config_updated(&env, symbol_short!("clawback"), admin);
+        Ok(())
+    }
+
+    /// View: get the clawback window in seconds (0 when disabled).
+    pub fn get_clawback_window(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&ClawbackKey::Window)
+            .unwrap_or(0)
+    }
+
+    /// View: recipients of a bounty whose releases are still held.
+    pub fn get_held_recipients(env: Env, bounty_id: u64) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&ClawbackKey::Held(bounty_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    fn has_pending_release(env: &Env, bounty_id: u64) -> bool {
+        !Self::get_held_recipients(env.clone(), bounty_id).is_empty()
+    }
+
+    /// Drop the hold of `recipient`, keeping the bounty's index in step.
+    fn remove_pending_release(env: &Env, bounty_id: u64, recipient: &Address) {
+        let storage = env.storage().persistent();
+        storage.remove(&ClawbackKey::Pending(bounty_id, recipient.clone()));
+        let mut held = Self::get_held_recipients(env.clone(), bounty_id);
+        if let Some(index) = held.first_index_of(recipient) {
+            held.remove(index);
+        }
+        if held.is_empty() {
+            storage.remove(&ClawbackKey::Held(bounty_id));
+        } else {
+            storage.set(&ClawbackKey::Held(bounty_id), &held);
+        }
+    }
+
+    /// Transfer `amount` released from a bounty to `recipient`, or hold it
+    /// until the clawback window closes when one is configured. Returns
+    /// whether the funds were transferred now.
+    ///
+    /// Each recipient has its own hold: further releases to the same
+    /// recipient are added to it and restart its window.
+    fn release_or_hold(
+        env: &Env,
+        client: &token::Client,
+        bounty_id: u64,
+        recipient: &Address,
+        amount: i128,
+    ) -> Result<bool, Error> {
+        let clawback_window = Self::get_clawback_window(env.clone());
+        if clawback_window == 0 {
+            Self::transfer_release(env, client, bounty_id, recipient, amount);
+            return Ok(true);
+        }
+        let storage = env.storage().persistent();
+        let key = ClawbackKey::Pending(bounty_id, recipient.clone());
+        let held = match storage.get::<ClawbackKey, PendingRelease>(&key) {
+            Some(held) => held.amount,
+            None => {
+                let mut recipients = Self::get_held_recipients(env.clone(), bounty_id);
+                recipients.push_back(recipient.clone());
+                storage.set(&ClawbackKey::Held(bounty_id), &recipients);
+                0
+            }
+        };
+        let pending = PendingRelease {
+            recipient: recipient.clone(),
+            amount: held.checked_add(amount).ok_or(Error::InvalidAmount)?,
+            final_at: env.ledger().timestamp().saturating_add(clawback_window),
+        };
+        storage.set(&key, &pending);
+        env.events()
+            .publish((symbol_short!("rel_pend"), bounty_id), pending);
+        Ok(false)
+    }
+
+    /// View: get the release to `recipient` still inside its clawback window.
+    pub fn get_pending_release(
+        env: Env,
+        bounty_id: u64,
+        recipient: Address,
+    ) -> Result<PendingRelease, Error> {
+        env.storage()
+            .persistent()
+            .get(&ClawbackKey::Pending(bounty_id, recipient))
+            .ok_or(Error::BountyNotFound)
+    }
+
+    /// Transfer a held release to its recipient once the clawback window has
+    /// passed. Anyone can call this, except while the bounty is disputed or
+    /// releases are paused.
+    pub fn finalize_release(env: Env, bounty_id: u64, recipient: Address) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+        Self::ensure_not_disputed(&env, bounty_id)?;
+        let pending = Self::get_pending_release(env.clone(), bounty_id, recipient.clone())?;
+        if env.ledger().timestamp() < pending.final_at {
+            return Err(Error::DeadlineNotPassed);
+        }
+        Self::remove_pending_release(&env, bounty_id, &recipient);
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        Self::transfer_release(&env, &client, bounty_id, &pending.recipient, pending.amount);
+
+        emit_funds_released(
+            &env,
+            FundsReleased {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                amount: pending.amount,
+                recipient: pending.recipient,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Reverse a held release before its clawback window closes. Needs both
+    /// the depositor and the bounty's arbiter (or the global arbiter). The
+    /// funds go back into escrow and a settled bounty returns to `Locked`,
+    /// so it can be refunded or released again. Its HTLC, release oracle,
+    /// review window and vesting schedule are dropped so they cannot pay
+    /// the recipient a second time.
+    pub fn clawback_release(env: Env, bounty_id: u64, recipient: Address) -> Result<(), Error> {
+        let pending = Self::get_pending_release(env.clone(), bounty_id, recipient.clone())?;
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+        Self::arbiter_for(&env, bounty_id)?.require_auth();
+
+        if env.ledger().timestamp() >= pending.final_at {
+            return Err(Error::TimelockExpired);
+        }
+        Self::remove_pending_release(&env, bounty_id, &recipient);
+
+        escrow.remaining_amount = escrow
+            .remaining_amount
+            .checked_add(pending.amount)
+            .ok_or(Error::InvalidAmount)?;
+        // Retire the triggers that released the funds, so nobody can pay
+        // the same recipient again without fresh authorization.
+        let storage = env.storage().persistent();
+        storage.remove(&DataKey::Htlc(bounty_id));
+        storage.remove(&DataKey::ReleaseOracle(bounty_id));
+        storage.remove(&DataKey::ReviewWindow(bounty_id));
+        storage.remove(&DataKey::Vesting(bounty_id));
+
+        if escrow.status.is_locked() {
+            storage.set(&DataKey::Escrow(bounty_id), &escrow);
+        } else {
+            // Only a settled bounty was dropped from the active count
+            if matches!(
+                escrow.status,
+                EscrowStatus::Released | EscrowStatus::Refunded
+            ) {
+                Self::record_locked(&env, 0, true);
+            }
+            Self::set_escrow_status(&env, bounty_id, &mut escrow, EscrowStatus::Locked);
+        }
+        env.events()
+            .publish((symbol_short!("clawback"), bounty_id), pending);
+        Ok(())
+    }
+
     /// Delegated release flow using a capability instead of admin auth.
     /// The capability amount limit is consumed by `payout_amount`.
     pub fn release_with_capability(
@@ -1467,6 +2756,8 @@ impl BountyEscrowContract {
         if Self::check_paused(&env, symbol_short!("release")) {
             return Err(Error::FundsPaused);
         }
+        Self::ensure_release_allowed(&env, bounty_id)?;
+        Self::ensure_submission_approved(&env, bounty_id, &contributor)?;
         if payout_amount <= 0 {
             return Err(Error::InvalidAmount);
         }
@@ -1479,9 +2770,10 @@ impl BountyEscrowContract {
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
             .unwrap();
-        if escrow.status != EscrowStatus::Locked {
+        if !escrow.status.is_locked() {
             return Err(Error::FundsNotLocked);
         }
+        Self::consume_multisig_approval(&env, bounty_id, &contributor, &escrow)?;
         if payout_amount > escrow.remaining_amount {
             return Err(Error::InsufficientFunds);
         }
@@ -1497,30 +2789,30 @@ impl BountyEscrowContract {
 
         let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
         let client = token::Client::new(&env, &token_addr);
-        client.transfer(
-            &env.current_contract_address(),
-            &contributor,
-            &payout_amount,
-        );
+        let transferred =
+            Self::release_or_hold(&env, &client, bounty_id, &contributor, payout_amount)?;
 
         escrow.remaining_amount -= payout_amount;
         if escrow.remaining_amount == 0 {
             escrow.status = EscrowStatus::Released;
+            Self::record_settled(&env);
         }
         env.storage()
             .persistent()
             .set(&DataKey::Escrow(bounty_id), &escrow);
 
-        emit_funds_released(
-            &env,
-            FundsReleased {
-                version: EVENT_VERSION_V2,
-                bounty_id,
-                amount: payout_amount,
-                recipient: contributor,
-                timestamp: env.ledger().timestamp(),
-            },
-        );
+        if transferred {
+            emit_funds_released(
+                &env,
+                FundsReleased {
+                    version: EVENT_VERSION_V2,
+                    bounty_id,
+                    amount: payout_amount,
+                    recipient: contributor,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
 
         Ok(())
     }
@@ -1536,6 +2828,7 @@ impl BountyEscrowContract {
         env.storage()
             .instance()
             .set(&DataKey::ClaimWindow, &claim_window);
+        Self::emit_admin_config_updated(&env, symbol_short!("claim_win"), admin);
         Ok(())
     }
 
@@ -1546,6 +2839,8 @@ impl BountyEscrowContract {
         if Self::check_paused(&env, symbol_short!("release")) {
             return Err(Error::FundsPaused);
         }
+        Self::ensure_release_allowed(&env, bounty_id)?;
+        Self::ensure_submission_approved(&env, bounty_id, &recipient)?;
         if !env.storage().instance().has(&DataKey::Admin) {
             return Err(Error::NotInitialized);
         }
@@ -1562,9 +2857,10 @@ impl BountyEscrowContract {
             .get(&DataKey::Escrow(bounty_id))
             .unwrap();
 
-        if escrow.status != EscrowStatus::Locked {
+        if !escrow.status.is_locked() {
             return Err(Error::FundsNotLocked);
         }
+        Self::consume_multisig_approval(&env, bounty_id, &recipient, &escrow)?;
 
         let now = env.ledger().timestamp();
         let claim_window: u64 = env
@@ -1575,7 +2871,7 @@ impl BountyEscrowContract {
         let claim = ClaimRecord {
             bounty_id,
             recipient: recipient.clone(),
-            amount: escrow.amount,
+            amount: escrow.remaining_amount,
             expires_at: now.saturating_add(claim_window),
             claimed: false,
         };
@@ -1589,18 +2885,97 @@ impl BountyEscrowContract {
             ClaimCreated {
                 bounty_id,
                 recipient,
-                amount: escrow.amount,
+                amount: claim.amount,
                 expires_at: claim.expires_at,
             },
         );
         Ok(())
     }
 
+    /// Approve a release that the contributor collects with `claim()`.
+    /// The admin, the approver or the bounty's depositor can approve.
+    ///
+    /// Unlike `release_funds`, nothing is transferred until the contributor
+    /// signs the claim, so they control the receiving transaction (and can use
+    /// a relayer). The approval expires after the claim window if one is
+    /// configured, otherwise it stays open until claimed or cancelled.
+    pub fn approve_release(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        approver: Address,
+    ) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+        Self::ensure_release_allowed(&env, bounty_id)?;
+        Self::ensure_submission_approved(&env, bounty_id, &contributor)?;
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if approver != admin
+            && approver != escrow.depositor
+            && !roles::has_role(&env, &APPROVER_ROLE, &approver)
+        {
+            return Err(Error::Unauthorized);
+        }
+        approver.require_auth();
+
+        if !escrow.status.is_locked() {
+            return Err(Error::FundsNotLocked);
+        }
+        Self::consume_multisig_approval(&env, bounty_id, &contributor, &escrow)?;
+        if Self::has_pending_claim(&env, bounty_id) {
+            return Err(Error::ClaimPending);
+        }
+
+        let claim_window: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ClaimWindow)
+            .unwrap_or(0);
+        let expires_at = if claim_window > 0 {
+            env.ledger().timestamp().saturating_add(claim_window)
+        } else {
+            u64::MAX
+        };
+        let claim = ClaimRecord {
+            bounty_id,
+            recipient: contributor.clone(),
+            amount: escrow.remaining_amount,
+            expires_at,
+            claimed: false,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::PendingClaim(bounty_id), &claim);
+
+        env.events().publish(
+            (symbol_short!("claim"), symbol_short!("created")),
+            ClaimCreated {
+                bounty_id,
+                recipient: contributor,
+                amount: claim.amount,
+                expires_at,
+            },
+        );
+        Ok(())
+    }
+
     /// Beneficiary calls this to claim their authorized funds within the window.
     pub fn claim(env: Env, bounty_id: u64) -> Result<(), Error> {
         if Self::check_paused(&env, symbol_short!("release")) {
             return Err(Error::FundsPaused);
         }
+        Self::ensure_not_disputed(&env, bounty_id)?;
         if !env
             .storage()
             .persistent()
@@ -1624,21 +2999,40 @@ impl BountyEscrowContract {
             return Err(Error::FundsNotLocked);
         }
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        client.transfer(
-            &env.current_contract_address(),
-            &claim.recipient,
-            &claim.amount,
-        );
+        Self::settle_claim(&env, &mut claim, now)
+    }
+
+    /// True while a bounty has an authorized claim that is not yet collected.
+    fn has_pending_claim(env: &Env, bounty_id: u64) -> bool {
+        env.storage()
+            .persistent()
+            .get::<DataKey, ClaimRecord>(&DataKey::PendingClaim(bounty_id))
+            .is_some_and(|claim| !claim.claimed)
+    }
 
-        // Update escrow status
+    /// Pay a pending claim. The claimant receives everything still in
+    /// escrow, so partial releases made since the claim was authorized
+    /// cannot leave funds stranded in a `Released` bounty.
+    fn settle_claim(env: &Env, claim: &mut ClaimRecord, now: u64) -> Result<(), Error> {
+        let bounty_id = claim.bounty_id;
         let mut escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
+            .ok_or(Error::BountyNotFound)?;
+        if !escrow.status.is_locked() {
+            return Err(Error::FundsNotLocked);
+        }
+
+        claim.amount = escrow.remaining_amount;
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(env, &token_addr);
+        Self::release_or_hold(env, &client, bounty_id, &claim.recipient, claim.amount)?;
+
+        escrow.remaining_amount = 0;
         escrow.status = EscrowStatus::Released;
+        Self::record_settled(env);
+        invariants::assert_escrow(env, &escrow);
         env.storage()
             .persistent()
             .set(&DataKey::Escrow(bounty_id), &escrow);
@@ -1646,7 +3040,7 @@ impl BountyEscrowContract {
         claim.claimed = true;
         env.storage()
             .persistent()
-            .set(&DataKey::PendingClaim(bounty_id), &claim);
+            .set(&DataKey::PendingClaim(bounty_id), claim);
 
         env.events().publish(
             (symbol_short!("claim"), symbol_short!("done")),
@@ -1671,6 +3065,7 @@ impl BountyEscrowContract {
         if Self::check_paused(&env, symbol_short!("release")) {
             return Err(Error::FundsPaused);
         }
+        Self::ensure_not_disputed(&env, bounty_id)?;
         if !env
             .storage()
             .persistent()
@@ -1693,48 +3088,17 @@ impl BountyEscrowContract {
             return Err(Error::FundsNotLocked);
         }
 
+        let remaining = Self::get_escrow_info(env.clone(), bounty_id)?.remaining_amount;
         Self::consume_capability(
             &env,
             &holder,
             capability_id,
             CapabilityAction::Claim,
             bounty_id,
-            claim.amount,
+            remaining,
         )?;
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        client.transfer(
-            &env.current_contract_address(),
-            &claim.recipient,
-            &claim.amount,
-        );
-
-        let mut escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-        escrow.status = EscrowStatus::Released;
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
-
-        claim.claimed = true;
-        env.storage()
-            .persistent()
-            .set(&DataKey::PendingClaim(bounty_id), &claim);
-
-        env.events().publish(
-            (symbol_short!("claim"), symbol_short!("done")),
-            ClaimExecuted {
-                bounty_id,
-                recipient: claim.recipient,
-                amount: claim.amount,
-                claimed_at: now,
-            },
-        );
-        Ok(())
+        Self::settle_claim(&env, &mut claim, now)
     }
 
     /// Admin can cancel an expired or unwanted pending claim, returning escrow to Locked.
@@ -1787,143 +3151,2066 @@ impl BountyEscrowContract {
             .ok_or(Error::BountyNotFound)
     }
 
-    /// Approve a refund before deadline (admin only).
-    /// This allows early refunds with admin approval.
-    pub fn approve_refund(
-        env: Env,
-        bounty_id: u64,
-        amount: i128,
-        recipient: Address,
-        mode: RefundMode,
-    ) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+    /// Assign the contributor working on a bounty (admin only).
+    /// Assigning again replaces the previous contributor and discards their
+    /// submission.
+    ///
+    /// Once assigned, the bounty can only be released after the depositor
+    /// approves the contributor's submission.
+    pub fn assign_contributor(env: Env, bounty_id: u64, contributor: Address) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if !escrow.status.is_locked() {
+            return Err(Error::FundsNotLocked);
         }
+        Self::ensure_not_disputed(&env, bounty_id)?;
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::RaceEntrants(bounty_id))
+        {
+            return Err(Error::BountyAssigned);
+        }
+        Self::ensure_min_reputation(&env, bounty_id, &contributor)?;
 
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+        if Self::get_assignee(env.clone(), bounty_id) != Some(contributor.clone()) {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::Submission(bounty_id));
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::Assignee(bounty_id), &contributor);
+        Self::set_escrow_status(&env, bounty_id, &mut escrow, EscrowStatus::Assigned);
+        events::emit_contributor_assigned(
+            &env,
+            events::ContributorAssigned {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                contributor,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
 
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+    /// Make a bounty depend on another (depositor only): it cannot be
+    /// released until `depends_on` has been released. Only allowed before a
+    /// contributor is assigned, so accepted work cannot be held back after
+    /// the fact. Fails with DependencyCycle if the dependency would form a
+    /// cycle.
+    pub fn set_bounty_dependency(env: Env, bounty_id: u64, depends_on: u64) -> Result<(), Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+        if !escrow.status.is_locked() {
+            return Err(Error::FundsNotLocked);
+        }
+        if escrow.status != EscrowStatus::Locked
+            || env
+                .storage()
+                .persistent()
+                .has(&DataKey::Assignee(bounty_id))
+        {
+            return Err(Error::BountyAssigned);
+        }
+        let storage = env.storage().persistent();
+        if !storage.has(&DataKey::Escrow(depends_on))
+            && !storage.has(&DataKey::Archived(depends_on))
+        {
             return Err(Error::BountyNotFound);
         }
 
+        let mut next = Some(depends_on);
+        while let Some(id) = next {
+            if id == bounty_id {
+                return Err(Error::DependencyCycle);
+            }
+            next = storage.get(&DataKey::DependsOn(id));
+        }
+
+        storage.set(&DataKey::DependsOn(bounty_id), &depends_on);
+        env.events()
+            .publish((symbol_short!("depends"), bounty_id), depends_on);
+        Ok(())
+    }
+
+    /// Drop a bounty's prerequisite (depositor only), e.g. once the
+    /// prerequisite has been refunded and will never be released.
+    pub fn clear_bounty_dependency(env: Env, bounty_id: u64) -> Result<(), Error> {
         let escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-
-        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+            .ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::DependsOn(bounty_id))
         {
-            return Err(Error::FundsNotLocked);
+            return Err(Error::BountyNotFound);
         }
 
-        if amount <= 0 || amount > escrow.remaining_amount {
-            return Err(Error::InvalidAmount);
-        }
+        env.storage()
+            .persistent()
+            .remove(&DataKey::DependsOn(bounty_id));
+        env.events()
+            .publish((symbol_short!("dep_clr"), bounty_id), ());
+        Ok(())
+    }
 
-        let approval = RefundApproval {
-            bounty_id,
-            amount,
-            recipient: recipient.clone(),
-            mode: mode.clone(),
-            approved_by: admin.clone(),
-            approved_at: env.ledger().timestamp(),
+    /// View: get the prerequisite of a bounty, if any.
+    pub fn get_bounty_dependency(env: Env, bounty_id: u64) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::DependsOn(bounty_id))
+    }
+
+    fn ensure_dependency_released(env: &Env, bounty_id: u64) -> Result<(), Error> {
+        let Some(depends_on) = Self::get_bounty_dependency(env.clone(), bounty_id) else {
+            return Ok(());
         };
+        if Self::has_pending_release(env, depends_on) {
+            return Err(Error::BountyNotSettled);
+        }
+        let storage = env.storage().persistent();
+        let released = match storage.get::<DataKey, Escrow>(&DataKey::Escrow(depends_on)) {
+            Some(escrow) => escrow.status == EscrowStatus::Released,
+            None => storage
+                .get::<DataKey, ArchivedBounty>(&DataKey::Archived(depends_on))
+                .is_some_and(|archived| archived.status == EscrowStatus::Released),
+        };
+        if !released {
+            return Err(Error::BountyNotSettled);
+        }
+        Ok(())
+    }
 
+    /// Point the escrow at the reputation store used by `set_min_reputation`
+    /// gates (admin only).
+    pub fn set_reputation_store(env: Env, store: Address) -> Result<(), Error> {
+        let admin = Self::require_admin(&env)?;
         env.storage()
+            .instance()
+            .set(&DataKey::ReputationStore, &store);
+        Self::emit_admin_config_updated(&env, symbol_short!("rep_store"), admin);
+        Ok(())
+    }
+
+    /// View: get the configured reputation store, if any.
+    pub fn get_reputation_store(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::ReputationStore)
+    }
+
+    /// Require contributors assigned to a bounty, including race entrants,
+    /// to hold at least `min_score` in the reputation store (depositor
+    /// only). 0 removes the requirement.
+    pub fn set_min_reputation(env: Env, bounty_id: u64, min_score: u32) -> Result<(), Error> {
+        let escrow: Escrow = env
+            .storage()
             .persistent()
-            .set(&DataKey::RefundApproval(bounty_id), &approval);
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
 
+        let key = DataKey::MinReputation(bounty_id);
+        if min_score == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &min_score);
+        }
+        env.events()
+            .publish((symbol_short!("min_rep"), bounty_id), min_score);
         Ok(())
     }
 
-    /// Release a partial amount of the locked funds to the contributor.
-    /// Only the admin (backend) can authorize this.
+    /// View: get the minimum reputation score of a bounty (0 when ungated).
+    pub fn get_min_reputation(env: Env, bounty_id: u64) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::MinReputation(bounty_id))
+            .unwrap_or(0)
+    }
+
+    /// Fails with Unauthorized when the bounty is gated and `contributor`
+    /// scores below the minimum, or no reputation store is configured.
+    fn ensure_min_reputation(
+        env: &Env,
+        bounty_id: u64,
+        contributor: &Address,
+    ) -> Result<(), Error> {
+        let min_score = Self::get_min_reputation(env.clone(), bounty_id);
+        if min_score == 0 {
+            return Ok(());
+        }
+        let store = Self::get_reputation_store(env.clone()).ok_or(Error::Unauthorized)?;
+        if ReputationStoreClient::new(env, &store).get_score(contributor) < min_score {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// View: get the contributor assigned to a bounty, if any.
+    pub fn get_assignee(env: Env, bounty_id: u64) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Assignee(bounty_id))
+    }
+
+    /// Enter a contributor into a race bounty (admin only).
     ///
-    /// - `payout_amount` must be > 0 and <= `remaining_amount`.
-    /// - `remaining_amount` is decremented by `payout_amount` after each call.
-    /// - When `remaining_amount` reaches 0 the escrow status is set to Released.
-    /// - The bounty stays Locked while any funds remain unreleased.
-    pub fn partial_release(
+    /// Up to `MAX_RACE_ENTRANTS` contributors work on the same funds, each
+    /// submitting before their own `deadline`. The first submission the
+    /// depositor approves with `approve_race_submission` takes the whole
+    /// bounty and closes the race for everyone else. Races and single
+    /// assignments are exclusive.
+    pub fn add_race_entrant(
         env: Env,
         bounty_id: u64,
         contributor: Address,
-        payout_amount: i128,
+        deadline: u64,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if !escrow.status.is_locked() {
+            return Err(Error::FundsNotLocked);
+        }
+        Self::ensure_not_disputed(&env, bounty_id)?;
+        if deadline <= env.ledger().timestamp() {
+            return Err(Error::InvalidDeadline);
+        }
+
+        let mut entrants = Self::get_race_entrants(env.clone(), bounty_id);
+        if Self::get_assignee(env.clone(), bounty_id).is_some()
+            || entrants.iter().any(|e| e.contributor == contributor)
+        {
+            return Err(Error::BountyAssigned);
+        }
+        if entrants.len() >= MAX_RACE_ENTRANTS {
+            return Err(Error::InvalidBatchSize);
+        }
+        Self::ensure_min_reputation(&env, bounty_id, &contributor)?;
+        entrants.push_back(RaceEntrant {
+            contributor: contributor.clone(),
+            deadline,
+        });
+        env.storage()
+            .persistent()
+            .set(&DataKey::RaceEntrants(bounty_id), &entrants);
+
+        if escrow.status == EscrowStatus::Locked {
+            Self::set_escrow_status(&env, bounty_id, &mut escrow, EscrowStatus::Assigned);
+        }
+        events::emit_contributor_assigned(
+            &env,
+            events::ContributorAssigned {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                contributor,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// View: contributors competing on a race bounty, in entry order.
+    pub fn get_race_entrants(env: Env, bounty_id: u64) -> Vec<RaceEntrant> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RaceEntrants(bounty_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Record a race entrant's deliverable before their own deadline.
+    /// Submitting again replaces the previous hash.
+    pub fn submit_race_work(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        submission_hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        contributor.require_auth();
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if !escrow.status.is_locked() {
+            return Err(Error::FundsNotLocked);
+        }
+        Self::ensure_not_disputed(&env, bounty_id)?;
+
+        let entrant = Self::get_race_entrants(env.clone(), bounty_id)
+            .iter()
+            .find(|e| e.contributor == contributor)
+            .ok_or(Error::Unauthorized)?;
+        let now = env.ledger().timestamp();
+        if now > entrant.deadline {
+            return Err(Error::InvalidDeadline);
+        }
+
+        env.storage().persistent().set(
+            &DataKey::RaceSubmission(bounty_id, contributor.clone()),
+            &Submission {
+                contributor: contributor.clone(),
+                submission_hash: submission_hash.clone(),
+                submitted_at: now,
+                approved: false,
+                approved_at: 0,
+            },
+        );
+        Self::set_escrow_status(&env, bounty_id, &mut escrow, EscrowStatus::Submitted);
+        events::emit_work_submitted(
+            &env,
+            events::WorkSubmitted {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                contributor,
+                submission_hash,
+                timestamp: now,
+            },
+        );
+        Ok(())
+    }
+
+    /// View: get a race entrant's submission.
+    pub fn get_race_submission(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+    ) -> Result<Submission, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RaceSubmission(bounty_id, contributor))
+            .ok_or(Error::SubmissionNotFound)
+    }
+
+    /// Approve a race entrant's submission and release the whole bounty to
+    /// them (depositor only). The race is closed and the other entrants'
+    /// submissions are discarded.
+    pub fn approve_race_submission(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+    ) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+        Self::ensure_release_allowed(&env, bounty_id)?;
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+        if !escrow.status.is_locked() {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let submission = Self::get_race_submission(env.clone(), bounty_id, contributor.clone())?;
+        Self::consume_multisig_approval(&env, bounty_id, &contributor, &escrow)?;
+
+        let now = env.ledger().timestamp();
+        events::emit_submission_approved(
+            &env,
+            events::SubmissionApproved {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                contributor: contributor.clone(),
+                submission_hash: submission.submission_hash,
+                approved_by: escrow.depositor.clone(),
+                timestamp: now,
+            },
+        );
+        Self::close_race(&env, bounty_id);
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        let amount = escrow.remaining_amount;
+        let transferred = Self::release_or_hold(&env, &client, bounty_id, &contributor, amount)?;
+        Self::record_release(&env, bounty_id, &contributor, amount);
+
+        escrow.status = EscrowStatus::Released;
+        Self::record_settled(&env);
+        escrow.remaining_amount = 0;
+        invariants::assert_escrow(&env, &escrow);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        if transferred {
+            emit_funds_released(
+                &env,
+                FundsReleased {
+                    version: EVENT_VERSION_V2,
+                    bounty_id,
+                    amount,
+                    recipient: contributor,
+                    timestamp: now,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    /// Drop every race entrant of a bounty along with their submissions.
+    fn close_race(env: &Env, bounty_id: u64) {
+        let storage = env.storage().persistent();
+        for entrant in Self::get_race_entrants(env.clone(), bounty_id).iter() {
+            storage.remove(&DataKey::RaceSubmission(bounty_id, entrant.contributor));
+        }
+        storage.remove(&DataKey::RaceEntrants(bounty_id));
+    }
+
+    /// Record the hash of the assigned contributor's deliverable.
+    /// Submitting again replaces the previous hash until the depositor starts
+    /// reviewing it.
+    pub fn submit_work(env: Env, bounty_id: u64, submission_hash: BytesN<32>) -> Result<(), Error> {
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if !escrow.status.is_locked() {
+            return Err(Error::FundsNotLocked);
+        }
+        let contributor = Self::get_assignee(env.clone(), bounty_id).ok_or(Error::Unauthorized)?;
+        contributor.require_auth();
+        Self::ensure_not_disputed(&env, bounty_id)?;
+
+        if Self::get_submission(env.clone(), bounty_id).is_ok_and(|s| s.approved) {
+            return Err(Error::Unauthorized);
+        }
+        if !matches!(
+            escrow.status,
+            EscrowStatus::Assigned | EscrowStatus::Submitted
+        ) {
+            return Err(Error::Unauthorized);
+        }
+
+        let now = env.ledger().timestamp();
+        env.storage().persistent().set(
+            &DataKey::Submission(bounty_id),
+            &Submission {
+                contributor: contributor.clone(),
+                submission_hash: submission_hash.clone(),
+                submitted_at: now,
+                approved: false,
+                approved_at: 0,
+            },
+        );
+        Self::set_escrow_status(&env, bounty_id, &mut escrow, EscrowStatus::Submitted);
+        events::emit_work_submitted(
+            &env,
+            events::WorkSubmitted {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                contributor,
+                submission_hash,
+                timestamp: now,
+            },
+        );
+        Ok(())
+    }
+
+    /// Mark the latest submission as under review (depositor only), freezing
+    /// it against resubmission.
+    pub fn start_review(env: Env, bounty_id: u64) -> Result<(), Error> {
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+        if escrow.status != EscrowStatus::Submitted {
+            return Err(Error::SubmissionNotFound);
+        }
+
+        Self::set_escrow_status(&env, bounty_id, &mut escrow, EscrowStatus::UnderReview);
+        Ok(())
+    }
+
+    /// Move a bounty to `to`, persisting it and emitting a status-change
+    /// event. A no-op when the bounty is already in that status.
+    fn set_escrow_status(env: &Env, bounty_id: u64, escrow: &mut Escrow, to: EscrowStatus) {
+        if escrow.status == to {
+            return;
+        }
+        let from = escrow.status.clone();
+        escrow.status = to.clone();
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), escrow);
+
+        events::emit_escrow_status_changed(
+            env,
+            events::EscrowStatusChanged {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                from,
+                to,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+
+    /// Approve the recorded submission, unlocking release (depositor only).
+    pub fn approve_submission(env: Env, bounty_id: u64) -> Result<(), Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+
+        let mut submission = Self::get_submission(env.clone(), bounty_id)?;
+        if submission.approved {
+            return Ok(());
+        }
+        submission.approved = true;
+        submission.approved_at = env.ledger().timestamp();
+        env.storage()
+            .persistent()
+            .set(&DataKey::Submission(bounty_id), &submission);
+
+        events::emit_submission_approved(
+            &env,
+            events::SubmissionApproved {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                contributor: submission.contributor,
+                submission_hash: submission.submission_hash,
+                approved_by: escrow.depositor,
+                timestamp: submission.approved_at,
+            },
+        );
+        Ok(())
+    }
+
+    /// View: get the submission recorded for a bounty.
+    pub fn get_submission(env: Env, bounty_id: u64) -> Result<Submission, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Submission(bounty_id))
+            .ok_or(Error::SubmissionNotFound)
+    }
+
+    /// Opt a bounty into auto-release (depositor only): once the assignee
+    /// submits work, anyone can call `auto_release` after `review_window`
+    /// seconds unless the depositor raised a dispute. 0 turns it off.
+    pub fn set_review_window(env: Env, bounty_id: u64, review_window: u64) -> Result<(), Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+        if !escrow.status.is_locked() {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let key = DataKey::ReviewWindow(bounty_id);
+        if review_window == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &review_window);
+        }
+        env.events()
+            .publish((symbol_short!("review"), bounty_id), review_window);
+        Ok(())
+    }
+
+    /// View: get the auto-release review window of a bounty, if enabled.
+    pub fn get_review_window(env: Env, bounty_id: u64) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReviewWindow(bounty_id))
+    }
+
+    /// Release the remaining funds to the submitting contributor once the
+    /// review window has passed without a dispute. Anyone can call this.
+    ///
+    /// Fails with Unauthorized if the bounty is not in auto-release mode and
+    /// DeadlineNotPassed while the review window is still open.
+    pub fn auto_release(env: Env, bounty_id: u64) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+        Self::ensure_release_allowed(&env, bounty_id)?;
+        let review_window =
+            Self::get_review_window(env.clone(), bounty_id).ok_or(Error::Unauthorized)?;
+        let submission = Self::get_submission(env.clone(), bounty_id)?;
+        let now = env.ledger().timestamp();
+        if now < submission.submitted_at.saturating_add(review_window) {
+            return Err(Error::DeadlineNotPassed);
+        }
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if !escrow.status.is_locked() {
+            return Err(Error::FundsNotLocked);
+        }
+        Self::consume_multisig_approval(&env, bounty_id, &submission.contributor, &escrow)?;
+
+        let amount = escrow.remaining_amount;
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        let transferred =
+            Self::release_or_hold(&env, &client, bounty_id, &submission.contributor, amount)?;
+
+        escrow.remaining_amount = 0;
+        escrow.status = EscrowStatus::Released;
+        Self::record_settled(&env);
+        invariants::assert_escrow(&env, &escrow);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        if transferred {
+            emit_funds_released(
+                &env,
+                FundsReleased {
+                    version: EVENT_VERSION_V2,
+                    bounty_id,
+                    amount,
+                    recipient: submission.contributor,
+                    timestamp: now,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    /// An assigned bounty pays out only to the contributor whose submission
+    /// the depositor approved.
+    fn ensure_submission_approved(
+        env: &Env,
+        bounty_id: u64,
+        contributor: &Address,
+    ) -> Result<(), Error> {
+        // Race bounties pay out only through `approve_race_submission`.
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::RaceEntrants(bounty_id))
+        {
+            return Err(Error::SubmissionNotApproved);
+        }
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::Assignee(bounty_id))
+        {
+            return Ok(());
+        }
+        match Self::get_submission(env.clone(), bounty_id) {
+            Ok(submission) if submission.approved && submission.contributor == *contributor => {
+                Ok(())
+            }
+            _ => Err(Error::SubmissionNotApproved),
+        }
+    }
+
+    /// Cancel a bounty nobody has started on and refund the depositor at once,
+    /// regardless of the deadline (depositor only).
+    ///
+    /// Fails with BountyAssigned once a contributor is assigned, a claim is
+    /// pending or any funds have been released.
+    pub fn cancel_bounty(env: Env, bounty_id: u64) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("refund")) {
+            return Err(Error::FundsPaused);
+        }
+        Self::ensure_not_disputed(&env, bounty_id)?;
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+
+        if !escrow.status.is_locked() {
+            return Err(Error::FundsNotLocked);
+        }
+        let claim_pending = env
+            .storage()
+            .persistent()
+            .get::<DataKey, ClaimRecord>(&DataKey::PendingClaim(bounty_id))
+            .is_some_and(|claim| !claim.claimed);
+        if escrow.remaining_amount != escrow.amount
+            || claim_pending
+            || env
+                .storage()
+                .persistent()
+                .has(&DataKey::Assignee(bounty_id))
+            || env
+                .storage()
+                .persistent()
+                .has(&DataKey::RaceEntrants(bounty_id))
+        {
+            return Err(Error::BountyAssigned);
+        }
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        let refund_amount = escrow.remaining_amount;
+        let now = env.ledger().timestamp();
+        client.transfer(
+            &env.current_contract_address(),
+            &escrow.depositor,
+            &refund_amount,
+        );
+        Self::record_refunded(&env, refund_amount);
+
+        escrow.remaining_amount = 0;
+        escrow.status = EscrowStatus::Refunded;
+        Self::record_settled(&env);
+        escrow.refund_history.push_back(RefundRecord {
+            amount: refund_amount,
+            recipient: escrow.depositor.clone(),
+            timestamp: now,
+            mode: RefundMode::Full,
+        });
+        invariants::assert_escrow(&env, &escrow);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::clear_refund_approvals(&env, bounty_id);
+
+        emit_funds_refunded(
+            &env,
+            FundsRefunded {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                amount: refund_amount,
+                refund_to: escrow.depositor,
+                timestamp: now,
+            },
+        );
+        Ok(())
+    }
+
+    /// Set the arbiter who resolves disputes (admin only).
+    pub fn set_arbiter(env: Env, arbiter: Address) -> Result<(), Error> {
+        let admin = Self::require_admin(&env)?;
+        env.storage().instance().set(&DataKey::Arbiter, &arbiter);
+        Self::emit_admin_config_updated(&env, symbol_short!("arbiter"), admin);
+        Ok(())
+    }
+
+    /// View: get the configured arbiter, if any.
+    pub fn get_arbiter(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Arbiter)
+    }
+
+    /// Lock funds and name the arbiter who alone resolves disputes on this
+    /// bounty, overriding the global arbiter.
+    pub fn lock_funds_with_arbiter(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        arbiter: Address,
+    ) -> Result<(), Error> {
+        if arbiter == depositor {
+            return Err(Error::Unauthorized);
+        }
+        Self::lock_funds(env.clone(), depositor, bounty_id, amount, deadline)?;
+        Self::store_bounty_arbiter(&env, bounty_id, arbiter);
+        Ok(())
+    }
+
+    /// Name a per-bounty arbiter after locking. Needs the depositor and the
+    /// contributor (the assignee, else the pending claimant), or the admin
+    /// while no contributor is attached, so neither side can pick the judge
+    /// alone. The arbiter cannot be one of the parties, and cannot be changed
+    /// while a dispute is open.
+    pub fn set_bounty_arbiter(env: Env, bounty_id: u64, arbiter: Address) -> Result<(), Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if !escrow.status.is_locked() && escrow.status != EscrowStatus::PartiallyRefunded {
+            return Err(Error::FundsNotLocked);
+        }
+        Self::ensure_not_disputed(&env, bounty_id)?;
+
+        let assignee = Self::get_assignee(env.clone(), bounty_id);
+        let claimant = Self::pending_claimant(&env, bounty_id);
+        if arbiter == escrow.depositor
+            || assignee.as_ref() == Some(&arbiter)
+            || claimant.as_ref() == Some(&arbiter)
+        {
+            return Err(Error::Unauthorized);
+        }
+
+        escrow.depositor.require_auth();
+        match assignee.or(claimant) {
+            Some(contributor) if contributor != escrow.depositor => contributor.require_auth(),
+            _ => {
+                let admin: Address = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::Admin)
+                    .ok_or(Error::NotInitialized)?;
+                if admin != escrow.depositor {
+                    admin.require_auth();
+                }
+            }
+        }
+
+        Self::store_bounty_arbiter(&env, bounty_id, arbiter);
+        Ok(())
+    }
+
+    /// The recipient of a bounty's authorized but uncollected claim.
+    fn pending_claimant(env: &Env, bounty_id: u64) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get::<DataKey, ClaimRecord>(&DataKey::PendingClaim(bounty_id))
+            .filter(|claim| !claim.claimed)
+            .map(|claim| claim.recipient)
+    }
+
+    /// The bounty's own arbiter, falling back to the global one.
+    fn arbiter_for(env: &Env, bounty_id: u64) -> Result<Address, Error> {
+        match Self::get_bounty_arbiter(env.clone(), bounty_id) {
+            Some(arbiter) => Ok(arbiter),
+            None => Self::get_arbiter(env.clone()).ok_or(Error::ArbiterNotSet),
+        }
+    }
+
+    /// View: get the arbiter named for a bounty, if any.
+    pub fn get_bounty_arbiter(env: Env, bounty_id: u64) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::BountyArbiter(bounty_id))
+    }
+
+    fn store_bounty_arbiter(env: &Env, bounty_id: u64, arbiter: Address) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::BountyArbiter(bounty_id), &arbiter);
+        env.events()
+            .publish((symbol_short!("b_arbiter"), bounty_id), arbiter);
+    }
+
+    /// Set the bond required to raise a dispute (admin only). 0 disables it.
+    pub fn set_dispute_bond(env: Env, amount: i128) -> Result<(), Error> {
+        let admin = Self::require_admin(&env)?;
+        if amount < 0 {
+            return Err(Error::InvalidAmount);
+        }
+        env.storage().instance().set(&DataKey::DisputeBond, &amount);
+        Self::emit_admin_config_updated(&env, symbol_short!("disp_bond"), admin);
+        Ok(())
+    }
+
+    /// View: get the bond required to raise a dispute.
+    pub fn get_dispute_bond(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::DisputeBond)
+            .unwrap_or(0)
+    }
+
+    /// Raise a dispute on a bounty, freezing every release and refund path
+    /// until the arbiter resolves it.
+    ///
+    /// `caller` must be the depositor, the assigned contributor or the
+    /// contributor holding the pending claim on the bounty, and posts the
+    /// configured dispute bond. The depositor can only dispute once a
+    /// contributor is attached through an assignment, a pending claim or a
+    /// held release, and an arbiter must be available to resolve it.
+    pub fn raise_dispute(env: Env, bounty_id: u64, caller: Address) -> Result<(), Error> {
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if !escrow.status.is_locked() && escrow.status != EscrowStatus::PartiallyRefunded {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let claimant = Self::pending_claimant(&env, bounty_id);
+        let assignee = Self::get_assignee(env.clone(), bounty_id);
+        if caller != escrow.depositor
+            && Some(caller.clone()) != claimant
+            && Some(caller.clone()) != assignee
+        {
+            return Err(Error::Unauthorized);
+        }
+        caller.require_auth();
+
+        Self::ensure_not_disputed(&env, bounty_id)?;
+        Self::arbiter_for(&env, bounty_id)?;
+
+        let counterparty = if caller == escrow.depositor {
+            let held = Self::get_held_recipients(env.clone(), bounty_id).first();
+            claimant.or(assignee).or(held)
+        } else {
+            Some(escrow.depositor.clone())
+        };
+        // Without a counterparty nobody could be paid the forfeited bond
+        if counterparty.is_none() {
+            return Err(Error::Unauthorized);
+        }
+        let bond = Self::get_dispute_bond(env.clone());
+        if bond > 0 {
+            let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+            let client = token::Client::new(&env, &token_addr);
+            client.transfer(&caller, &env.current_contract_address(), &bond);
+            Self::adjust_liabilities(&env, bond);
+        }
+
+        let now = env.ledger().timestamp();
+        env.storage().persistent().set(
+            &DataKey::Dispute(bounty_id),
+            &DisputeRecord {
+                bounty_id,
+                raised_by: caller.clone(),
+                raised_at: now,
+                bond,
+                counterparty,
+            },
+        );
+        Self::set_escrow_status(&env, bounty_id, &mut escrow, EscrowStatus::Disputed);
+
+        events::emit_dispute_raised(
+            &env,
+            events::DisputeRaised {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                raised_by: caller,
+                bond,
+                timestamp: now,
+            },
+        );
+        Ok(())
+    }
+
+    /// Resolve a dispute by paying out the remaining funds (the bounty's own
+    /// arbiter if one was named, otherwise the global arbiter).
+    ///
+    /// `amounts` must sum to the bounty's remaining amount. Awards to the
+    /// depositor are recorded as refunds, all others as releases and are
+    /// held like any other release while a clawback window is set. When
+    /// `forfeit_bond` is set the arbiter ruled against whoever raised the
+    /// dispute and their bond goes to the counterparty; otherwise it is
+    /// returned.
+    pub fn resolve_dispute(
+        env: Env,
+        bounty_id: u64,
+        award_to: Vec<Address>,
+        amounts: Vec<i128>,
+        forfeit_bond: bool,
+    ) -> Result<(), Error> {
+        let arbiter = Self::arbiter_for(&env, bounty_id)?;
+        arbiter.require_auth();
+
+        let dispute: DisputeRecord = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Dispute(bounty_id))
+            .ok_or(Error::DisputeNotFound)?;
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        if award_to.is_empty() || award_to.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+        if amounts.len() != award_to.len() {
+            return Err(Error::BatchSizeMismatch);
+        }
+        let mut total: i128 = 0;
+        for amount in amounts.iter() {
+            if amount < 0 {
+                return Err(Error::InvalidAmount);
+            }
+            total = total.checked_add(amount).ok_or(Error::InvalidAmount)?;
+        }
+        if total != escrow.remaining_amount {
+            return Err(Error::InvalidAmount);
+        }
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        let contract_address = env.current_contract_address();
+        let now = env.ledger().timestamp();
+        let mut released = false;
+        for (recipient, amount) in award_to.iter().zip(amounts.iter()) {
+            if amount == 0 {
+                continue;
+            }
+            if recipient == escrow.depositor {
+                client.transfer(&contract_address, &recipient, &amount);
+                Self::record_refunded(&env, amount);
+                escrow.refund_history.push_back(RefundRecord {
+                    amount,
+                    recipient,
+                    timestamp: now,
+                    mode: RefundMode::Partial,
+                });
+            } else {
+                released = true;
+                Self::release_or_hold(&env, &client, bounty_id, &recipient, amount)?;
+                Self::record_release(&env, bounty_id, &recipient, amount);
+            }
+        }
+
+        escrow.remaining_amount = 0;
+        escrow.status = if released {
+            EscrowStatus::Released
+        } else {
+            EscrowStatus::Refunded
+        };
+        Self::record_settled(&env);
+        invariants::assert_escrow(&env, &escrow);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Dispute(bounty_id));
+
+        if dispute.bond > 0 {
+            let bond_to = match dispute.counterparty {
+                Some(counterparty) if forfeit_bond => counterparty,
+                _ => dispute.raised_by,
+            };
+            client.transfer(&contract_address, &bond_to, &dispute.bond);
+            Self::adjust_liabilities(&env, -dispute.bond);
+        }
+
+        events::emit_dispute_resolved(
+            &env,
+            events::DisputeResolved {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                arbiter,
+                award_to,
+                amounts,
+                bond_forfeited: forfeit_bond && dispute.bond > 0,
+                timestamp: now,
+            },
+        );
+        Ok(())
+    }
+
+    /// View: get the open dispute on a bounty.
+    pub fn get_dispute(env: Env, bounty_id: u64) -> Result<DisputeRecord, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Dispute(bounty_id))
+            .ok_or(Error::DisputeNotFound)
+    }
+
+    /// Require the depositor's authorization when a refund before the
+    /// deadline pays someone else, so an admin cannot divert funds alone.
+    /// `signer` has already authorized the call and is not asked twice.
+    fn require_refund_consent(env: &Env, escrow: &Escrow, pays_other: bool, signer: &Address) {
+        if pays_other && env.ledger().timestamp() < escrow.deadline && *signer != escrow.depositor {
+            escrow.depositor.require_auth();
+        }
+    }
+
+    /// Checks every release path other than `claim_htlc` runs before paying
+    /// out: the bounty is not disputed, not hash-time-locked (those settle
+    /// only through `claim_htlc` or `refund`), and its prerequisite bounty
+    /// has been released.
+    fn ensure_release_allowed(env: &Env, bounty_id: u64) -> Result<(), Error> {
+        Self::ensure_not_disputed(env, bounty_id)?;
+        if env.storage().persistent().has(&DataKey::Htlc(bounty_id)) {
+            return Err(Error::Unauthorized);
+        }
+        Self::ensure_dependency_released(env, bounty_id)
+    }
+
+    fn ensure_not_disputed(env: &Env, bounty_id: u64) -> Result<(), Error> {
+        if env.storage().persistent().has(&DataKey::Dispute(bounty_id)) {
+            return Err(Error::BountyDisputed);
+        }
+        Ok(())
+    }
+
+    /// Approve a refund before deadline (admin only).
+    /// This allows early refunds with admin approval. Refunding an address
+    /// other than the depositor before the deadline also needs the
+    /// depositor's authorization.
+    ///
+    /// Approvals queue up, so several partial refunds can be approved at
+    /// once; each is consumed when executed. Returns the approval id.
+    pub fn approve_refund(
+        env: Env,
+        bounty_id: u64,
+        amount: i128,
+        recipient: Address,
+        mode: RefundMode,
+    ) -> Result<u64, Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        Self::approve_refund_as_approver(env, bounty_id, amount, recipient, mode, admin)
+    }
+
+    /// Same as `approve_refund`, signed by `approver`, which may be the admin
+    /// or an address granted the role with `grant_approver`.
+    pub fn approve_refund_as_approver(
+        env: Env,
+        bounty_id: u64,
+        amount: i128,
+        recipient: Address,
+        mode: RefundMode,
+        approver: Address,
+    ) -> Result<u64, Error> {
+        Self::require_approver(&env, &approver)?;
+        anti_abuse::check_rate_limit(
+            &env,
+            RateLimitRole::Admin,
+            RateLimitOp::Refund,
+            approver.clone(),
+        )?;
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+
+        if !escrow.status.is_locked() && escrow.status != EscrowStatus::PartiallyRefunded {
+            return Err(Error::FundsNotLocked);
+        }
+
+        if amount <= 0 || amount > escrow.remaining_amount {
+            return Err(Error::InvalidAmount);
+        }
+        Self::require_refund_consent(&env, &escrow, recipient != escrow.depositor, &approver);
+
+        let approval_id = Self::next_refund_approval_id(&env);
+        let approval = RefundApproval {
+            bounty_id,
+            approval_id,
+            amount,
+            recipient: recipient.clone(),
+            mode: mode.clone(),
+            approved_by: approver.clone(),
+            approved_at: env.ledger().timestamp(),
+        };
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::RefundApproval(bounty_id, approval_id), &approval);
+        let mut ids = Self::refund_approval_ids(&env, bounty_id);
+        ids.push_back(approval_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::RefundApprovalIds(bounty_id), &ids);
+
+        events::emit_refund_approved(
+            &env,
+            events::RefundApproved {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                approval_id,
+                amount,
+                recipient,
+                mode,
+                approved_by: approver,
+                timestamp: approval.approved_at,
+            },
+        );
+        Ok(approval_id)
+    }
+
+    /// View: queued refund approvals for a bounty, oldest first.
+    pub fn get_refund_approvals(env: Env, bounty_id: u64) -> Vec<RefundApproval> {
+        let mut approvals = Vec::new(&env);
+        for approval_id in Self::refund_approval_ids(&env, bounty_id).iter() {
+            if let Some(approval) = env
+                .storage()
+                .persistent()
+                .get(&DataKey::RefundApproval(bounty_id, approval_id))
+            {
+                approvals.push_back(approval);
+            }
+        }
+        approvals
+    }
+
+    /// View: a single queued refund approval.
+    pub fn get_refund_approval(
+        env: Env,
+        bounty_id: u64,
+        approval_id: u64,
+    ) -> Result<RefundApproval, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RefundApproval(bounty_id, approval_id))
+            .ok_or(Error::RefundApprovalNotFound)
+    }
+
+    /// View: every approved-but-unexecuted refund across all bounties,
+    /// ordered by bounty index and then by approval age, paginated.
+    pub fn list_refund_approvals(env: Env, offset: u32, limit: u32) -> Vec<RefundApproval> {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        let mut results = Vec::new(&env);
+        let mut skipped = 0u32;
+
+        for bounty_id in index.iter() {
+            for approval_id in Self::refund_approval_ids(&env, bounty_id).iter() {
+                if results.len() >= limit {
+                    return results;
+                }
+                let Some(approval) = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::RefundApproval(bounty_id, approval_id))
+                else {
+                    continue;
+                };
+                if skipped < offset {
+                    skipped += 1;
+                    continue;
+                }
+                results.push_back(approval);
+            }
+        }
+        results
+    }
+
+    fn next_refund_approval_id(env: &Env) -> u64 {
+        let last_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::RefundApprovalNonce)
+            .unwrap_or(0);
+        let next_id = last_id.saturating_add(1);
+        env.storage()
+            .instance()
+            .set(&DataKey::RefundApprovalNonce, &next_id);
+        next_id
+    }
+
+    fn refund_approval_ids(env: &Env, bounty_id: u64) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RefundApprovalIds(bounty_id))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Consume one queued refund approval.
+    fn remove_refund_approval(env: &Env, bounty_id: u64, approval_id: u64) {
+        let mut ids = Self::refund_approval_ids(env, bounty_id);
+        if let Some(pos) = ids.first_index_of(approval_id) {
+            ids.remove(pos);
+        }
+        let storage = env.storage().persistent();
+        storage.remove(&DataKey::RefundApproval(bounty_id, approval_id));
+        if ids.is_empty() {
+            storage.remove(&DataKey::RefundApprovalIds(bounty_id));
+        } else {
+            storage.set(&DataKey::RefundApprovalIds(bounty_id), &ids);
+        }
+    }
+
+    /// Drop every queued refund approval, e.g. once a bounty is fully refunded.
+    fn clear_refund_approvals(env: &Env, bounty_id: u64) {
+        let storage = env.storage().persistent();
+        for approval_id in Self::refund_approval_ids(env, bounty_id).iter() {
+            storage.remove(&DataKey::RefundApproval(bounty_id, approval_id));
+        }
+        storage.remove(&DataKey::RefundApprovalIds(bounty_id));
+    }
+
+    /// Release a partial amount of the locked funds to the contributor.
+    /// Only the admin (backend) can authorize this.
+    ///
+    /// - `payout_amount` must be > 0 and <= `remaining_amount`.
+    /// - `remaining_amount` is decremented by `payout_amount` after each call.
+    /// - When `remaining_amount` reaches 0 the escrow status is set to Released.
+    /// - The bounty stays Locked while any funds remain unreleased.
+    pub fn partial_release(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        payout_amount: i128,
+    ) -> Result<(), Error> {
+        Self::ensure_release_allowed(&env, bounty_id)?;
+        Self::ensure_submission_approved(&env, bounty_id, &contributor)?;
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        anti_abuse::check_rate_limit(
+            &env,
+            RateLimitRole::Admin,
+            RateLimitOp::Release,
+            admin.clone(),
+        )?;
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+
+        if !escrow.status.is_locked() {
+            return Err(Error::FundsNotLocked);
+        }
+        Self::consume_multisig_approval(&env, bounty_id, &contributor, &escrow)?;
+
+        // Guard: zero or negative payout makes no sense and would corrupt state
+        if payout_amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        // Guard: prevent overpayment — payout cannot exceed what is still owed
+        if payout_amount > escrow.remaining_amount {
+            return Err(Error::InsufficientFunds);
+        }
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+
+        // Transfer only the requested partial amount to the contributor
+        let transferred =
+            Self::release_or_hold(&env, &client, bounty_id, &contributor, payout_amount)?;
+
+        // Decrement remaining; this is always an exact integer subtraction — no rounding
+        escrow.remaining_amount -= payout_amount;
+
+        // Automatically transition to Released once fully paid out
+        if escrow.remaining_amount == 0 {
+            escrow.status = EscrowStatus::Released;
+            Self::record_settled(&env);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        if transferred {
+            events::emit_funds_released(
+                &env,
+                FundsReleased {
+                    version: EVENT_VERSION_V2,
+                    bounty_id,
+                    amount: payout_amount,
+                    recipient: contributor.clone(),
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Release the remaining funds split among several contributors.
+    /// Only the admin (backend) can authorize this.
+    ///
+    /// `shares` are basis points and must sum to 10000. Rounding dust goes to
+    /// the first contributor so the escrow is always emptied exactly. Each
+    /// transfer is recorded in the bounty's release history. Above the
+    /// multisig threshold the approvals must name the lead contributor: the
+    /// approved submitter of an assigned bounty, otherwise the first one.
+    /// While a clawback window is configured each contributor's share is held
+    /// separately.
+    pub fn release_split(
+        env: Env,
+        bounty_id: u64,
+        contributors: Vec<Address>,
+        shares: Vec<u32>,
+    ) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+        Self::ensure_release_allowed(&env, bounty_id)?;
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if !escrow.status.is_locked() {
+            return Err(Error::FundsNotLocked);
+        }
+
+        if contributors.is_empty() || contributors.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+        if shares.len() != contributors.len() {
+            return Err(Error::BatchSizeMismatch);
+        }
+        // An assigned bounty must include its approved contributor, who is
+        // also the one multisig signers approve the split for.
+        let lead = contributors
+            .iter()
+            .find(|contributor| {
+                Self::ensure_submission_approved(&env, bounty_id, contributor).is_ok()
+            })
+            .ok_or(Error::SubmissionNotApproved)?;
+        Self::consume_multisig_approval(&env, bounty_id, &lead, &escrow)?;
+        let (mut amounts, dust) = math::split_bps(&env, escrow.remaining_amount, &shares)?;
+        amounts.set(0, amounts.get(0).unwrap() + dust);
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        let timestamp = env.ledger().timestamp();
+        for (contributor, amount) in contributors.iter().zip(amounts.iter()) {
+            if amount == 0 {
+                continue;
+            }
+            let transferred =
+                Self::release_or_hold(&env, &client, bounty_id, &contributor, amount)?;
+            Self::record_release(&env, bounty_id, &contributor, amount);
+            if transferred {
+                emit_funds_released(
+                    &env,
+                    FundsReleased {
+                        version: EVENT_VERSION_V2,
+                        bounty_id,
+                        amount,
+                        recipient: contributor,
+                        timestamp,
+                    },
+                );
+            }
+        }
+
+        escrow.remaining_amount = 0;
+        escrow.status = EscrowStatus::Released;
+        Self::record_settled(&env);
+        invariants::assert_escrow(&env, &escrow);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        Ok(())
+    }
+
+    fn record_release(env: &Env, bounty_id: u64, recipient: &Address, amount: i128) {
+        let mut history = Self::get_release_history(env.clone(), bounty_id);
+        history.push_back(ReleaseRecord {
+            amount,
+            recipient: recipient.clone(),
+            timestamp: env.ledger().timestamp(),
+        });
+        env.storage()
+            .persistent()
+            .set(&DataKey::ReleaseHistory(bounty_id), &history);
+        Self::extend_bounty_ttl(env, bounty_id);
+    }
+
+    /// View: get every recorded release for a bounty, oldest first.
+    pub fn get_release_history(env: Env, bounty_id: u64) -> Vec<ReleaseRecord> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReleaseHistory(bounty_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Split a locked bounty into milestones (depositor only).
+    ///
+    /// `amounts` must be positive and sum to the bounty's remaining amount.
+    /// Milestones can be redefined until the first one is released.
+    pub fn define_milestones(env: Env, bounty_id: u64, amounts: Vec<i128>) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+
+        if !escrow.status.is_locked() {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let existing = Self::get_milestones(env.clone(), bounty_id);
+        if existing.iter().any(|milestone| milestone.released) {
+            return Err(Error::MilestoneAlreadyReleased);
+        }
+
+        if amounts.is_empty() || amounts.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+        let mut total: i128 = 0;
+        let mut milestones = Vec::new(&env);
+        for amount in amounts.iter() {
+            if amount <= 0 {
+                return Err(Error::InvalidAmount);
+            }
+            total = total.checked_add(amount).ok_or(Error::InvalidAmount)?;
+            milestones.push_back(Milestone {
+                amount,
+                released: false,
+            });
+        }
+        if total != escrow.remaining_amount {
+            return Err(Error::InvalidAmount);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Milestones(bounty_id), &milestones);
+        Ok(())
+    }
+
+    /// Release the milestone at `idx` to the contributor.
+    /// Only the admin (backend) can authorize this.
+    ///
+    /// The escrow stays Locked until the last milestone is released.
+    pub fn release_milestone(
+        env: Env,
+        bounty_id: u64,
+        idx: u32,
+        contributor: Address,
+    ) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+        Self::ensure_release_allowed(&env, bounty_id)?;
+        Self::ensure_submission_approved(&env, bounty_id, &contributor)?;
+        if env.storage().instance().has(&DataKey::ReentrancyGuard) {
+            panic!("Reentrancy detected");
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::ReentrancyGuard, &true);
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if !escrow.status.is_locked() {
+            return Err(Error::FundsNotLocked);
+        }
+        Self::consume_multisig_approval(&env, bounty_id, &contributor, &escrow)?;
+
+        let mut milestones: Vec<Milestone> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Milestones(bounty_id))
+            .ok_or(Error::MilestonesNotDefined)?;
+        let mut milestone = milestones.get(idx).ok_or(Error::InvalidMilestone)?;
+        if milestone.released {
+            return Err(Error::MilestoneAlreadyReleased);
+        }
+        if milestone.amount > escrow.remaining_amount {
+            return Err(Error::InsufficientFunds);
+        }
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        let transferred =
+            Self::release_or_hold(&env, &client, bounty_id, &contributor, milestone.amount)?;
+
+        Self::record_release(&env, bounty_id, &contributor, milestone.amount);
+        milestone.released = true;
+        milestones.set(idx, milestone.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::Milestones(bounty_id), &milestones);
+
+        escrow.remaining_amount -= milestone.amount;
+        if escrow.remaining_amount == 0 {
+            escrow.status = EscrowStatus::Released;
+            Self::record_settled(&env);
+        }
+        invariants::assert_escrow(&env, &escrow);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        let timestamp = env.ledger().timestamp();
+        events::emit_milestone_released(
+            &env,
+            events::MilestoneReleased {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                index: idx,
+                amount: milestone.amount,
+                recipient: contributor.clone(),
+                timestamp,
+            },
+        );
+        if transferred {
+            emit_funds_released(
+                &env,
+                FundsReleased {
+                    version: EVENT_VERSION_V2,
+                    bounty_id,
+                    amount: milestone.amount,
+                    recipient: contributor,
+                    timestamp,
+                },
+            );
+        }
+
+        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+        Ok(())
+    }
+
+    /// View: get the milestones defined for a bounty (empty if none).
+    pub fn get_milestones(env: Env, bounty_id: u64) -> Vec<Milestone> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Milestones(bounty_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Release a bounty's remaining amount linearly over `duration` seconds
+    /// (admin only).
+    ///
+    /// Nothing is transferred now; the contributor collects whatever has
+    /// vested so far with `claim_vested`. The bounty can no longer be
+    /// refunded once vesting starts.
+    pub fn release_vested(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        duration: u64,
+    ) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+        Self::ensure_release_allowed(&env, bounty_id)?;
+        Self::ensure_submission_approved(&env, bounty_id, &contributor)?;
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if duration == 0 {
+            return Err(Error::InvalidDeadline);
+        }
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if !escrow.status.is_locked() {
+            return Err(Error::FundsNotLocked);
+        }
+        Self::consume_multisig_approval(&env, bounty_id, &contributor, &escrow)?;
+        if Self::has_pending_claim(&env, bounty_id) {
+            return Err(Error::ClaimPending);
+        }
+
+        let schedule = VestingSchedule {
+            contributor: contributor.clone(),
+            total: escrow.remaining_amount,
+            start: env.ledger().timestamp(),
+            duration,
+            claimed: 0,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Vesting(bounty_id), &schedule);
+        escrow.status = EscrowStatus::Vesting;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        events::emit_vesting_started(
+            &env,
+            events::VestingStarted {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                contributor,
+                total: schedule.total,
+                start: schedule.start,
+                duration,
+            },
+        );
+        Ok(())
+    }
+
+    /// Contributor collects everything vested so far; returns the amount paid.
+    pub fn claim_vested(env: Env, bounty_id: u64) -> Result<i128, Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+        Self::ensure_not_disputed(&env, bounty_id)?;
+        let mut schedule = Self::get_vesting(env.clone(), bounty_id)?;
+        schedule.contributor.require_auth();
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        let elapsed = env
+            .ledger()
+            .timestamp()
+            .saturating_sub(schedule.start)
+            .min(schedule.duration);
+        let vested = schedule
+            .total
+            .checked_mul(elapsed as i128)
+            .ok_or(Error::InvalidAmount)?
+            / schedule.duration as i128;
+        let amount = vested - schedule.claimed;
+        if amount <= 0 {
+            return Err(Error::NothingToClaim);
+        }
+        if escrow.status != EscrowStatus::Vesting {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        Self::release_or_hold(&env, &client, bounty_id, &schedule.contributor, amount)?;
+        Self::record_release(&env, bounty_id, &schedule.contributor, amount);
+
+        schedule.claimed += amount;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Vesting(bounty_id), &schedule);
+
+        escrow.remaining_amount -= amount;
+        if escrow.remaining_amount == 0 {
+            escrow.status = EscrowStatus::Released;
+            Self::record_settled(&env);
+        }
+        invariants::assert_escrow(&env, &escrow);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        let timestamp = env.ledger().timestamp();
+        events::emit_vesting_claimed(
+            &env,
+            events::VestingClaimed {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                amount,
+                total_claimed: schedule.claimed,
+                timestamp,
+            },
+        );
+        emit_funds_released(
+            &env,
+            FundsReleased {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                amount,
+                recipient: schedule.contributor,
+                timestamp,
+            },
+        );
+
+        Ok(amount)
+    }
+
+    /// View: get the vesting schedule of a bounty.
+    pub fn get_vesting(env: Env, bounty_id: u64) -> Result<VestingSchedule, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Vesting(bounty_id))
+            .ok_or(Error::VestingNotFound)
+    }
+
+    /// Lock funds behind a hash-time-lock for `contributor`.
+    ///
+    /// Anyone holding the preimage of `hashlock` can settle the bounty to
+    /// `contributor` with `claim_htlc` before `timelock`; once it passes the
+    /// depositor refunds through `refund`.
+    pub fn lock_funds_htlc(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        contributor: Address,
+        hashlock: BytesN<32>,
+        timelock: u64,
+    ) -> Result<(), Error> {
+        if timelock <= env.ledger().timestamp() {
+            return Err(Error::InvalidDeadline);
+        }
+        Self::lock_funds(env.clone(), depositor, bounty_id, amount, timelock)?;
+
+        let lock = HtlcLock {
+            contributor,
+            hashlock,
+            timelock,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Htlc(bounty_id), &lock);
+
+        events::emit_htlc_locked(
+            &env,
+            events::HtlcLocked {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                contributor: lock.contributor,
+                hashlock: lock.hashlock,
+                timelock,
+            },
+        );
+        Ok(())
+    }
+
+    /// Release an HTLC bounty to its contributor by revealing the preimage.
+    ///
+    /// Permissionless: the payout can only go to the contributor fixed at
+    /// lock time. The preimage is published in the `htlc_clm` event.
+    pub fn claim_htlc(env: Env, bounty_id: u64, preimage: Bytes) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+        Self::ensure_not_disputed(&env, bounty_id)?;
+        Self::ensure_dependency_released(&env, bounty_id)?;
+        let lock = Self::get_htlc(env.clone(), bounty_id)?;
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if !escrow.status.is_locked() {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let now = env.ledger().timestamp();
+        if now >= lock.timelock {
+            return Err(Error::TimelockExpired);
+        }
+        let digest: BytesN<32> = env.crypto().sha256(&preimage).into();
+        if digest != lock.hashlock {
+            return Err(Error::InvalidPreimage);
+        }
+        Self::consume_multisig_approval(&env, bounty_id, &lock.contributor, &escrow)?;
+
+        let amount = escrow.remaining_amount;
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        let transferred =
+            Self::release_or_hold(&env, &client, bounty_id, &lock.contributor, amount)?;
+
+        escrow.remaining_amount = 0;
+        escrow.status = EscrowStatus::Released;
+        Self::record_settled(&env);
+        invariants::assert_escrow(&env, &escrow);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        events::emit_htlc_claimed(
+            &env,
+            events::HtlcClaimed {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                preimage,
+                timestamp: now,
+            },
+        );
+        if transferred {
+            emit_funds_released(
+                &env,
+                FundsReleased {
+                    version: EVENT_VERSION_V2,
+                    bounty_id,
+                    amount,
+                    recipient: lock.contributor,
+                    timestamp: now,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    /// View: get the hash-time-lock of a bounty.
+    pub fn get_htlc(env: Env, bounty_id: u64) -> Result<HtlcLock, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Htlc(bounty_id))
+            .ok_or(Error::HtlcNotFound)
+    }
+
+    /// Register the ed25519 key whose attestations release a bounty
+    /// (depositor only, while the bounty is locked).
+    pub fn set_release_oracle(
+        env: Env,
+        bounty_id: u64,
+        public_key: BytesN<32>,
+    ) -> Result<(), Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+        if !escrow.status.is_locked() {
+            return Err(Error::FundsNotLocked);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::ReleaseOracle(bounty_id), &public_key);
+        env.events()
+            .publish((symbol_short!("oracle"), bounty_id), public_key);
+        Ok(())
+    }
+
+    /// View: get the release oracle key of a bounty, if configured.
+    pub fn get_release_oracle(env: Env, bounty_id: u64) -> Option<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReleaseOracle(bounty_id))
+    }
+
+    /// Release the remaining funds to the contributor named in an attestation
+    /// signed by the bounty's release oracle (e.g. CI passed, PR merged).
+    ///
+    /// Anyone may submit the attestation. The release goes through the same
+    /// checks as `release_funds`: an assigned bounty pays only its approved
+    /// submitter, large bounties need multisig approval for the contributor,
+    /// and the payout is held during the clawback window. Panics if the
+    /// signature does not verify.
+    pub fn release_with_attestation(
+        env: Env,
+        bounty_id: u64,
+        payload: ReleaseAttestation,
+        signature: BytesN<64>,
     ) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
         }
-
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
+        Self::ensure_release_allowed(&env, bounty_id)?;
+        let public_key =
+            Self::get_release_oracle(env.clone(), bounty_id).ok_or(Error::ReleaseOracleNotSet)?;
+        if payload.bounty_id != bounty_id || payload.contract != env.current_contract_address() {
+            return Err(Error::InvalidAttestation);
+        }
+        Self::ensure_submission_approved(&env, bounty_id, &payload.contributor)?;
+        let now = env.ledger().timestamp();
+        if now > payload.expires_at {
+            return Err(Error::AttestationExpired);
         }
+        env.crypto()
+            .ed25519_verify(&public_key, &payload.clone().to_xdr(&env), &signature);
 
         let mut escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-
-        if escrow.status != EscrowStatus::Locked {
+            .ok_or(Error::BountyNotFound)?;
+        if !escrow.status.is_locked() {
             return Err(Error::FundsNotLocked);
         }
+        Self::consume_multisig_approval(&env, bounty_id, &payload.contributor, &escrow)?;
 
-        // Guard: zero or negative payout makes no sense and would corrupt state
-        if payout_amount <= 0 {
-            return Err(Error::InvalidAmount);
-        }
-
-        // Guard: prevent overpayment — payout cannot exceed what is still owed
-        if payout_amount > escrow.remaining_amount {
-            return Err(Error::InsufficientFunds);
-        }
-
+        let amount = escrow.remaining_amount;
         let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
         let client = token::Client::new(&env, &token_addr);
+        let transferred =
+            Self::release_or_hold(&env, &client, bounty_id, &payload.contributor, amount)?;
 
-        // Transfer only the requested partial amount to the contributor
-        client.transfer(
-            &env.current_contract_address(),
-            &contributor,
-            &payout_amount,
-        );
-
-        // Decrement remaining; this is always an exact integer subtraction — no rounding
-        escrow.remaining_amount -= payout_amount;
-
-        // Automatically transition to Released once fully paid out
-        if escrow.remaining_amount == 0 {
-            escrow.status = EscrowStatus::Released;
-        }
-
+        escrow.remaining_amount = 0;
+        escrow.status = EscrowStatus::Released;
+        Self::record_settled(&env);
+        invariants::assert_escrow(&env, &escrow);
         env.storage()
             .persistent()
             .set(&DataKey::Escrow(bounty_id), &escrow);
 
-        events::emit_funds_released(
-            &env,
-            FundsReleased {
-                version: EVENT_VERSION_V2,
-                bounty_id,
-                amount: payout_amount,
-                recipient: contributor.clone(),
-                timestamp: env.ledger().timestamp(),
-            },
-        );
-
+        if transferred {
+            emit_funds_released(
+                &env,
+                FundsReleased {
+                    version: EVENT_VERSION_V2,
+                    bounty_id,
+                    amount,
+                    recipient: payload.contributor,
+                    timestamp: now,
+                },
+            );
+        }
         Ok(())
     }
 
     /// Refund funds to the original depositor if the deadline has passed.
     /// Refunds the full remaining_amount (accounts for any prior partial releases).
+    ///
+    /// If refund approvals are queued, the oldest one is executed instead.
     pub fn refund(env: Env, bounty_id: u64) -> Result<(), Error> {
+        let approval_id = Self::refund_approval_ids(&env, bounty_id).first();
+        Self::refund_internal(env, bounty_id, approval_id)
+    }
+
+    /// Execute a specific queued refund approval, independently of any others.
+    pub fn refund_approved(env: Env, bounty_id: u64, approval_id: u64) -> Result<(), Error> {
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::RefundApproval(bounty_id, approval_id))
+        {
+            return Err(Error::RefundApprovalNotFound);
+        }
+        Self::refund_internal(env, bounty_id, Some(approval_id))
+    }
+
+    fn refund_internal(env: Env, bounty_id: u64, approval_id: Option<u64>) -> Result<(), Error> {
         if Self::check_paused(&env, symbol_short!("refund")) {
             return Err(Error::FundsPaused);
         }
+        Self::ensure_not_disputed(&env, bounty_id)?;
 
         if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
             return Err(Error::BountyNotFound);
@@ -1935,8 +5222,7 @@ impl BountyEscrowContract {
             .get(&DataKey::Escrow(bounty_id))
             .unwrap();
 
-        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
-        {
+        if !escrow.status.is_locked() && escrow.status != EscrowStatus::PartiallyRefunded {
             return Err(Error::FundsNotLocked);
         }
 
@@ -1957,8 +5243,11 @@ impl BountyEscrowContract {
         }
 
         let now = env.ledger().timestamp();
-        let approval_key = DataKey::RefundApproval(bounty_id);
-        let approval: Option<RefundApproval> = env.storage().persistent().get(&approval_key);
+        let approval: Option<RefundApproval> = approval_id.and_then(|approval_id| {
+            env.storage()
+                .persistent()
+                .get(&DataKey::RefundApproval(bounty_id, approval_id))
+        });
 
         // Refund is allowed if:
         // 1. Deadline has passed (returns full amount to depositor)
@@ -1984,12 +5273,14 @@ impl BountyEscrowContract {
 
         // Transfer the calculated refund amount to the designated recipient
         client.transfer(&env.current_contract_address(), &refund_to, &refund_amount);
+        Self::record_refunded(&env, refund_amount);
 
         invariants::assert_escrow(&env, &escrow);
         // Update escrow state: subtract the amount exactly refunded
         escrow.remaining_amount -= refund_amount;
         if is_full || escrow.remaining_amount == 0 {
             escrow.status = EscrowStatus::Refunded;
+            Self::record_settled(&env);
         } else {
             escrow.status = EscrowStatus::PartiallyRefunded;
         }
@@ -2011,9 +5302,11 @@ impl BountyEscrowContract {
             .persistent()
             .set(&DataKey::Escrow(bounty_id), &escrow);
 
-        // Remove approval after successful execution
-        if approval.is_some() {
-            env.storage().persistent().remove(&approval_key);
+        // Consume the approval; a full refund voids the rest of the queue
+        if escrow.status == EscrowStatus::Refunded {
+            Self::clear_refund_approvals(&env, bounty_id);
+        } else if let Some(app) = approval {
+            Self::remove_refund_approval(&env, bounty_id, app.approval_id);
         }
 
         emit_funds_refunded(
@@ -2029,6 +5322,211 @@ impl BountyEscrowContract {
         Ok(())
     }
 
+    /// Refund a bounty's remaining amount to several recipients at once
+    /// (admin only), e.g. the backers of a crowdfunded bounty.
+    ///
+    /// `amounts` must be positive and sum to the remaining amount; each
+    /// payout is recorded in the refund history. Before the deadline, paying
+    /// anyone other than the depositor also needs the depositor's
+    /// authorization.
+    pub fn refund_split(
+        env: Env,
+        bounty_id: u64,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+    ) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("refund")) {
+            return Err(Error::FundsPaused);
+        }
+        Self::ensure_not_disputed(&env, bounty_id)?;
+        let admin = Self::require_admin(&env)?;
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if !escrow.status.is_locked() && escrow.status != EscrowStatus::PartiallyRefunded {
+            return Err(Error::FundsNotLocked);
+        }
+        if Self::has_pending_claim(&env, bounty_id) {
+            return Err(Error::ClaimPending);
+        }
+
+        if recipients.is_empty() || recipients.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+        if amounts.len() != recipients.len() {
+            return Err(Error::BatchSizeMismatch);
+        }
+        if amounts.iter().any(|amount| amount <= 0)
+            || amounts.iter().sum::<i128>() != escrow.remaining_amount
+        {
+            return Err(Error::InvalidAmount);
+        }
+        let pays_other = recipients.iter().any(|r| r != escrow.depositor);
+        Self::require_refund_consent(&env, &escrow, pays_other, &admin);
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        let contract_address = env.current_contract_address();
+        let now = env.ledger().timestamp();
+        for (recipient, amount) in recipients.iter().zip(amounts.iter()) {
+            client.transfer(&contract_address, &recipient, &amount);
+            Self::record_refunded(&env, amount);
+            escrow.refund_history.push_back(RefundRecord {
+                amount,
+                recipient: recipient.clone(),
+                timestamp: now,
+                mode: RefundMode::Partial,
+            });
+            emit_funds_refunded(
+                &env,
+                FundsRefunded {
+                    version: EVENT_VERSION_V2,
+                    bounty_id,
+                    amount,
+                    refund_to: recipient,
+                    timestamp: now,
+                },
+            );
+        }
+
+        escrow.remaining_amount = 0;
+        escrow.status = EscrowStatus::Refunded;
+        Self::record_settled(&env);
+        invariants::assert_escrow(&env, &escrow);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::clear_refund_approvals(&env, bounty_id);
+        Ok(())
+    }
+
+    /// Set the share of each swept refund paid to the keeper, in basis points
+    /// (admin only, at most 1%).
+    pub fn set_keeper_incentive(env: Env, incentive_bps: i128) -> Result<(), Error> {
+        let admin = Self::require_admin(&env)?;
+
+        if !(0..=MAX_KEEPER_INCENTIVE).contains(&incentive_bps) {
+            return Err(Error::InvalidFeeRate);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::KeeperIncentive, &incentive_bps);
+        Self::emit_admin_config_updated(&env, symbol_short!("keeper"), admin);
+        Ok(())
+    }
+
+    /// Get the keeper incentive in basis points (defaults to 0)
+    pub fn get_keeper_incentive(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::KeeperIncentive)
+            .unwrap_or(0)
+    }
+
+    /// Refund every expired bounty in `bounty_ids` to its depositor.
+    ///
+    /// Callable by anyone. Bounties that are not yet expired, not refundable,
+    /// disputed or blocked by a pending claim are skipped rather than failing
+    /// the sweep. The configured keeper incentive is deducted from each refund
+    /// and paid to `keeper`.
+    ///
+    /// # Returns
+    /// Number of bounties refunded
+    pub fn sweep_expired(env: Env, keeper: Address, bounty_ids: Vec<u64>) -> Result<u32, Error> {
+        if Self::check_paused(&env, symbol_short!("refund")) {
+            return Err(Error::FundsPaused);
+        }
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        if bounty_ids.is_empty() || bounty_ids.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        let contract_address = env.current_contract_address();
+        let incentive_bps = Self::get_keeper_incentive(env.clone());
+        let now = env.ledger().timestamp();
+        let mut swept = 0u32;
+        let mut total_incentive: i128 = 0;
+
+        for bounty_id in bounty_ids.iter() {
+            let Some(mut escrow) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            else {
+                continue;
+            };
+            if !escrow.status.is_locked() && escrow.status != EscrowStatus::PartiallyRefunded {
+                continue;
+            }
+            if now < escrow.deadline || escrow.remaining_amount <= 0 {
+                continue;
+            }
+            if Self::has_pending_claim(&env, bounty_id)
+                || env.storage().persistent().has(&DataKey::Dispute(bounty_id))
+            {
+                continue;
+            }
+
+            // The keeper incentive is paid out of the refund, so the whole
+            // remaining amount is recorded as refunded.
+            let refunded = escrow.remaining_amount;
+            let incentive = math::bps_of(refunded, incentive_bps)?;
+            let refund_amount = refunded - incentive;
+            if incentive > 0 {
+                client.transfer(&contract_address, &keeper, &incentive);
+                total_incentive += incentive;
+            }
+            client.transfer(&contract_address, &escrow.depositor, &refund_amount);
+            Self::record_refunded(&env, refunded);
+
+            escrow.remaining_amount = 0;
+            escrow.status = EscrowStatus::Refunded;
+            Self::record_settled(&env);
+            escrow.refund_history.push_back(RefundRecord {
+                amount: refunded,
+                recipient: escrow.depositor.clone(),
+                timestamp: now,
+                mode: RefundMode::Full,
+            });
+            invariants::assert_escrow(&env, &escrow);
+            env.storage()
+                .persistent()
+                .set(&DataKey::Escrow(bounty_id), &escrow);
+            Self::clear_refund_approvals(&env, bounty_id);
+
+            emit_funds_refunded(
+                &env,
+                FundsRefunded {
+                    version: EVENT_VERSION_V2,
+                    bounty_id,
+                    amount: refund_amount,
+                    refund_to: escrow.depositor,
+                    timestamp: now,
+                },
+            );
+            swept += 1;
+        }
+
+        events::emit_expired_swept(
+            &env,
+            events::ExpiredSwept {
+                version: EVENT_VERSION_V2,
+                keeper,
+                count: swept,
+                total_incentive,
+                timestamp: now,
+            },
+        );
+        Ok(swept)
+    }
+
     /// Delegated refund path using a capability.
     /// This can be used for short-lived, bounded delegated refunds without granting admin rights.
     pub fn refund_with_capability(
@@ -2041,6 +5539,7 @@ impl BountyEscrowContract {
         if Self::check_paused(&env, symbol_short!("refund")) {
             return Err(Error::FundsPaused);
         }
+        Self::ensure_not_disputed(&env, bounty_id)?;
         if amount <= 0 {
             return Err(Error::InvalidAmount);
         }
@@ -2054,8 +5553,7 @@ impl BountyEscrowContract {
             .get(&DataKey::Escrow(bounty_id))
             .unwrap();
 
-        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
-        {
+        if !escrow.status.is_locked() && escrow.status != EscrowStatus::PartiallyRefunded {
             return Err(Error::FundsNotLocked);
         }
         if amount > escrow.remaining_amount {
@@ -2092,10 +5590,12 @@ impl BountyEscrowContract {
         let refund_to = escrow.depositor.clone();
 
         client.transfer(&env.current_contract_address(), &refund_to, &amount);
+        Self::record_refunded(&env, amount);
 
         escrow.remaining_amount -= amount;
         if escrow.remaining_amount == 0 {
             escrow.status = EscrowStatus::Refunded;
+            Self::record_settled(&env);
         } else {
             escrow.status = EscrowStatus::PartiallyRefunded;
         }
@@ -2134,6 +5634,7 @@ impl BountyEscrowContract {
         if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
             return Err(Error::BountyNotFound);
         }
+        Self::extend_bounty_ttl(&env, bounty_id);
         Ok(env
             .storage()
             .persistent()
@@ -2141,6 +5642,79 @@ impl BountyEscrowContract {
             .unwrap())
     }
 
+    /// Compact a settled (Released or Refunded) bounty (admin only).
+    ///
+    /// The escrow and its per-bounty records are deleted so they stop
+    /// accruing rent; only a small `ArchivedBounty` is kept, and the id can
+    /// never be reused. Archived bounties are skipped by the query views.
+    pub fn archive_bounty(env: Env, bounty_id: u64) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Released && escrow.status != EscrowStatus::Refunded {
+            return Err(Error::BountyNotSettled);
+        }
+        if Self::has_pending_release(&env, bounty_id) {
+            return Err(Error::BountyNotSettled);
+        }
+
+        let archived = ArchivedBounty {
+            depositor: escrow.depositor,
+            amount: escrow.amount,
+            status: escrow.status,
+            archived_at: env.ledger().timestamp(),
+        };
+        Self::clear_refund_approvals(&env, bounty_id);
+        Self::unlink_external_ref(&env, bounty_id);
+        Self::untag_bounty(&env, bounty_id);
+        Self::close_race(&env, bounty_id);
+        let storage = env.storage().persistent();
+        storage.set(&DataKey::Archived(bounty_id), &archived);
+        for key in [
+            DataKey::Escrow(bounty_id),
+            DataKey::Metadata(bounty_id),
+            DataKey::ReleaseApproval(bounty_id),
+            DataKey::PendingClaim(bounty_id),
+            DataKey::Milestones(bounty_id),
+            DataKey::ReleaseHistory(bounty_id),
+            DataKey::Assignee(bounty_id),
+            DataKey::Submission(bounty_id),
+            DataKey::Vesting(bounty_id),
+            DataKey::Htlc(bounty_id),
+            DataKey::ReleaseOracle(bounty_id),
+            DataKey::ReviewWindow(bounty_id),
+            DataKey::BountyArbiter(bounty_id),
+            DataKey::DependsOn(bounty_id),
+            DataKey::MinReputation(bounty_id),
+        ] {
+            storage.remove(&key);
+        }
+        storage.remove(&FeePolicyKey::ReleaseRate(bounty_id));
+
+        events::emit_bounty_archived(
+            &env,
+            events::BountyArchived {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                status: archived.status,
+                timestamp: archived.archived_at,
+            },
+        );
+        Ok(())
+    }
+
+    /// View: get the terminal record of an archived bounty.
+    pub fn get_archived_bounty(env: Env, bounty_id: u64) -> Result<ArchivedBounty, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Archived(bounty_id))
+            .ok_or(Error::BountyNotFound)
+    }
+
     /// view function to get contract balance of the token
     pub fn get_balance(env: Env) -> Result<i128, Error> {
         if !env.storage().instance().has(&DataKey::Token) {
@@ -2280,27 +5854,219 @@ impl BountyEscrowContract {
         depositor: Address,
         offset: u32,
         limit: u32,
-    ) -> Vec<EscrowWithId> {
+    ) -> Vec<EscrowWithId> {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::DepositorIndex(depositor))
+            .unwrap_or(Vec::new(&env));
+        let mut results = Vec::new(&env);
+        let start = offset.min(index.len());
+        let end = (offset + limit).min(index.len());
+
+        for i in start..end {
+            let bounty_id = index.get(i).unwrap();
+            if let Some(escrow) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                results.push_back(EscrowWithId { bounty_id, escrow });
+            }
+        }
+        results
+    }
+
+    /// List bounties in creation order as compact summaries, optionally
+    /// filtered by status. `offset` counts matching bounties only.
+    pub fn list_bounties(
+        env: Env,
+        status_filter: Option<EscrowStatus>,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<BountySummary> {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        let mut results = Vec::new(&env);
+        let mut count = 0u32;
+        let mut skipped = 0u32;
+
+        for i in 0..index.len() {
+            if count >= limit {
+                break;
+            }
+
+            let bounty_id = index.get(i).unwrap();
+            if let Some(escrow) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                if status_filter.as_ref().is_some_and(|s| *s != escrow.status) {
+                    continue;
+                }
+                if skipped < offset {
+                    skipped += 1;
+                    continue;
+                }
+                results.push_back(BountySummary {
+                    bounty_id,
+                    amount: escrow.amount,
+                    deadline: escrow.deadline,
+                    status: escrow.status,
+                });
+                count += 1;
+            }
+        }
+        results
+    }
+
+    /// Get a page of bounty ids locked by `depositor`, oldest first
+    pub fn get_bounties_by_depositor(
+        env: Env,
+        depositor: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<u64> {
         let index: Vec<u64> = env
             .storage()
             .persistent()
             .get(&DataKey::DepositorIndex(depositor))
             .unwrap_or(Vec::new(&env));
-        let mut results = Vec::new(&env);
         let start = offset.min(index.len());
-        let end = (offset + limit).min(index.len());
+        let end = offset.saturating_add(limit).min(index.len());
+        index.slice(start..end)
+    }
 
-        for i in start..end {
-            let bounty_id = index.get(i).unwrap();
-            if let Some(escrow) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-            {
-                results.push_back(EscrowWithId { bounty_id, escrow });
+    /// Add a newly locked bounty to the global and per-depositor indexes.
+    fn index_escrow(env: &Env, bounty_id: u64, depositor: &Address) {
+        let mut index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(env));
+        index.push_back(bounty_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::EscrowIndex, &index);
+
+        let mut depositor_index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::DepositorIndex(depositor.clone()))
+            .unwrap_or(Vec::new(env));
+        depositor_index.push_back(bounty_id);
+        env.storage().persistent().set(
+            &DataKey::DepositorIndex(depositor.clone()),
+            &depositor_index,
+        );
+        Self::extend_bounty_ttl(env, bounty_id);
+    }
+
+    /// Keep a bounty's core records alive: the escrow (which holds its
+    /// refund history), metadata, release history and the indexes it is in.
+    /// Cheap enough to run on every lock, release and read.
+    fn extend_bounty_ttl(env: &Env, bounty_id: u64) {
+        let storage = env.storage().persistent();
+        let Some(escrow) = storage.get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id)) else {
+            return;
+        };
+        for key in [
+            DataKey::Escrow(bounty_id),
+            DataKey::Metadata(bounty_id),
+            DataKey::ReleaseHistory(bounty_id),
+            DataKey::EscrowIndex,
+            DataKey::DepositorIndex(escrow.depositor),
+        ] {
+            Self::extend_if_present(env, &key);
+        }
+    }
+
+    /// `extend_bounty_ttl` plus every optional per-bounty record (milestones,
+    /// vesting, HTLC, assignment, submissions, approvals, tags, races, the
+    /// external reference and a held release), so a keeper bump keeps the
+    /// whole bounty usable.
+    fn extend_all_bounty_ttl(env: &Env, bounty_id: u64) {
+        Self::extend_bounty_ttl(env, bounty_id);
+        let storage = env.storage().persistent();
+        for key in [
+            DataKey::Milestones(bounty_id),
+            DataKey::Vesting(bounty_id),
+            DataKey::Htlc(bounty_id),
+            DataKey::ReleaseOracle(bounty_id),
+            DataKey::Assignee(bounty_id),
+            DataKey::Submission(bounty_id),
+            DataKey::ReviewWindow(bounty_id),
+            DataKey::ReleaseApproval(bounty_id),
+            DataKey::PendingClaim(bounty_id),
+            DataKey::Dispute(bounty_id),
+            DataKey::DependsOn(bounty_id),
+            DataKey::BountyArbiter(bounty_id),
+            DataKey::MinReputation(bounty_id),
+        ] {
+            Self::extend_if_present(env, &key);
+        }
+        if let Some(reference) =
+            storage.get::<DataKey, BytesN<32>>(&DataKey::ExternalRef(bounty_id))
+        {
+            Self::extend_if_present(env, &DataKey::ExternalRef(bounty_id));
+            Self::extend_if_present(env, &DataKey::RefIndex(reference));
+        }
+        if let Some(ids) = storage.get::<DataKey, Vec<u64>>(&DataKey::RefundApprovalIds(bounty_id))
+        {
+            Self::extend_if_present(env, &DataKey::RefundApprovalIds(bounty_id));
+            for approval_id in ids.iter() {
+                Self::extend_if_present(env, &DataKey::RefundApproval(bounty_id, approval_id));
             }
         }
-        results
+        if let Some(entrants) =
+            storage.get::<DataKey, Vec<RaceEntrant>>(&DataKey::RaceEntrants(bounty_id))
+        {
+            Self::extend_if_present(env, &DataKey::RaceEntrants(bounty_id));
+            for entrant in entrants.iter() {
+                Self::extend_if_present(
+                    env,
+                    &DataKey::RaceSubmission(bounty_id, entrant.contributor),
+                );
+            }
+        }
+        if let Some(tags) = storage.get::<DataKey, Vec<Symbol>>(&DataKey::Tags(bounty_id)) {
+            Self::extend_if_present(env, &DataKey::Tags(bounty_id));
+            for tag in tags.iter() {
+                Self::extend_if_present(env, &DataKey::TagIndex(tag));
+            }
+        }
+        for recipient in Self::get_held_recipients(env.clone(), bounty_id).iter() {
+            Self::extend_if_present(env, &ClawbackKey::Pending(bounty_id, recipient));
+        }
+        Self::extend_if_present(env, &ClawbackKey::Held(bounty_id));
+        Self::extend_if_present(env, &FeePolicyKey::ReleaseRate(bounty_id));
+    }
+
+    fn extend_if_present<K>(env: &Env, key: &K)
+    where
+        K: soroban_sdk::IntoVal<Env, soroban_sdk::Val>,
+    {
+        let storage = env.storage().persistent();
+        if storage.has(key) {
+            storage.extend_ttl(key, BOUNTY_TTL_THRESHOLD, BOUNTY_TTL_EXTEND_TO);
+        }
+    }
+
+    /// Extend the storage TTL of a bounty's records and the contract instance.
+    /// Anyone may call this, e.g. a keeper keeping long-deadline bounties alive.
+    pub fn bump_bounty_ttl(env: Env, bounty_id: u64) -> Result<(), Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        Self::extend_all_bounty_ttl(&env, bounty_id);
+        env.storage()
+            .instance()
+            .extend_ttl(BOUNTY_TTL_THRESHOLD, BOUNTY_TTL_EXTEND_TO);
+        Ok(())
     }
 
     /// Get aggregate statistics
@@ -2327,7 +6093,12 @@ impl BountyEscrowContract {
                 .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
             {
                 match escrow.status {
-                    EscrowStatus::Locked => {
+                    EscrowStatus::Locked
+                    | EscrowStatus::Assigned
+                    | EscrowStatus::Submitted
+                    | EscrowStatus::UnderReview
+                    | EscrowStatus::Disputed
+                    | EscrowStatus::Vesting => {
                         stats.total_locked += escrow.amount;
                         stats.count_locked += 1;
                     }
@@ -2388,10 +6159,43 @@ impl BountyEscrowContract {
         env.storage()
             .instance()
             .set(&DataKey::AmountPolicy, &(min_amount, max_amount));
+        Self::emit_admin_config_updated(&env, symbol_short!("amt_pol"), admin);
+
+        Ok(())
+    }
+
+    /// Cap how far in the future a lock deadline may be set (admin only).
+    ///
+    /// `lock_funds` and `batch_lock_funds` reject deadlines later than
+    /// `now + max_deadline_duration` with InvalidDeadline. Passing 0 removes
+    /// the cap.
+    pub fn set_max_deadline_duration(env: Env, max_deadline_duration: u64) -> Result<(), Error> {
+        let admin = Self::require_admin(&env)?;
+
+        if max_deadline_duration == 0 {
+            env.storage()
+                .instance()
+                .remove(&DataKey::MaxDeadlineDuration);
+        } else {
+            env.storage()
+                .instance()
+                .set(&DataKey::MaxDeadlineDuration, &max_deadline_duration);
+        }
+        Self::emit_admin_config_updated(&env, symbol_short!("max_dl"), admin);
 
         Ok(())
     }
 
+    /// Return the configured maximum deadline duration in seconds, if any.
+    pub fn get_max_deadline_duration(env: Env) -> Option<u64> {
+        env.storage().instance().get(&DataKey::MaxDeadlineDuration)
+    }
+
+    /// Return the configured `(min_amount, max_amount)` lock policy, if any.
+    pub fn get_amount_policy(env: Env) -> Option<(i128, i128)> {
+        env.storage().instance().get(&DataKey::AmountPolicy)
+    }
+
     /// Get escrow IDs by status
     pub fn get_escrow_ids_by_status(
         env: Env,
@@ -2439,6 +6243,7 @@ impl BountyEscrowContract {
             .ok_or(Error::NotInitialized)?;
         current.require_auth();
         anti_abuse::set_admin(&env, admin);
+        Self::emit_admin_config_updated(&env, symbol_short!("abuse_adm"), current);
         Ok(())
     }
 
@@ -2451,13 +6256,18 @@ impl BountyEscrowContract {
         whitelisted_address: Address,
         whitelisted: bool,
     ) -> Result<(), Error> {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(Error::NotInitialized)?;
-        admin.require_auth();
-        anti_abuse::set_whitelist(&env, whitelisted_address, whitelisted);
+        let admin = Self::require_admin(&env)?;
+        anti_abuse::set_whitelist(&env, whitelisted_address.clone(), whitelisted);
+        events::emit_whitelist_updated(
+            &env,
+            events::WhitelistUpdated {
+                version: EVENT_VERSION_V2,
+                address: whitelisted_address,
+                whitelisted,
+                admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
         Ok(())
     }
 
@@ -2468,18 +6278,14 @@ impl BountyEscrowContract {
         max_operations: u32,
         cooldown_period: u64,
     ) -> Result<(), Error> {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(Error::NotInitialized)?;
-        admin.require_auth();
+        let admin = Self::require_admin(&env)?;
         let config = anti_abuse::AntiAbuseConfig {
             window_size,
             max_operations,
             cooldown_period,
         };
         anti_abuse::set_config(&env, config);
+        Self::emit_rate_limit_config_updated(&env, RateLimitRole::Depositor, admin);
         Ok(())
     }
 
@@ -2493,6 +6299,151 @@ impl BountyEscrowContract {
         }
     }
 
+    /// Set the rate limit for one role's operations (admin only).
+    ///
+    /// Passing `None` for `RateLimitRole::Admin` exempts admin/approver
+    /// operations again; for `RateLimitRole::Depositor` it restores the default.
+    pub fn set_role_rate_limit(
+        env: Env,
+        role: RateLimitRole,
+        config: Option<AntiAbuseConfigView>,
+    ) -> Result<(), Error> {
+        let admin = Self::require_admin(&env)?;
+        let config = config.map(|c| anti_abuse::AntiAbuseConfig {
+            window_size: c.window_size,
+            max_operations: c.max_operations,
+            cooldown_period: c.cooldown_period,
+        });
+        anti_abuse::set_role_config(&env, role.clone(), config);
+        Self::emit_rate_limit_config_updated(&env, role, admin);
+        Ok(())
+    }
+
+    fn emit_rate_limit_config_updated(env: &Env, role: RateLimitRole, admin: Address) {
+        let config = anti_abuse::get_role_config(env, role.clone());
+        events::emit_rate_limit_config_updated(
+            env,
+            events::RateLimitConfigUpdated {
+                version: EVENT_VERSION_V2,
+                role,
+                enabled: config.is_some(),
+                window_size: config.as_ref().map_or(0, |c| c.window_size),
+                max_operations: config.as_ref().map_or(0, |c| c.max_operations),
+                cooldown_period: config.as_ref().map_or(0, |c| c.cooldown_period),
+                admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+
+    /// Turn the admin override on or off (admin only). While on, operations
+    /// authorized by the admin or approver bypass the cooldown and rate
+    /// limits, e.g. to push through emergency refunds.
+    pub fn set_admin_rate_limit_override(env: Env, enabled: bool) -> Result<(), Error> {
+        let admin = Self::require_admin(&env)?;
+        anti_abuse::set_admin_override(&env, enabled);
+        Self::emit_admin_config_updated(&env, symbol_short!("rl_ovr"), admin);
+        Ok(())
+    }
+
+    /// View: whether the admin rate limit override is on.
+    pub fn get_admin_rate_limit_override(env: Env) -> bool {
+        anti_abuse::is_admin_override(&env)
+    }
+
+    /// Get the rate limit for one role (None means the role is exempt).
+    pub fn get_role_rate_limit(env: Env, role: RateLimitRole) -> Option<AntiAbuseConfigView> {
+        anti_abuse::get_role_config(&env, role).map(|c| AntiAbuseConfigView {
+            window_size: c.window_size,
+            max_operations: c.max_operations,
+            cooldown_period: c.cooldown_period,
+        })
+    }
+
+    /// Give one operation type its own rate limit, separate from the limit
+    /// its role shares with other operations (admin only). Passing `None`
+    /// makes the operation share its role's limit again.
+    pub fn set_op_rate_limit(
+        env: Env,
+        op: RateLimitOp,
+        config: Option<AntiAbuseConfigView>,
+    ) -> Result<(), Error> {
+        let admin = Self::require_admin(&env)?;
+        let config = config.map(|c| anti_abuse::AntiAbuseConfig {
+            window_size: c.window_size,
+            max_operations: c.max_operations,
+            cooldown_period: c.cooldown_period,
+        });
+        anti_abuse::set_op_config(&env, op, config);
+        Self::emit_admin_config_updated(&env, symbol_short!("rl_op"), admin);
+        Ok(())
+    }
+
+    /// Get the rate limit of one operation type (None means it shares its
+    /// role's limit).
+    pub fn get_op_rate_limit(env: Env, op: RateLimitOp) -> Option<AntiAbuseConfigView> {
+        anti_abuse::get_op_config(&env, op).map(|c| AntiAbuseConfigView {
+            window_size: c.window_size,
+            max_operations: c.max_operations,
+            cooldown_period: c.cooldown_period,
+        })
+    }
+
+    /// Seconds `address` must wait before its next `op` operation in `role`,
+    /// under the operation's own limit if it has one and the role's shared
+    /// limit otherwise (0 if it can act now or is exempt).
+    pub fn get_op_rate_limit_retry_after(
+        env: Env,
+        role: RateLimitRole,
+        op: RateLimitOp,
+        address: Address,
+    ) -> u64 {
+        anti_abuse::op_retry_after(&env, role, op, address)
+    }
+
+    /// Like `get_rate_limit_status`, but for the limit that applies to `op`:
+    /// the operation's own bucket if it has one, the role's otherwise.
+    pub fn get_op_rate_limit_status(
+        env: Env,
+        role: RateLimitRole,
+        op: RateLimitOp,
+        address: Address,
+    ) -> RateLimitStatus {
+        anti_abuse::op_status(&env, role, op, address)
+    }
+
+    /// Delete rate limit entries of `addresses` whose window and cooldown
+    /// have both lapsed, so they stop accruing rent. Anyone (e.g. a keeper)
+    /// can call this; live entries are left untouched. Returns the number
+    /// of entries removed.
+    pub fn prune_rate_data(env: Env, addresses: Vec<Address>) -> Result<u32, Error> {
+        if addresses.is_empty() || addresses.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+        let mut pruned = 0u32;
+        for address in addresses.iter() {
+            pruned += anti_abuse::prune(&env, address);
+        }
+        env.events().publish((symbol_short!("rl_prune"),), pruned);
+        Ok(pruned)
+    }
+
+    /// Seconds `address` must wait before its next `role` operation without
+    /// hitting CooldownActive or RateLimited (0 if it can act now).
+    pub fn get_rate_limit_retry_after(env: Env, role: RateLimitRole, address: Address) -> u64 {
+        anti_abuse::retry_after(&env, role, address)
+    }
+
+    /// Operations `address` has used in its current `role` window, when the
+    /// window resets and the cooldown left, so callers can schedule retries.
+    pub fn get_rate_limit_status(
+        env: Env,
+        role: RateLimitRole,
+        address: Address,
+    ) -> RateLimitStatus {
+        anti_abuse::status(&env, role, address)
+    }
+
     /// Retrieves the refund history for a specific bounty.
     ///
     /// # Arguments
@@ -2514,6 +6465,36 @@ impl BountyEscrowContract {
         Ok(escrow.refund_history)
     }
 
+    /// Check that the token balance covers every outstanding obligation.
+    ///
+    /// Compares the running liabilities total, which lock, release, refund
+    /// and dispute bond transfers keep up to date, with the contract's
+    /// balance, so the cost does not grow with the number of bounties.
+    /// Until an upgraded deployment runs `migrate_liabilities`, the total is
+    /// summed from the per-bounty records instead. Never panics, so
+    /// monitoring can poll it to detect corrupted accounting.
+    pub fn assert_solvency(env: Env) -> Result<SolvencyReport, Error> {
+        let token: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .ok_or(Error::NotInitialized)?;
+        let balance = token::Client::new(&env, &token).balance(&env.current_contract_address());
+        let liabilities: i128 = env
+            .storage()
+            .instance()
+            .get(&SolvencyKey::Liabilities)
+            .unwrap_or_else(|| Self::sum_liabilities(&env));
+
+        Ok(SolvencyReport {
+            token,
+            liabilities,
+            balance,
+            active_bounties: Self::get_stats(env.clone()).active_count,
+            solvent: liabilities <= balance,
+        })
+    }
+
     /// NEW: Verify escrow invariants for a specific bounty
     pub fn verify_state(env: Env, bounty_id: u64) -> bool {
         if let Some(escrow) = env
@@ -2537,7 +6518,7 @@ impl BountyEscrowContract {
     ///   - can_refund: Whether refund is possible
     ///   - deadline_passed: Whether the deadline has passed
     ///   - remaining: Remaining amount in escrow
-    ///   - approval: Optional refund approval if exists
+    ///   - approval: The oldest queued refund approval, if any
     /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
     pub fn get_refund_eligibility(
         env: Env,
@@ -2555,25 +6536,12 @@ impl BountyEscrowContract {
         let now = env.ledger().timestamp();
         let deadline_passed = now >= escrow.deadline;
 
-        let approval = if env
-            .storage()
-            .persistent()
-            .has(&DataKey::RefundApproval(bounty_id))
-        {
-            Some(
-                env.storage()
-                    .persistent()
-                    .get(&DataKey::RefundApproval(bounty_id))
-                    .unwrap(),
-            )
-        } else {
-            None
-        };
+        let approval = Self::get_refund_approvals(env.clone(), bounty_id).first();
 
         // can_refund is true if:
         // 1. Status is Locked or PartiallyRefunded AND
         // 2. (deadline has passed OR there's an approval)
-        let can_refund = (escrow.status == EscrowStatus::Locked
+        let can_refund = (escrow.status.is_locked()
             || escrow.status == EscrowStatus::PartiallyRefunded)
             && (deadline_passed || approval.is_some());
 
@@ -2630,6 +6598,10 @@ impl BountyEscrowContract {
                 .storage()
                 .persistent()
                 .has(&DataKey::Escrow(item.bounty_id))
+                || env
+                    .storage()
+                    .persistent()
+                    .has(&DataKey::Archived(item.bounty_id))
             {
                 return Err(Error::BountyExists);
             }
@@ -2638,6 +6610,7 @@ impl BountyEscrowContract {
             if item.amount <= 0 {
                 return Err(Error::InvalidAmount);
             }
+            Self::check_lock_limits(&env, item.amount, item.deadline)?;
 
             // Check for duplicate bounty_ids in the batch
             let mut count = 0u32;
@@ -2671,50 +6644,161 @@ impl BountyEscrowContract {
         // Process all items (atomic - all succeed or all fail)
         let mut locked_count = 0u32;
         for item in items.iter() {
-            // Transfer funds from depositor to contract
-            client.transfer(&item.depositor, &contract_address, &item.amount);
+            // Transfer funds from depositor to contract
+            client.transfer(&item.depositor, &contract_address, &item.amount);
+            Self::record_locked(&env, item.amount, true);
+
+            // Create escrow record
+            let escrow = Escrow {
+                depositor: item.depositor.clone(),
+                amount: item.amount,
+                status: EscrowStatus::Locked,
+                deadline: item.deadline,
+                refund_history: vec![&env],
+                remaining_amount: item.amount,
+            };
+
+            // Store escrow
+            env.storage()
+                .persistent()
+                .set(&DataKey::Escrow(item.bounty_id), &escrow);
+            Self::index_escrow(&env, item.bounty_id, &item.depositor);
+
+            // Emit individual event for each locked bounty
+            emit_funds_locked(
+                &env,
+                FundsLocked {
+                    version: EVENT_VERSION_V2,
+                    bounty_id: item.bounty_id,
+                    amount: item.amount,
+                    depositor: item.depositor.clone(),
+                    deadline: item.deadline,
+                },
+            );
+
+            locked_count += 1;
+        }
+
+        // Emit batch event
+        emit_batch_funds_locked(
+            &env,
+            BatchFundsLocked {
+                count: locked_count,
+                total_amount: items.iter().map(|i| i.amount).sum(),
+                timestamp,
+            },
+        );
+
+        Ok(locked_count)
+    }
+
+    /// Create many bounties for a single depositor in one transaction.
+    ///
+    /// Unlike `batch_lock_funds`, the depositor authorizes once and the total
+    /// funding is pulled with a single token transfer. Every item is validated
+    /// up front against the same rules as `lock_funds`, so the batch is
+    /// all-or-nothing.
+    ///
+    /// # Errors
+    /// * InvalidBatchSize - if the batch is empty or exceeds MAX_BATCH_SIZE
+    /// * BountyExists - if any bounty_id already exists or was archived
+    /// * DuplicateBountyId - if a bounty_id appears twice in the batch
+    /// * InvalidAmount - if an amount is not positive or the total overflows
+    pub fn lock_funds_batch(
+        env: Env,
+        depositor: Address,
+        items: Vec<BountyParams>,
+    ) -> Result<u32, Error> {
+        let role = if env.storage().instance().get(&DataKey::Admin) == Some(depositor.clone()) {
+            RateLimitRole::Admin
+        } else {
+            RateLimitRole::Depositor
+        };
+        anti_abuse::check_rate_limit(&env, role, RateLimitOp::Lock, depositor.clone())?;
+
+        if Self::check_paused(&env, symbol_short!("lock")) {
+            return Err(Error::FundsPaused);
+        }
+        if items.is_empty() || items.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        depositor.require_auth();
+
+        let mut total: i128 = 0;
+        for (i, item) in items.iter().enumerate() {
+            if env
+                .storage()
+                .persistent()
+                .has(&DataKey::Escrow(item.bounty_id))
+                || env
+                    .storage()
+                    .persistent()
+                    .has(&DataKey::Archived(item.bounty_id))
+            {
+                return Err(Error::BountyExists);
+            }
+            if item.amount <= 0 {
+                return Err(Error::InvalidAmount);
+            }
+            Self::check_lock_limits(&env, item.amount, item.deadline)?;
+            if items
+                .iter()
+                .skip(i + 1)
+                .any(|other| other.bounty_id == item.bounty_id)
+            {
+                return Err(Error::DuplicateBountyId);
+            }
+            total = total.checked_add(item.amount).ok_or(Error::InvalidAmount)?;
+        }
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&depositor, &env.current_contract_address(), &total);
+
+        for item in items.iter() {
+            Self::record_locked(&env, item.amount, true);
 
-            // Create escrow record
             let escrow = Escrow {
-                depositor: item.depositor.clone(),
+                depositor: depositor.clone(),
                 amount: item.amount,
                 status: EscrowStatus::Locked,
                 deadline: item.deadline,
                 refund_history: vec![&env],
                 remaining_amount: item.amount,
             };
+            invariants::assert_escrow(&env, &escrow);
 
-            // Store escrow
             env.storage()
                 .persistent()
                 .set(&DataKey::Escrow(item.bounty_id), &escrow);
+            Self::index_escrow(&env, item.bounty_id, &depositor);
 
-            // Emit individual event for each locked bounty
             emit_funds_locked(
                 &env,
                 FundsLocked {
                     version: EVENT_VERSION_V2,
                     bounty_id: item.bounty_id,
                     amount: item.amount,
-                    depositor: item.depositor.clone(),
+                    depositor: depositor.clone(),
                     deadline: item.deadline,
                 },
             );
-
-            locked_count += 1;
         }
 
-        // Emit batch event
         emit_batch_funds_locked(
             &env,
             BatchFundsLocked {
-                count: locked_count,
-                total_amount: items.iter().map(|i| i.amount).sum(),
-                timestamp,
+                count: items.len(),
+                total_amount: total,
+                timestamp: env.ledger().timestamp(),
             },
         );
 
-        Ok(locked_count)
+        Ok(items.len())
     }
 
     /// Batch release funds to multiple contributors in a single transaction.
@@ -2753,10 +6837,15 @@ impl BountyEscrowContract {
 
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
+        anti_abuse::check_rate_limit(
+            &env,
+            RateLimitRole::Admin,
+            RateLimitOp::Release,
+            admin.clone(),
+        )?;
 
         let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
         let client = token::Client::new(&env, &token_addr);
-        let contract_address = env.current_contract_address();
         let timestamp = env.ledger().timestamp();
 
         // Validate all items before processing (all-or-nothing approach)
@@ -2778,9 +6867,11 @@ impl BountyEscrowContract {
                 .unwrap();
 
             // Check if funds are locked
-            if escrow.status != EscrowStatus::Locked {
+            if !escrow.status.is_locked() {
                 return Err(Error::FundsNotLocked);
             }
+            Self::ensure_release_allowed(&env, item.bounty_id)?;
+            Self::ensure_submission_approved(&env, item.bounty_id, &item.contributor)?;
 
             // Check for duplicate bounty_ids in the batch
             let mut count = 0u32;
@@ -2794,10 +6885,20 @@ impl BountyEscrowContract {
             }
 
             total_amount = total_amount
-                .checked_add(escrow.amount)
+                .checked_add(escrow.remaining_amount)
                 .ok_or(Error::InvalidAmount)?;
         }
 
+        // Approvals are consumed only once every item has passed its checks
+        for item in items.iter() {
+            let escrow: Escrow = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Escrow(item.bounty_id))
+                .unwrap();
+            Self::consume_multisig_approval(&env, item.bounty_id, &item.contributor, &escrow)?;
+        }
+
         // Process all items (atomic - all succeed or all fail)
         let mut released_count = 0u32;
         for item in items.iter() {
@@ -2808,25 +6909,32 @@ impl BountyEscrowContract {
                 .unwrap();
 
             // Transfer funds to contributor
-            client.transfer(&contract_address, &item.contributor, &escrow.amount);
+            let amount = escrow.remaining_amount;
+            let transferred =
+                Self::release_or_hold(&env, &client, item.bounty_id, &item.contributor, amount)?;
 
             // Update escrow status
             escrow.status = EscrowStatus::Released;
+            Self::record_settled(&env);
+            escrow.remaining_amount = 0;
+            invariants::assert_escrow(&env, &escrow);
             env.storage()
                 .persistent()
                 .set(&DataKey::Escrow(item.bounty_id), &escrow);
 
             // Emit individual event for each released bounty
-            emit_funds_released(
-                &env,
-                FundsReleased {
-                    version: EVENT_VERSION_V2,
-                    bounty_id: item.bounty_id,
-                    amount: escrow.amount,
-                    recipient: item.contributor.clone(),
-                    timestamp,
-                },
-            );
+            if transferred {
+                emit_funds_released(
+                    &env,
+                    FundsReleased {
+                        version: EVENT_VERSION_V2,
+                        bounty_id: item.bounty_id,
+                        amount,
+                        recipient: item.contributor.clone(),
+                        timestamp,
+                    },
+                );
+            }
 
             released_count += 1;
         }
@@ -2843,6 +6951,134 @@ impl BountyEscrowContract {
 
         Ok(released_count)
     }
+
+    /// Release many bounties with a single admin authorization.
+    ///
+    /// With `atomic` set this behaves exactly like `batch_release_funds`: any
+    /// failing item reverts the whole batch. Otherwise items are released one
+    /// at a time and failures are reported in the returned results instead of
+    /// aborting the batch, so one stale entry cannot block end-of-sprint
+    /// settlement.
+    pub fn release_batch(
+        env: Env,
+        items: Vec<ReleaseFundsItem>,
+        atomic: bool,
+    ) -> Result<Vec<ReleaseItemResult>, Error> {
+        if atomic {
+            Self::batch_release_funds(env.clone(), items.clone())?;
+            let mut results = Vec::new(&env);
+            for item in items.iter() {
+                results.push_back(ReleaseItemResult {
+                    bounty_id: item.bounty_id,
+                    released: true,
+                    error_code: 0,
+                });
+            }
+            return Ok(results);
+        }
+
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+        if items.is_empty() || items.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        let admin = Self::require_admin(&env)?;
+        anti_abuse::check_rate_limit(
+            &env,
+            RateLimitRole::Admin,
+            RateLimitOp::Release,
+            admin.clone(),
+        )?;
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        let timestamp = env.ledger().timestamp();
+
+        let mut results = Vec::new(&env);
+        let mut released_count = 0u32;
+        let mut total_amount: i128 = 0;
+        for item in items.iter() {
+            let result = match Self::release_batch_item(&env, &client, &item, timestamp) {
+                Ok(amount) => {
+                    released_count += 1;
+                    total_amount = total_amount.saturating_add(amount);
+                    ReleaseItemResult {
+                        bounty_id: item.bounty_id,
+                        released: true,
+                        error_code: 0,
+                    }
+                }
+                Err(err) => ReleaseItemResult {
+                    bounty_id: item.bounty_id,
+                    released: false,
+                    error_code: err as u32,
+                },
+            };
+            results.push_back(result);
+        }
+
+        emit_batch_funds_released(
+            &env,
+            BatchFundsReleased {
+                count: released_count,
+                total_amount,
+                timestamp,
+            },
+        );
+
+        Ok(results)
+    }
+
+    /// Validate and release a single lenient `release_batch` entry, returning
+    /// the amount paid out. Nothing is written unless every check passes.
+    fn release_batch_item(
+        env: &Env,
+        client: &token::Client,
+        item: &ReleaseFundsItem,
+        timestamp: u64,
+    ) -> Result<i128, Error> {
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(item.bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if !escrow.status.is_locked() {
+            return Err(Error::FundsNotLocked);
+        }
+        Self::ensure_release_allowed(env, item.bounty_id)?;
+        Self::ensure_submission_approved(env, item.bounty_id, &item.contributor)?;
+        // Last check: approvals must not be spent on an item that fails
+        Self::consume_multisig_approval(env, item.bounty_id, &item.contributor, &escrow)?;
+
+        let amount = escrow.remaining_amount;
+        let transferred =
+            Self::release_or_hold(env, client, item.bounty_id, &item.contributor, amount)?;
+        escrow.status = EscrowStatus::Released;
+        Self::record_settled(env);
+        escrow.remaining_amount = 0;
+        invariants::assert_escrow(env, &escrow);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(item.bounty_id), &escrow);
+
+        if transferred {
+            emit_funds_released(
+                env,
+                FundsReleased {
+                    version: EVENT_VERSION_V2,
+                    bounty_id: item.bounty_id,
+                    amount,
+                    recipient: item.contributor.clone(),
+                    timestamp,
+                },
+            );
+        }
+
+        Ok(amount)
+    }
+
     pub fn update_metadata(
         env: Env,
         _admin: Address,
@@ -2875,6 +7111,271 @@ impl BountyEscrowContract {
             .get(&DataKey::Metadata(bounty_id))
             .ok_or(Error::BountyNotFound)
     }
+
+    /// Lock funds and link the bounty to an external reference in one call,
+    /// so no other bounty can claim the reference in between. Returns the
+    /// stored hash.
+    pub fn lock_funds_with_ref(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        reference: Bytes,
+    ) -> Result<BytesN<32>, Error> {
+        Self::lock_funds(env.clone(), depositor, bounty_id, amount, deadline)?;
+        Self::store_external_ref(&env, bounty_id, &reference)
+    }
+
+    /// Link a bounty to an external reference such as "org/repo#123"
+    /// (depositor or admin). Returns the stored hash.
+    ///
+    /// The reference is trimmed and ASCII-lowercased before hashing, so
+    /// "Org/Repo#123 " and "org/repo#123" match. Fails with ExternalRefInUse
+    /// while another unsettled bounty holds the same reference.
+    pub fn link_external_ref(
+        env: Env,
+        caller: Address,
+        bounty_id: u64,
+        reference: Bytes,
+    ) -> Result<BytesN<32>, Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        let admin: Option<Address> = env.storage().instance().get(&DataKey::Admin);
+        if caller != escrow.depositor && Some(&caller) != admin.as_ref() {
+            return Err(Error::Unauthorized);
+        }
+        caller.require_auth();
+
+        Self::store_external_ref(&env, bounty_id, &reference)
+    }
+
+    fn store_external_ref(
+        env: &Env,
+        bounty_id: u64,
+        reference: &Bytes,
+    ) -> Result<BytesN<32>, Error> {
+        let ref_hash = Self::external_ref_hash(env, reference);
+        let ref_key = DataKey::RefIndex(ref_hash.clone());
+        if let Some(other_id) = env.storage().persistent().get::<DataKey, u64>(&ref_key) {
+            let other_active = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Escrow>(&DataKey::Escrow(other_id))
+                .is_some_and(|other| {
+                    other.status != EscrowStatus::Released && other.status != EscrowStatus::Refunded
+                });
+            if other_id != bounty_id && other_active {
+                return Err(Error::ExternalRefInUse);
+            }
+        }
+
+        Self::unlink_external_ref(env, bounty_id);
+        env.storage().persistent().set(&ref_key, &bounty_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ExternalRef(bounty_id), &ref_hash);
+        env.events()
+            .publish((symbol_short!("ext_ref"), bounty_id), ref_hash.clone());
+        Ok(ref_hash)
+    }
+
+    /// View: get the external reference hash linked to a bounty, if any.
+    pub fn get_external_ref(env: Env, bounty_id: u64) -> Option<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ExternalRef(bounty_id))
+    }
+
+    /// View: get the bounty most recently linked to `reference`, if any.
+    pub fn get_bounty_by_ref(env: Env, reference: Bytes) -> Option<u64> {
+        let ref_hash = Self::external_ref_hash(&env, &reference);
+        env.storage().persistent().get(&DataKey::RefIndex(ref_hash))
+    }
+
+    fn external_ref_hash(env: &Env, reference: &Bytes) -> BytesN<32> {
+        let is_space = |i: u32| reference.get(i).is_some_and(|b| b.is_ascii_whitespace());
+        let mut start = 0;
+        let mut end = reference.len();
+        while start < end && is_space(start) {
+            start += 1;
+        }
+        while end > start && is_space(end - 1) {
+            end -= 1;
+        }
+        let mut normalized = Bytes::new(env);
+        for byte in reference.slice(start..end).iter() {
+            normalized.push_back(byte.to_ascii_lowercase());
+        }
+        env.crypto().sha256(&normalized).into()
+    }
+
+    fn unlink_external_ref(env: &Env, bounty_id: u64) {
+        let storage = env.storage().persistent();
+        if let Some(old_hash) = storage.get::<DataKey, BytesN<32>>(&DataKey::ExternalRef(bounty_id))
+        {
+            let old_key = DataKey::RefIndex(old_hash);
+            if storage.get::<DataKey, u64>(&old_key) == Some(bounty_id) {
+                storage.remove(&old_key);
+            }
+            storage.remove(&DataKey::ExternalRef(bounty_id));
+        }
+    }
+
+    /// Replace a bounty's category tags (depositor only).
+    ///
+    /// At most `MAX_TAGS_PER_BOUNTY` tags are kept; repeated tags are stored
+    /// once. An empty list clears the tags. A tag already carried by
+    /// `MAX_BOUNTIES_PER_TAG` bounties is rejected until some are archived.
+    pub fn set_bounty_tags(env: Env, bounty_id: u64, tags: Vec<Symbol>) -> Result<(), Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+        if tags.len() > MAX_TAGS_PER_BOUNTY {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        Self::untag_bounty(&env, bounty_id);
+        let storage = env.storage().persistent();
+        let mut stored = Vec::new(&env);
+        for tag in tags.iter() {
+            if stored.contains(&tag) {
+                continue;
+            }
+            let index_key = DataKey::TagIndex(tag.clone());
+            let mut ids: Vec<u64> = storage.get(&index_key).unwrap_or(Vec::new(&env));
+            if ids.len() >= MAX_BOUNTIES_PER_TAG {
+                return Err(Error::InvalidBatchSize);
+            }
+            ids.push_back(bounty_id);
+            storage.set(&index_key, &ids);
+            stored.push_back(tag);
+        }
+        if !stored.is_empty() {
+            storage.set(&DataKey::Tags(bounty_id), &stored);
+        }
+
+        env.events()
+            .publish((symbol_short!("tags"), bounty_id), stored);
+        Ok(())
+    }
+
+    /// View: get the tags of a bounty.
+    pub fn get_bounty_tags(env: Env, bounty_id: u64) -> Vec<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Tags(bounty_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// View: bounty IDs carrying `tag`, in tagging order, paginated.
+    pub fn list_bounties_by_tag(env: Env, tag: Symbol, offset: u32, limit: u32) -> Vec<u64> {
+        let ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::TagIndex(tag))
+            .unwrap_or(Vec::new(&env));
+        let mut results = Vec::new(&env);
+        for bounty_id in ids.iter().skip(offset as usize).take(limit as usize) {
+            results.push_back(bounty_id);
+        }
+        results
+    }
+
+    fn untag_bounty(env: &Env, bounty_id: u64) {
+        let storage = env.storage().persistent();
+        let Some(tags) = storage.get::<DataKey, Vec<Symbol>>(&DataKey::Tags(bounty_id)) else {
+            return;
+        };
+        for tag in tags.iter() {
+            let index_key = DataKey::TagIndex(tag);
+            let mut ids: Vec<u64> = storage.get(&index_key).unwrap_or(Vec::new(env));
+            if let Some(pos) = ids.first_index_of(bounty_id) {
+                ids.remove(pos);
+            }
+            if ids.is_empty() {
+                storage.remove(&index_key);
+            } else {
+                storage.set(&index_key, &ids);
+            }
+        }
+        storage.remove(&DataKey::Tags(bounty_id));
+    }
+
+    // ========================================================================
+    // Upgrade & Version Management
+    // ========================================================================
+
+    /// Upgrade the contract to new WASM code (admin only).
+    ///
+    /// Escrow state and locked funds stay in place. The current version is
+    /// kept as the previous version; call `set_version` once migrated.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        let admin = Self::require_admin(&env)?;
+
+        let current_version = Self::get_version(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::PreviousVersion, &current_version);
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        events::emit_contract_upgraded(
+            &env,
+            events::ContractUpgraded {
+                version: EVENT_VERSION_V2,
+                new_wasm_hash,
+                previous_version: current_version,
+                admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Current contract version. Deployments that predate version tracking
+    /// report `CONTRACT_VERSION`.
+    pub fn get_version(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Version)
+            .unwrap_or(CONTRACT_VERSION)
+    }
+
+    /// Version recorded before the last upgrade, if any.
+    pub fn get_previous_version(env: Env) -> Option<u32> {
+        env.storage().instance().get(&DataKey::PreviousVersion)
+    }
+
+    /// Seed the running liabilities total from the per-bounty records
+    /// (admin only). Run once after upgrading a deployment that predates
+    /// the total; running it again resets any drift to the summed value.
+    pub fn migrate_liabilities(env: Env) -> Result<i128, Error> {
+        Self::require_admin(&env)?;
+
+        let liabilities = Self::sum_liabilities(&env);
+        env.storage()
+            .instance()
+            .set(&SolvencyKey::Liabilities, &liabilities);
+        Ok(liabilities)
+    }
+
+    /// Update the contract version number after a migration (admin only).
+    pub fn set_version(env: Env, new_version: u32) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::Version, &new_version);
+        Ok(())
+    }
 }
 
 impl traits::EscrowInterface for BountyEscrowContract {
@@ -2913,31 +7414,80 @@ impl traits::EscrowInterface for BountyEscrowContract {
 impl traits::UpgradeInterface for BountyEscrowContract {
     /// Get contract version
     fn get_version(env: &Env) -> u32 {
-        1 // Current version
+        BountyEscrowContract::get_version(env.clone())
     }
 
     /// Set contract version (admin only)
-    fn set_version(env: &Env, _new_version: u32) -> Result<(), soroban_sdk::String> {
-        // Version management - reserved for future use
-        // Currently, version is hardcoded to 1
-        Ok(())
+    fn set_version(env: &Env, new_version: u32) -> Result<(), soroban_sdk::String> {
+        BountyEscrowContract::set_version(env.clone(), new_version)
+            .map_err(|_| soroban_sdk::String::from_str(env, "Unauthorized"))
     }
 }
 
+#[cfg(test)]
+mod test_clawback;
+#[cfg(test)]
+mod test_dependencies;
+#[cfg(test)]
+mod test_external_ref;
+#[cfg(test)]
+mod test_htlc;
+#[cfg(test)]
+mod test_lock_batch;
+#[cfg(test)]
+mod test_lock_limits;
+#[cfg(test)]
+mod test_multisig_release;
+#[cfg(test)]
+mod test_oracle_release;
+#[cfg(test)]
+mod test_race_bounty;
+#[cfg(test)]
+mod test_release_batch;
+#[cfg(test)]
+mod test_reputation_gate;
 #[cfg(test)]
 mod test_state_verification;
+#[cfg(test)]
+mod test_stats;
+#[cfg(test)]
+mod test_submissions;
+#[cfg(test)]
+mod test_sweep_expired;
+#[cfg(test)]
+mod test_tags;
+#[cfg(test)]
+mod test_templates;
+#[cfg(test)]
+mod test_two_step_release;
+#[cfg(test)]
+mod test_upgrade;
+#[cfg(test)]
+mod test_vesting;
 
 #[cfg(test)]
 mod test;
 #[cfg(test)]
+mod test_admin_events;
+#[cfg(test)]
+mod test_admin_transfer;
+#[cfg(test)]
 mod test_analytics_monitoring;
 #[cfg(test)]
+mod test_archive;
+#[cfg(test)]
 mod test_auto_refund_permissions;
 #[cfg(test)]
 mod test_blacklist_and_whitelist;
 #[cfg(test)]
+mod test_boost_bounty;
+#[cfg(test)]
 mod test_bounty_escrow;
 #[cfg(test)]
+mod test_bounty_ttl;
+#[cfg(test)]
+mod test_cancel_bounty;
+#[cfg(test)]
 mod test_capability_tokens;
 #[cfg(test)]
 mod test_dispute_resolution;
@@ -2953,10 +7503,22 @@ mod test_lifecycle;
 #[cfg(test)]
 mod test_metadata_tagging;
 #[cfg(test)]
+mod test_milestones;
+#[cfg(test)]
 mod test_partial_payout_rounding;
 #[cfg(test)]
 mod test_pause;
 #[cfg(test)]
+mod test_refund_approvals;
+#[cfg(test)]
+mod test_refund_split;
+#[cfg(test)]
+mod test_release_fees;
+#[cfg(test)]
+mod test_release_split;
+#[cfg(test)]
+mod test_setup;
+#[cfg(test)]
 mod escrow_status_transition_tests {
     use super::*;
     use soroban_sdk::{
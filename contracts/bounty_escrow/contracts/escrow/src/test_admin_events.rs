@@ -0,0 +1,92 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_setup::Setup;
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    Address, IntoVal, Symbol, TryIntoVal, Val,
+};
+
+/// Topics and data of the last event published by the escrow contract.
+fn last_event(s: &Setup) -> (soroban_sdk::Vec<Val>, Val) {
+    let (contract, topics, data) = s.env.events().all().last().unwrap();
+    assert_eq!(contract, s.escrow.address);
+    (topics, data)
+}
+
+#[test]
+fn test_approve_refund_emits_event() {
+    let s = Setup::new();
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    s.escrow.lock_funds(&s.depositor, &1, &1_000, &deadline);
+
+    s.escrow
+        .approve_refund(&1, &400, &s.depositor, &RefundMode::Partial);
+
+    let (topics, data) = last_event(&s);
+    let topic_0: Symbol = topics.get(0).unwrap().into_val(&s.env);
+    assert_eq!(topic_0, symbol_short!("ref_appr"));
+    let event: events::RefundApproved = data.try_into_val(&s.env).unwrap();
+    assert_eq!(event.bounty_id, 1);
+    assert_eq!(event.amount, 400);
+    assert_eq!(event.recipient, s.depositor);
+    assert_eq!(event.mode, RefundMode::Partial);
+    assert_eq!(event.approved_by, s.admin);
+}
+
+#[test]
+fn test_set_whitelist_emits_event() {
+    let s = Setup::new();
+    let address = Address::generate(&s.env);
+
+    s.escrow.set_whitelist(&address, &true);
+
+    let (topics, data) = last_event(&s);
+    let topic_0: Symbol = topics.get(0).unwrap().into_val(&s.env);
+    assert_eq!(topic_0, symbol_short!("wl_upd"));
+    let event: events::WhitelistUpdated = data.try_into_val(&s.env).unwrap();
+    assert_eq!(event.address, address);
+    assert!(event.whitelisted);
+    assert_eq!(event.admin, s.admin);
+}
+
+#[test]
+fn test_rate_limit_config_changes_emit_events() {
+    let s = Setup::new();
+
+    s.escrow.update_anti_abuse_config(&3600, &5, &30);
+    let (_, data) = last_event(&s);
+    let event: events::RateLimitConfigUpdated = data.try_into_val(&s.env).unwrap();
+    assert_eq!(event.role, RateLimitRole::Depositor);
+    assert!(event.enabled);
+    assert_eq!(event.window_size, 3600);
+    assert_eq!(event.max_operations, 5);
+    assert_eq!(event.cooldown_period, 30);
+
+    s.escrow.set_role_rate_limit(&RateLimitRole::Admin, &None);
+    let (topics, data) = last_event(&s);
+    let topic_0: Symbol = topics.get(0).unwrap().into_val(&s.env);
+    assert_eq!(topic_0, symbol_short!("rl_cfg"));
+    let event: events::RateLimitConfigUpdated = data.try_into_val(&s.env).unwrap();
+    assert_eq!(event.role, RateLimitRole::Admin);
+    assert!(!event.enabled);
+}
+
+#[test]
+fn test_other_admin_settings_emit_admin_config_event() {
+    let s = Setup::new();
+
+    s.escrow.set_claim_window(&600);
+    let (topics, data) = last_event(&s);
+    let topic_0: Symbol = topics.get(0).unwrap().into_val(&s.env);
+    let topic_1: Symbol = topics.get(1).unwrap().into_val(&s.env);
+    assert_eq!(topic_0, symbol_short!("adm_cfg"));
+    assert_eq!(topic_1, symbol_short!("claim_win"));
+    let event: events::AdminConfigUpdated = data.try_into_val(&s.env).unwrap();
+    assert_eq!(event.admin, s.admin);
+
+    s.escrow.set_arbiter(&Address::generate(&s.env));
+    let (_, data) = last_event(&s);
+    let event: events::AdminConfigUpdated = data.try_into_val(&s.env).unwrap();
+    assert_eq!(event.setting, symbol_short!("arbiter"));
+}
@@ -0,0 +1,53 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_setup::Setup;
+use soroban_sdk::{testutils::Address as _, Address};
+
+#[test]
+fn test_admin_transfer_takes_effect_on_accept() {
+    let s = Setup::new();
+    let next = Address::generate(&s.env);
+
+    s.escrow.propose_admin(&next);
+    assert!(s.env.auths().iter().any(|(a, _)| *a == s.admin));
+    assert_eq!(s.escrow.get_pending_admin(), Some(next.clone()));
+    assert_eq!(s.escrow.get_admin(), Some(s.admin.clone()));
+
+    s.escrow.accept_admin();
+    assert!(s.env.auths().iter().any(|(a, _)| *a == next));
+    assert_eq!(s.escrow.get_admin(), Some(next.clone()));
+    assert_eq!(s.escrow.get_pending_admin(), None);
+
+    // The new admin now authorizes admin operations
+    s.escrow.set_clawback_window(&60);
+    assert!(s.env.auths().iter().any(|(a, _)| *a == next));
+}
+
+#[test]
+fn test_accept_admin_without_proposal_fails() {
+    let s = Setup::new();
+    assert_eq!(s.escrow.try_accept_admin(), Err(Ok(Error::NotInitialized)));
+}
+
+#[test]
+fn test_cancel_admin_transfer() {
+    let s = Setup::new();
+    let next = Address::generate(&s.env);
+
+    s.escrow.propose_admin(&next);
+    s.escrow.cancel_admin_transfer();
+    assert_eq!(s.escrow.get_pending_admin(), None);
+    assert_eq!(s.escrow.try_accept_admin(), Err(Ok(Error::NotInitialized)));
+    assert_eq!(s.escrow.get_admin(), Some(s.admin));
+}
+
+#[test]
+fn test_init_twice_fails() {
+    let s = Setup::new();
+    let token = Address::generate(&s.env);
+    assert_eq!(
+        s.escrow.try_init(&s.admin, &token),
+        Err(Ok(Error::AlreadyInitialized))
+    );
+}
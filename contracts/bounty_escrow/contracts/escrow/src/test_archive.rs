@@ -0,0 +1,71 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_setup::Setup;
+use soroban_sdk::testutils::Ledger;
+
+#[test]
+fn test_archive_released_bounty_keeps_terminal_record() {
+    let s = Setup::new();
+    s.lock(1, 1_000);
+    s.escrow.release_funds(&1, &s.contributor);
+
+    s.escrow.archive_bounty(&1);
+
+    assert_eq!(
+        s.escrow.try_get_escrow_info(&1),
+        Err(Ok(Error::BountyNotFound))
+    );
+    assert_eq!(s.escrow.get_release_history(&1).len(), 0);
+    let archived = s.escrow.get_archived_bounty(&1);
+    assert_eq!(archived.depositor, s.depositor);
+    assert_eq!(archived.amount, 1_000);
+    assert_eq!(archived.status, EscrowStatus::Released);
+    assert_eq!(s.escrow.list_bounties(&None, &0, &10).len(), 0);
+}
+
+#[test]
+fn test_archive_refunded_bounty() {
+    let s = Setup::new();
+    let deadline = s.lock(1, 1_000);
+    s.env.ledger().set_timestamp(deadline + 1);
+    s.escrow.refund(&1);
+
+    s.escrow.archive_bounty(&1);
+    assert_eq!(
+        s.escrow.get_archived_bounty(&1).status,
+        EscrowStatus::Refunded
+    );
+}
+
+#[test]
+fn test_archive_rejects_unsettled_bounty() {
+    let s = Setup::new();
+    s.lock(1, 1_000);
+
+    assert_eq!(
+        s.escrow.try_archive_bounty(&1),
+        Err(Ok(Error::BountyNotSettled))
+    );
+    assert_eq!(
+        s.escrow.try_archive_bounty(&2),
+        Err(Ok(Error::BountyNotFound))
+    );
+    assert_eq!(
+        s.escrow.try_get_archived_bounty(&1),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_archived_bounty_id_cannot_be_reused() {
+    let s = Setup::new();
+    let deadline = s.lock(1, 1_000);
+    s.escrow.release_funds(&1, &s.contributor);
+    s.escrow.archive_bounty(&1);
+
+    assert_eq!(
+        s.escrow.try_lock_funds(&s.depositor, &1, &100, &deadline),
+        Err(Ok(Error::BountyExists))
+    );
+}
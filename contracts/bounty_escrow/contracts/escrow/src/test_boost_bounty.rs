@@ -0,0 +1,73 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_setup::Setup;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address,
+};
+
+#[test]
+fn test_anyone_can_boost_and_release_pays_boosted_amount() {
+    let s = Setup::new();
+    let booster = Address::generate(&s.env);
+    s.token_admin.mint(&booster, &500);
+    s.lock(1, 1_000);
+
+    s.escrow.boost_bounty(&1, &booster, &500);
+    let info = s.escrow.get_escrow_info(&1);
+    assert_eq!(info.amount, 1_500);
+    assert_eq!(info.remaining_amount, 1_500);
+    assert_eq!(s.token.balance(&booster), 0);
+
+    s.escrow.release_funds(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 1_500);
+}
+
+#[test]
+fn test_boost_goes_to_depositor_on_refund() {
+    let s = Setup::new();
+    let deadline = s.lock(1, 1_000);
+    s.escrow.boost_bounty(&1, &s.depositor, &250);
+    s.env.ledger().set_timestamp(deadline);
+
+    s.escrow.refund(&1);
+
+    assert_eq!(s.token.balance(&s.depositor), 10_000);
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
+}
+
+#[test]
+fn test_boost_extends_last_unreleased_milestone() {
+    let s = Setup::new();
+    s.lock(1, 1_000);
+    s.escrow
+        .define_milestones(&1, &vec![&s.env, 400i128, 600i128]);
+
+    s.escrow.boost_bounty(&1, &s.depositor, &100);
+
+    assert_eq!(s.escrow.get_milestones(&1).get(1).unwrap().amount, 700);
+    s.escrow.release_milestone(&1, &0, &s.contributor);
+    s.escrow.release_milestone(&1, &1, &s.contributor);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_boost_rejects_invalid_targets() {
+    let s = Setup::new();
+    s.lock(1, 1_000);
+
+    assert_eq!(
+        s.escrow.try_boost_bounty(&1, &s.depositor, &0),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        s.escrow.try_boost_bounty(&2, &s.depositor, &100),
+        Err(Ok(Error::BountyNotFound))
+    );
+    s.escrow.release_funds(&1, &s.contributor);
+    assert_eq!(
+        s.escrow.try_boost_bounty(&1, &s.depositor, &100),
+        Err(Ok(Error::FundsNotLocked))
+    );
+}
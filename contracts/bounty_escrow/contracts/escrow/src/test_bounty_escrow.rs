@@ -1,8 +1,11 @@
-use crate::{BountyEscrowContract, BountyEscrowContractClient, Error as ContractError};
+use crate::{
+    AntiAbuseConfigView, BountyEscrowContract, BountyEscrowContractClient, Error as ContractError,
+    RateLimitOp, RateLimitRole, RefundMode,
+};
 use soroban_sdk::testutils::Events;
 use soroban_sdk::{
     testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke},
-    token, Address, Env, IntoVal, Map, Symbol, TryFromVal, Val,
+    token, vec, Address, Env, IntoVal, Map, Symbol, TryFromVal, Val,
 };
 
 fn create_test_env() -> (Env, BountyEscrowContractClient<'static>, Address) {
@@ -1240,9 +1243,8 @@ fn test_max_bounty_count_queries_accurate() {
 // Rate limit and cooldown enforcement (Issue #460)
 // =============================================================================
 
-/// Exactly at rate limit: max_operations locks succeed; the next one panics with "Rate limit exceeded".
+/// Exactly at rate limit: max_operations locks succeed; the next one fails with RateLimited.
 #[test]
-#[should_panic(expected = "Rate limit exceeded")]
 fn test_anti_abuse_exact_rate_limit_then_exceeded() {
     let (env, client, _) = create_test_env();
     let admin = Address::generate(&env);
@@ -1264,8 +1266,15 @@ fn test_anti_abuse_exact_rate_limit_then_exceeded() {
     client.lock_funds(&depositor, &1, &100, &deadline);
     client.lock_funds(&depositor, &2, &100, &deadline);
 
-    // Third lock in same window must panic
-    client.lock_funds(&depositor, &3, &100, &deadline);
+    // Third lock in same window is rejected until the window resets
+    assert_eq!(
+        client.try_lock_funds(&depositor, &3, &100, &deadline),
+        Err(Ok(ContractError::RateLimited))
+    );
+    assert_eq!(
+        client.get_rate_limit_retry_after(&RateLimitRole::Depositor, &depositor),
+        3600
+    );
 }
 
 /// Exactly at limit: max_operations locks succeed; no panic at the boundary.
@@ -1293,10 +1302,9 @@ fn test_anti_abuse_exact_rate_limit_boundary_succeeds() {
     assert_eq!(client.get_escrow_count(), 3);
 }
 
-/// Rapid repeated lock within cooldown period must panic.
+/// Rapid repeated lock within cooldown period fails with CooldownActive.
 #[test]
-#[should_panic(expected = "Operation in cooldown period")]
-fn test_anti_abuse_cooldown_violation_panics() {
+fn test_anti_abuse_cooldown_violation_rejected() {
     let (env, client, _) = create_test_env();
     let admin = Address::generate(&env);
     let depositor = Address::generate(&env);
@@ -1318,7 +1326,14 @@ fn test_anti_abuse_cooldown_violation_panics() {
     client.lock_funds(&depositor, &1, &100, &deadline);
 
     env.ledger().set_timestamp(start + 50);
-    client.lock_funds(&depositor, &2, &100, &deadline);
+    assert_eq!(
+        client.try_lock_funds(&depositor, &2, &100, &deadline),
+        Err(Ok(ContractError::CooldownActive))
+    );
+    assert_eq!(
+        client.get_rate_limit_retry_after(&RateLimitRole::Depositor, &depositor),
+        50
+    );
 }
 
 /// After cooldown period, next lock succeeds.
@@ -1375,6 +1390,361 @@ fn test_anti_abuse_whitelisted_address_bypasses_checks() {
     assert_eq!(client.get_escrow_count(), 5);
 }
 
+/// Admin operations are exempt by default, even under a strict depositor config.
+#[test]
+fn test_anti_abuse_admin_exempt_by_default() {
+    let (env, client, _) = create_test_env();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let start = 1_000_000_u64;
+    env.ledger().set_timestamp(start);
+    let deadline = start + 10_000;
+
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    client.init(&admin, &token);
+
+    client.update_anti_abuse_config(&3600, &1, &60);
+    assert_eq!(client.get_role_rate_limit(&RateLimitRole::Admin), None);
+
+    token_admin_client.mint(&admin, &10_000);
+    token_admin_client.mint(&depositor, &10_000);
+
+    // Admin maintenance: several locks and releases in the same ledger
+    for i in 1..=3 {
+        client.lock_funds(&admin, &i, &100, &deadline);
+        client.release_funds(&i, &contributor);
+    }
+
+    // Depositors still draw from the global config
+    client.lock_funds(&depositor, &10, &100, &deadline);
+    assert_eq!(
+        client.try_lock_funds(&depositor, &11, &100, &deadline),
+        Err(Ok(ContractError::CooldownActive))
+    );
+}
+
+/// A configured admin limit throttles admin operations separately from depositors.
+#[test]
+fn test_anti_abuse_admin_role_has_separate_config() {
+    let (env, client, _) = create_test_env();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 10_000;
+
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    client.init(&admin, &token);
+
+    let admin_limit = AntiAbuseConfigView {
+        window_size: 3600,
+        max_operations: 2,
+        cooldown_period: 0,
+    };
+    client.set_role_rate_limit(&RateLimitRole::Admin, &Some(admin_limit.clone()));
+    assert_eq!(
+        client.get_role_rate_limit(&RateLimitRole::Admin),
+        Some(admin_limit)
+    );
+
+    token_admin_client.mint(&depositor, &10_000);
+    for i in 1..=3 {
+        client.lock_funds(&depositor, &i, &100, &deadline);
+    }
+
+    client.release_funds(&1, &contributor);
+    client.release_funds(&2, &contributor);
+    assert_eq!(
+        client.try_release_funds(&3, &contributor),
+        Err(Ok(ContractError::RateLimited))
+    );
+    assert_eq!(
+        client.get_rate_limit_retry_after(&RateLimitRole::Admin, &admin),
+        3600
+    );
+
+    // Clearing the admin config restores the exemption
+    client.set_role_rate_limit(&RateLimitRole::Admin, &None);
+    client.release_funds(&3, &contributor);
+}
+
+/// The admin override lets admin and approver operations through a configured
+/// admin limit, e.g. for emergency refunds.
+#[test]
+fn test_anti_abuse_admin_override_bypasses_limits() {
+    let (env, client, _) = create_test_env();
+    let admin = Address::generate(&env);
+    let approver = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 10_000;
+
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    client.init(&admin, &token);
+    client.grant_approver(&approver);
+    client.set_role_rate_limit(
+        &RateLimitRole::Admin,
+        &Some(AntiAbuseConfigView {
+            window_size: 3600,
+            max_operations: 1,
+            cooldown_period: 0,
+        }),
+    );
+
+    token_admin_client.mint(&depositor, &10_000);
+    for i in 1..=3 {
+        client.lock_funds(&depositor, &i, &100, &deadline);
+    }
+    client.approve_refund_as_approver(&1, &100, &depositor, &RefundMode::Full, &approver);
+    assert_eq!(
+        client.try_approve_refund_as_approver(&2, &100, &depositor, &RefundMode::Full, &approver),
+        Err(Ok(ContractError::RateLimited))
+    );
+
+    client.set_admin_rate_limit_override(&true);
+    assert!(client.get_admin_rate_limit_override());
+    assert_eq!(
+        client.get_rate_limit_retry_after(&RateLimitRole::Admin, &approver),
+        0
+    );
+    client.approve_refund_as_approver(&2, &100, &depositor, &RefundMode::Full, &approver);
+    client.approve_refund(&3, &100, &depositor, &RefundMode::Full);
+
+    // Depositors stay limited while the override is on
+    client.update_anti_abuse_config(&3600, &1, &0);
+    assert_eq!(
+        client.try_lock_funds(&depositor, &4, &100, &deadline),
+        Err(Ok(ContractError::RateLimited))
+    );
+}
+
+/// An operation with its own limit stops sharing its role's bucket, so heavy
+/// releases no longer block refund approvals.
+#[test]
+fn test_anti_abuse_op_limits_are_independent() {
+    let (env, client, _) = create_test_env();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 10_000;
+
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    client.init(&admin, &token);
+    client.set_role_rate_limit(
+        &RateLimitRole::Admin,
+        &Some(AntiAbuseConfigView {
+            window_size: 3600,
+            max_operations: 1,
+            cooldown_period: 0,
+        }),
+    );
+    let refund_limit = AntiAbuseConfigView {
+        window_size: 3600,
+        max_operations: 2,
+        cooldown_period: 0,
+    };
+    client.set_op_rate_limit(&RateLimitOp::Refund, &Some(refund_limit.clone()));
+    assert_eq!(
+        client.get_op_rate_limit(&RateLimitOp::Refund),
+        Some(refund_limit.clone())
+    );
+    assert_eq!(client.get_op_rate_limit(&RateLimitOp::Release), None);
+
+    token_admin_client.mint(&depositor, &10_000);
+    for i in 1..=5 {
+        client.lock_funds(&depositor, &i, &100, &deadline);
+    }
+
+    // Releases still share the admin role bucket
+    client.release_funds(&1, &contributor);
+    assert_eq!(
+        client.try_release_funds(&2, &contributor),
+        Err(Ok(ContractError::RateLimited))
+    );
+
+    // Refund approvals draw from their own bucket
+    client.approve_refund(&3, &100, &depositor, &RefundMode::Full);
+    client.approve_refund(&4, &100, &depositor, &RefundMode::Full);
+    assert_eq!(
+        client.try_approve_refund(&5, &100, &depositor, &RefundMode::Full),
+        Err(Ok(ContractError::RateLimited))
+    );
+    assert_eq!(
+        client.get_op_rate_limit_retry_after(&RateLimitRole::Admin, &RateLimitOp::Refund, &admin),
+        3600
+    );
+    let status =
+        client.get_op_rate_limit_status(&RateLimitRole::Admin, &RateLimitOp::Refund, &admin);
+    assert_eq!(status.operations_used, 2);
+    assert_eq!(status.max_operations, 2);
+
+    // Clearing the op limit puts refunds back on the exhausted admin bucket
+    client.set_op_rate_limit(&RateLimitOp::Refund, &None);
+    assert_eq!(
+        client.get_op_rate_limit_retry_after(&RateLimitRole::Admin, &RateLimitOp::Refund, &admin),
+        3600
+    );
+    let status =
+        client.get_op_rate_limit_status(&RateLimitRole::Admin, &RateLimitOp::Refund, &admin);
+    assert_eq!(status.operations_used, 1);
+    assert_eq!(status.max_operations, 1);
+    assert_eq!(
+        client.try_approve_refund(&5, &100, &depositor, &RefundMode::Full),
+        Err(Ok(ContractError::RateLimited))
+    );
+
+    // The admin override exempts op limits too
+    client.set_op_rate_limit(&RateLimitOp::Refund, &Some(refund_limit));
+    client.set_admin_rate_limit_override(&true);
+    assert_eq!(
+        client.get_op_rate_limit_retry_after(&RateLimitRole::Admin, &RateLimitOp::Refund, &admin),
+        0
+    );
+    assert!(
+        client
+            .get_op_rate_limit_status(&RateLimitRole::Admin, &RateLimitOp::Refund, &admin)
+            .exempt
+    );
+    client.approve_refund(&5, &100, &depositor, &RefundMode::Full);
+}
+
+/// A lock limit replaces the depositor's global config for lock operations.
+#[test]
+fn test_anti_abuse_lock_op_limit_overrides_depositor_config() {
+    let (env, client, _) = create_test_env();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let start = 1_000_000_u64;
+    env.ledger().set_timestamp(start);
+    let deadline = start + 10_000;
+
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    client.init(&admin, &token);
+    client.set_op_rate_limit(
+        &RateLimitOp::Lock,
+        &Some(AntiAbuseConfigView {
+            window_size: 3600,
+            max_operations: 10,
+            cooldown_period: 0,
+        }),
+    );
+    token_admin_client.mint(&depositor, &10_000);
+
+    // No 60s cooldown from the default depositor config
+    client.lock_funds(&depositor, &1, &100, &deadline);
+    client.lock_funds(&depositor, &2, &100, &deadline);
+    assert_eq!(
+        client
+            .get_rate_limit_status(&RateLimitRole::Depositor, &depositor)
+            .operations_used,
+        0
+    );
+}
+
+/// Pruning removes limiter entries only once their window and cooldown lapse.
+#[test]
+fn test_prune_rate_data_removes_only_stale_entries() {
+    let (env, client, _) = create_test_env();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let start = 1_000_000_u64;
+    env.ledger().set_timestamp(start);
+    let deadline = start + 100_000;
+
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    client.init(&admin, &token);
+    token_admin_client.mint(&depositor, &10_000);
+    let addresses = vec![&env, depositor.clone()];
+
+    // Default depositor config: 3600s window, 60s cooldown
+    client.lock_funds(&depositor, &1, &100, &deadline);
+    assert_eq!(client.prune_rate_data(&addresses), 0);
+    env.ledger().set_timestamp(start + 60);
+    assert_eq!(client.prune_rate_data(&addresses), 0);
+    env.ledger().set_timestamp(start + 3_600);
+    assert_eq!(client.prune_rate_data(&addresses), 1);
+    assert_eq!(client.prune_rate_data(&addresses), 0);
+
+    // Per-operation entries are pruned against their own window
+    client.set_op_rate_limit(
+        &RateLimitOp::Lock,
+        &Some(AntiAbuseConfigView {
+            window_size: 600,
+            max_operations: 10,
+            cooldown_period: 0,
+        }),
+    );
+    client.lock_funds(&depositor, &2, &100, &deadline);
+    assert_eq!(client.prune_rate_data(&addresses), 0);
+    env.ledger().set_timestamp(start + 4_200);
+    assert_eq!(client.prune_rate_data(&addresses), 1);
+
+    assert_eq!(
+        client.try_prune_rate_data(&vec![&env]),
+        Err(Ok(ContractError::InvalidBatchSize))
+    );
+}
+
+/// The status view reports window usage, reset time and cooldown.
+#[test]
+fn test_anti_abuse_rate_limit_status() {
+    let (env, client, _) = create_test_env();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let start = 1_000_000_u64;
+    env.ledger().set_timestamp(start);
+    let deadline = start + 10_000;
+
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    client.init(&admin, &token);
+    client.update_anti_abuse_config(&3600, &3, &60);
+    token_admin_client.mint(&depositor, &10_000);
+
+    let status = client.get_rate_limit_status(&RateLimitRole::Depositor, &depositor);
+    assert!(!status.exempt);
+    assert_eq!(status.operations_used, 0);
+    assert_eq!(status.max_operations, 3);
+    assert_eq!(status.window_resets_at, 0);
+
+    client.lock_funds(&depositor, &1, &100, &deadline);
+    env.ledger().set_timestamp(start + 20);
+    let status = client.get_rate_limit_status(&RateLimitRole::Depositor, &depositor);
+    assert_eq!(status.operations_used, 1);
+    assert_eq!(status.window_resets_at, start + 3600);
+    assert_eq!(status.cooldown_remaining, 40);
+
+    env.ledger().set_timestamp(start + 3600);
+    let status = client.get_rate_limit_status(&RateLimitRole::Depositor, &depositor);
+    assert_eq!(status.operations_used, 0);
+    assert_eq!(status.cooldown_remaining, 0);
+
+    assert!(
+        client
+            .get_rate_limit_status(&RateLimitRole::Admin, &admin)
+            .exempt
+    );
+}
+
 // =============================================================================
 // Admin and config updates (Issue #465)
 // =============================================================================
@@ -0,0 +1,85 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_setup::Setup;
+use soroban_sdk::testutils::{storage::Persistent as _, Ledger};
+
+fn setup() -> Setup<'static> {
+    let s = Setup::new();
+    s.env.ledger().with_mut(|li| {
+        li.min_persistent_entry_ttl = 4_096;
+        li.max_entry_ttl = BOUNTY_TTL_EXTEND_TO + 1;
+    });
+    s
+}
+
+fn ttl(s: &Setup, key: &DataKey) -> u32 {
+    s.env.as_contract(&s.escrow.address, || {
+        s.env.storage().persistent().get_ttl(key)
+    })
+}
+
+fn advance_ledgers(s: &Setup, ledgers: u32) {
+    s.env.ledger().with_mut(|li| li.sequence_number += ledgers);
+}
+
+#[test]
+fn test_lock_extends_bounty_records() {
+    let s = setup();
+    s.lock(1, 1_000);
+
+    assert_eq!(ttl(&s, &DataKey::Escrow(1)), BOUNTY_TTL_EXTEND_TO);
+    assert_eq!(ttl(&s, &DataKey::EscrowIndex), BOUNTY_TTL_EXTEND_TO);
+    assert_eq!(
+        ttl(&s, &DataKey::DepositorIndex(s.depositor.clone())),
+        BOUNTY_TTL_EXTEND_TO
+    );
+}
+
+#[test]
+fn test_anyone_can_bump_bounty_ttl() {
+    let s = setup();
+    s.lock(1, 1_000);
+    s.escrow.release_split(
+        &1,
+        &soroban_sdk::vec![&s.env, s.contributor.clone()],
+        &soroban_sdk::vec![&s.env, 10_000u32],
+    );
+    s.escrow.bump_bounty_ttl(&1);
+
+    advance_ledgers(&s, BOUNTY_TTL_EXTEND_TO - BOUNTY_TTL_THRESHOLD + 1);
+    assert_eq!(ttl(&s, &DataKey::Escrow(1)), BOUNTY_TTL_THRESHOLD - 1);
+
+    s.env.set_auths(&[]);
+    s.escrow.bump_bounty_ttl(&1);
+
+    assert_eq!(ttl(&s, &DataKey::Escrow(1)), BOUNTY_TTL_EXTEND_TO);
+    assert_eq!(ttl(&s, &DataKey::ReleaseHistory(1)), BOUNTY_TTL_EXTEND_TO);
+}
+
+#[test]
+fn test_bump_unknown_bounty_fails() {
+    let s = setup();
+    assert_eq!(
+        s.escrow.try_bump_bounty_ttl(&1),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_bump_covers_auxiliary_bounty_records() {
+    let s = setup();
+    s.lock(1, 1_000);
+    let tag = soroban_sdk::symbol_short!("rust");
+    s.escrow.assign_contributor(&1, &s.contributor);
+    s.escrow
+        .set_bounty_tags(&1, &soroban_sdk::vec![&s.env, tag.clone()]);
+    s.escrow.bump_bounty_ttl(&1);
+
+    advance_ledgers(&s, BOUNTY_TTL_EXTEND_TO - BOUNTY_TTL_THRESHOLD + 1);
+    s.escrow.bump_bounty_ttl(&1);
+
+    assert_eq!(ttl(&s, &DataKey::Assignee(1)), BOUNTY_TTL_EXTEND_TO);
+    assert_eq!(ttl(&s, &DataKey::Tags(1)), BOUNTY_TTL_EXTEND_TO);
+    assert_eq!(ttl(&s, &DataKey::TagIndex(tag)), BOUNTY_TTL_EXTEND_TO);
+}
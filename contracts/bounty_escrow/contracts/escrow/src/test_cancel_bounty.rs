@@ -0,0 +1,60 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_setup::Setup;
+
+#[test]
+fn test_cancel_unassigned_bounty_refunds_before_deadline() {
+    let s = Setup::new();
+    s.lock(1, 1_000);
+
+    s.escrow.cancel_bounty(&1);
+
+    assert_eq!(s.token.balance(&s.depositor), 10_000);
+    let info = s.escrow.get_escrow_info(&1);
+    assert_eq!(info.status, EscrowStatus::Refunded);
+    assert_eq!(info.remaining_amount, 0);
+    assert_eq!(info.refund_history.len(), 1);
+}
+
+#[test]
+fn test_cancel_rejected_once_contributor_assigned() {
+    let s = Setup::new();
+    s.lock(1, 1_000);
+    s.escrow.assign_contributor(&1, &s.contributor);
+    assert_eq!(s.escrow.get_assignee(&1), Some(s.contributor.clone()));
+
+    let result = s.escrow.try_cancel_bounty(&1);
+    assert_eq!(result, Err(Ok(Error::BountyAssigned)));
+    assert_eq!(s.token.balance(&s.escrow.address), 1_000);
+}
+
+#[test]
+fn test_cancel_rejected_with_pending_claim_or_partial_release() {
+    let s = Setup::new();
+    s.lock(1, 1_000);
+    s.lock(2, 1_000);
+    s.escrow.authorize_claim(&1, &s.contributor);
+    s.escrow.partial_release(&2, &s.contributor, &100);
+
+    assert_eq!(
+        s.escrow.try_cancel_bounty(&1),
+        Err(Ok(Error::BountyAssigned))
+    );
+    assert_eq!(
+        s.escrow.try_cancel_bounty(&2),
+        Err(Ok(Error::BountyAssigned))
+    );
+}
+
+#[test]
+fn test_cancel_rejected_after_release() {
+    let s = Setup::new();
+    s.lock(1, 1_000);
+    s.escrow.release_funds(&1, &s.contributor);
+
+    assert_eq!(
+        s.escrow.try_cancel_bounty(&1),
+        Err(Ok(Error::FundsNotLocked))
+    );
+}
@@ -0,0 +1,336 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_setup::Setup;
+use soroban_sdk::{testutils::Address as _, vec, Address, Bytes, BytesN};
+
+const WINDOW: u64 = 24 * 3_600;
+
+fn setup() -> Setup<'static> {
+    let s = Setup::new();
+    s.escrow.set_arbiter(&Address::generate(&s.env));
+    s.escrow.set_clawback_window(&WINDOW);
+    let deadline = s.env.ledger().timestamp() + 10 * WINDOW;
+    s.escrow.lock_funds(&s.depositor, &1, &1_000, &deadline);
+    s
+}
+
+#[test]
+fn test_release_is_held_then_finalized() {
+    let s = setup();
+    assert_eq!(s.escrow.get_clawback_window(), WINDOW);
+
+    s.escrow.release_funds(&1, &s.contributor);
+    let pending = s.escrow.get_pending_release(&1, &s.contributor);
+    assert_eq!(pending.recipient, s.contributor);
+    assert_eq!(pending.amount, 1_000);
+    assert_eq!(s.token.balance(&s.contributor), 0);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Released);
+    assert_eq!(s.escrow.assert_solvency().liabilities, 1_000);
+
+    assert_eq!(
+        s.escrow.try_finalize_release(&1, &s.contributor),
+        Err(Ok(Error::DeadlineNotPassed))
+    );
+
+    s.advance(WINDOW);
+    s.escrow.finalize_release(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+    assert_eq!(
+        s.escrow.try_get_pending_release(&1, &s.contributor),
+        Err(Ok(Error::BountyNotFound))
+    );
+    assert_eq!(s.escrow.assert_solvency().liabilities, 0);
+}
+
+#[test]
+fn test_clawback_returns_funds_to_escrow() {
+    let s = setup();
+    s.escrow.release_funds(&1, &s.contributor);
+
+    s.advance(WINDOW - 1);
+    s.escrow.clawback_release(&1, &s.contributor);
+    assert!(s.env.auths().iter().any(|(a, _)| *a == s.depositor));
+    assert!(s
+        .env
+        .auths()
+        .iter()
+        .any(|(a, _)| *a == s.escrow.get_arbiter().unwrap()));
+
+    let escrow = s.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+    assert_eq!(escrow.remaining_amount, 1_000);
+    assert_eq!(s.escrow.get_stats().active_count, 1);
+    assert_eq!(
+        s.escrow.try_finalize_release(&1, &s.contributor),
+        Err(Ok(Error::BountyNotFound))
+    );
+    assert_eq!(s.token.balance(&s.contributor), 0);
+}
+
+#[test]
+fn test_clawback_after_window_fails() {
+    let s = setup();
+    s.escrow.release_funds(&1, &s.contributor);
+
+    s.advance(WINDOW);
+    assert_eq!(
+        s.escrow.try_clawback_release(&1, &s.contributor),
+        Err(Ok(Error::TimelockExpired))
+    );
+}
+
+#[test]
+fn test_pending_release_blocks_archive() {
+    let s = setup();
+    s.escrow.release_funds(&1, &s.contributor);
+    assert_eq!(
+        s.escrow.try_archive_bounty(&1),
+        Err(Ok(Error::BountyNotSettled))
+    );
+
+    s.advance(WINDOW);
+    s.escrow.finalize_release(&1, &s.contributor);
+    s.escrow.archive_bounty(&1);
+}
+
+#[test]
+fn test_disabled_window_releases_immediately() {
+    let s = setup();
+    s.escrow.set_clawback_window(&0);
+    s.escrow.release_funds(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+    assert_eq!(
+        s.escrow.try_get_pending_release(&1, &s.contributor),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_partial_releases_accumulate_in_the_hold() {
+    let s = setup();
+    s.escrow.partial_release(&1, &s.contributor, &300);
+    s.escrow.partial_release(&1, &s.contributor, &200);
+    assert_eq!(s.token.balance(&s.contributor), 0);
+    assert_eq!(s.escrow.get_pending_release(&1, &s.contributor).amount, 500);
+
+    // Another recipient gets a hold of its own
+    let other = Address::generate(&s.env);
+    s.escrow.partial_release(&1, &other, &100);
+    assert_eq!(s.escrow.get_pending_release(&1, &other).amount, 100);
+    assert_eq!(
+        s.escrow.get_held_recipients(&1),
+        vec![&s.env, s.contributor.clone(), other.clone()]
+    );
+
+    s.escrow.clawback_release(&1, &s.contributor);
+    let escrow = s.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+    assert_eq!(escrow.remaining_amount, 900);
+    assert_eq!(
+        s.escrow.get_held_recipients(&1),
+        vec![&s.env, other.clone()]
+    );
+
+    s.advance(WINDOW);
+    s.escrow.finalize_release(&1, &other);
+    assert_eq!(s.token.balance(&other), 100);
+    assert!(s.escrow.get_held_recipients(&1).is_empty());
+}
+
+#[test]
+fn test_milestone_release_is_held() {
+    let s = setup();
+    let milestones = vec![&s.env, 400i128, 600i128];
+    s.escrow.define_milestones(&1, &milestones);
+
+    s.escrow.release_milestone(&1, &0, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 0);
+    assert_eq!(s.escrow.get_pending_release(&1, &s.contributor).amount, 400);
+    assert_eq!(s.escrow.get_escrow_info(&1).remaining_amount, 600);
+}
+
+#[test]
+fn test_split_to_several_contributors_holds_each_share() {
+    let s = setup();
+    let other = Address::generate(&s.env);
+    let contributors = vec![&s.env, s.contributor.clone(), other.clone()];
+    let shares = vec![&s.env, 5_000u32, 5_000u32];
+    s.escrow.release_split(&1, &contributors, &shares);
+    assert_eq!(s.escrow.get_pending_release(&1, &s.contributor).amount, 500);
+    assert_eq!(s.escrow.get_pending_release(&1, &other).amount, 500);
+
+    s.advance(WINDOW);
+    s.escrow.finalize_release(&1, &s.contributor);
+    s.escrow.finalize_release(&1, &other);
+    assert_eq!(s.token.balance(&s.contributor), 500);
+    assert_eq!(s.token.balance(&other), 500);
+}
+
+#[test]
+fn test_htlc_claim_is_held() {
+    let s = setup();
+    let preimage = Bytes::from_slice(&s.env, b"secret");
+    let hashlock: BytesN<32> = s.env.crypto().sha256(&preimage).into();
+    let timelock = s.env.ledger().timestamp() + WINDOW;
+    s.escrow.lock_funds_htlc(
+        &s.depositor,
+        &2,
+        &1_000,
+        &s.contributor,
+        &hashlock,
+        &timelock,
+    );
+
+    s.escrow.claim_htlc(&2, &preimage);
+    assert_eq!(s.token.balance(&s.contributor), 0);
+    assert_eq!(
+        s.escrow.get_pending_release(&2, &s.contributor).amount,
+        1_000
+    );
+}
+
+#[test]
+fn test_dispute_blocks_finalizing_a_held_release() {
+    let s = setup();
+    s.escrow.partial_release(&1, &s.contributor, &400);
+    s.escrow.raise_dispute(&1, &s.depositor);
+
+    s.advance(WINDOW);
+    assert_eq!(
+        s.escrow.try_finalize_release(&1, &s.contributor),
+        Err(Ok(Error::BountyDisputed))
+    );
+    assert_eq!(s.token.balance(&s.contributor), 0);
+}
+
+#[test]
+fn test_claim_is_held() {
+    let s = setup();
+    s.escrow.set_claim_window(&WINDOW);
+    s.escrow.authorize_claim(&1, &s.contributor);
+
+    s.escrow.claim(&1);
+    assert_eq!(s.token.balance(&s.contributor), 0);
+    assert_eq!(
+        s.escrow.get_pending_release(&1, &s.contributor).amount,
+        1_000
+    );
+
+    s.escrow.clawback_release(&1, &s.contributor);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Locked);
+}
+
+#[test]
+fn test_vested_claim_is_held() {
+    let s = setup();
+    s.escrow.release_vested(&1, &s.contributor, &100);
+
+    s.advance(50);
+    assert_eq!(s.escrow.claim_vested(&1), 500);
+    assert_eq!(s.token.balance(&s.contributor), 0);
+    assert_eq!(s.escrow.get_pending_release(&1, &s.contributor).amount, 500);
+}
+
+#[test]
+fn test_pause_blocks_finalizing_a_held_release() {
+    let s = setup();
+    s.escrow.release_funds(&1, &s.contributor);
+    s.escrow.set_paused(&None, &Some(true), &None, &None);
+
+    s.advance(WINDOW);
+    assert_eq!(
+        s.escrow.try_finalize_release(&1, &s.contributor),
+        Err(Ok(Error::FundsPaused))
+    );
+
+    s.escrow.set_paused(&None, &Some(false), &None, &None);
+    s.escrow.finalize_release(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+}
+
+#[test]
+fn test_clawed_back_htlc_claim_cannot_be_replayed() {
+    let s = setup();
+    let preimage = Bytes::from_slice(&s.env, b"secret");
+    let hashlock: BytesN<32> = s.env.crypto().sha256(&preimage).into();
+    let timelock = s.env.ledger().timestamp() + WINDOW;
+    s.escrow.lock_funds_htlc(
+        &s.depositor,
+        &2,
+        &1_000,
+        &s.contributor,
+        &hashlock,
+        &timelock,
+    );
+    s.escrow.claim_htlc(&2, &preimage);
+    s.escrow.clawback_release(&2, &s.contributor);
+
+    assert_eq!(
+        s.escrow.try_claim_htlc(&2, &preimage),
+        Err(Ok(Error::HtlcNotFound))
+    );
+    assert_eq!(s.escrow.get_escrow_info(&2).status, EscrowStatus::Locked);
+}
+
+#[test]
+fn test_clawed_back_auto_release_cannot_be_repeated() {
+    let s = setup();
+    s.escrow.assign_contributor(&1, &s.contributor);
+    s.escrow.set_review_window(&1, &300);
+    s.escrow
+        .submit_work(&1, &BytesN::from_array(&s.env, &[3; 32]));
+    s.advance(300);
+    s.escrow.auto_release(&1);
+    assert_eq!(
+        s.escrow.get_pending_release(&1, &s.contributor).amount,
+        1_000
+    );
+
+    s.escrow.clawback_release(&1, &s.contributor);
+    assert_eq!(s.escrow.try_auto_release(&1), Err(Ok(Error::Unauthorized)));
+    assert_eq!(s.escrow.get_review_window(&1), None);
+}
+
+#[test]
+fn test_clawed_back_vesting_claim_stops_the_schedule() {
+    let s = setup();
+    s.escrow.release_vested(&1, &s.contributor, &100);
+    s.advance(50);
+    assert_eq!(s.escrow.claim_vested(&1), 500);
+
+    s.escrow.clawback_release(&1, &s.contributor);
+    let escrow = s.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+    assert_eq!(escrow.remaining_amount, 1_000);
+    assert_eq!(s.escrow.get_stats().active_count, 1);
+
+    s.advance(50);
+    assert_eq!(
+        s.escrow.try_claim_vested(&1),
+        Err(Ok(Error::VestingNotFound))
+    );
+    assert_eq!(s.token.balance(&s.contributor), 0);
+}
+
+#[test]
+fn test_dispute_award_is_held() {
+    let s = setup();
+    s.escrow.assign_contributor(&1, &s.contributor);
+    s.escrow.raise_dispute(&1, &s.depositor);
+    s.escrow.resolve_dispute(
+        &1,
+        &vec![&s.env, s.contributor.clone()],
+        &vec![&s.env, 1_000i128],
+        &false,
+    );
+    assert_eq!(s.token.balance(&s.contributor), 0);
+    assert_eq!(
+        s.escrow.get_pending_release(&1, &s.contributor).amount,
+        1_000
+    );
+
+    s.advance(WINDOW);
+    s.escrow.finalize_release(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+}
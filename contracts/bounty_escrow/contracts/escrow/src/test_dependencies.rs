@@ -0,0 +1,108 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_setup::Setup;
+
+fn lock_many(s: &Setup, bounty_ids: &[u64]) {
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    let mut items = soroban_sdk::vec![&s.env];
+    for &bounty_id in bounty_ids {
+        items.push_back(BountyParams {
+            bounty_id,
+            amount: 100,
+            deadline,
+        });
+    }
+    s.escrow.lock_funds_batch(&s.depositor, &items);
+}
+
+#[test]
+fn test_dependent_bounty_waits_for_prerequisite() {
+    let s = Setup::new();
+    lock_many(&s, &[1, 2]);
+    s.escrow.set_bounty_dependency(&2, &1);
+    assert_eq!(s.escrow.get_bounty_dependency(&2), Some(1));
+
+    assert_eq!(
+        s.escrow.try_release_funds(&2, &s.contributor),
+        Err(Ok(Error::BountyNotSettled))
+    );
+
+    s.escrow.release_funds(&1, &s.contributor);
+    s.escrow.release_funds(&2, &s.contributor);
+    assert_eq!(s.escrow.get_escrow_info(&2).status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_refunded_prerequisite_does_not_unblock_release() {
+    let s = Setup::new();
+    lock_many(&s, &[1, 2]);
+    s.escrow.set_bounty_dependency(&2, &1);
+    s.escrow.cancel_bounty(&1);
+
+    assert_eq!(
+        s.escrow.try_release_funds(&2, &s.contributor),
+        Err(Ok(Error::BountyNotSettled))
+    );
+    // Refunds are never blocked by dependencies
+    s.escrow.cancel_bounty(&2);
+}
+
+#[test]
+fn test_cleared_dependency_unblocks_release() {
+    let s = Setup::new();
+    lock_many(&s, &[1, 2]);
+    s.escrow.set_bounty_dependency(&2, &1);
+    s.escrow.cancel_bounty(&1);
+
+    s.escrow.clear_bounty_dependency(&2);
+    assert_eq!(s.escrow.get_bounty_dependency(&2), None);
+    s.escrow.release_funds(&2, &s.contributor);
+    assert_eq!(
+        s.escrow.try_clear_bounty_dependency(&2),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_dependency_cannot_be_added_after_assignment() {
+    let s = Setup::new();
+    lock_many(&s, &[1, 2]);
+    s.escrow.assign_contributor(&2, &s.contributor);
+    assert_eq!(
+        s.escrow.try_set_bounty_dependency(&2, &1),
+        Err(Ok(Error::BountyAssigned))
+    );
+}
+
+#[test]
+fn test_archived_prerequisite_still_counts() {
+    let s = Setup::new();
+    lock_many(&s, &[1, 2]);
+    s.escrow.set_bounty_dependency(&2, &1);
+    s.escrow.release_funds(&1, &s.contributor);
+    s.escrow.archive_bounty(&1);
+
+    s.escrow.release_funds(&2, &s.contributor);
+}
+
+#[test]
+fn test_dependency_validation() {
+    let s = Setup::new();
+    lock_many(&s, &[1, 2, 3]);
+    s.escrow.set_bounty_dependency(&2, &1);
+    s.escrow.set_bounty_dependency(&3, &2);
+
+    assert_eq!(
+        s.escrow.try_set_bounty_dependency(&1, &3),
+        Err(Ok(Error::DependencyCycle))
+    );
+    assert_eq!(
+        s.escrow.try_set_bounty_dependency(&1, &1),
+        Err(Ok(Error::DependencyCycle))
+    );
+    assert_eq!(
+        s.escrow.try_set_bounty_dependency(&1, &9),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
@@ -1,54 +1,333 @@
-// Dispute resolution test stubs
-// These tests will be implemented once Issue 61 (dispute resolution) is complete
+#![cfg(test)]
+
+use super::*;
+use crate::test_setup::Setup;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env,
+};
+
+fn setup() -> (Setup<'static>, Address) {
+    let s = Setup::with_balance(1_000_000);
+    let arbiter = Address::generate(&s.env);
+    s.escrow.set_arbiter(&arbiter);
+    (s, arbiter)
+}
+
+/// Lock a bounty with the contributor assigned, so the depositor has a
+/// counterparty to dispute with.
+fn lock_assigned(s: &Setup, bounty_id: u64, amount: i128) -> u64 {
+    let deadline = s.lock(bounty_id, amount);
+    s.escrow.assign_contributor(&bounty_id, &s.contributor);
+    deadline
+}
 
 #[test]
 fn test_open_dispute_blocks_release() {
-    // TODO: Once dispute resolution is implemented (Issue 61), add:
-    // 1. Lock funds for a bounty
-    // 2. Open a dispute
-    // 3. Attempt to release funds
-    // 4. Assert that release is blocked while dispute is open
+    let (s, _) = setup();
+    lock_assigned(&s, 1, 1_000);
+    s.escrow.raise_dispute(&1, &s.depositor);
+
+    let result = s.escrow.try_release_funds(&1, &s.contributor);
+    assert_eq!(result, Err(Ok(Error::BountyDisputed)));
+    let result = s.escrow.try_partial_release(&1, &s.contributor, &100);
+    assert_eq!(result, Err(Ok(Error::BountyDisputed)));
+    let result = s.escrow.try_authorize_claim(&1, &s.contributor);
+    assert_eq!(result, Err(Ok(Error::BountyDisputed)));
 }
 
 #[test]
 fn test_open_dispute_blocks_refund() {
-    // TODO: Once dispute resolution is implemented (Issue 61), add:
-    // 1. Lock funds for a bounty
-    // 2. Wait for deadline to pass
-    // 3. Open a dispute
-    // 4. Attempt to refund
-    // 5. Assert that refund is blocked while dispute is open
+    let (s, _) = setup();
+    let deadline = lock_assigned(&s, 1, 1_000);
+    s.escrow.raise_dispute(&1, &s.depositor);
+    s.env.ledger().set_timestamp(deadline + 1);
+
+    let result = s.escrow.try_refund(&1);
+    assert_eq!(result, Err(Ok(Error::BountyDisputed)));
+    assert_eq!(s.token.balance(&s.escrow.address), 1_000);
 }
 
 #[test]
 fn test_resolve_dispute_in_favor_of_release() {
-    // TODO: Once dispute resolution is implemented (Issue 61), add:
-    // 1. Lock funds for a bounty
-    // 2. Open a dispute
-    // 3. Resolve dispute in favor of release
-    // 4. Verify funds are released to contributor
-    // 5. Verify escrow status is Released
-    // 6. Verify final balances are correct
+    let (s, _) = setup();
+    lock_assigned(&s, 1, 1_000);
+    s.escrow.raise_dispute(&1, &s.depositor);
+
+    s.escrow.resolve_dispute(
+        &1,
+        &vec![&s.env, s.contributor.clone()],
+        &vec![&s.env, 1_000i128],
+        &false,
+    );
+
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+    let info = s.escrow.get_escrow_info(&1);
+    assert_eq!(info.status, EscrowStatus::Released);
+    assert_eq!(info.remaining_amount, 0);
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
 }
 
 #[test]
 fn test_resolve_dispute_in_favor_of_refund() {
-    // TODO: Once dispute resolution is implemented (Issue 61), add:
-    // 1. Lock funds for a bounty
-    // 2. Open a dispute
-    // 3. Resolve dispute in favor of refund
-    // 4. Verify funds are refunded to depositor
-    // 5. Verify escrow status is Refunded
-    // 6. Verify final balances are correct
+    let (s, _) = setup();
+    lock_assigned(&s, 1, 1_000);
+    s.escrow.raise_dispute(&1, &s.depositor);
+
+    s.escrow.resolve_dispute(
+        &1,
+        &vec![&s.env, s.depositor.clone()],
+        &vec![&s.env, 1_000i128],
+        &false,
+    );
+
+    assert_eq!(s.token.balance(&s.depositor), 1_000_000);
+    let info = s.escrow.get_escrow_info(&1);
+    assert_eq!(info.status, EscrowStatus::Refunded);
+    assert_eq!(info.refund_history.len(), 1);
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
+}
+
+#[test]
+fn test_resolve_dispute_can_split_award() {
+    let (s, _) = setup();
+    lock_assigned(&s, 1, 1_000);
+    s.escrow.raise_dispute(&1, &s.depositor);
+
+    let result = s.escrow.try_resolve_dispute(
+        &1,
+        &vec![&s.env, s.contributor.clone(), s.depositor.clone()],
+        &vec![&s.env, 700i128, 200i128],
+        &false,
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+
+    s.escrow.resolve_dispute(
+        &1,
+        &vec![&s.env, s.contributor.clone(), s.depositor.clone()],
+        &vec![&s.env, 700i128, 300i128],
+        &false,
+    );
+    assert_eq!(s.token.balance(&s.contributor), 700);
+    assert_eq!(s.token.balance(&s.depositor), 999_300);
+    assert_eq!(s.escrow.get_release_history(&1).len(), 1);
 }
 
 #[test]
 fn test_dispute_status_tracking() {
-    // TODO: Once dispute resolution is implemented (Issue 61), add:
-    // 1. Lock funds for a bounty
-    // 2. Verify dispute status is not disputed
-    // 3. Open a dispute
-    // 4. Verify dispute status shows disputed with correct opener
-    // 5. Resolve dispute
-    // 6. Verify dispute status is no longer disputed
+    let (s, arbiter) = setup();
+    s.lock(1, 1_000);
+    assert_eq!(
+        s.escrow.try_get_dispute(&1),
+        Err(Ok(Error::DisputeNotFound))
+    );
+
+    s.escrow.authorize_claim(&1, &s.contributor);
+    s.escrow.raise_dispute(&1, &s.contributor);
+    let dispute = s.escrow.get_dispute(&1);
+    assert_eq!(dispute.raised_by, s.contributor);
+    assert_eq!(s.escrow.get_arbiter(), Some(arbiter.clone()));
+
+    s.escrow.resolve_dispute(
+        &1,
+        &vec![&s.env, s.contributor.clone()],
+        &vec![&s.env, 1_000i128],
+        &false,
+    );
+    assert_eq!(
+        s.escrow.try_get_dispute(&1),
+        Err(Ok(Error::DisputeNotFound))
+    );
+}
+
+#[test]
+fn test_only_parties_can_raise_dispute() {
+    let (s, _) = setup();
+    s.lock(1, 1_000);
+
+    let stranger = Address::generate(&s.env);
+    let result = s.escrow.try_raise_dispute(&1, &stranger);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    let result = s.escrow.try_raise_dispute(&1, &s.contributor);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    // Nobody is attached yet, so there is no one to dispute with
+    let result = s.escrow.try_raise_dispute(&1, &s.depositor);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    s.escrow.assign_contributor(&1, &s.contributor);
+    s.escrow.raise_dispute(&1, &s.depositor);
+    let result = s.escrow.try_raise_dispute(&1, &s.depositor);
+    assert_eq!(result, Err(Ok(Error::BountyDisputed)));
+}
+
+#[test]
+fn test_dispute_bond_forfeited_to_counterparty() {
+    let (s, _) = setup();
+    s.lock(1, 1_000);
+    s.escrow.set_dispute_bond(&50);
+    token::StellarAssetClient::new(&s.env, &s.token.address).mint(&s.contributor, &50);
+
+    s.escrow.authorize_claim(&1, &s.contributor);
+    s.escrow.raise_dispute(&1, &s.contributor);
+    let dispute = s.escrow.get_dispute(&1);
+    assert_eq!(dispute.bond, 50);
+    assert_eq!(dispute.counterparty, Some(s.depositor.clone()));
+    assert_eq!(s.token.balance(&s.contributor), 0);
+
+    s.escrow.resolve_dispute(
+        &1,
+        &vec![&s.env, s.depositor.clone()],
+        &vec![&s.env, 1_000i128],
+        &true,
+    );
+    assert_eq!(s.token.balance(&s.depositor), 1_000_050);
+    assert_eq!(s.token.balance(&s.contributor), 0);
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
+}
+
+#[test]
+fn test_dispute_bond_returned_when_upheld() {
+    let (s, _) = setup();
+    s.lock(1, 1_000);
+    s.escrow.set_dispute_bond(&50);
+    s.escrow.assign_contributor(&1, &s.contributor);
+
+    s.escrow.raise_dispute(&1, &s.depositor);
+    assert_eq!(s.token.balance(&s.escrow.address), 1_050);
+    assert_eq!(s.escrow.assert_solvency().liabilities, 1_050);
+
+    s.escrow.resolve_dispute(
+        &1,
+        &vec![&s.env, s.depositor.clone()],
+        &vec![&s.env, 1_000i128],
+        &false,
+    );
+    assert_eq!(s.token.balance(&s.depositor), 1_000_000);
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
+    assert_eq!(s.escrow.assert_solvency().liabilities, 0);
+}
+
+#[test]
+fn test_depositor_dispute_over_held_release_names_its_recipient() {
+    let (s, _) = setup();
+    s.escrow.set_clawback_window(&3_600);
+    s.lock(1, 1_000);
+    s.escrow.set_dispute_bond(&50);
+    s.escrow.partial_release(&1, &s.contributor, &400);
+
+    s.escrow.raise_dispute(&1, &s.depositor);
+    let dispute = s.escrow.get_dispute(&1);
+    assert_eq!(dispute.bond, 50);
+    assert_eq!(dispute.counterparty, Some(s.contributor.clone()));
+}
+
+#[test]
+fn test_dispute_requires_an_arbiter() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&depositor, &1_000);
+    let escrow =
+        BountyEscrowContractClient::new(&env, &env.register_contract(None, BountyEscrowContract));
+    escrow.init(&admin, &sac.address());
+
+    let deadline = env.ledger().timestamp() + 1_000;
+    escrow.lock_funds(&depositor, &1, &1_000, &deadline);
+    escrow.assign_contributor(&1, &contributor);
+    assert_eq!(
+        escrow.try_raise_dispute(&1, &depositor),
+        Err(Ok(Error::ArbiterNotSet))
+    );
+}
+
+#[test]
+fn test_dispute_bond_must_not_be_negative() {
+    let (s, _) = setup();
+    assert_eq!(
+        s.escrow.try_set_dispute_bond(&-1),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(s.escrow.get_dispute_bond(), 0);
+}
+
+#[test]
+fn test_bounty_arbiter_overrides_global_arbiter() {
+    let (s, arbiter) = setup();
+    let partner = Address::generate(&s.env);
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    s.escrow
+        .lock_funds_with_arbiter(&s.depositor, &1, &1_000, &deadline, &partner);
+    assert_eq!(s.escrow.get_bounty_arbiter(&1), Some(partner.clone()));
+    s.escrow.assign_contributor(&1, &s.contributor);
+
+    s.escrow.raise_dispute(&1, &s.depositor);
+    s.escrow.resolve_dispute(
+        &1,
+        &vec![&s.env, s.depositor.clone()],
+        &vec![&s.env, 1_000],
+        &false,
+    );
+
+    let auths = s.env.auths();
+    assert!(auths.iter().any(|(address, _)| *address == partner));
+    assert!(!auths.iter().any(|(address, _)| *address == arbiter));
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Refunded);
+}
+
+#[test]
+fn test_set_bounty_arbiter_needs_both_parties() {
+    let (s, arbiter) = setup();
+    let partner = Address::generate(&s.env);
+    s.lock(1, 1_000);
+    s.escrow.assign_contributor(&1, &s.contributor);
+
+    s.escrow.set_bounty_arbiter(&1, &partner);
+    let auths = s.env.auths();
+    assert!(auths.iter().any(|(address, _)| *address == s.depositor));
+    assert!(auths.iter().any(|(address, _)| *address == s.contributor));
+    assert_eq!(s.escrow.get_bounty_arbiter(&1), Some(partner.clone()));
+
+    s.escrow.raise_dispute(&1, &s.contributor);
+    assert_eq!(
+        s.escrow.try_set_bounty_arbiter(&1, &arbiter),
+        Err(Ok(Error::BountyDisputed))
+    );
+}
+
+#[test]
+fn test_bounty_arbiter_cannot_be_a_party() {
+    let (s, _) = setup();
+    lock_assigned(&s, 1, 1_000);
+    assert_eq!(
+        s.escrow.try_set_bounty_arbiter(&1, &s.depositor),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.escrow.try_set_bounty_arbiter(&1, &s.contributor),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    assert_eq!(
+        s.escrow
+            .try_lock_funds_with_arbiter(&s.depositor, &2, &1_000, &deadline, &s.depositor),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_set_bounty_arbiter_without_contributor_needs_admin() {
+    let (s, _) = setup();
+    let partner = Address::generate(&s.env);
+    s.lock(1, 1_000);
+
+    s.escrow.set_bounty_arbiter(&1, &partner);
+    let auths = s.env.auths();
+    assert!(auths.iter().any(|(address, _)| *address == s.depositor));
+    assert!(auths
+        .iter()
+        .any(|(address, _)| *address == s.escrow.get_admin().unwrap()));
 }
@@ -0,0 +1,127 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_setup::Setup;
+use soroban_sdk::{testutils::Ledger, Bytes};
+
+fn reference(s: &Setup, text: &str) -> Bytes {
+    Bytes::from_slice(&s.env, text.as_bytes())
+}
+
+#[test]
+fn test_duplicate_reference_rejected_while_active() {
+    let s = Setup::new();
+    s.lock(1, 1_000);
+    s.lock(2, 1_000);
+
+    let hash = s
+        .escrow
+        .link_external_ref(&s.depositor, &1, &reference(&s, "grainlify/app#123"));
+    assert_eq!(s.escrow.get_external_ref(&1), Some(hash));
+    assert_eq!(
+        s.escrow
+            .get_bounty_by_ref(&reference(&s, "grainlify/app#123")),
+        Some(1)
+    );
+
+    // Case and surrounding whitespace are normalized away.
+    assert_eq!(
+        s.escrow
+            .try_link_external_ref(&s.depositor, &2, &reference(&s, "  Grainlify/App#123\n")),
+        Err(Ok(Error::ExternalRefInUse))
+    );
+    s.escrow
+        .link_external_ref(&s.depositor, &2, &reference(&s, "grainlify/app#124"));
+}
+
+#[test]
+fn test_reference_reusable_after_settlement() {
+    let s = Setup::new();
+    let deadline = s.lock(1, 1_000);
+    s.lock(2, 1_000);
+    s.escrow
+        .link_external_ref(&s.depositor, &1, &reference(&s, "grainlify/app#7"));
+
+    s.escrow.release_funds(&1, &s.contributor);
+    s.escrow
+        .link_external_ref(&s.depositor, &2, &reference(&s, "grainlify/app#7"));
+    assert_eq!(
+        s.escrow
+            .get_bounty_by_ref(&reference(&s, "grainlify/app#7")),
+        Some(2)
+    );
+
+    s.env.ledger().set_timestamp(deadline + 1);
+    s.escrow.refund(&2);
+    s.escrow.archive_bounty(&2);
+    assert_eq!(
+        s.escrow
+            .get_bounty_by_ref(&reference(&s, "grainlify/app#7")),
+        None
+    );
+}
+
+#[test]
+fn test_relinking_frees_previous_reference() {
+    let s = Setup::new();
+    s.lock(1, 1_000);
+    s.lock(2, 1_000);
+
+    s.escrow
+        .link_external_ref(&s.depositor, &1, &reference(&s, "grainlify/app#1"));
+    s.escrow
+        .link_external_ref(&s.depositor, &1, &reference(&s, "grainlify/app#2"));
+    assert_eq!(
+        s.escrow
+            .get_bounty_by_ref(&reference(&s, "grainlify/app#1")),
+        None
+    );
+    s.escrow
+        .link_external_ref(&s.depositor, &2, &reference(&s, "grainlify/app#1"));
+}
+
+#[test]
+fn test_lock_with_reference_is_atomic() {
+    let s = Setup::new();
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    let hash = s.escrow.lock_funds_with_ref(
+        &s.depositor,
+        &1,
+        &1_000,
+        &deadline,
+        &reference(&s, "grainlify/app#9"),
+    );
+    assert_eq!(s.escrow.get_external_ref(&1), Some(hash));
+
+    // A clashing reference leaves no second bounty behind
+    assert_eq!(
+        s.escrow.try_lock_funds_with_ref(
+            &s.depositor,
+            &2,
+            &1_000,
+            &deadline,
+            &reference(&s, "Grainlify/App#9"),
+        ),
+        Err(Ok(Error::ExternalRefInUse))
+    );
+    assert_eq!(
+        s.escrow.try_get_escrow_info(&2),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_only_depositor_or_admin_can_link() {
+    let s = Setup::new();
+    s.lock(1, 1_000);
+
+    assert_eq!(
+        s.escrow
+            .try_link_external_ref(&s.contributor, &1, &reference(&s, "grainlify/app#5")),
+        Err(Ok(Error::Unauthorized))
+    );
+    let admin = s.escrow.get_admin().unwrap();
+    s.escrow
+        .link_external_ref(&admin, &1, &reference(&s, "grainlify/app#5"));
+    assert!(s.env.auths().iter().any(|(a, _)| *a == admin));
+}
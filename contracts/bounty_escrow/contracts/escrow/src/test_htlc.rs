@@ -0,0 +1,118 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_setup::Setup;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Bytes, BytesN,
+};
+
+fn secret(s: &Setup) -> Bytes {
+    Bytes::from_slice(&s.env, b"ci-run-4821")
+}
+
+fn lock(s: &Setup, bounty_id: u64, amount: i128) -> u64 {
+    let hashlock: BytesN<32> = s.env.crypto().sha256(&secret(s)).into();
+    let timelock = s.env.ledger().timestamp() + 1_000;
+    s.escrow.lock_funds_htlc(
+        &s.depositor,
+        &bounty_id,
+        &amount,
+        &s.contributor,
+        &hashlock,
+        &timelock,
+    );
+    timelock
+}
+
+#[test]
+fn test_claim_with_preimage_releases_to_contributor() {
+    let s = Setup::new();
+    lock(&s, 1, 1_000);
+    assert_eq!(s.escrow.get_htlc(&1).contributor, s.contributor);
+
+    s.env.set_auths(&[]);
+    s.escrow.claim_htlc(&1, &secret(&s));
+
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+    let info = s.escrow.get_escrow_info(&1);
+    assert_eq!(info.status, EscrowStatus::Released);
+    assert_eq!(info.remaining_amount, 0);
+}
+
+#[test]
+fn test_wrong_preimage_rejected() {
+    let s = Setup::new();
+    lock(&s, 1, 1_000);
+
+    let wrong = Bytes::from_slice(&s.env, b"guess");
+    assert_eq!(
+        s.escrow.try_claim_htlc(&1, &wrong),
+        Err(Ok(Error::InvalidPreimage))
+    );
+    assert_eq!(s.token.balance(&s.escrow.address), 1_000);
+}
+
+#[test]
+fn test_depositor_refunds_after_timelock() {
+    let s = Setup::new();
+    let timelock = lock(&s, 1, 1_000);
+    s.env.ledger().set_timestamp(timelock);
+
+    assert_eq!(
+        s.escrow.try_claim_htlc(&1, &secret(&s)),
+        Err(Ok(Error::TimelockExpired))
+    );
+
+    s.env.ledger().set_timestamp(timelock + 1);
+    s.escrow.refund(&1);
+    assert_eq!(s.token.balance(&s.depositor), 10_000);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Refunded);
+}
+
+#[test]
+fn test_htlc_lock_validation() {
+    let s = Setup::new();
+    let hashlock = BytesN::from_array(&s.env, &[1u8; 32]);
+    let now = s.env.ledger().timestamp();
+    assert_eq!(
+        s.escrow
+            .try_lock_funds_htlc(&s.depositor, &1, &1_000, &s.contributor, &hashlock, &now),
+        Err(Ok(Error::InvalidDeadline))
+    );
+
+    s.escrow
+        .lock_funds(&s.depositor, &2, &1_000, &(now + 1_000));
+    assert_eq!(
+        s.escrow.try_claim_htlc(&2, &secret(&s)),
+        Err(Ok(Error::HtlcNotFound))
+    );
+}
+
+#[test]
+fn test_htlc_bounty_rejects_other_release_paths() {
+    let s = Setup::new();
+    lock(&s, 1, 1_000);
+    let other = Address::generate(&s.env);
+
+    assert_eq!(
+        s.escrow.try_release_funds(&1, &other),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.escrow.try_partial_release(&1, &other, &100),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.escrow.try_release_milestone(&1, &0, &other),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.escrow.try_approve_release(&1, &other, &s.depositor),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(s.token.balance(&other), 0);
+
+    s.escrow.claim_htlc(&1, &secret(&s));
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+}
@@ -0,0 +1,110 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_setup::Setup;
+use soroban_sdk::{
+    testutils::{AuthorizedFunction, Ledger},
+    vec, IntoVal, Symbol,
+};
+
+fn setup() -> Setup<'static> {
+    let s = Setup::new();
+    s.env.ledger().set_timestamp(1_000);
+    s
+}
+
+fn params(s: &Setup, bounty_id: u64, amount: i128) -> BountyParams {
+    BountyParams {
+        bounty_id,
+        amount,
+        deadline: s.env.ledger().timestamp() + 1_000,
+    }
+}
+
+#[test]
+fn test_lock_funds_batch_creates_all_bounties() {
+    let s = setup();
+    let items = vec![
+        &s.env,
+        params(&s, 1, 100),
+        params(&s, 2, 200),
+        params(&s, 3, 300),
+    ];
+
+    assert_eq!(s.escrow.lock_funds_batch(&s.depositor, &items), 3);
+
+    for (bounty_id, amount) in [(1u64, 100i128), (2, 200), (3, 300)] {
+        let escrow = s.escrow.get_escrow_info(&bounty_id);
+        assert_eq!(escrow.depositor, s.depositor);
+        assert_eq!(escrow.amount, amount);
+        assert_eq!(escrow.status, EscrowStatus::Locked);
+    }
+    assert_eq!(s.token.balance(&s.depositor), 9_400);
+    assert_eq!(s.escrow.get_balance(), 600);
+    assert_eq!(s.escrow.get_stats().total_locked, 600);
+}
+
+#[test]
+fn test_lock_funds_batch_authorizes_once_for_the_total() {
+    let s = setup();
+    let items = vec![&s.env, params(&s, 1, 100), params(&s, 2, 200)];
+    s.escrow.lock_funds_batch(&s.depositor, &items);
+
+    let auths = s.env.auths();
+    assert_eq!(auths.len(), 1);
+    let (address, invocation) = &auths[0];
+    assert_eq!(address, &s.depositor);
+    assert_eq!(
+        invocation.function,
+        AuthorizedFunction::Contract((
+            s.escrow.address.clone(),
+            Symbol::new(&s.env, "lock_funds_batch"),
+            (s.depositor.clone(), items.clone()).into_val(&s.env),
+        ))
+    );
+    assert_eq!(invocation.sub_invocations.len(), 1);
+    assert_eq!(
+        invocation.sub_invocations[0].function,
+        AuthorizedFunction::Contract((
+            s.token.address.clone(),
+            Symbol::new(&s.env, "transfer"),
+            (s.depositor.clone(), s.escrow.address.clone(), 300i128).into_val(&s.env),
+        ))
+    );
+}
+
+#[test]
+fn test_lock_funds_batch_is_all_or_nothing() {
+    let s = setup();
+    s.escrow
+        .lock_funds(&s.depositor, &2, &50, &(s.env.ledger().timestamp() + 1_000));
+    // Step past the depositor's rate-limit cooldown from the lock above.
+    s.env.ledger().with_mut(|li| li.timestamp += 3_600);
+
+    let existing = vec![&s.env, params(&s, 1, 100), params(&s, 2, 200)];
+    assert_eq!(
+        s.escrow.try_lock_funds_batch(&s.depositor, &existing),
+        Err(Ok(Error::BountyExists))
+    );
+
+    let duplicate = vec![&s.env, params(&s, 3, 100), params(&s, 3, 200)];
+    assert_eq!(
+        s.escrow.try_lock_funds_batch(&s.depositor, &duplicate),
+        Err(Ok(Error::DuplicateBountyId))
+    );
+
+    let zero = vec![&s.env, params(&s, 4, 100), params(&s, 5, 0)];
+    assert_eq!(
+        s.escrow.try_lock_funds_batch(&s.depositor, &zero),
+        Err(Ok(Error::InvalidAmount))
+    );
+
+    assert_eq!(
+        s.escrow.try_lock_funds_batch(&s.depositor, &vec![&s.env]),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+
+    assert!(s.escrow.try_get_escrow_info(&1).is_err());
+    assert!(s.escrow.try_get_escrow_info(&4).is_err());
+    assert_eq!(s.token.balance(&s.depositor), 9_950);
+}
@@ -0,0 +1,96 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_setup::Setup;
+use soroban_sdk::{testutils::Ledger, vec};
+
+fn setup() -> Setup<'static> {
+    let s = Setup::new();
+    s.env.ledger().set_timestamp(1_000);
+    s
+}
+
+fn now(s: &Setup) -> u64 {
+    s.env.ledger().timestamp()
+}
+
+#[test]
+fn test_max_deadline_duration_defaults_to_unlimited() {
+    let s = setup();
+    assert_eq!(s.escrow.get_max_deadline_duration(), None);
+
+    let far = now(&s) + 10 * 365 * 24 * 60 * 60;
+    s.escrow.lock_funds(&s.depositor, &1, &100, &far);
+    assert_eq!(s.escrow.get_escrow_info(&1).deadline, far);
+}
+
+#[test]
+fn test_lock_rejects_deadline_beyond_max_duration() {
+    let s = setup();
+    s.escrow.set_max_deadline_duration(&500);
+    assert_eq!(s.escrow.get_max_deadline_duration(), Some(500));
+
+    let limit = now(&s) + 500;
+    assert_eq!(
+        s.escrow
+            .try_lock_funds(&s.depositor, &1, &100, &(limit + 1)),
+        Err(Ok(Error::InvalidDeadline))
+    );
+
+    s.escrow.lock_funds(&s.depositor, &1, &100, &limit);
+    assert_eq!(s.escrow.get_escrow_info(&1).deadline, limit);
+}
+
+#[test]
+fn test_zero_duration_removes_cap() {
+    let s = setup();
+    s.escrow.set_max_deadline_duration(&500);
+    s.escrow.set_max_deadline_duration(&0);
+    assert_eq!(s.escrow.get_max_deadline_duration(), None);
+
+    s.escrow
+        .lock_funds(&s.depositor, &1, &100, &(now(&s) + 10_000));
+}
+
+#[test]
+fn test_batch_lock_enforces_limits() {
+    let s = setup();
+    s.escrow.set_amount_policy(&s.admin, &50, &5_000);
+    s.escrow.set_max_deadline_duration(&500);
+    assert_eq!(s.escrow.get_amount_policy(), Some((50, 5_000)));
+
+    let item = |bounty_id: u64, amount: i128, deadline: u64| LockFundsItem {
+        bounty_id,
+        depositor: s.depositor.clone(),
+        amount,
+        deadline,
+    };
+
+    let dust = vec![
+        &s.env,
+        item(1, 100, now(&s) + 100),
+        item(2, 10, now(&s) + 100),
+    ];
+    assert_eq!(
+        s.escrow.try_batch_lock_funds(&dust),
+        Err(Ok(Error::AmountBelowMinimum))
+    );
+
+    let too_late = vec![
+        &s.env,
+        item(1, 100, now(&s) + 100),
+        item(2, 100, now(&s) + 501),
+    ];
+    assert_eq!(
+        s.escrow.try_batch_lock_funds(&too_late),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    assert!(s.escrow.try_get_escrow_info(&1).is_err());
+
+    let ok = vec![
+        &s.env,
+        item(1, 100, now(&s) + 100),
+        item(2, 100, now(&s) + 500),
+    ];
+    assert_eq!(s.escrow.batch_lock_funds(&ok), 2);
+}
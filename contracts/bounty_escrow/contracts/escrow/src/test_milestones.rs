@@ -0,0 +1,80 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_setup::Setup;
+use soroban_sdk::vec;
+
+#[test]
+fn test_milestones_release_tranche_by_tranche() {
+    let s = Setup::with_balance(1_000_000);
+    s.lock(1, 1_000);
+    s.escrow
+        .define_milestones(&1, &vec![&s.env, 200i128, 300i128, 500i128]);
+
+    s.escrow.release_milestone(&1, &1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 300);
+    let info = s.escrow.get_escrow_info(&1);
+    assert_eq!(info.remaining_amount, 700);
+    assert_eq!(info.status, EscrowStatus::Locked);
+    assert!(s.escrow.get_milestones(&1).get(1).unwrap().released);
+
+    s.escrow.release_milestone(&1, &0, &s.contributor);
+    s.escrow.release_milestone(&1, &2, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+    let info = s.escrow.get_escrow_info(&1);
+    assert_eq!(info.remaining_amount, 0);
+    assert_eq!(info.status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_milestone_cannot_be_released_twice() {
+    let s = Setup::with_balance(1_000_000);
+    s.lock(1, 1_000);
+    s.escrow
+        .define_milestones(&1, &vec![&s.env, 400i128, 600i128]);
+    s.escrow.release_milestone(&1, &0, &s.contributor);
+
+    let result = s.escrow.try_release_milestone(&1, &0, &s.contributor);
+    assert_eq!(result, Err(Ok(Error::MilestoneAlreadyReleased)));
+    let result = s.escrow.try_release_milestone(&1, &2, &s.contributor);
+    assert_eq!(result, Err(Ok(Error::InvalidMilestone)));
+}
+
+#[test]
+fn test_milestones_must_sum_to_remaining_amount() {
+    let s = Setup::with_balance(1_000_000);
+    s.lock(1, 1_000);
+
+    let result = s
+        .escrow
+        .try_define_milestones(&1, &vec![&s.env, 400i128, 500i128]);
+    assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+    let result = s
+        .escrow
+        .try_define_milestones(&1, &vec![&s.env, 1_001i128, -1i128]);
+    assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+}
+
+#[test]
+fn test_milestones_locked_in_after_first_release() {
+    let s = Setup::with_balance(1_000_000);
+    s.lock(1, 1_000);
+    s.escrow
+        .define_milestones(&1, &vec![&s.env, 500i128, 500i128]);
+    s.escrow
+        .define_milestones(&1, &vec![&s.env, 250i128, 750i128]);
+    s.escrow.release_milestone(&1, &0, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 250);
+
+    let result = s.escrow.try_define_milestones(&1, &vec![&s.env, 750i128]);
+    assert_eq!(result, Err(Ok(Error::MilestoneAlreadyReleased)));
+}
+
+#[test]
+fn test_release_milestone_requires_definition() {
+    let s = Setup::with_balance(1_000_000);
+    s.lock(1, 1_000);
+
+    let result = s.escrow.try_release_milestone(&1, &0, &s.contributor);
+    assert_eq!(result, Err(Ok(Error::MilestonesNotDefined)));
+}
@@ -0,0 +1,154 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_setup::Setup;
+use soroban_sdk::{testutils::Address as _, vec, Address, Bytes, BytesN};
+
+fn setup() -> Setup<'static> {
+    let s = Setup::with_balance(100_000);
+    let signers = vec![
+        &s.env,
+        Address::generate(&s.env),
+        Address::generate(&s.env),
+        Address::generate(&s.env),
+    ];
+    // 2-of-3 above 10_000
+    s.escrow.update_multisig_config(&10_000, &signers, &2);
+    s
+}
+
+fn signers(s: &Setup) -> Vec<Address> {
+    s.escrow.get_multisig_config().signers
+}
+
+fn approve(s: &Setup, bounty_id: u64, contributor: &Address, signer: u32) {
+    s.escrow
+        .approve_large_release(&bounty_id, contributor, &signers(s).get(signer).unwrap());
+}
+
+#[test]
+fn test_small_release_needs_no_approvals() {
+    let s = setup();
+    s.lock(1, 10_000);
+    s.escrow.release_funds(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 10_000);
+}
+
+#[test]
+fn test_large_release_requires_threshold_approvals() {
+    let s = setup();
+    s.lock(1, 50_000);
+
+    assert_eq!(
+        s.escrow.try_release_funds(&1, &s.contributor),
+        Err(Ok(Error::Unauthorized))
+    );
+    approve(&s, 1, &s.contributor, 0);
+    assert_eq!(
+        s.escrow.try_release_funds(&1, &s.contributor),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    approve(&s, 1, &s.contributor, 2);
+    s.escrow.release_funds(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 50_000);
+}
+
+#[test]
+fn test_approvals_bind_to_contributor() {
+    let s = setup();
+    s.lock(1, 50_000);
+    let other = Address::generate(&s.env);
+
+    approve(&s, 1, &s.contributor, 0);
+    approve(&s, 1, &s.contributor, 1);
+    assert_eq!(
+        s.escrow.try_release_funds(&1, &other),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // Switching contributor starts a fresh approval set.
+    approve(&s, 1, &other, 0);
+    assert_eq!(
+        s.escrow.try_partial_release(&1, &s.contributor, &1_000),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_partial_releases_consume_approvals() {
+    let s = setup();
+    s.lock(1, 50_000);
+
+    approve(&s, 1, &s.contributor, 0);
+    approve(&s, 1, &s.contributor, 1);
+    s.escrow.partial_release(&1, &s.contributor, &20_000);
+    assert_eq!(
+        s.escrow.try_partial_release(&1, &s.contributor, &20_000),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_removed_signer_approval_no_longer_counts() {
+    let s = setup();
+    s.lock(1, 50_000);
+    approve(&s, 1, &s.contributor, 0);
+    approve(&s, 1, &s.contributor, 1);
+
+    let mut remaining = signers(&s);
+    remaining.remove(0);
+    s.escrow.update_multisig_config(&10_000, &remaining, &2);
+    assert_eq!(
+        s.escrow.try_release_funds(&1, &s.contributor),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_split_release_approvals_bind_to_lead_contributor() {
+    let s = setup();
+    s.lock(1, 50_000);
+    let other = Address::generate(&s.env);
+    let contributors = vec![&s.env, s.contributor.clone(), other.clone()];
+    let shares = vec![&s.env, 5_000u32, 5_000u32];
+
+    // Approvals for a non-lead contributor do not cover the split
+    approve(&s, 1, &other, 0);
+    approve(&s, 1, &other, 1);
+    assert_eq!(
+        s.escrow.try_release_split(&1, &contributors, &shares),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    approve(&s, 1, &s.contributor, 0);
+    approve(&s, 1, &s.contributor, 1);
+    s.escrow.release_split(&1, &contributors, &shares);
+    assert_eq!(s.token.balance(&s.contributor), 25_000);
+    assert_eq!(s.token.balance(&other), 25_000);
+}
+
+#[test]
+fn test_large_htlc_claim_requires_approvals() {
+    let s = setup();
+    let preimage = Bytes::from_slice(&s.env, b"secret");
+    let hashlock: BytesN<32> = s.env.crypto().sha256(&preimage).into();
+    let timelock = s.env.ledger().timestamp() + 1_000;
+    s.escrow.lock_funds_htlc(
+        &s.depositor,
+        &1,
+        &50_000,
+        &s.contributor,
+        &hashlock,
+        &timelock,
+    );
+
+    assert_eq!(
+        s.escrow.try_claim_htlc(&1, &preimage),
+        Err(Ok(Error::Unauthorized))
+    );
+    approve(&s, 1, &s.contributor, 0);
+    approve(&s, 1, &s.contributor, 1);
+    s.escrow.claim_htlc(&1, &preimage);
+    assert_eq!(s.token.balance(&s.contributor), 50_000);
+}
@@ -0,0 +1,160 @@
+#![cfg(test)]
+
+extern crate std;
+
+use super::*;
+use crate::test_setup::Setup;
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, BytesN,
+};
+
+fn oracle() -> SigningKey {
+    SigningKey::from_bytes(&[7u8; 32])
+}
+
+fn lock_with_oracle(s: &Setup, bounty_id: u64, amount: i128) {
+    s.lock(bounty_id, amount);
+    let public_key = BytesN::from_array(&s.env, &oracle().verifying_key().to_bytes());
+    s.escrow.set_release_oracle(&bounty_id, &public_key);
+}
+
+fn attestation(s: &Setup, bounty_id: u64) -> ReleaseAttestation {
+    ReleaseAttestation {
+        contract: s.escrow.address.clone(),
+        bounty_id,
+        contributor: s.contributor.clone(),
+        expires_at: s.env.ledger().timestamp() + 600,
+    }
+}
+
+fn sign(s: &Setup, key: &SigningKey, payload: &ReleaseAttestation) -> BytesN<64> {
+    let message: std::vec::Vec<u8> = payload.clone().to_xdr(&s.env).iter().collect();
+    BytesN::from_array(&s.env, &key.sign(&message).to_bytes())
+}
+
+#[test]
+fn test_attested_release_pays_contributor() {
+    let s = Setup::new();
+    lock_with_oracle(&s, 1, 1_000);
+    let payload = attestation(&s, 1);
+    let signature = sign(&s, &oracle(), &payload);
+
+    s.env.set_auths(&[]);
+    s.escrow.release_with_attestation(&1, &payload, &signature);
+
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_signature_from_other_key_rejected() {
+    let s = Setup::new();
+    lock_with_oracle(&s, 1, 1_000);
+    let payload = attestation(&s, 1);
+    let signature = sign(&s, &SigningKey::from_bytes(&[9u8; 32]), &payload);
+
+    assert!(s
+        .escrow
+        .try_release_with_attestation(&1, &payload, &signature)
+        .is_err());
+    assert_eq!(s.token.balance(&s.escrow.address), 1_000);
+}
+
+#[test]
+fn test_attestation_must_match_bounty_and_be_fresh() {
+    let s = Setup::new();
+    lock_with_oracle(&s, 1, 1_000);
+    lock_with_oracle(&s, 2, 1_000);
+
+    let payload = attestation(&s, 2);
+    let signature = sign(&s, &oracle(), &payload);
+    assert_eq!(
+        s.escrow
+            .try_release_with_attestation(&1, &payload, &signature),
+        Err(Ok(Error::InvalidAttestation))
+    );
+
+    let payload = attestation(&s, 1);
+    let signature = sign(&s, &oracle(), &payload);
+    s.env.ledger().set_timestamp(payload.expires_at + 1);
+    assert_eq!(
+        s.escrow
+            .try_release_with_attestation(&1, &payload, &signature),
+        Err(Ok(Error::AttestationExpired))
+    );
+}
+
+#[test]
+fn test_release_requires_configured_oracle() {
+    let s = Setup::new();
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    s.escrow.lock_funds(&s.depositor, &1, &1_000, &deadline);
+    assert_eq!(s.escrow.get_release_oracle(&1), None);
+
+    let payload = attestation(&s, 1);
+    let signature = sign(&s, &oracle(), &payload);
+    assert_eq!(
+        s.escrow
+            .try_release_with_attestation(&1, &payload, &signature),
+        Err(Ok(Error::ReleaseOracleNotSet))
+    );
+}
+
+#[test]
+fn test_attestation_for_assigned_bounty_pays_only_approved_submitter() {
+    let s = Setup::new();
+    lock_with_oracle(&s, 1, 1_000);
+    let assignee = Address::generate(&s.env);
+    s.escrow.assign_contributor(&1, &assignee);
+
+    // The oracle names someone other than the assignee
+    let payload = attestation(&s, 1);
+    let signature = sign(&s, &oracle(), &payload);
+    assert_eq!(
+        s.escrow
+            .try_release_with_attestation(&1, &payload, &signature),
+        Err(Ok(Error::SubmissionNotApproved))
+    );
+    assert_eq!(s.token.balance(&s.escrow.address), 1_000);
+}
+
+#[test]
+fn test_attested_release_is_held_during_clawback_window() {
+    let s = Setup::new();
+    s.escrow.set_clawback_window(&3_600);
+    lock_with_oracle(&s, 1, 1_000);
+    let payload = attestation(&s, 1);
+    let signature = sign(&s, &oracle(), &payload);
+
+    s.escrow.release_with_attestation(&1, &payload, &signature);
+    assert_eq!(s.token.balance(&s.contributor), 0);
+    let pending = s.escrow.get_pending_release(&1, &s.contributor);
+    assert_eq!(pending.recipient, s.contributor);
+    assert_eq!(pending.amount, 1_000);
+
+    s.env.ledger().set_timestamp(pending.final_at);
+    s.escrow.finalize_release(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+}
+
+#[test]
+fn test_clawed_back_attestation_cannot_be_replayed() {
+    let s = Setup::new();
+    s.escrow.set_arbiter(&Address::generate(&s.env));
+    s.escrow.set_clawback_window(&3_600);
+    lock_with_oracle(&s, 1, 1_000);
+    let payload = attestation(&s, 1);
+    let signature = sign(&s, &oracle(), &payload);
+    s.escrow.release_with_attestation(&1, &payload, &signature);
+
+    s.escrow.clawback_release(&1, &s.contributor);
+    assert_eq!(
+        s.escrow
+            .try_release_with_attestation(&1, &payload, &signature),
+        Err(Ok(Error::ReleaseOracleNotSet))
+    );
+    assert_eq!(s.token.balance(&s.contributor), 0);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Locked);
+}
@@ -483,14 +483,10 @@ fn test_prime_amount_with_coprime_payouts() {
 }
 
 // ===========================================================================
-// 11. Partial release then full release_funds must fail
+// 11. Partial release then full release
 // ===========================================================================
 
-/// After a partial release (leaving some remaining), release_funds must still
-/// try to transfer the *original* full amount and succeed only when remaining
-/// matches. In the current implementation, release_funds always transfers
-/// `escrow.amount` which would exceed the contract balance — it should fail
-/// after a partial release has already reduced the actual balance.
+/// Two partial releases drain the escrow exactly.
 #[test]
 fn test_partial_release_then_full_release_drains_correctly() {
     let s = Setup::new();
@@ -511,6 +507,46 @@ fn test_partial_release_then_full_release_drains_correctly() {
     assert_eq!(s.token.balance(&s.contributor), amount);
 }
 
+/// `release_funds` after a partial release pays only what is left.
+#[test]
+fn test_partial_release_then_release_funds_pays_remaining() {
+    let s = Setup::new();
+    s.lock(19, 100);
+
+    s.escrow.partial_release(&19, &s.contributor, &30_i128);
+    s.escrow.release_funds(&19, &s.contributor);
+
+    let info = s.escrow.get_escrow_info(&19);
+    assert_eq!(info.remaining_amount, 0);
+    assert_eq!(info.status, EscrowStatus::Released);
+    assert_eq!(s.token.balance(&s.contributor), 100);
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
+}
+
+/// A held release after a partial release records only what is left.
+#[test]
+fn test_partial_release_then_held_release_holds_remaining() {
+    let s = Setup::new();
+    s.lock(20, 100);
+    s.escrow.set_clawback_window(&3_600);
+
+    // The partial payout is held too; the remainder joins the same hold
+    s.escrow.partial_release(&20, &s.contributor, &30_i128);
+    assert_eq!(s.escrow.get_pending_release(&20, &s.contributor).amount, 30);
+    s.escrow.release_funds(&20, &s.contributor);
+    assert_eq!(
+        s.escrow.get_pending_release(&20, &s.contributor).amount,
+        100
+    );
+
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + 3_600);
+    s.escrow.finalize_release(&20, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 100);
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
+}
+
 // ===========================================================================
 // 12. Single-unit payout to different contributor each time
 // ===========================================================================
@@ -637,3 +637,77 @@ fn test_rbac_after_emergency_withdraw_can_unpause_and_reuse() {
     escrow_client.lock_funds(&new_depositor, &99u64, &200i128, &deadline);
     assert_eq!(token_client.balance(&escrow_client.address), 200);
 }
+
+#[test]
+fn test_pause_blocks_lock_release_and_refund() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, _, _token_client, escrow_client) = setup_rbac_env(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 1000;
+
+    escrow_client.pause(&Some(soroban_sdk::String::from_str(&env, "incident")));
+    assert!(escrow_client.is_paused());
+    let flags = escrow_client.get_pause_flags();
+    assert!(flags.lock_paused && flags.release_paused && flags.refund_paused);
+
+    assert_eq!(
+        escrow_client.try_lock_funds(&depositor, &2u64, &100i128, &deadline),
+        Err(Ok(Error::FundsPaused))
+    );
+    assert_eq!(
+        escrow_client.try_release_funds(&1u64, &contributor),
+        Err(Ok(Error::FundsPaused))
+    );
+    env.ledger().set_timestamp(deadline + 1);
+    assert_eq!(escrow_client.try_refund(&1u64), Err(Ok(Error::FundsPaused)));
+
+    escrow_client.unpause();
+    assert!(!escrow_client.is_paused());
+    assert_eq!(escrow_client.get_pause_flags().pause_reason, None);
+    escrow_client.refund(&1u64);
+}
+
+#[test]
+fn test_pause_emits_event_per_operation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, _, _token_client, escrow_client) = setup_rbac_env(&env);
+    escrow_client.pause(&None);
+
+    let events = env.events().all();
+    let mut operations = Vec::new(&env);
+    for (contract, topics, data) in events.iter() {
+        let topic_0: Symbol = topics.get(0).unwrap().into_val(&env);
+        if contract != escrow_client.address || topic_0 != Symbol::new(&env, "pause") {
+            continue;
+        }
+        let pause_state: PauseStateChanged = data.try_into_val(&env).unwrap();
+        assert!(pause_state.paused);
+        assert_eq!(pause_state.admin, admin);
+        operations.push_back(pause_state.operation);
+    }
+    assert_eq!(
+        operations,
+        vec![
+            &env,
+            symbol_short!("lock"),
+            symbol_short!("release"),
+            symbol_short!("refund")
+        ]
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_pause_requires_admin_auth() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, _, _token_client, escrow_client) = setup_rbac_env(&env);
+    env.set_auths(&[]);
+    escrow_client.pause(&None);
+}
@@ -502,3 +502,80 @@ fn test_aggregate_stats_amounts_invariant_sum_equals_total_locked() {
     let total = stats.total_locked + stats.total_released + stats.total_refunded;
     assert_eq!(total, 1000);
 }
+
+#[test]
+fn test_get_bounties_by_depositor_pages_ids_in_lock_order() {
+    let s = Setup::new();
+    let other = Address::generate(&s.env);
+    s.token_admin.mint(&other, &1_000);
+    let dl = s.env.ledger().timestamp() + 1000;
+
+    s.escrow.lock_funds(&s.depositor, &7, &100, &dl);
+    s.escrow.lock_funds(&other, &8, &100, &dl);
+    s.escrow.batch_lock_funds(&soroban_sdk::vec![
+        &s.env,
+        LockFundsItem {
+            bounty_id: 9,
+            depositor: s.depositor.clone(),
+            amount: 100,
+            deadline: dl,
+        },
+        LockFundsItem {
+            bounty_id: 10,
+            depositor: s.depositor.clone(),
+            amount: 100,
+            deadline: dl,
+        },
+    ]);
+
+    let all = s.escrow.get_bounties_by_depositor(&s.depositor, &0, &10);
+    assert_eq!(all, soroban_sdk::vec![&s.env, 7u64, 9, 10]);
+    assert_eq!(
+        s.escrow.get_bounties_by_depositor(&s.depositor, &1, &1),
+        soroban_sdk::vec![&s.env, 9u64]
+    );
+    assert_eq!(
+        s.escrow
+            .get_bounties_by_depositor(&s.depositor, &3, &u32::MAX)
+            .len(),
+        0
+    );
+    assert_eq!(
+        s.escrow.get_bounties_by_depositor(&other, &0, &10),
+        soroban_sdk::vec![&s.env, 8u64]
+    );
+}
+
+#[test]
+fn test_list_bounties_returns_summaries_with_optional_status_filter() {
+    let s = Setup::new();
+    let dl = s.env.ledger().timestamp() + 1000;
+
+    s.escrow.lock_funds(&s.depositor, &1, &100, &dl);
+    s.escrow.lock_funds(&s.depositor, &2, &200, &dl);
+    s.escrow.lock_funds(&s.depositor, &3, &300, &(dl + 5));
+    s.escrow.release_funds(&2, &s.contributor);
+
+    let all = s.escrow.list_bounties(&None, &0, &10);
+    assert_eq!(all.len(), 3);
+    assert_eq!(
+        all.get(2).unwrap(),
+        BountySummary {
+            bounty_id: 3,
+            amount: 300,
+            deadline: dl + 5,
+            status: EscrowStatus::Locked,
+        }
+    );
+    assert_eq!(all.get(1).unwrap().status, EscrowStatus::Released);
+
+    let locked = s.escrow.list_bounties(&Some(EscrowStatus::Locked), &0, &10);
+    assert_eq!(locked.len(), 2);
+    assert_eq!(locked.get(0).unwrap().bounty_id, 1);
+    assert_eq!(locked.get(1).unwrap().bounty_id, 3);
+
+    let page = s.escrow.list_bounties(&Some(EscrowStatus::Locked), &1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().bounty_id, 3);
+    assert_eq!(s.escrow.list_bounties(&None, &3, &10).len(), 0);
+}
@@ -0,0 +1,112 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_setup::Setup;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, BytesN,
+};
+
+/// Lock bounty 1 and enter alice and bob with different deadlines.
+fn race(s: &Setup) -> (Address, Address) {
+    let alice = Address::generate(&s.env);
+    let bob = Address::generate(&s.env);
+    let now = s.env.ledger().timestamp();
+    s.escrow
+        .lock_funds(&s.depositor, &1, &1_000, &(now + 1_000));
+    s.escrow.add_race_entrant(&1, &alice, &(now + 100));
+    s.escrow.add_race_entrant(&1, &bob, &(now + 200));
+    (alice, bob)
+}
+
+fn hash(s: &Setup, byte: u8) -> BytesN<32> {
+    BytesN::from_array(&s.env, &[byte; 32])
+}
+
+#[test]
+fn test_first_approved_submission_wins_and_closes_race() {
+    let s = Setup::new();
+    let (alice, bob) = race(&s);
+    assert_eq!(s.escrow.get_race_entrants(&1).len(), 2);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Assigned);
+
+    s.escrow.submit_race_work(&1, &alice, &hash(&s, 1));
+    s.escrow.submit_race_work(&1, &bob, &hash(&s, 2));
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Submitted);
+
+    s.escrow.approve_race_submission(&1, &bob);
+
+    assert_eq!(s.token.balance(&bob), 1_000);
+    assert_eq!(s.token.balance(&alice), 0);
+    let escrow = s.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Released);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(s.escrow.get_race_entrants(&1).len(), 0);
+    assert_eq!(
+        s.escrow.try_get_race_submission(&1, &alice),
+        Err(Ok(Error::SubmissionNotFound))
+    );
+    assert_eq!(
+        s.escrow.try_approve_race_submission(&1, &alice),
+        Err(Ok(Error::FundsNotLocked))
+    );
+}
+
+#[test]
+fn test_entrants_submit_before_their_own_deadline() {
+    let s = Setup::new();
+    let (alice, bob) = race(&s);
+    s.env.ledger().with_mut(|li| li.timestamp += 150);
+
+    assert_eq!(
+        s.escrow.try_submit_race_work(&1, &alice, &hash(&s, 1)),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    s.escrow.submit_race_work(&1, &bob, &hash(&s, 2));
+
+    let outsider = Address::generate(&s.env);
+    assert_eq!(
+        s.escrow.try_submit_race_work(&1, &outsider, &hash(&s, 3)),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.escrow.try_approve_race_submission(&1, &alice),
+        Err(Ok(Error::SubmissionNotFound))
+    );
+}
+
+#[test]
+fn test_race_entry_rules() {
+    let s = Setup::new();
+    let (alice, _bob) = race(&s);
+    let now = s.env.ledger().timestamp();
+
+    assert_eq!(
+        s.escrow.try_add_race_entrant(&1, &alice, &(now + 100)),
+        Err(Ok(Error::BountyAssigned))
+    );
+    assert_eq!(
+        s.escrow
+            .try_add_race_entrant(&1, &Address::generate(&s.env), &now),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    for _ in 0..3 {
+        s.escrow
+            .add_race_entrant(&1, &Address::generate(&s.env), &(now + 100));
+    }
+    assert_eq!(
+        s.escrow
+            .try_add_race_entrant(&1, &Address::generate(&s.env), &(now + 100)),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+
+    // Races pay out only through approve_race_submission
+    assert_eq!(
+        s.escrow.try_assign_contributor(&1, &alice),
+        Err(Ok(Error::BountyAssigned))
+    );
+    assert_eq!(
+        s.escrow.try_release_funds(&1, &alice),
+        Err(Ok(Error::SubmissionNotApproved))
+    );
+}
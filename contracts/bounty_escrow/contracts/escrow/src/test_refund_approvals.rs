@@ -0,0 +1,243 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_setup::Setup;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke},
+    Address, IntoVal,
+};
+
+/// Mock only the admin's authorization for an `approve_refund` call.
+fn admin_only_approval(s: &Setup, bounty_id: u64, amount: i128, recipient: &Address) {
+    s.env.mock_auths(&[MockAuth {
+        address: &s.admin,
+        invoke: &MockAuthInvoke {
+            contract: &s.escrow.address,
+            fn_name: "approve_refund",
+            args: (bounty_id, amount, recipient.clone(), RefundMode::Partial).into_val(&s.env),
+            sub_invokes: &[],
+        },
+    }]);
+}
+
+#[test]
+fn test_queued_approvals_execute_independently() {
+    let s = Setup::new();
+    let backer_a = Address::generate(&s.env);
+    let backer_b = Address::generate(&s.env);
+    s.lock(1, 1_000);
+
+    let first = s
+        .escrow
+        .approve_refund(&1, &300, &backer_a, &RefundMode::Partial);
+    let second = s
+        .escrow
+        .approve_refund(&1, &200, &backer_b, &RefundMode::Partial);
+    assert_ne!(first, second);
+    assert_eq!(s.escrow.get_refund_approvals(&1).len(), 2);
+
+    // Execute the newer approval first
+    s.escrow.refund_approved(&1, &second);
+    assert_eq!(s.token.balance(&backer_b), 200);
+    let remaining = s.escrow.get_refund_approvals(&1);
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining.get(0).unwrap().approval_id, first);
+
+    // Consumed approvals cannot be replayed
+    assert_eq!(
+        s.escrow.try_refund_approved(&1, &second),
+        Err(Ok(Error::RefundApprovalNotFound))
+    );
+
+    s.escrow.refund_approved(&1, &first);
+    assert_eq!(s.token.balance(&backer_a), 300);
+
+    let escrow = s.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.remaining_amount, 500);
+    assert_eq!(escrow.status, EscrowStatus::PartiallyRefunded);
+    assert_eq!(escrow.refund_history.len(), 2);
+    assert_eq!(s.escrow.get_refund_approvals(&1).len(), 0);
+}
+
+#[test]
+fn test_refund_uses_oldest_approval() {
+    let s = Setup::new();
+    let recipient = Address::generate(&s.env);
+    s.lock(1, 1_000);
+
+    s.escrow
+        .approve_refund(&1, &100, &recipient, &RefundMode::Partial);
+    s.escrow
+        .approve_refund(&1, &400, &recipient, &RefundMode::Partial);
+
+    s.escrow.refund(&1);
+    assert_eq!(s.token.balance(&recipient), 100);
+    assert_eq!(
+        s.escrow.get_refund_approvals(&1).get(0).unwrap().amount,
+        400
+    );
+}
+
+#[test]
+fn test_full_refund_clears_remaining_approvals() {
+    let s = Setup::new();
+    s.lock(1, 1_000);
+
+    s.escrow
+        .approve_refund(&1, &200, &s.depositor, &RefundMode::Partial);
+    let full = s
+        .escrow
+        .approve_refund(&1, &1_000, &s.depositor, &RefundMode::Full);
+
+    s.escrow.refund_approved(&1, &full);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Refunded);
+    assert_eq!(s.escrow.get_refund_approvals(&1).len(), 0);
+}
+
+#[test]
+fn test_approver_can_approve_without_admin_powers() {
+    let s = Setup::new();
+    let approver = Address::generate(&s.env);
+    s.lock(1, 1_000);
+
+    assert_eq!(
+        s.escrow.try_approve_refund_as_approver(
+            &1,
+            &100,
+            &s.depositor,
+            &RefundMode::Partial,
+            &approver
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    s.escrow.grant_approver(&approver);
+    assert!(s.escrow.is_approver(&approver));
+    s.escrow
+        .approve_refund_as_approver(&1, &100, &s.depositor, &RefundMode::Partial, &approver);
+    assert_eq!(
+        s.escrow
+            .get_refund_approvals(&1)
+            .get(0)
+            .unwrap()
+            .approved_by,
+        approver
+    );
+
+    s.escrow.revoke_approver(&approver);
+    assert!(!s.escrow.is_approver(&approver));
+    assert_eq!(
+        s.escrow.try_approve_refund_as_approver(
+            &1,
+            &100,
+            &s.depositor,
+            &RefundMode::Partial,
+            &approver
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_approver_cannot_change_config() {
+    let s = Setup::new();
+    let approver = Address::generate(&s.env);
+    s.escrow.grant_approver(&approver);
+
+    s.env.mock_auths(&[MockAuth {
+        address: &approver,
+        invoke: &MockAuthInvoke {
+            contract: &s.escrow.address,
+            fn_name: "update_anti_abuse_config",
+            args: (60u64, 1u32, 0u64).into_val(&s.env),
+            sub_invokes: &[],
+        },
+    }]);
+    s.escrow.update_anti_abuse_config(&60, &1, &0);
+}
+
+#[test]
+fn test_early_refund_to_other_address_needs_depositor_consent() {
+    let s = Setup::new();
+    let backer = Address::generate(&s.env);
+    s.lock(1, 1_000);
+
+    admin_only_approval(&s, 1, 300, &backer);
+    assert!(s
+        .escrow
+        .try_approve_refund(&1, &300, &backer, &RefundMode::Partial)
+        .is_err());
+
+    s.env.mock_all_auths();
+    s.escrow
+        .approve_refund(&1, &300, &backer, &RefundMode::Partial);
+    let auths = s.env.auths();
+    assert!(auths.iter().any(|(address, _)| *address == s.depositor));
+    assert!(auths.iter().any(|(address, _)| *address == s.admin));
+}
+
+#[test]
+fn test_admin_alone_can_refund_depositor_or_after_deadline() {
+    let s = Setup::new();
+    let backer = Address::generate(&s.env);
+    s.lock(1, 1_000);
+
+    admin_only_approval(&s, 1, 300, &s.depositor);
+    s.escrow
+        .approve_refund(&1, &300, &s.depositor, &RefundMode::Partial);
+
+    s.env
+        .ledger()
+        .set_timestamp(s.escrow.get_escrow_info(&1).deadline);
+    admin_only_approval(&s, 1, 200, &backer);
+    s.escrow
+        .approve_refund(&1, &200, &backer, &RefundMode::Partial);
+    assert_eq!(s.escrow.get_refund_approvals(&1).len(), 2);
+}
+
+#[test]
+fn test_list_refund_approvals_across_bounties() {
+    let s = Setup::new();
+    let backer = Address::generate(&s.env);
+    s.lock(1, 1_000);
+    s.lock(2, 1_000);
+
+    let a = s
+        .escrow
+        .approve_refund(&1, &100, &s.depositor, &RefundMode::Partial);
+    let b = s
+        .escrow
+        .approve_refund(&2, &200, &s.depositor, &RefundMode::Partial);
+    let c = s
+        .escrow
+        .approve_refund(&1, &300, &backer, &RefundMode::Partial);
+
+    let ids = |approvals: soroban_sdk::Vec<RefundApproval>| {
+        let mut ids = soroban_sdk::vec![&s.env];
+        for approval in approvals.iter() {
+            ids.push_back(approval.approval_id);
+        }
+        ids
+    };
+    assert_eq!(
+        ids(s.escrow.list_refund_approvals(&0, &10)),
+        soroban_sdk::vec![&s.env, a, c, b]
+    );
+    assert_eq!(
+        ids(s.escrow.list_refund_approvals(&1, &1)),
+        soroban_sdk::vec![&s.env, c]
+    );
+    assert_eq!(s.escrow.get_refund_approval(&2, &b).amount, 200);
+
+    // Executed approvals drop out of the listing
+    s.escrow.refund_approved(&1, &a);
+    assert_eq!(
+        ids(s.escrow.list_refund_approvals(&0, &10)),
+        soroban_sdk::vec![&s.env, c, b]
+    );
+    assert_eq!(
+        s.escrow.try_get_refund_approval(&1, &a),
+        Err(Ok(Error::RefundApprovalNotFound))
+    );
+}
@@ -0,0 +1,86 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_setup::Setup;
+use soroban_sdk::{testutils::Address as _, vec, Address};
+
+#[test]
+fn test_refund_split_pays_each_recipient_and_records_history() {
+    let s = Setup::new();
+    let backer_a = Address::generate(&s.env);
+    let backer_b = Address::generate(&s.env);
+    s.lock(1, 1_000);
+
+    s.escrow.refund_split(
+        &1,
+        &vec![&s.env, backer_a.clone(), backer_b.clone()],
+        &vec![&s.env, 600i128, 400i128],
+    );
+
+    assert_eq!(s.token.balance(&backer_a), 600);
+    assert_eq!(s.token.balance(&backer_b), 400);
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
+
+    let escrow = s.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(escrow.refund_history.len(), 2);
+    assert_eq!(escrow.refund_history.get(1).unwrap().recipient, backer_b);
+}
+
+#[test]
+fn test_refund_split_covers_remaining_after_partial_release() {
+    let s = Setup::new();
+    s.lock(1, 1_000);
+    s.escrow.partial_release(&1, &s.contributor, &300);
+
+    let recipients = vec![&s.env, s.depositor.clone(), Address::generate(&s.env)];
+    assert_eq!(
+        s.escrow
+            .try_refund_split(&1, &recipients, &vec![&s.env, 600i128, 400i128]),
+        Err(Ok(Error::InvalidAmount))
+    );
+
+    s.escrow
+        .refund_split(&1, &recipients, &vec![&s.env, 500i128, 200i128]);
+    assert_eq!(s.token.balance(&s.depositor), 9_500);
+}
+
+#[test]
+fn test_refund_split_rejects_bad_input() {
+    let s = Setup::new();
+    s.lock(1, 1_000);
+    let recipients = vec![&s.env, Address::generate(&s.env), Address::generate(&s.env)];
+
+    assert_eq!(
+        s.escrow
+            .try_refund_split(&1, &recipients, &vec![&s.env, 1_000i128]),
+        Err(Ok(Error::BatchSizeMismatch))
+    );
+    assert_eq!(
+        s.escrow
+            .try_refund_split(&1, &recipients, &vec![&s.env, 1_100i128, -100i128]),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        s.escrow
+            .try_refund_split(&1, &Vec::new(&s.env), &Vec::new(&s.env)),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+}
+
+#[test]
+fn test_early_refund_split_to_others_needs_depositor_consent() {
+    let s = Setup::new();
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    s.escrow.lock_funds(&s.depositor, &1, &1_000, &deadline);
+
+    s.escrow.refund_split(
+        &1,
+        &vec![&s.env, s.depositor.clone(), s.contributor.clone()],
+        &vec![&s.env, 600, 400],
+    );
+
+    let auths = s.env.auths();
+    assert!(auths.iter().any(|(address, _)| *address == s.depositor));
+}
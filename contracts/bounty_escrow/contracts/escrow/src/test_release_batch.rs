@@ -0,0 +1,170 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_setup::Setup;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address,
+};
+
+fn lock_many(s: &Setup, amounts: &[(u64, i128)]) {
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    let mut items = vec![&s.env];
+    for &(bounty_id, amount) in amounts {
+        items.push_back(BountyParams {
+            bounty_id,
+            amount,
+            deadline,
+        });
+    }
+    s.escrow.lock_funds_batch(&s.depositor, &items);
+}
+
+fn item(s: &Setup, bounty_id: u64) -> ReleaseFundsItem {
+    ReleaseFundsItem {
+        bounty_id,
+        contributor: s.contributor.clone(),
+    }
+}
+
+#[test]
+fn test_atomic_release_batch_releases_everything() {
+    let s = Setup::new();
+    lock_many(&s, &[(1, 100), (2, 200)]);
+
+    let results = s
+        .escrow
+        .release_batch(&vec![&s.env, item(&s, 1), item(&s, 2)], &true);
+
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|r| r.released && r.error_code == 0));
+    assert_eq!(s.token.balance(&s.contributor), 300);
+    assert_eq!(s.escrow.get_escrow_info(&2).status, EscrowStatus::Released);
+    assert_eq!(s.escrow.get_escrow_info(&2).remaining_amount, 0);
+}
+
+#[test]
+fn test_atomic_release_batch_reverts_on_any_failure() {
+    let s = Setup::new();
+    lock_many(&s, &[(1, 100)]);
+
+    assert_eq!(
+        s.escrow
+            .try_release_batch(&vec![&s.env, item(&s, 1), item(&s, 9)], &true),
+        Err(Ok(Error::BountyNotFound))
+    );
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Locked);
+    assert_eq!(s.token.balance(&s.contributor), 0);
+}
+
+#[test]
+fn test_lenient_release_batch_reports_per_item_results() {
+    let s = Setup::new();
+    lock_many(&s, &[(1, 100), (2, 200)]);
+
+    let results = s.escrow.release_batch(
+        &vec![&s.env, item(&s, 1), item(&s, 9), item(&s, 2), item(&s, 1)],
+        &false,
+    );
+
+    assert_eq!(
+        results,
+        vec![
+            &s.env,
+            ReleaseItemResult {
+                bounty_id: 1,
+                released: true,
+                error_code: 0,
+            },
+            ReleaseItemResult {
+                bounty_id: 9,
+                released: false,
+                error_code: Error::BountyNotFound as u32,
+            },
+            ReleaseItemResult {
+                bounty_id: 2,
+                released: true,
+                error_code: 0,
+            },
+            ReleaseItemResult {
+                bounty_id: 1,
+                released: false,
+                error_code: Error::FundsNotLocked as u32,
+            },
+        ]
+    );
+    assert_eq!(s.token.balance(&s.contributor), 300);
+    assert_eq!(s.escrow.get_stats().active_count, 0);
+    assert_eq!(s.escrow.get_escrow_info(&2).remaining_amount, 0);
+    assert_eq!(s.escrow.assert_solvency().liabilities, 0);
+}
+
+#[test]
+fn test_release_batch_authorizes_admin_once() {
+    let s = Setup::new();
+    lock_many(&s, &[(1, 100), (2, 200)]);
+
+    s.escrow
+        .release_batch(&vec![&s.env, item(&s, 1), item(&s, 2)], &false);
+
+    let auths = s.env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, s.admin);
+}
+
+#[test]
+fn test_release_batch_pays_remaining_after_partial_release() {
+    let s = Setup::new();
+    lock_many(&s, &[(1, 100), (2, 200)]);
+    s.escrow.partial_release(&1, &s.contributor, &40);
+    s.escrow.partial_release(&2, &s.contributor, &50);
+
+    s.escrow.release_batch(&vec![&s.env, item(&s, 1)], &false);
+    s.escrow.release_batch(&vec![&s.env, item(&s, 2)], &true);
+
+    assert_eq!(s.token.balance(&s.contributor), 300);
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
+}
+
+#[test]
+fn test_release_batch_holds_during_clawback_window() {
+    let s = Setup::new();
+    lock_many(&s, &[(1, 100), (2, 200)]);
+    s.escrow.set_clawback_window(&3_600);
+
+    s.escrow.release_batch(&vec![&s.env, item(&s, 1)], &false);
+    s.escrow.release_batch(&vec![&s.env, item(&s, 2)], &true);
+    assert_eq!(s.token.balance(&s.contributor), 0);
+    assert_eq!(s.escrow.get_pending_release(&1, &s.contributor).amount, 100);
+    assert_eq!(s.escrow.get_pending_release(&2, &s.contributor).amount, 200);
+
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + 3_600);
+    s.escrow.finalize_release(&1, &s.contributor);
+    s.escrow.finalize_release(&2, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 300);
+}
+
+#[test]
+fn test_failed_release_batch_item_keeps_multisig_approval() {
+    let s = Setup::new();
+    lock_many(&s, &[(1, 100), (2, 200)]);
+    let signer = Address::generate(&s.env);
+    s.escrow
+        .update_multisig_config(&50, &vec![&s.env, signer.clone()], &1);
+    s.escrow.set_bounty_dependency(&1, &2);
+    s.escrow.approve_large_release(&1, &s.contributor, &signer);
+
+    let results = s.escrow.release_batch(&vec![&s.env, item(&s, 1)], &false);
+    assert_eq!(
+        results.get(0).unwrap().error_code,
+        Error::BountyNotSettled as u32
+    );
+
+    s.escrow.approve_large_release(&2, &s.contributor, &signer);
+    s.escrow.release_batch(&vec![&s.env, item(&s, 2)], &false);
+    // The approval for bounty 1 was not spent by the failed attempt
+    let results = s.escrow.release_batch(&vec![&s.env, item(&s, 1)], &false);
+    assert!(results.get(0).unwrap().released);
+}
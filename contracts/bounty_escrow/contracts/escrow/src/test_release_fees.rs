@@ -0,0 +1,95 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_setup::Setup;
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    vec, Address, TryFromVal,
+};
+
+/// Escrow charging a 2.5% release fee, and its treasury.
+fn setup() -> (Setup<'static>, Address) {
+    let s = Setup::with_balance(100_000);
+    let treasury = Address::generate(&s.env);
+    s.escrow
+        .update_fee_config(&None, &Some(250), &Some(treasury.clone()), &Some(true));
+    (s, treasury)
+}
+
+#[test]
+fn test_release_routes_fee_to_treasury() {
+    let (s, treasury) = setup();
+    s.lock(1, 10_000);
+
+    s.escrow.release_funds(&1, &s.contributor);
+
+    assert_eq!(s.token.balance(&s.contributor), 9_750);
+    assert_eq!(s.token.balance(&treasury), 250);
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
+    let accounting = s.escrow.get_fee_accounting();
+    assert_eq!(accounting.total_release_fees, 250);
+    assert_eq!(accounting.release_fee_count, 1);
+
+    let paid = s
+        .env
+        .events()
+        .all()
+        .iter()
+        .find_map(|(_, topics, data)| {
+            let topic = Symbol::try_from_val(&s.env, &topics.get(0)?).ok()?;
+            if topic != symbol_short!("rel_paid") {
+                return None;
+            }
+            events::ReleasePaid::try_from_val(&s.env, &data).ok()
+        })
+        .unwrap();
+    assert_eq!(paid.recipient, s.contributor);
+    assert_eq!(paid.gross_amount, 10_000);
+    assert_eq!(paid.fee, 250);
+    assert_eq!(paid.net_amount, 9_750);
+}
+
+#[test]
+fn test_partial_and_split_releases_are_charged() {
+    let (s, _) = setup();
+    let other = Address::generate(&s.env);
+    s.lock(1, 10_000);
+
+    s.escrow.partial_release(&1, &s.contributor, &4_000);
+    s.escrow.release_split(
+        &1,
+        &vec![&s.env, s.contributor.clone(), other.clone()],
+        &vec![&s.env, 5_000u32, 5_000u32],
+    );
+
+    assert_eq!(s.token.balance(&s.contributor), 3_900 + 2_925);
+    assert_eq!(s.token.balance(&other), 2_925);
+    assert_eq!(s.escrow.get_fee_accounting().total_release_fees, 250);
+    assert_eq!(s.escrow.get_fee_accounting().release_fee_count, 3);
+}
+
+#[test]
+fn test_refund_is_not_charged() {
+    let (s, treasury) = setup();
+    let deadline = s.lock(1, 10_000);
+    s.env.ledger().set_timestamp(deadline);
+
+    s.escrow.refund(&1);
+
+    assert_eq!(s.token.balance(&s.depositor), 100_000);
+    assert_eq!(s.token.balance(&treasury), 0);
+    assert_eq!(s.escrow.get_fee_accounting().total_release_fees, 0);
+}
+
+#[test]
+fn test_disabled_fee_releases_full_amount() {
+    let (s, treasury) = setup();
+    s.escrow
+        .update_fee_config(&None, &None, &None, &Some(false));
+    s.lock(1, 10_000);
+
+    s.escrow.release_funds(&1, &s.contributor);
+
+    assert_eq!(s.token.balance(&s.contributor), 10_000);
+    assert_eq!(s.token.balance(&treasury), 0);
+}
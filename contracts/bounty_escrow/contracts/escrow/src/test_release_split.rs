@@ -0,0 +1,83 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_setup::Setup;
+use soroban_sdk::{testutils::Address as _, vec, Address};
+
+#[test]
+fn test_release_split_pays_proportional_shares() {
+    let s = Setup::with_balance(1_000_000);
+    let alice = Address::generate(&s.env);
+    let bob = Address::generate(&s.env);
+    s.lock(1, 1_000);
+
+    s.escrow.release_split(
+        &1,
+        &vec![&s.env, alice.clone(), bob.clone()],
+        &vec![&s.env, 6_000u32, 4_000u32],
+    );
+
+    assert_eq!(s.token.balance(&alice), 600);
+    assert_eq!(s.token.balance(&bob), 400);
+    let info = s.escrow.get_escrow_info(&1);
+    assert_eq!(info.status, EscrowStatus::Released);
+    assert_eq!(info.remaining_amount, 0);
+
+    let history = s.escrow.get_release_history(&1);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(0).unwrap().recipient, alice);
+    assert_eq!(history.get(1).unwrap().amount, 400);
+}
+
+#[test]
+fn test_release_split_gives_dust_to_first_contributor() {
+    let s = Setup::with_balance(1_000_000);
+    let contributors = vec![
+        &s.env,
+        Address::generate(&s.env),
+        Address::generate(&s.env),
+        Address::generate(&s.env),
+    ];
+    s.lock(1, 100);
+
+    s.escrow.release_split(
+        &1,
+        &contributors,
+        &vec![&s.env, 3_334u32, 3_333u32, 3_333u32],
+    );
+
+    assert_eq!(s.token.balance(&contributors.get(0).unwrap()), 34);
+    assert_eq!(s.token.balance(&contributors.get(1).unwrap()), 33);
+    assert_eq!(s.token.balance(&contributors.get(2).unwrap()), 33);
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
+}
+
+#[test]
+fn test_release_split_rejects_bad_shares() {
+    let s = Setup::with_balance(1_000_000);
+    let contributors = vec![&s.env, Address::generate(&s.env), Address::generate(&s.env)];
+    s.lock(1, 1_000);
+
+    let result = s
+        .escrow
+        .try_release_split(&1, &contributors, &vec![&s.env, 5_000u32, 4_000u32]);
+    assert_eq!(result, Err(Ok(Error::InvalidShares)));
+    let result = s
+        .escrow
+        .try_release_split(&1, &contributors, &vec![&s.env, 10_000u32]);
+    assert_eq!(result, Err(Ok(Error::BatchSizeMismatch)));
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Locked);
+}
+
+#[test]
+fn test_release_split_requires_locked_funds() {
+    let s = Setup::with_balance(1_000_000);
+    let contributor = Address::generate(&s.env);
+    s.lock(1, 1_000);
+    s.escrow.release_funds(&1, &contributor);
+
+    let result =
+        s.escrow
+            .try_release_split(&1, &vec![&s.env, contributor], &vec![&s.env, 10_000u32]);
+    assert_eq!(result, Err(Ok(Error::FundsNotLocked)));
+}
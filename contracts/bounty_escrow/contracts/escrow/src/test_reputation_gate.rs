@@ -0,0 +1,82 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_setup::Setup;
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Env};
+
+#[contract]
+pub struct MockReputation;
+
+#[contractimpl]
+impl MockReputation {
+    pub fn set_score(env: Env, account: Address, score: u32) {
+        env.storage().persistent().set(&account, &score);
+    }
+
+    pub fn get_score(env: Env, account: Address) -> u32 {
+        env.storage().persistent().get(&account).unwrap_or(0)
+    }
+}
+
+fn setup() -> (Setup<'static>, MockReputationClient<'static>) {
+    let s = Setup::new();
+    let reputation_id = s.env.register_contract(None, MockReputation);
+    let reputation = MockReputationClient::new(&s.env, &reputation_id);
+    (s, reputation)
+}
+
+#[test]
+fn test_assignment_requires_min_reputation() {
+    let (s, reputation) = setup();
+    s.escrow.set_reputation_store(&reputation.address);
+    s.lock(1, 1_000);
+    s.escrow.set_min_reputation(&1, &50);
+    assert_eq!(s.escrow.get_min_reputation(&1), 50);
+
+    let newcomer = Address::generate(&s.env);
+    let veteran = Address::generate(&s.env);
+    reputation.set_score(&newcomer, &10);
+    reputation.set_score(&veteran, &50);
+
+    assert_eq!(
+        s.escrow.try_assign_contributor(&1, &newcomer),
+        Err(Ok(Error::Unauthorized))
+    );
+    s.escrow.assign_contributor(&1, &veteran);
+    assert_eq!(s.escrow.get_assignee(&1), Some(veteran));
+}
+
+#[test]
+fn test_race_entrants_are_gated() {
+    let (s, reputation) = setup();
+    s.escrow.set_reputation_store(&reputation.address);
+    let deadline = s.lock(1, 1_000);
+    s.escrow.set_min_reputation(&1, &20);
+
+    let newcomer = Address::generate(&s.env);
+    assert_eq!(
+        s.escrow.try_add_race_entrant(&1, &newcomer, &deadline),
+        Err(Ok(Error::Unauthorized))
+    );
+    reputation.set_score(&newcomer, &25);
+    s.escrow.add_race_entrant(&1, &newcomer, &deadline);
+}
+
+#[test]
+fn test_ungated_and_unconfigured_store() {
+    let (s, _) = setup();
+    s.lock(1, 1_000);
+    let contributor = Address::generate(&s.env);
+
+    // Gated bounty without a store cannot be assigned
+    s.escrow.set_min_reputation(&1, &1);
+    assert_eq!(
+        s.escrow.try_assign_contributor(&1, &contributor),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // Clearing the gate restores open assignment
+    s.escrow.set_min_reputation(&1, &0);
+    assert_eq!(s.escrow.get_min_reputation(&1), 0);
+    s.escrow.assign_contributor(&1, &contributor);
+}
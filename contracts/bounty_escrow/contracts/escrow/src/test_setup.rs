@@ -0,0 +1,67 @@
+#![cfg(test)]
+
+//! Fixture shared by the feature test modules: an initialized escrow over a
+//! fresh Stellar asset, with the depositor already funded.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+pub(crate) struct Setup<'a> {
+    pub env: Env,
+    pub admin: Address,
+    pub depositor: Address,
+    pub contributor: Address,
+    pub token: token::Client<'a>,
+    pub token_admin: token::StellarAssetClient<'a>,
+    pub escrow: BountyEscrowContractClient<'a>,
+}
+
+impl<'a> Setup<'a> {
+    pub fn new() -> Self {
+        Self::with_balance(10_000)
+    }
+
+    /// Same as `new`, minting `balance` to the depositor.
+    pub fn with_balance(balance: i128) -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let depositor = Address::generate(&env);
+        let contributor = Address::generate(&env);
+
+        let sac = env.register_stellar_asset_contract_v2(admin.clone());
+        let token = token::Client::new(&env, &sac.address());
+        let token_admin = token::StellarAssetClient::new(&env, &sac.address());
+        token_admin.mint(&depositor, &balance);
+
+        let contract_id = env.register_contract(None, BountyEscrowContract);
+        let escrow = BountyEscrowContractClient::new(&env, &contract_id);
+        escrow.init(&admin, &token.address);
+
+        Self {
+            env,
+            admin,
+            depositor,
+            contributor,
+            token,
+            token_admin,
+            escrow,
+        }
+    }
+
+    /// Lock `amount` from the depositor with a deadline 1_000 seconds out.
+    pub fn lock(&self, bounty_id: u64, amount: i128) -> u64 {
+        let deadline = self.env.ledger().timestamp() + 1_000;
+        self.escrow
+            .lock_funds(&self.depositor, &bounty_id, &amount, &deadline);
+        deadline
+    }
+
+    pub fn advance(&self, seconds: u64) {
+        self.env.ledger().with_mut(|li| li.timestamp += seconds);
+    }
+}
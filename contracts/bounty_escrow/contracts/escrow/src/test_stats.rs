@@ -0,0 +1,70 @@
+#![cfg(test)]
+
+use crate::test_setup::Setup;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address,
+};
+
+fn setup() -> Setup<'static> {
+    let s = Setup::with_balance(100_000);
+    // 2.5% release fee
+    let treasury = Address::generate(&s.env);
+    s.escrow
+        .update_fee_config(&None, &Some(250), &Some(treasury), &Some(true));
+    s
+}
+
+#[test]
+fn test_stats_start_empty() {
+    let s = setup();
+    let stats = s.escrow.get_stats();
+    assert_eq!(stats.total_locked, 0);
+    assert_eq!(stats.total_released, 0);
+    assert_eq!(stats.total_refunded, 0);
+    assert_eq!(stats.total_fees, 0);
+    assert_eq!(stats.active_count, 0);
+}
+
+#[test]
+fn test_stats_track_lock_release_and_refund() {
+    let s = setup();
+    s.lock(1, 10_000);
+    let deadline = s.lock(2, 4_000);
+    s.escrow.boost_bounty(&2, &s.depositor, &1_000);
+
+    let stats = s.escrow.get_stats();
+    assert_eq!(stats.total_locked, 15_000);
+    assert_eq!(stats.active_count, 2);
+
+    s.escrow.release_funds(&1, &s.contributor);
+    s.env.ledger().set_timestamp(deadline + 1);
+    s.escrow.refund(&2);
+
+    let stats = s.escrow.get_stats();
+    assert_eq!(stats.total_released, 10_000);
+    assert_eq!(stats.total_fees, 250);
+    assert_eq!(stats.total_refunded, 5_000);
+    assert_eq!(stats.active_count, 0);
+    assert_eq!(
+        stats.total_locked - stats.total_released - stats.total_refunded,
+        s.token.balance(&s.escrow.address)
+    );
+}
+
+#[test]
+fn test_partial_payouts_keep_bounty_active() {
+    let s = setup();
+    s.lock(1, 10_000);
+
+    s.escrow.partial_release(&1, &s.contributor, &4_000);
+    let stats = s.escrow.get_stats();
+    assert_eq!(stats.total_released, 4_000);
+    assert_eq!(stats.active_count, 1);
+
+    s.escrow.partial_release(&1, &s.contributor, &6_000);
+    let stats = s.escrow.get_stats();
+    assert_eq!(stats.total_released, 10_000);
+    assert_eq!(stats.active_count, 0);
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
+}
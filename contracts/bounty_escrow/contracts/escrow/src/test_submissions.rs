@@ -0,0 +1,169 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_setup::Setup;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, BytesN,
+};
+
+fn lock_assigned(s: &Setup, bounty_id: u64, amount: i128) {
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    s.escrow
+        .lock_funds(&s.depositor, &bounty_id, &amount, &deadline);
+    s.escrow.assign_contributor(&bounty_id, &s.contributor);
+}
+
+#[test]
+fn test_approved_submission_unlocks_release() {
+    let s = Setup::new();
+    let hash = BytesN::from_array(&s.env, &[7; 32]);
+    lock_assigned(&s, 1, 1_000);
+
+    s.escrow.submit_work(&1, &hash);
+    let submission = s.escrow.get_submission(&1);
+    assert_eq!(submission.submission_hash, hash);
+    assert_eq!(submission.contributor, s.contributor);
+    assert!(!submission.approved);
+
+    s.escrow.approve_submission(&1);
+    assert!(s.escrow.get_submission(&1).approved);
+    s.escrow.release_funds(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+}
+
+#[test]
+fn test_assigned_bounty_release_requires_approval() {
+    let s = Setup::new();
+    lock_assigned(&s, 1, 1_000);
+
+    let result = s.escrow.try_release_funds(&1, &s.contributor);
+    assert_eq!(result, Err(Ok(Error::SubmissionNotApproved)));
+
+    s.escrow
+        .submit_work(&1, &BytesN::from_array(&s.env, &[1; 32]));
+    let result = s.escrow.try_partial_release(&1, &s.contributor, &100);
+    assert_eq!(result, Err(Ok(Error::SubmissionNotApproved)));
+}
+
+#[test]
+fn test_approved_submission_pays_only_its_contributor() {
+    let s = Setup::new();
+    lock_assigned(&s, 1, 1_000);
+    s.escrow
+        .submit_work(&1, &BytesN::from_array(&s.env, &[1; 32]));
+    s.escrow.approve_submission(&1);
+
+    let stranger = Address::generate(&s.env);
+    assert_eq!(
+        s.escrow.try_release_funds(&1, &stranger),
+        Err(Ok(Error::SubmissionNotApproved))
+    );
+    assert_eq!(
+        s.escrow.try_partial_release(&1, &stranger, &100),
+        Err(Ok(Error::SubmissionNotApproved))
+    );
+    assert_eq!(
+        s.escrow.try_release_split(
+            &1,
+            &soroban_sdk::vec![&s.env, stranger.clone()],
+            &soroban_sdk::vec![&s.env, 10_000]
+        ),
+        Err(Ok(Error::SubmissionNotApproved))
+    );
+
+    s.escrow.release_split(
+        &1,
+        &soroban_sdk::vec![&s.env, stranger.clone(), s.contributor.clone()],
+        &soroban_sdk::vec![&s.env, 2_000, 8_000],
+    );
+    assert_eq!(s.token.balance(&s.contributor), 800);
+}
+
+#[test]
+fn test_submission_requires_assignment() {
+    let s = Setup::new();
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    s.escrow.lock_funds(&s.depositor, &1, &1_000, &deadline);
+
+    let result = s
+        .escrow
+        .try_submit_work(&1, &BytesN::from_array(&s.env, &[1; 32]));
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    assert_eq!(
+        s.escrow.try_approve_submission(&1),
+        Err(Ok(Error::SubmissionNotFound))
+    );
+}
+
+#[test]
+fn test_reassignment_discards_previous_submission() {
+    let s = Setup::new();
+    lock_assigned(&s, 1, 1_000);
+    s.escrow
+        .submit_work(&1, &BytesN::from_array(&s.env, &[1; 32]));
+
+    s.escrow.assign_contributor(&1, &Address::generate(&s.env));
+    assert_eq!(
+        s.escrow.try_get_submission(&1),
+        Err(Ok(Error::SubmissionNotFound))
+    );
+}
+
+#[test]
+fn test_submission_auto_releases_after_review_window() {
+    let s = Setup::new();
+    lock_assigned(&s, 1, 1_000);
+    s.escrow.set_review_window(&1, &300);
+    assert_eq!(s.escrow.get_review_window(&1), Some(300));
+
+    s.escrow
+        .submit_work(&1, &BytesN::from_array(&s.env, &[3; 32]));
+    let submitted_at = s.env.ledger().timestamp();
+    s.env.ledger().set_timestamp(submitted_at + 299);
+    assert_eq!(
+        s.escrow.try_auto_release(&1),
+        Err(Ok(Error::DeadlineNotPassed))
+    );
+
+    s.env.ledger().set_timestamp(submitted_at + 300);
+    s.env.set_auths(&[]);
+    s.escrow.auto_release(&1);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_depositor_dispute_stops_auto_release() {
+    let s = Setup::new();
+    lock_assigned(&s, 1, 1_000);
+    s.escrow.set_review_window(&1, &300);
+    s.escrow
+        .submit_work(&1, &BytesN::from_array(&s.env, &[3; 32]));
+
+    s.escrow.set_arbiter(&Address::generate(&s.env));
+    s.escrow.raise_dispute(&1, &s.depositor);
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + 300);
+    assert_eq!(
+        s.escrow.try_auto_release(&1),
+        Err(Ok(Error::BountyDisputed))
+    );
+}
+
+#[test]
+fn test_auto_release_requires_mode_and_submission() {
+    let s = Setup::new();
+    lock_assigned(&s, 1, 1_000);
+    assert_eq!(s.escrow.try_auto_release(&1), Err(Ok(Error::Unauthorized)));
+
+    s.escrow.set_review_window(&1, &300);
+    assert_eq!(
+        s.escrow.try_auto_release(&1),
+        Err(Ok(Error::SubmissionNotFound))
+    );
+
+    s.escrow.set_review_window(&1, &0);
+    assert_eq!(s.escrow.get_review_window(&1), None);
+}
@@ -0,0 +1,79 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_setup::Setup;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address,
+};
+
+fn lock(s: &Setup, bounty_id: u64, amount: i128, deadline: u64) {
+    s.escrow
+        .lock_funds(&s.depositor, &bounty_id, &amount, &deadline);
+}
+
+#[test]
+fn test_sweep_refunds_only_expired_bounties() {
+    let s = Setup::with_balance(100_000);
+    let keeper = Address::generate(&s.env);
+    lock(&s, 1, 1_000, 100);
+    lock(&s, 2, 2_000, 100);
+    lock(&s, 3, 3_000, 10_000);
+    s.env.ledger().set_timestamp(101);
+
+    let swept = s
+        .escrow
+        .sweep_expired(&keeper, &vec![&s.env, 1u64, 2u64, 3u64, 99u64]);
+
+    assert_eq!(swept, 2);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Refunded);
+    assert_eq!(s.escrow.get_escrow_info(&2).status, EscrowStatus::Refunded);
+    assert_eq!(s.escrow.get_escrow_info(&3).status, EscrowStatus::Locked);
+    assert_eq!(s.token.balance(&s.depositor), 97_000);
+    assert_eq!(s.token.balance(&keeper), 0);
+}
+
+#[test]
+fn test_sweep_pays_keeper_incentive() {
+    let s = Setup::with_balance(100_000);
+    let keeper = Address::generate(&s.env);
+    s.escrow.set_keeper_incentive(&50);
+    lock(&s, 1, 10_000, 100);
+    s.env.ledger().set_timestamp(100);
+
+    s.escrow.sweep_expired(&keeper, &vec![&s.env, 1u64]);
+
+    assert_eq!(s.token.balance(&keeper), 50);
+    assert_eq!(s.token.balance(&s.depositor), 99_950);
+    let info = s.escrow.get_escrow_info(&1);
+    assert_eq!(info.refund_history.get(0).unwrap().amount, 10_000);
+    assert_eq!(s.escrow.get_stats().total_refunded, 10_000);
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
+}
+
+#[test]
+fn test_sweep_skips_pending_claims_and_already_refunded() {
+    let s = Setup::with_balance(100_000);
+    let keeper = Address::generate(&s.env);
+    lock(&s, 1, 1_000, 100);
+    lock(&s, 2, 1_000, 100);
+    s.escrow.authorize_claim(&1, &Address::generate(&s.env));
+    s.env.ledger().set_timestamp(200);
+
+    assert_eq!(
+        s.escrow.sweep_expired(&keeper, &vec![&s.env, 1u64, 2u64]),
+        1
+    );
+    assert_eq!(s.escrow.sweep_expired(&keeper, &vec![&s.env, 2u64]), 0);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Locked);
+}
+
+#[test]
+fn test_keeper_incentive_is_capped() {
+    let s = Setup::with_balance(100_000);
+    assert_eq!(
+        s.escrow.try_set_keeper_incentive(&101),
+        Err(Ok(Error::InvalidFeeRate))
+    );
+    assert_eq!(s.escrow.get_keeper_incentive(), 0);
+}
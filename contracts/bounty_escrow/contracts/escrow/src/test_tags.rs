@@ -0,0 +1,127 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_setup::Setup;
+use soroban_sdk::vec;
+
+fn lock_many(s: &Setup, bounty_ids: &[u64]) {
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    let mut items = vec![&s.env];
+    for &bounty_id in bounty_ids {
+        items.push_back(BountyParams {
+            bounty_id,
+            amount: 100,
+            deadline,
+        });
+    }
+    s.escrow.lock_funds_batch(&s.depositor, &items);
+}
+
+#[test]
+fn test_list_bounties_by_tag() {
+    let s = Setup::new();
+    lock_many(&s, &[1, 2, 3]);
+    let rust = symbol_short!("rust");
+    let docs = symbol_short!("docs");
+
+    s.escrow
+        .set_bounty_tags(&1, &vec![&s.env, rust.clone(), docs.clone()]);
+    s.escrow.set_bounty_tags(&2, &vec![&s.env, docs.clone()]);
+    s.escrow
+        .set_bounty_tags(&3, &vec![&s.env, rust.clone(), rust.clone()]);
+
+    assert_eq!(s.escrow.get_bounty_tags(&3), vec![&s.env, rust.clone()]);
+    assert_eq!(
+        s.escrow.list_bounties_by_tag(&rust, &0, &10),
+        vec![&s.env, 1, 3]
+    );
+    assert_eq!(
+        s.escrow.list_bounties_by_tag(&docs, &1, &10),
+        vec![&s.env, 2]
+    );
+    assert_eq!(
+        s.escrow.list_bounties_by_tag(&docs, &0, &1),
+        vec![&s.env, 1]
+    );
+}
+
+#[test]
+fn test_retagging_updates_indexes() {
+    let s = Setup::new();
+    lock_many(&s, &[1]);
+    let rust = symbol_short!("rust");
+    let docs = symbol_short!("docs");
+
+    s.escrow.set_bounty_tags(&1, &vec![&s.env, rust.clone()]);
+    s.escrow.set_bounty_tags(&1, &vec![&s.env, docs.clone()]);
+    assert_eq!(s.escrow.list_bounties_by_tag(&rust, &0, &10).len(), 0);
+    assert_eq!(
+        s.escrow.list_bounties_by_tag(&docs, &0, &10),
+        vec![&s.env, 1]
+    );
+
+    s.escrow.set_bounty_tags(&1, &vec![&s.env]);
+    assert_eq!(s.escrow.get_bounty_tags(&1).len(), 0);
+    assert_eq!(s.escrow.list_bounties_by_tag(&docs, &0, &10).len(), 0);
+}
+
+#[test]
+fn test_tag_limit_and_archive_cleanup() {
+    let s = Setup::new();
+    lock_many(&s, &[1]);
+
+    let too_many = vec![
+        &s.env,
+        symbol_short!("a"),
+        symbol_short!("b"),
+        symbol_short!("c"),
+        symbol_short!("d"),
+        symbol_short!("e"),
+        symbol_short!("f"),
+    ];
+    assert_eq!(
+        s.escrow.try_set_bounty_tags(&1, &too_many),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+    assert_eq!(
+        s.escrow.try_set_bounty_tags(&9, &vec![&s.env]),
+        Err(Ok(Error::BountyNotFound))
+    );
+
+    s.escrow
+        .set_bounty_tags(&1, &vec![&s.env, symbol_short!("rust")]);
+    s.escrow.release_funds(&1, &s.contributor);
+    s.escrow.archive_bounty(&1);
+    assert_eq!(
+        s.escrow
+            .list_bounties_by_tag(&symbol_short!("rust"), &0, &10)
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_tag_index_is_capped() {
+    let s = Setup::new();
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    for bounty_id in 1..=MAX_BOUNTIES_PER_TAG as u64 + 1 {
+        s.escrow
+            .lock_funds(&s.depositor, &bounty_id, &100, &deadline);
+    }
+    let rust = vec![&s.env, symbol_short!("rust")];
+    for bounty_id in 1..=MAX_BOUNTIES_PER_TAG as u64 {
+        s.escrow.set_bounty_tags(&bounty_id, &rust);
+    }
+    let last = MAX_BOUNTIES_PER_TAG as u64 + 1;
+    assert_eq!(
+        s.escrow.try_set_bounty_tags(&last, &rust),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+
+    // Retagging a bounty already in the index does not need a free slot
+    s.escrow.set_bounty_tags(&1, &rust);
+
+    s.escrow.release_funds(&1, &s.contributor);
+    s.escrow.archive_bounty(&1);
+    s.escrow.set_bounty_tags(&last, &rust);
+}
@@ -0,0 +1,96 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_setup::Setup;
+use soroban_sdk::{testutils::Address as _, Address};
+
+fn template(s: &Setup, deadline_offset: u64, review_window: u64) -> BountyTemplate {
+    BountyTemplate {
+        owner: s.depositor.clone(),
+        deadline_offset,
+        review_window,
+        arbiter: None,
+        release_fee_rate: None,
+    }
+}
+
+#[test]
+fn test_lock_from_template_applies_settings() {
+    let s = Setup::new();
+    let template_id = s.escrow.create_template(&template(&s, 7_200, 600));
+    assert_eq!(template_id, 1);
+
+    let now = s.env.ledger().timestamp();
+    s.escrow.lock_from_template(&template_id, &10, &1_000);
+    s.escrow.lock_from_template(&template_id, &11, &2_000);
+
+    let info = s.escrow.get_escrow_info(&10);
+    assert_eq!(info.depositor, s.depositor);
+    assert_eq!(info.amount, 1_000);
+    assert_eq!(info.deadline, now + 7_200);
+    assert_eq!(s.escrow.get_review_window(&11), Some(600));
+    assert_eq!(s.token.balance(&s.escrow.address), 3_000);
+}
+
+#[test]
+fn test_template_without_review_window() {
+    let s = Setup::new();
+    let template_id = s.escrow.create_template(&template(&s, 3_600, 0));
+    s.escrow.lock_from_template(&template_id, &1, &500);
+
+    assert_eq!(s.escrow.get_review_window(&1), None);
+    assert_eq!(s.escrow.get_template(&template_id).deadline_offset, 3_600);
+}
+
+#[test]
+fn test_template_validation() {
+    let s = Setup::new();
+    assert_eq!(
+        s.escrow.try_create_template(&template(&s, 0, 0)),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    assert_eq!(
+        s.escrow.try_lock_from_template(&99, &1, &500),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_template_applies_arbiter_and_fee_rate() {
+    let s = Setup::new();
+    let arbiter = Address::generate(&s.env);
+    let treasury = Address::generate(&s.env);
+    let contributor = Address::generate(&s.env);
+    // 1% globally, 5% on bounties stamped from the template
+    s.escrow
+        .update_fee_config(&None, &Some(100), &Some(treasury.clone()), &Some(true));
+    let mut params = template(&s, 3_600, 0);
+    params.arbiter = Some(arbiter.clone());
+    params.release_fee_rate = Some(500);
+    let template_id = s.escrow.create_template(&params);
+
+    s.escrow.lock_from_template(&template_id, &1, &1_000);
+    assert_eq!(s.escrow.get_bounty_arbiter(&1), Some(arbiter));
+
+    s.escrow.release_funds(&1, &contributor);
+    assert_eq!(s.token.balance(&contributor), 950);
+    assert_eq!(s.token.balance(&treasury), 50);
+}
+
+#[test]
+fn test_template_policy_validation() {
+    let s = Setup::new();
+    let mut params = template(&s, 3_600, 0);
+    params.arbiter = Some(s.depositor.clone());
+    assert_eq!(
+        s.escrow.try_create_template(&params),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    let mut params = template(&s, 3_600, 0);
+    params.release_fee_rate = Some(MAX_FEE_RATE + 1);
+    assert_eq!(
+        s.escrow.try_create_template(&params),
+        Err(Ok(Error::InvalidFeeRate))
+    );
+}
@@ -0,0 +1,103 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_setup::Setup;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address,
+};
+
+#[test]
+fn test_depositor_approves_and_contributor_claims() {
+    let s = Setup::new();
+    s.lock(1, 1_000);
+
+    s.escrow.approve_release(&1, &s.contributor, &s.depositor);
+    assert_eq!(s.token.balance(&s.contributor), 0);
+    let claim = s.escrow.get_pending_claim(&1);
+    assert_eq!(claim.amount, 1_000);
+    assert_eq!(claim.expires_at, u64::MAX);
+
+    s.env.ledger().set_timestamp(50_000);
+    s.escrow.claim(&1);
+
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+    let info = s.escrow.get_escrow_info(&1);
+    assert_eq!(info.status, EscrowStatus::Released);
+    assert_eq!(info.remaining_amount, 0);
+}
+
+#[test]
+fn test_admin_approval_pays_remaining_after_partial_release() {
+    let s = Setup::new();
+    s.lock(1, 1_000);
+    s.escrow.partial_release(&1, &s.contributor, &300);
+
+    s.escrow.approve_release(&1, &s.contributor, &s.admin);
+    s.escrow.claim(&1);
+
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
+}
+
+#[test]
+fn test_approval_respects_claim_window() {
+    let s = Setup::new();
+    s.escrow.set_claim_window(&100);
+    s.lock(1, 1_000);
+    s.escrow.approve_release(&1, &s.contributor, &s.depositor);
+
+    s.env.ledger().set_timestamp(101);
+    assert_eq!(s.escrow.try_claim(&1), Err(Ok(Error::DeadlineNotPassed)));
+}
+
+#[test]
+fn test_only_admin_or_depositor_can_approve() {
+    let s = Setup::new();
+    s.lock(1, 1_000);
+
+    let result = s
+        .escrow
+        .try_approve_release(&1, &s.contributor, &s.contributor);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    s.escrow.approve_release(&1, &s.contributor, &s.admin);
+    let result = s
+        .escrow
+        .try_approve_release(&1, &s.contributor, &s.depositor);
+    assert_eq!(result, Err(Ok(Error::ClaimPending)));
+}
+
+#[test]
+fn test_claim_pays_remaining_after_later_partial_release() {
+    let s = Setup::new();
+    s.lock(1, 1_000);
+    s.escrow.authorize_claim(&1, &s.contributor);
+    assert_eq!(s.escrow.get_pending_claim(&1).amount, 1_000);
+
+    // A partial payout after authorization must not strand the rest
+    let other = Address::generate(&s.env);
+    s.escrow.partial_release(&1, &other, &400);
+    s.escrow.claim(&1);
+
+    assert_eq!(s.token.balance(&s.contributor), 600);
+    assert_eq!(s.escrow.get_pending_claim(&1).amount, 600);
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
+    assert_eq!(s.escrow.get_escrow_info(&1).remaining_amount, 0);
+}
+
+#[test]
+fn test_boost_rejected_while_claim_pending() {
+    let s = Setup::new();
+    s.lock(1, 1_000);
+    s.escrow.approve_release(&1, &s.contributor, &s.depositor);
+
+    assert_eq!(
+        s.escrow.try_boost_bounty(&1, &s.depositor, &500),
+        Err(Ok(Error::ClaimPending))
+    );
+
+    s.escrow.cancel_pending_claim(&1);
+    s.escrow.boost_bounty(&1, &s.depositor, &500);
+    assert_eq!(s.escrow.get_escrow_info(&1).remaining_amount, 1_500);
+}
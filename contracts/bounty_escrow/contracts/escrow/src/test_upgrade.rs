@@ -0,0 +1,66 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
+
+fn setup(env: &Env) -> BountyEscrowContractClient<'static> {
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let token = Address::generate(env);
+    env.mock_all_auths();
+    client.init(&admin, &token);
+    env.set_auths(&[]);
+    client
+}
+
+#[test]
+fn test_version_recorded_on_init() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    assert_eq!(client.get_version(), CONTRACT_VERSION);
+    assert_eq!(client.get_previous_version(), None);
+}
+
+#[test]
+fn test_admin_can_set_version() {
+    let env = Env::default();
+    let client = setup(&env);
+    env.mock_all_auths();
+
+    client.set_version(&2);
+    assert_eq!(client.get_version(), 2);
+}
+
+#[test]
+fn test_set_version_requires_admin_auth() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    assert!(client.try_set_version(&2).is_err());
+    assert_eq!(client.get_version(), CONTRACT_VERSION);
+}
+
+#[test]
+fn test_upgrade_requires_admin_auth() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    let result = client.try_upgrade(&BytesN::from_array(&env, &[7u8; 32]));
+    assert!(result.is_err());
+    assert_eq!(client.get_previous_version(), None);
+}
+
+#[test]
+fn test_upgrade_before_init_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let client = BountyEscrowContractClient::new(&env, &contract_id);
+
+    assert_eq!(
+        client.try_upgrade(&BytesN::from_array(&env, &[7u8; 32])),
+        Err(Ok(Error::NotInitialized))
+    );
+}
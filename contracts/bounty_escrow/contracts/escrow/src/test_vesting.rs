@@ -0,0 +1,85 @@
+#![cfg(test)]
+
+use super::*;
+use crate::test_setup::Setup;
+use soroban_sdk::testutils::Ledger;
+
+#[test]
+fn test_contributor_claims_linearly_over_duration() {
+    let s = Setup::new();
+    s.lock(1, 1_000);
+    s.escrow.release_vested(&1, &s.contributor, &100);
+
+    let schedule = s.escrow.get_vesting(&1);
+    assert_eq!(schedule.total, 1_000);
+    assert_eq!(schedule.claimed, 0);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Vesting);
+    assert_eq!(s.token.balance(&s.contributor), 0);
+
+    s.advance(25);
+    assert_eq!(s.escrow.claim_vested(&1), 250);
+    assert_eq!(s.token.balance(&s.contributor), 250);
+    assert_eq!(s.escrow.get_escrow_info(&1).remaining_amount, 750);
+
+    s.advance(50);
+    assert_eq!(s.escrow.claim_vested(&1), 500);
+    assert_eq!(s.escrow.get_vesting(&1).claimed, 750);
+
+    // Claiming past the end pays only the remainder
+    s.advance(1_000);
+    assert_eq!(s.escrow.claim_vested(&1), 250);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+
+    let info = s.escrow.get_escrow_info(&1);
+    assert_eq!(info.remaining_amount, 0);
+    assert_eq!(info.status, EscrowStatus::Released);
+    assert_eq!(s.escrow.get_release_history(&1).len(), 3);
+}
+
+#[test]
+fn test_claim_with_nothing_vested_fails() {
+    let s = Setup::new();
+    s.lock(1, 1_000);
+    s.escrow.release_vested(&1, &s.contributor, &100);
+
+    assert_eq!(
+        s.escrow.try_claim_vested(&1),
+        Err(Ok(Error::NothingToClaim))
+    );
+
+    s.advance(100);
+    s.escrow.claim_vested(&1);
+    assert_eq!(
+        s.escrow.try_claim_vested(&1),
+        Err(Ok(Error::NothingToClaim))
+    );
+}
+
+#[test]
+fn test_vesting_bounty_cannot_be_refunded() {
+    let s = Setup::new();
+    let deadline = s.lock(1, 1_000);
+    s.escrow.release_vested(&1, &s.contributor, &10_000);
+
+    s.env.ledger().set_timestamp(deadline + 1);
+    assert_eq!(s.escrow.try_refund(&1), Err(Ok(Error::FundsNotLocked)));
+    assert_eq!(
+        s.escrow.try_release_vested(&1, &s.contributor, &100),
+        Err(Ok(Error::FundsNotLocked))
+    );
+}
+
+#[test]
+fn test_release_vested_rejects_zero_duration() {
+    let s = Setup::new();
+    s.lock(1, 1_000);
+
+    assert_eq!(
+        s.escrow.try_release_vested(&1, &s.contributor, &0),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    assert_eq!(
+        s.escrow.try_get_vesting(&1),
+        Err(Ok(Error::VestingNotFound))
+    );
+}
@@ -0,0 +1,16 @@
+//! Errors returned by the shared helpers.
+//!
+//! Each contract keeps its own `#[contracterror]` enum because the numeric
+//! codes are part of its public interface. Contracts convert a
+//! [`CommonError`] into their own error type at the call site.
+
+/// Failure of a shared arithmetic helper.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CommonError {
+    /// An intermediate result does not fit in an `i128`.
+    Overflow,
+    /// A basis point value is negative or above [`crate::math::BASIS_POINTS`].
+    InvalidBasisPoints,
+    /// Split shares do not sum to [`crate::math::BASIS_POINTS`].
+    InvalidShares,
+}
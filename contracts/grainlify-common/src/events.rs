@@ -0,0 +1,19 @@
+//! Event conventions.
+//!
+//! Structured events carry a `version` field set to [`EVENT_VERSION_V2`] so
+//! indexers can tell schemas apart. Simple per-record events use a
+//! `(name, id)` topic pair and publish the changed value as data.
+
+use soroban_sdk::{Env, IntoVal, Symbol, Val};
+
+/// Schema version carried by every structured event.
+pub const EVENT_VERSION_V2: u32 = 2;
+
+/// Publish `data` under the `(name, id)` topic pair, e.g.
+/// `(symbol_short!("tags"), bounty_id)`.
+pub fn publish_for<D>(env: &Env, name: Symbol, id: u64, data: D)
+where
+    D: IntoVal<Env, Val>,
+{
+    env.events().publish((name, id), data);
+}
@@ -0,0 +1,19 @@
+#![no_std]
+//! # Grainlify Common
+//!
+//! Building blocks shared by the grainlify-core, program-escrow and
+//! bounty_escrow contracts, so basis point math, event versioning and TTL
+//! policy behave the same way in every contract.
+//!
+//! - [`errors`]: failures reported by the shared helpers
+//! - [`events`]: event schema version and topic conventions
+//! - [`math`]: checked basis point and sum arithmetic
+//! - [`storage`]: ledger TTL policy and storage key conventions
+
+pub mod errors;
+pub mod events;
+pub mod math;
+pub mod storage;
+
+#[cfg(test)]
+mod test;
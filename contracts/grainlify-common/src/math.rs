@@ -0,0 +1,59 @@
+//! Checked arithmetic for amounts and basis points.
+
+use crate::errors::CommonError;
+use soroban_sdk::{Env, Vec};
+
+/// 100% expressed in basis points.
+pub const BASIS_POINTS: i128 = 10_000;
+
+/// `amount * bps / BASIS_POINTS`, rounded toward zero.
+pub fn bps_of(amount: i128, bps: i128) -> Result<i128, CommonError> {
+    if !(0..=BASIS_POINTS).contains(&bps) {
+        return Err(CommonError::InvalidBasisPoints);
+    }
+    amount
+        .checked_mul(bps)
+        .map(|x| x / BASIS_POINTS)
+        .ok_or(CommonError::Overflow)
+}
+
+/// Fee charged on `amount` at `fee_rate` basis points. Returns 0 when the
+/// rate is zero or the fee cannot be computed, so a misconfigured rate
+/// never blocks a payout.
+pub fn fee_of(amount: i128, fee_rate: i128) -> i128 {
+    if fee_rate == 0 {
+        return 0;
+    }
+    bps_of(amount, fee_rate).unwrap_or(0)
+}
+
+/// Sum of `amounts`, failing instead of wrapping on overflow.
+pub fn checked_sum<I>(amounts: I) -> Result<i128, CommonError>
+where
+    I: IntoIterator<Item = i128>,
+{
+    amounts.into_iter().try_fold(0i128, |total, amount| {
+        total.checked_add(amount).ok_or(CommonError::Overflow)
+    })
+}
+
+/// Split `total` by `shares` (basis points summing to [`BASIS_POINTS`]).
+/// Returns the amount for each share and the rounding dust left over,
+/// which callers assign as they see fit.
+pub fn split_bps(
+    env: &Env,
+    total: i128,
+    shares: &Vec<u32>,
+) -> Result<(Vec<i128>, i128), CommonError> {
+    if checked_sum(shares.iter().map(|bps| bps as i128))? != BASIS_POINTS {
+        return Err(CommonError::InvalidShares);
+    }
+    let mut amounts = Vec::new(env);
+    let mut distributed: i128 = 0;
+    for bps in shares.iter() {
+        let amount = bps_of(total, bps as i128)?;
+        distributed += amount;
+        amounts.push_back(amount);
+    }
+    Ok((amounts, total - distributed))
+}
@@ -0,0 +1,39 @@
+//! Storage TTL policy and key conventions.
+//!
+//! Keys are `#[contracttype]` enums, one per subsystem (e.g. `DataKey`,
+//! `AntiAbuseKey`), with per-record variants taking the record id. A contract
+//! type enum holds at most 50 variants, so a subsystem that would push the
+//! main enum past that gets its own key enum. Older contracts use
+//! `symbol_short!` constants of at most 9 characters for singleton keys.
+//!
+//! Global configuration lives in instance storage. Per-record data lives in
+//! persistent storage and is extended with [`extend_persistent`].
+
+use soroban_sdk::{Env, IntoVal, Val};
+
+/// Ledgers closed per day at a 5 second close time.
+pub const LEDGERS_PER_DAY: u32 = 17_280;
+
+/// Persistent entries are extended once their TTL drops below this.
+pub const PERSISTENT_TTL_THRESHOLD: u32 = LEDGERS_PER_DAY * 15;
+
+/// TTL persistent entries are extended to.
+pub const PERSISTENT_TTL_EXTEND_TO: u32 = LEDGERS_PER_DAY * 30;
+
+/// Extend a persistent entry using the shared TTL policy. The entry must
+/// exist.
+pub fn extend_persistent<K>(env: &Env, key: &K)
+where
+    K: IntoVal<Env, Val>,
+{
+    env.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_EXTEND_TO);
+}
+
+/// Extend the contract instance (and its code) using the shared TTL policy.
+pub fn extend_instance(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_EXTEND_TO);
+}
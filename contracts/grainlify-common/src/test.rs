@@ -0,0 +1,44 @@
+use crate::errors::CommonError;
+use crate::math::{bps_of, checked_sum, fee_of, split_bps, BASIS_POINTS};
+use crate::storage::{PERSISTENT_TTL_EXTEND_TO, PERSISTENT_TTL_THRESHOLD};
+use soroban_sdk::{vec, Env};
+
+#[test]
+fn test_bps_of() {
+    assert_eq!(bps_of(1_000, 250), Ok(25));
+    assert_eq!(bps_of(999, 1), Ok(0));
+    assert_eq!(bps_of(1_000, BASIS_POINTS), Ok(1_000));
+    assert_eq!(bps_of(1_000, -1), Err(CommonError::InvalidBasisPoints));
+    assert_eq!(bps_of(1_000, 10_001), Err(CommonError::InvalidBasisPoints));
+    assert_eq!(bps_of(i128::MAX, 2), Err(CommonError::Overflow));
+}
+
+#[test]
+fn test_fee_of_never_fails() {
+    assert_eq!(fee_of(1_000, 0), 0);
+    assert_eq!(fee_of(1_000, 500), 50);
+    assert_eq!(fee_of(i128::MAX, 500), 0);
+}
+
+#[test]
+fn test_checked_sum() {
+    assert_eq!(checked_sum([1, 2, 3]), Ok(6));
+    assert_eq!(checked_sum([i128::MAX, 1]), Err(CommonError::Overflow));
+}
+
+#[test]
+fn test_split_bps_returns_dust() {
+    let env = Env::default();
+    let (amounts, dust) = split_bps(&env, 100, &vec![&env, 3_333, 3_333, 3_334]).unwrap();
+    assert_eq!(amounts, vec![&env, 33, 33, 33]);
+    assert_eq!(dust, 1);
+
+    assert_eq!(
+        split_bps(&env, 100, &vec![&env, 5_000, 4_999]),
+        Err(CommonError::InvalidShares)
+    );
+}
+
+// Entries must be extended before they drop to the threshold again
+const _: () = assert!(PERSISTENT_TTL_THRESHOLD < PERSISTENT_TTL_EXTEND_TO);
+const _: () = assert!(PERSISTENT_TTL_EXTEND_TO == 518_400);
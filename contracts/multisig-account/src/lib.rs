@@ -0,0 +1,168 @@
+#![no_std]
+//! # Multisig Account
+//!
+//! An M-of-N ed25519 account contract. Deploy one and use its address
+//! wherever the grainlify contracts expect a single key (admin, organizer,
+//! payout key): every `require_auth` on that address is checked here, and
+//! passes only when at least `threshold` of the configured signers signed the
+//! authorization payload.
+//!
+//! ## Signatures
+//! `__check_auth` takes a `Vec<Signature>` sorted by strictly increasing
+//! public key, so each signer is counted at most once.
+//!
+//! ## Deployment
+//! `init` is not access controlled: whoever calls it first picks the
+//! signers. Deploy and initialize in the same transaction (e.g. from a
+//! factory contract that calls `init` right after deploying), and check
+//! `get_signers` before handing out the address.
+//!
+//! ## Changing signers
+//! `set_signers` requires the account's own authorization, i.e. the current
+//! signers must approve the new set.
+
+use grainlify_common::storage;
+use soroban_sdk::{
+    auth::{Context, CustomAccountInterface},
+    contract, contracterror, contractimpl, contracttype,
+    crypto::Hash,
+    symbol_short, BytesN, Env, Vec,
+};
+
+#[cfg(test)]
+mod test;
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Signers,   // Vec<BytesN<32>> of ed25519 public keys
+    Threshold, // u32 signatures required
+}
+
+/// One signer's signature over the authorization payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Signature {
+    pub public_key: BytesN<32>,
+    pub signature: BytesN<64>,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    /// Returned when the threshold is 0 or exceeds the number of signers
+    InvalidThreshold = 3,
+    /// Returned when a signer is listed twice
+    DuplicateSigner = 4,
+    /// Returned when a signature is from a key that is not a signer
+    UnknownSigner = 5,
+    /// Returned when signatures are not sorted by increasing public key
+    SignaturesNotSorted = 6,
+    /// Returned when fewer than `threshold` signatures were given
+    ThresholdNotMet = 7,
+}
+
+#[contract]
+pub struct MultisigAccount;
+
+#[contractimpl]
+impl MultisigAccount {
+    /// Initialize with the signers' ed25519 public keys and the number of
+    /// signatures required. Must run in the same transaction as the deploy,
+    /// see the crate docs.
+    pub fn init(env: Env, signers: Vec<BytesN<32>>, threshold: u32) -> Result<(), Error> {
+        if env.storage().instance().has(&DataKey::Signers) {
+            return Err(Error::AlreadyInitialized);
+        }
+        Self::store_signers(&env, &signers, threshold)
+    }
+
+    /// Replace the signers and threshold (the account itself only).
+    pub fn set_signers(env: Env, signers: Vec<BytesN<32>>, threshold: u32) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Signers) {
+            return Err(Error::NotInitialized);
+        }
+        env.current_contract_address().require_auth();
+        Self::store_signers(&env, &signers, threshold)
+    }
+
+    /// View: get the signers' public keys.
+    pub fn get_signers(env: Env) -> Vec<BytesN<32>> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Signers)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// View: get the number of signatures required (0 before `init`).
+    pub fn get_threshold(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Threshold)
+            .unwrap_or(0)
+    }
+
+    fn store_signers(env: &Env, signers: &Vec<BytesN<32>>, threshold: u32) -> Result<(), Error> {
+        if threshold == 0 || threshold > signers.len() {
+            return Err(Error::InvalidThreshold);
+        }
+        for (i, signer) in signers.iter().enumerate() {
+            if signers.first_index_of(&signer) != Some(i as u32) {
+                return Err(Error::DuplicateSigner);
+            }
+        }
+        env.storage().instance().set(&DataKey::Signers, signers);
+        env.storage()
+            .instance()
+            .set(&DataKey::Threshold, &threshold);
+        storage::extend_instance(env);
+        env.events()
+            .publish((symbol_short!("signers"),), (signers.clone(), threshold));
+        Ok(())
+    }
+}
+
+#[contractimpl]
+impl CustomAccountInterface for MultisigAccount {
+    type Signature = Vec<Signature>;
+    type Error = Error;
+
+    #[allow(non_snake_case)]
+    fn __check_auth(
+        env: Env,
+        signature_payload: Hash<32>,
+        signatures: Vec<Signature>,
+        _auth_contexts: Vec<Context>,
+    ) -> Result<(), Error> {
+        let signers = Self::get_signers(env.clone());
+        let threshold = Self::get_threshold(env.clone());
+        if threshold == 0 {
+            return Err(Error::NotInitialized);
+        }
+        if signatures.len() < threshold {
+            return Err(Error::ThresholdNotMet);
+        }
+
+        let payload = signature_payload.to_bytes().into();
+        let mut previous: Option<BytesN<32>> = None;
+        for signature in signatures.iter() {
+            if let Some(previous) = previous {
+                if previous >= signature.public_key {
+                    return Err(Error::SignaturesNotSorted);
+                }
+            }
+            if !signers.contains(&signature.public_key) {
+                return Err(Error::UnknownSigner);
+            }
+            // Traps if the signature does not verify
+            env.crypto()
+                .ed25519_verify(&signature.public_key, &payload, &signature.signature);
+            previous = Some(signature.public_key);
+        }
+        storage::extend_instance(&env);
+        Ok(())
+    }
+}
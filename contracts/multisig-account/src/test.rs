@@ -0,0 +1,279 @@
+extern crate std;
+
+use super::*;
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{storage::Instance as _, BytesN as _, Ledger},
+    vec,
+    xdr::{
+        self, HashIdPreimage, HashIdPreimageSorobanAuthorization, InvokeContractArgs, Limits,
+        ScAddress, ScSymbol, ScVal, SorobanAddressCredentials, SorobanAuthorizationEntry,
+        SorobanAuthorizedFunction, SorobanAuthorizedInvocation, SorobanCredentials, WriteXdr,
+    },
+    Address, Bytes, IntoVal, TryFromVal, Val,
+};
+
+/// Stands in for an escrow entrypoint gated on an admin address.
+#[contract]
+pub struct Guarded;
+
+#[contractimpl]
+impl Guarded {
+    pub fn protected(_env: Env, admin: Address) -> u32 {
+        admin.require_auth();
+        7
+    }
+}
+
+struct Setup<'a> {
+    env: Env,
+    keys: [SigningKey; 3],
+    account: MultisigAccountClient<'a>,
+}
+
+impl<'a> Setup<'a> {
+    /// A 2-of-3 account.
+    fn new() -> Self {
+        let env = Env::default();
+        let keys = [
+            SigningKey::from_bytes(&[1u8; 32]),
+            SigningKey::from_bytes(&[2u8; 32]),
+            SigningKey::from_bytes(&[3u8; 32]),
+        ];
+        let account_id = env.register_contract(None, MultisigAccount);
+        let account = MultisigAccountClient::new(&env, &account_id);
+        let signers = vec![
+            &env,
+            public_key(&env, &keys[0]),
+            public_key(&env, &keys[1]),
+            public_key(&env, &keys[2]),
+        ];
+        account.init(&signers, &2);
+        Self { env, keys, account }
+    }
+
+    /// Sign `payload` with `keys`, sorted as `__check_auth` expects.
+    fn sign(&self, payload: &[u8; 32], keys: &[&SigningKey]) -> Vec<Signature> {
+        let mut signatures = Vec::new(&self.env);
+        let mut keys = keys.to_vec();
+        keys.sort_by_key(|key| key.verifying_key().to_bytes());
+        for key in keys {
+            signatures.push_back(Signature {
+                public_key: public_key(&self.env, key),
+                signature: BytesN::from_array(&self.env, &key.sign(payload).to_bytes()),
+            });
+        }
+        signatures
+    }
+
+    /// Authorize `contract.fn_name(args)` on behalf of the account, signed
+    /// by `keys`, so that `require_auth` runs `__check_auth` for real.
+    fn authorize(
+        &self,
+        nonce: i64,
+        contract: &Address,
+        fn_name: &str,
+        args: Vec<Val>,
+        keys: &[&SigningKey],
+    ) {
+        let env = &self.env;
+        let invocation = SorobanAuthorizedInvocation {
+            function: SorobanAuthorizedFunction::ContractFn(InvokeContractArgs {
+                contract_address: ScAddress::from(contract),
+                function_name: ScSymbol(fn_name.try_into().unwrap()),
+                args: args
+                    .iter()
+                    .map(|arg| ScVal::try_from_val(env, &arg).unwrap())
+                    .collect::<std::vec::Vec<_>>()
+                    .try_into()
+                    .unwrap(),
+            }),
+            sub_invocations: Default::default(),
+        };
+        let signature_expiration_ledger = env.ledger().sequence() + 100;
+        let preimage = HashIdPreimage::SorobanAuthorization(HashIdPreimageSorobanAuthorization {
+            network_id: xdr::Hash(env.ledger().network_id().to_array()),
+            nonce,
+            signature_expiration_ledger,
+            invocation: invocation.clone(),
+        });
+        let preimage = preimage.to_xdr(Limits::none()).unwrap();
+        let payload = env
+            .crypto()
+            .sha256(&Bytes::from_slice(env, &preimage))
+            .to_array();
+
+        let signatures = self.sign(&payload, keys);
+        env.set_auths(&[SorobanAuthorizationEntry {
+            credentials: SorobanCredentials::Address(SorobanAddressCredentials {
+                address: ScAddress::from(&self.account.address),
+                nonce,
+                signature_expiration_ledger,
+                signature: ScVal::try_from_val(env, &signatures.to_val()).unwrap(),
+            }),
+            root_invocation: invocation,
+        }]);
+    }
+
+    fn check_auth(&self, payload: &BytesN<32>, signatures: Vec<Signature>) -> Result<(), Error> {
+        self.env
+            .try_invoke_contract_check_auth::<Error>(
+                &self.account.address,
+                payload,
+                signatures.into_val(&self.env),
+                &Vec::new(&self.env),
+            )
+            .map_err(|err| err.unwrap())
+    }
+}
+
+fn public_key(env: &Env, key: &SigningKey) -> BytesN<32> {
+    BytesN::from_array(env, &key.verifying_key().to_bytes())
+}
+
+#[test]
+fn test_init_validates_signers() {
+    let env = Env::default();
+    let account = MultisigAccountClient::new(&env, &env.register_contract(None, MultisigAccount));
+    let a = BytesN::<32>::random(&env);
+    let b = BytesN::<32>::random(&env);
+
+    assert_eq!(
+        account.try_init(&vec![&env, a.clone(), b.clone()], &3),
+        Err(Ok(Error::InvalidThreshold))
+    );
+    assert_eq!(
+        account.try_init(&vec![&env, a.clone()], &0),
+        Err(Ok(Error::InvalidThreshold))
+    );
+    assert_eq!(
+        account.try_init(&vec![&env, a.clone(), a.clone()], &1),
+        Err(Ok(Error::DuplicateSigner))
+    );
+
+    account.init(&vec![&env, a.clone(), b.clone()], &2);
+    assert_eq!(account.get_signers(), vec![&env, a.clone(), b]);
+    assert_eq!(account.get_threshold(), 2);
+    assert_eq!(
+        account.try_init(&vec![&env, a], &1),
+        Err(Ok(Error::AlreadyInitialized))
+    );
+}
+
+#[test]
+fn test_require_auth_passes_with_threshold_signatures() {
+    let s = Setup::new();
+    let guarded_id = s.env.register_contract(None, Guarded);
+    let guarded = GuardedClient::new(&s.env, &guarded_id);
+
+    let args = vec![&s.env, s.account.address.to_val()];
+    s.authorize(1, &guarded_id, "protected", args, &[&s.keys[2], &s.keys[0]]);
+    assert_eq!(guarded.protected(&s.account.address), 7);
+}
+
+#[test]
+fn test_check_auth_extends_instance_ttl() {
+    let s = Setup::new();
+    let ttl = || {
+        s.env
+            .as_contract(&s.account.address, || s.env.storage().instance().get_ttl())
+    };
+    assert_eq!(ttl(), storage::PERSISTENT_TTL_EXTEND_TO);
+
+    s.env
+        .ledger()
+        .with_mut(|li| li.sequence_number += 20 * 17_280);
+    let payload = BytesN::<32>::random(&s.env);
+    let signatures = s.sign(&payload.to_array(), &[&s.keys[0], &s.keys[1]]);
+    assert_eq!(s.check_auth(&payload, signatures), Ok(()));
+    assert_eq!(ttl(), storage::PERSISTENT_TTL_EXTEND_TO);
+}
+
+#[test]
+fn test_require_auth_fails_below_threshold() {
+    let s = Setup::new();
+    let guarded_id = s.env.register_contract(None, Guarded);
+    let guarded = GuardedClient::new(&s.env, &guarded_id);
+
+    let args = vec![&s.env, s.account.address.to_val()];
+    s.authorize(1, &guarded_id, "protected", args, &[&s.keys[1]]);
+    assert!(guarded.try_protected(&s.account.address).is_err());
+}
+
+#[test]
+fn test_check_auth_rejections() {
+    let s = Setup::new();
+    let payload = BytesN::<32>::random(&s.env);
+    let stranger = SigningKey::from_bytes(&[9u8; 32]);
+
+    let signatures = s.sign(&payload.to_array(), &[&s.keys[0]]);
+    assert_eq!(
+        s.check_auth(&payload, signatures),
+        Err(Error::ThresholdNotMet)
+    );
+
+    let signatures = s.sign(&payload.to_array(), &[&s.keys[0], &stranger]);
+    assert_eq!(
+        s.check_auth(&payload, signatures),
+        Err(Error::UnknownSigner)
+    );
+
+    // The same signer twice does not count as two signatures
+    let mut signatures = s.sign(&payload.to_array(), &[&s.keys[0]]);
+    signatures.push_back(signatures.get(0).unwrap());
+    assert_eq!(
+        s.check_auth(&payload, signatures),
+        Err(Error::SignaturesNotSorted)
+    );
+
+    let signatures = s.sign(&payload.to_array(), &[&s.keys[0], &s.keys[1]]);
+    assert_eq!(s.check_auth(&payload, signatures), Ok(()));
+}
+
+#[test]
+fn test_check_auth_rejects_bad_signature() {
+    let s = Setup::new();
+    let payload = BytesN::<32>::random(&s.env);
+    let other = BytesN::<32>::random(&s.env);
+
+    // Valid signatures, but over a different payload
+    let signatures = s.sign(&other.to_array(), &[&s.keys[0], &s.keys[1]]);
+    assert!(s
+        .env
+        .try_invoke_contract_check_auth::<Error>(
+            &s.account.address,
+            &payload,
+            signatures.into_val(&s.env),
+            &Vec::new(&s.env),
+        )
+        .is_err());
+}
+
+#[test]
+fn test_set_signers_needs_the_account_itself() {
+    let s = Setup::new();
+    let new_key = SigningKey::from_bytes(&[4u8; 32]);
+    let signers = vec![
+        &s.env,
+        public_key(&s.env, &s.keys[0]),
+        public_key(&s.env, &new_key),
+    ];
+
+    let args = vec![&s.env, signers.to_val(), 1u32.into_val(&s.env)];
+    s.authorize(
+        1,
+        &s.account.address,
+        "set_signers",
+        args,
+        &[&s.keys[0], &s.keys[1]],
+    );
+    s.account.set_signers(&signers, &1);
+    assert_eq!(s.account.get_signers(), signers);
+    assert_eq!(s.account.get_threshold(), 1);
+
+    // One signature from the new set is now enough
+    let payload = BytesN::<32>::random(&s.env);
+    let signatures = s.sign(&payload.to_array(), &[&new_key]);
+    assert_eq!(s.check_auth(&payload, signatures), Ok(()));
+}
@@ -17,8 +17,9 @@
 //
 // ============================================================
 
+use grainlify_common::storage;
 use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol};
-use crate::{DataKey, ProgramData, PROGRAM_DATA};
+use crate::{adjust_total_paid, deliver_payout, load_program_data, save_program_data, DataKey, ProgramData};
 
 /// The status of a pending claim record.
 #[contracttype]
@@ -48,10 +49,14 @@ pub struct ClaimRecord {
 const CLAIM_CREATED: Symbol = symbol_short!("ClmCrtd");
 const CLAIM_EXECUTED: Symbol = symbol_short!("ClmExec");
 const CLAIM_CANCELLED: Symbol = symbol_short!("ClmCncl");
+const CLAIM_RECLAIMED: Symbol = symbol_short!("ClmRclm");
 
 // Storage key for auto-incrementing claim IDs
 const NEXT_CLAIM_ID: Symbol = symbol_short!("NxtClmId");
 
+// Storage key for the total amount held for pending claims
+const RESERVED_TOTAL: Symbol = symbol_short!("ClmRsrvd");
+
 fn next_claim_id(env: &Env) -> u64 {
     let id: u64 = env
         .storage()
@@ -63,20 +68,22 @@ fn next_claim_id(env: &Env) -> u64 {
 }
 
 fn get_program(env: &Env) -> ProgramData {
-    env.storage()
-        .instance()
-        .get(&PROGRAM_DATA)
-        .unwrap_or_else(|| panic!("Program not initialized"))
+    load_program_data(env).unwrap_or_else(|| panic!("Program not initialized"))
 }
 
 fn save_program(env: &Env, data: &ProgramData) {
-    env.storage().instance().set(&PROGRAM_DATA, data);
+    save_program_data(env, data);
 }
 
 fn claim_key(program_id: &String, claim_id: u64) -> DataKey {
     DataKey::PendingClaim(program_id.clone(), claim_id)
 }
 
+fn adjust_reserved_total(env: &Env, delta: i128) {
+    let total = get_reserved_total(env) + delta;
+    env.storage().instance().set(&RESERVED_TOTAL, &total);
+}
+
 // ── Public functions ─────────────────────────────────────────
 // These functions should be called from the ProgramEscrowContract impl.
 
@@ -109,9 +116,26 @@ pub fn create_pending_claim(
     if claim_deadline <= env.ledger().timestamp() {
         panic!("Claim deadline must be in the future");
     }
+    reserve_claim(env, &mut program, program_id, recipient, amount, claim_deadline)
+}
+
+/// Reserve an already validated claim against `program` and record it.
+///
+/// Callers are responsible for authorization and for checking the amount,
+/// balance and deadline.
+pub(crate) fn reserve_claim(
+    env: &Env,
+    program: &mut ProgramData,
+    program_id: &String,
+    recipient: &Address,
+    amount: i128,
+    claim_deadline: u64,
+) -> u64 {
     // Reserve the funds (deduct from remaining balance)
     program.remaining_balance -= amount;
-    save_program(env, &program);
+    save_program(env, program);
+    adjust_total_paid(env, amount);
+    adjust_reserved_total(env, amount);
 
     let claim_id = next_claim_id(env);
     let now = env.ledger().timestamp();
@@ -129,9 +153,10 @@ pub fn create_pending_claim(
     env.storage()
         .persistent()
         .set(&claim_key(program_id, claim_id), &record);
+    storage::extend_persistent(env, &claim_key(program_id, claim_id));
 
     env.events().publish(
-        (CLAIM_CREATED,),
+        (CLAIM_CREATED, program_id.clone(), recipient.clone()),
         (program_id.clone(), claim_id, recipient.clone(), amount, claim_deadline),
     );
 
@@ -170,18 +195,15 @@ pub fn execute_claim(env: &Env, program_id: &String, claim_id: u64, caller: &Add
     // transfer funds to recipient
     let program = get_program(env);
     let token_client = soroban_sdk::token::Client::new(env, &program.token_address);
-    token_client.transfer(
-        &env.current_contract_address(),
-        &record.recipient,
-        &record.amount,
-    );
+    deliver_payout(env, &token_client, &record.recipient, record.amount, false);
 
     // marks the claim as completed and persist the update.
     record.status = ClaimStatus::Completed;
     env.storage().persistent().set(&key, &record);
+    adjust_reserved_total(env, -record.amount);
 
     env.events().publish(
-        (CLAIM_EXECUTED,),
+        (CLAIM_EXECUTED, program_id.clone(), record.recipient.clone()),
         (program_id.clone(), claim_id, record.recipient.clone(), record.amount),
     );
 }
@@ -215,17 +237,55 @@ pub fn cancel_claim(env: &Env, program_id: &String, claim_id: u64, admin: &Addre
     let mut program = get_program(env);
     program.remaining_balance += record.amount;
     save_program(env, &program);
+    adjust_total_paid(env, -record.amount);
+    adjust_reserved_total(env, -record.amount);
 
     // mark claim as cancelled
     record.status = ClaimStatus::Cancelled;
     env.storage().persistent().set(&key, &record);
 
     env.events().publish(
-        (CLAIM_CANCELLED,),
+        (CLAIM_CANCELLED, program_id.clone(), record.recipient.clone()),
         (program_id.clone(), claim_id, record.recipient.clone(), record.amount),
     );
 }
 
+/// Returns an expired, unclaimed claim's funds to the escrow balance.
+///
+/// Callable by anyone once `claim_deadline` has passed, so unclaimed
+/// payouts never stay locked.
+pub fn reclaim_expired_claim(env: &Env, program_id: &String, claim_id: u64) -> i128 {
+    let key = claim_key(program_id, claim_id);
+    let mut record: ClaimRecord = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| panic!("Claim not found"));
+
+    match record.status {
+        ClaimStatus::Pending => {}
+        _ => panic!("ClaimAlreadyProcessed"),
+    }
+    if env.ledger().timestamp() <= record.claim_deadline {
+        panic!("Claim not expired");
+    }
+
+    let mut program = get_program(env);
+    program.remaining_balance += record.amount;
+    save_program(env, &program);
+    adjust_total_paid(env, -record.amount);
+    adjust_reserved_total(env, -record.amount);
+
+    record.status = ClaimStatus::Cancelled;
+    env.storage().persistent().set(&key, &record);
+
+    env.events().publish(
+        (CLAIM_RECLAIMED, program_id.clone(), record.recipient.clone()),
+        (program_id.clone(), claim_id, record.recipient.clone(), record.amount),
+    );
+
+    record.amount
+}
 
 /// Returns a claim record by its ID.
 ///
@@ -237,6 +297,14 @@ pub fn get_claim(env: &Env, program_id: &String, claim_id: u64) -> ClaimRecord {
         .unwrap_or_else(|| panic!("Claim not found"))
 }
 
+/// Returns the total amount reserved by claims that are still pending.
+///
+/// These tokens are held by the contract but no longer counted in
+/// `remaining_balance`.
+pub fn get_reserved_total(env: &Env) -> i128 {
+    env.storage().instance().get(&RESERVED_TOTAL).unwrap_or(0)
+}
+
 /// Set the global default claim window in seconds.
 /// Admin only.
 pub fn set_claim_window(env: &Env, admin: &Address, window_seconds: u64) {
@@ -135,6 +135,7 @@
 //! 1. **Verify Winners**: Confirm winner addresses off-chain before payout
 //! 2. **Test Payouts**: Use testnet for testing prize distributions
 //! 3. **Secure Backend**: Protect authorized payout key with HSM/multi-sig
+//!    (e.g. a `contracts/multisig-account` address)
 //! 4. **Audit History**: Review payout history before each distribution
 //! 5. **Balance Checks**: Verify remaining balance matches expectations
 //! 6. **Token Approval**: Ensure contract has token allowance before locking funds
@@ -266,30 +267,360 @@ pub fn emergency_open_circuit(env: Env, admin: Address) {
     error_recovery::open_circuit(&env);
 }
 
+use access_control::{ownable, roles, AccessError};
+use grainlify_common::errors::CommonError;
+use grainlify_common::events::EVENT_VERSION_V2;
+use grainlify_common::math::{self, BASIS_POINTS};
+use grainlify_common::storage;
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, Address, Env,
-    String, Symbol, Vec,
+    contract, contractclient, contracterror, contractimpl, contracttype, symbol_short, token, vec,
+    xdr::ToXdr, Address, Bytes, BytesN, Env, String, Symbol, Vec,
 };
 
 // Event types
+//
+// Fund-flow events publish `program_id` (and the recipient, when there is one)
+// as topics after the event symbol, e.g. `("Payout", program_id, recipient)`,
+// so RPC consumers can filter without scanning event bodies.
 const PROGRAM_INITIALIZED: Symbol = symbol_short!("PrgInit");
 const FUNDS_LOCKED: Symbol = symbol_short!("FndsLock");
 const BATCH_PAYOUT: Symbol = symbol_short!("BatchPay");
 const PAYOUT: Symbol = symbol_short!("Payout");
-const EVENT_VERSION_V2: u32 = 2;
 const PAUSE_STATE_CHANGED: Symbol = symbol_short!("PauseSt");
 const PROGRAM_REGISTRY: Symbol = symbol_short!("ProgReg");
 const PROGRAM_REGISTERED: Symbol = symbol_short!("ProgRgd");
 const FEE_CONFIG: Symbol = symbol_short!("FeeCfg");
-const BASIS_POINTS: i128 = 10_000;
+const PROGRAM_CANCELLED: Symbol = symbol_short!("PrgCncl");
+const MAX_FEE_RATE: i128 = 5_000; // 50% max fee
+const FEE_LEDGER: Symbol = symbol_short!("FeeLedgr");
+const TOTAL_FEES: Symbol = symbol_short!("TotFees");
+const TOTAL_REFUNDED: Symbol = symbol_short!("TotRefnd");
+const TOTAL_PAID: Symbol = symbol_short!("TotPaid");
+const FEE_ON_TRANSFER: Symbol = symbol_short!("FeeXfer");
+const PAYOUT_LIMITS_SET: Symbol = symbol_short!("PayLimit");
+const LARGE_PAYOUT_APPROVED: Symbol = symbol_short!("LrgAppr");
+const BALANCE_MISMATCH: Symbol = symbol_short!("BalMism");
+const SURPLUS_SWEPT: Symbol = symbol_short!("Swept");
+const ELIGIBILITY_REGISTRY_SET: Symbol = symbol_short!("EligReg");
+const PAYOUT_HELD: Symbol = symbol_short!("PayHeld");
+const HELD_PAYOUT_RELEASED: Symbol = symbol_short!("HeldRls");
+const PAYOUT_CLAWED_BACK: Symbol = symbol_short!("Clawback");
+const DISTRIBUTION_PROPOSED: Symbol = symbol_short!("DistProp");
+const DISTRIBUTION_AMENDED: Symbol = symbol_short!("DistAmnd");
+const DISTRIBUTION_FINALIZED: Symbol = symbol_short!("DistFin");
+const DIRECT_PAYOUTS_SET: Symbol = symbol_short!("DirectPay");
+const CHALLENGE_PERIOD_SET: Symbol = symbol_short!("ChalPer");
+const CLAWBACK_WINDOW_SET: Symbol = symbol_short!("ClawWin");
+const LARGE_PAYOUT_THRESHOLD_SET: Symbol = symbol_short!("LrgThrsh");
+const SCHEDULE_SKIPPED: Symbol = symbol_short!("SchedSkip");
+const SCHEDULE_CANCELLED: Symbol = symbol_short!("SchedCncl");
+const DEFAULT_CHALLENGE_PERIOD: u64 = 86_400;
+const MAX_SESSION_DURATION: u64 = 86_400;
+const PAYOUT_SIGNER_ROLE: Symbol = symbol_short!("signer");
+const JUDGE_ROLE: Symbol = symbol_short!("judge");
+const CONTRACT_UPGRADED: Symbol = symbol_short!("Upgraded");
+const ADMIN_PROPOSED: Symbol = symbol_short!("AdmProp");
+const ADMIN_TRANSFERRED: Symbol = symbol_short!("AdmXfer");
+const CONTRACT_VERSION: u32 = 1;
+const RECIPIENT_BLOCKED: Symbol = symbol_short!("RcptBlk");
+const RECIPIENT_UNBLOCKED: Symbol = symbol_short!("RcptUnblk");
+const PAYOUT_SIGNER_SET: Symbol = symbol_short!("SignerSet");
+const PAYOUT_SIGNER_REMOVED: Symbol = symbol_short!("SignerRm");
+const PRIZE_TIERS_SET: Symbol = symbol_short!("TiersSet");
+const JUDGE_ADDED: Symbol = symbol_short!("JudgeAdd");
+const JUDGE_REMOVED: Symbol = symbol_short!("JudgeRm");
+const SUBMISSION_REGISTERED: Symbol = symbol_short!("SubReg");
+const VOTE_CAST: Symbol = symbol_short!("VoteCast");
+const WINNERS_FINALIZED: Symbol = symbol_short!("WinFinal");
+const WINNERS_SHORTLISTED: Symbol = symbol_short!("Shortlst");
+const WINNER_ADDRESS_REGISTERED: Symbol = symbol_short!("WinAddr");
+const SWAP_ROUTER_SET: Symbol = symbol_short!("SwapRtr");
+const PAYOUT_SWAPPED: Symbol = symbol_short!("PaySwap");
+const SEASON_OPENED: Symbol = symbol_short!("SeasOpen");
+const SEASON_CLOSED: Symbol = symbol_short!("SeasClse");
+const RECIPIENT_FROZEN: Symbol = symbol_short!("RcptFrz");
+const RECIPIENT_UNFROZEN: Symbol = symbol_short!("RcptUnfz");
+const RETRY_QUEUED: Symbol = symbol_short!("RetryQd");
+const RETRY_PAID: Symbol = symbol_short!("RetryPd");
+const RETRY_CANCELLED: Symbol = symbol_short!("RetryCnl");
+const DUST_POLICY_SET: Symbol = symbol_short!("DustPol");
+const FEE_ON_TRANSFER_SET: Symbol = symbol_short!("FeeXfrSet");
+const DUST_PAID: Symbol = symbol_short!("DustPaid");
+const TRACK_CREATED: Symbol = symbol_short!("TrackNew");
+const TRACK_EARMARKED: Symbol = symbol_short!("Earmark");
+const PAYOUT_ADDRESS_SET: Symbol = symbol_short!("PayAddr");
+const TEAM_REGISTERED: Symbol = symbol_short!("TeamReg");
+const MAX_TEAM_MEMBERS: u32 = 20;
+const MAX_TRACKS: u32 = 20;
+// Smallest dust residue worth a separate transfer to the fee recipient
+const MIN_DUST_TRANSFER: i128 = 10;
+const MAX_SCHEDULES: u32 = 100;
+const MAX_RELEASE_HISTORY: u32 = 100;
+const MAX_JUDGES: u32 = 20;
+const MAX_SUBMISSIONS: u32 = 200;
+const MAX_SPONSORS: u32 = 100;
+const PAYOUT_NONCE_REQUIRED: Symbol = symbol_short!("NonceReq");
+const PERMIT_KEY_SET: Symbol = symbol_short!("PermitKey");
+const MAX_BATCH_SIZE_SET: Symbol = symbol_short!("MaxBatch");
+const EPOCH_LIMIT_SET: Symbol = symbol_short!("EpochLim");
+const OVER_CAP_APPROVED: Symbol = symbol_short!("CapAppr");
+const TRIPWIRE_SET: Symbol = symbol_short!("TripSet");
+const TRIPWIRE_TRIPPED: Symbol = symbol_short!("Tripped");
+const TRIPWIRE_RESET: Symbol = symbol_short!("TripRst");
+/// Pause reason recorded when the outflow tripwire pauses payouts.
+const TRIPWIRE_PAUSE_REASON: &str = "Outflow tripwire";
+const PROGRAM_ARCHIVED: Symbol = symbol_short!("PrgArch");
+const PROGRAM_STATUS_CHANGED: Symbol = symbol_short!("PrgState");
+const PROGRAM_CLOSED: Symbol = symbol_short!("PrgClose");
 
 // Storage keys
 const PROGRAM_DATA: Symbol = symbol_short!("ProgData");
 const SCHEDULES: Symbol = symbol_short!("Scheds");
 const RELEASE_HISTORY: Symbol = symbol_short!("RelHist");
+const ARCHIVED_SUMMARY: Symbol = symbol_short!("ArchSum");
 const NEXT_SCHEDULE_ID: Symbol = symbol_short!("NxtSched");
 const PROGRAM_INDEX: Symbol = symbol_short!("ProgIdx");
 const AUTH_KEY_INDEX: Symbol = symbol_short!("AuthIdx");
+const DIRECT_PAYOUTS: Symbol = symbol_short!("DirPay");
+
+/// Load the program record from persistent storage, extending its TTL.
+///
+/// Deployments from before the move kept the record in instance storage; it
+/// is migrated to persistent storage the first time it is read.
+pub(crate) fn load_program_data(env: &Env) -> Option<ProgramData> {
+    let data: Option<ProgramData> = env.storage().persistent().get(&PROGRAM_DATA);
+    if data.is_some() {
+        storage::extend_persistent(env, &PROGRAM_DATA);
+        return data;
+    }
+    let legacy: Option<ProgramData> = env.storage().instance().get(&PROGRAM_DATA);
+    if let Some(ref data) = legacy {
+        env.storage().instance().remove(&PROGRAM_DATA);
+        save_program_data(env, data);
+    }
+    legacy
+}
+
+/// Store the program record in persistent storage, extending its TTL and the
+/// contract instance's TTL alongside it.
+pub(crate) fn save_program_data(env: &Env, data: &ProgramData) {
+    env.storage().persistent().set(&PROGRAM_DATA, data);
+    storage::extend_persistent(env, &PROGRAM_DATA);
+    storage::extend_instance(env);
+}
+
+/// Lifecycle transition table; every status change goes through it.
+fn status_transition_allowed(from: ProgramStatus, to: ProgramStatus) -> bool {
+    use ProgramStatus::*;
+    matches!(
+        (from, to),
+        (Setup, Funded)
+            | (Funded, Active)
+            | (Funded | Active, PayoutPhase)
+            | (Funded | Active | PayoutPhase, Closed)
+            | (Setup | Funded | Active | PayoutPhase, Cancelled)
+            | (Setup | Funded | Active | PayoutPhase | Closed, Archived)
+    )
+}
+
+/// Abort with the message matching a failed access check.
+fn access_panic(err: AccessError) -> ! {
+    match err {
+        AccessError::NotInitialized => panic!("Not initialized"),
+        AccessError::AlreadyInitialized => panic!("Already initialized"),
+        AccessError::Unauthorized => panic!("Unauthorized"),
+        AccessError::NoPendingOwner => panic!("No pending admin"),
+    }
+}
+
+/// Abort with the message matching a failed shared arithmetic helper.
+fn common_panic(err: CommonError) -> ! {
+    match err {
+        CommonError::Overflow => panic!("Payout amount overflow"),
+        CommonError::InvalidBasisPoints | CommonError::InvalidShares => {
+            panic!("Shares must sum to 10000 basis points")
+        }
+    }
+}
+
+/// Resolve where a payout to `recipient` is delivered, honouring the address
+/// a shortlisted participant confirmed with `register_winner_address`, then an
+/// alternate the winner designated with `set_payout_address`. Either must
+/// itself pass the blocklist and eligibility checks.
+fn payout_destination(env: &Env, recipient: &Address) -> Address {
+    let confirmed = if env
+        .storage()
+        .instance()
+        .has(&DataKey::WinnerConfirmDeadline)
+    {
+        ProgramEscrowContract::get_winner_address(env.clone(), recipient.clone())
+    } else {
+        None
+    };
+    let alternate = confirmed.or_else(|| {
+        if !env.storage().instance().has(&WinnerKey::AlternatesSet) {
+            return None;
+        }
+        env.storage()
+            .persistent()
+            .get(&WinnerKey::PayoutAddress(recipient.clone()))
+    });
+    let Some(alternate) = alternate else {
+        return recipient.clone();
+    };
+    if alternate == *recipient {
+        return alternate;
+    }
+    ProgramEscrowContract::assert_recipient_not_blocked(env, &alternate);
+    let registry = ProgramEscrowContract::get_eligibility_registry(env.clone());
+    ProgramEscrowContract::assert_recipient_eligible(env, &registry, &alternate);
+    alternate
+}
+
+/// Running total debited from `remaining_balance` by payouts.
+///
+/// Programs created before the total was tracked have no entry; on first read
+/// it is backfilled from the payout history plus collected fees and stored.
+fn load_total_paid(env: &Env) -> i128 {
+    if let Some(total) = env.storage().instance().get(&TOTAL_PAID) {
+        return total;
+    }
+    let mut total: i128 = env.storage().instance().get(&TOTAL_FEES).unwrap_or(0);
+    if let Some(program_data) = load_program_data(env) {
+        for record in program_data.payout_history.iter() {
+            total += record.amount;
+        }
+    }
+    env.storage().instance().set(&TOTAL_PAID, &total);
+    total
+}
+
+/// Adjust the running total debited from `remaining_balance` by payouts,
+/// including funds moved into held, claimable or retry reserves.
+pub(crate) fn adjust_total_paid(env: &Env, delta: i128) {
+    let total = load_total_paid(env);
+    env.storage().instance().set(&TOTAL_PAID, &(total + delta));
+}
+
+/// Store the schedule release history, keeping only the most recent
+/// `MAX_RELEASE_HISTORY` entries.
+fn save_release_history(env: &Env, mut history: Vec<ProgramReleaseHistory>) {
+    while history.len() > MAX_RELEASE_HISTORY {
+        history.pop_front();
+    }
+    env.storage().instance().set(&RELEASE_HISTORY, &history);
+}
+
+/// Contract balance before a payout, if the token is flagged as
+/// fee-on-transfer and the payout's balance delta must be measured.
+fn payout_balance_before(env: &Env, token_client: &token::Client) -> Option<i128> {
+    let flagged: bool = env
+        .storage()
+        .instance()
+        .get(&FEE_ON_TRANSFER)
+        .unwrap_or(false);
+    flagged.then(|| token_client.balance(&env.current_contract_address()))
+}
+
+/// Amount the contract balance dropped beyond the `sent` total since
+/// `balance_before`, i.e. what a fee-on-transfer token charged the sender.
+fn transfer_overhead(
+    env: &Env,
+    token_client: &token::Client,
+    balance_before: Option<i128>,
+    sent: i128,
+) -> i128 {
+    match balance_before {
+        Some(before) => {
+            let spent = before - token_client.balance(&env.current_contract_address());
+            (spent - sent).max(0)
+        }
+        None => 0,
+    }
+}
+
+/// Transfer `amount` owed to `recipient` out of the contract.
+///
+/// Every payout path ends here, so the blocklist is enforced for the
+/// recipient at delivery time: a recipient blocked after a schedule, claim,
+/// hold or retry was created is not paid. A recipient registered as a team is paid as one transfer per member
+/// according to their shares, with rounding dust going to the first member.
+/// Every member must pass the blocklist and eligibility checks and is paid at
+/// their own payout destination. In `lenient` mode a member transfer that
+/// fails is queued as a retry for that member's share, so the rest of the
+/// team is still paid; otherwise a `lenient` failed transfer returns `None`.
+///
+/// # Returns
+/// The amount actually delivered, excluding team shares queued for retry
+pub(crate) fn deliver_payout(
+    env: &Env,
+    token_client: &token::Client,
+    recipient: &Address,
+    amount: i128,
+    lenient: bool,
+) -> Option<i128> {
+    ProgramEscrowContract::assert_recipient_not_blocked(env, recipient);
+    let contract_address = env.current_contract_address();
+    if let Some(team) = load_team(env, recipient) {
+        let (mut shares, dust) =
+            math::split_bps(env, amount, &team.shares_bps).unwrap_or_else(|e| common_panic(e));
+        // Rounding dust goes to the first member
+        shares.set(0, shares.get(0).unwrap() + dust);
+
+        let registry = ProgramEscrowContract::get_eligibility_registry(env.clone());
+        let mut destinations = Vec::new(env);
+        for member in team.members.iter() {
+            ProgramEscrowContract::assert_recipient_not_blocked(env, &member);
+            ProgramEscrowContract::assert_recipient_eligible(env, &registry, &member);
+            destinations.push_back(payout_destination(env, &member));
+        }
+
+        let payouts = team
+            .members
+            .iter()
+            .zip(destinations.iter())
+            .zip(shares.iter());
+        let mut delivered = 0;
+        for ((member, destination), share) in payouts {
+            if share <= 0 {
+                continue;
+            }
+            if !lenient {
+                token_client.transfer(&contract_address, &destination, &share);
+            } else if !matches!(
+                token_client.try_transfer(&contract_address, &destination, &share),
+                Ok(Ok(()))
+            ) {
+                // Limits were charged to the team entry, so the retry has no gross amount
+                ProgramEscrowContract::queue_retry(env, &member, 0, share, 0, share);
+                continue;
+            }
+            delivered += share;
+        }
+        return Some(delivered);
+    }
+
+    let destination = payout_destination(env, recipient);
+    if lenient {
+        matches!(
+            token_client.try_transfer(&contract_address, &destination, &amount),
+            Ok(Ok(()))
+        )
+        .then_some(amount)
+    } else {
+        token_client.transfer(&contract_address, &destination, &amount);
+        Some(amount)
+    }
+}
+
+fn load_team(env: &Env, recipient: &Address) -> Option<Team> {
+    if !env.storage().instance().has(&WinnerKey::TeamsSet) {
+        return None;
+    }
+    env.storage()
+        .persistent()
+        .get(&WinnerKey::Team(recipient.clone()))
+}
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -297,6 +628,9 @@ pub struct PayoutRecord {
     pub recipient: Address,
     pub amount: i128,
     pub timestamp: u64,
+    /// Caller-supplied 32-byte reference (e.g. submission or bounty hash).
+    /// Held as `Bytes` since `contracttype` cannot encode `Option<BytesN<N>>` in SDK 21.
+    pub memo: Option<Bytes>,
 }
 
 #[contracttype]
@@ -306,6 +640,30 @@ pub struct FeeConfig {
     pub payout_fee_rate: i128,
     pub fee_recipient: Address,
     pub fee_enabled: bool,
+    /// If true the fee is charged on top of the payout amount; otherwise it is
+    /// deducted from the amount the recipient receives.
+    pub fee_on_top: bool,
+}
+
+/// Entry in the platform fee ledger, one per fee-bearing payout.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeRecord {
+    pub payee: Address,
+    pub payout_amount: i128,
+    pub fee_amount: i128,
+    pub fee_recipient: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeConfigUpdatedEvent {
+    pub version: u32,
+    pub payout_fee_rate: i128,
+    pub fee_recipient: Address,
+    pub fee_enabled: bool,
+    pub fee_on_top: bool,
 }
 
 #[contracttype]
@@ -325,6 +683,7 @@ pub struct FundsLockedEvent {
     pub program_id: String,
     pub amount: i128,
     pub remaining_balance: i128,
+    pub sponsor: Option<Address>,
 }
 
 #[contracttype]
@@ -334,7 +693,9 @@ pub struct BatchPayoutEvent {
     pub program_id: String,
     pub recipient_count: u32,
     pub total_amount: i128,
+    pub total_fee: i128,
     pub remaining_balance: i128,
+    pub memos: Vec<BytesN<32>>,
 }
 
 #[contracttype]
@@ -344,7 +705,9 @@ pub struct PayoutEvent {
     pub program_id: String,
     pub recipient: Address,
     pub amount: i128,
+    pub fee: i128,
     pub remaining_balance: i128,
+    pub memo: Option<Bytes>,
 }
 
 #[contracttype]
@@ -359,6 +722,22 @@ pub struct ProgramData {
     pub initial_liquidity: i128, // Initial liquidity provided by creator
 }
 
+/// Descriptive program details for explorers and frontends.
+///
+/// Stored under `DataKey::ProgramMetadata` rather than on ProgramData:
+/// soroban-sdk 21 cannot convert an `Option` of a custom struct field.
+/// `url_hash` uses `Bytes` (holding 32 bytes) for the same reason as
+/// `PayoutRecord::memo`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramMetadata {
+    pub display_name: String,
+    pub organizer: Address,
+    pub url_hash: Option<Bytes>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+}
+
 /// Storage key type for individual programs
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -374,6 +753,418 @@ pub enum DataKey {
     ClaimWindow,                     // u64 seconds (global config)
     PauseFlags,                      // PauseFlags struct
     RateLimitConfig,                 // RateLimitConfig struct
+    Organizer,                       // Address that created the program
+    ProgramStatus,                   // ProgramStatus enum
+    ProcessedPayout(BytesN<32>),     // payout_id -> processed timestamp
+    PayoutLimits,                    // PayoutLimits struct
+    RecipientPaid(Address),          // recipient -> cumulative amount paid
+    LargePayoutThreshold,            // i128 amount above which organizer approval is needed
+    LargePayoutApproval(BytesN<32>), // payout_id -> hash of the payout the organizer approved
+    Sponsors,                        // Vec<SponsorContribution>
+    EligibilityRegistry,             // Address of the recipient eligibility registry
+    ClawbackWindow,                  // u64 seconds payouts are held before release
+    HeldPayout(BytesN<32>),          // payout_id -> HeldPayout
+    HeldTotal,                       // i128 total of all held payouts
+    ChallengePeriod,                 // u64 seconds a proposed distribution stays contestable
+    ProposedDistribution,            // ProposedDistribution awaiting finalization
+    Version,                         // u32 contract version
+    PreviousVersion,                 // u32 version before the last upgrade
+    ProgramMetadata,                 // ProgramMetadata set at init
+    BlockedRecipient(Address),       // recipient -> blocked by organizer
+    PayoutSigner(Address),           // signer -> PayoutSigner
+    PrizeTiers,                      // Vec<i128> prize amount per rank (index 0 = 1st place)
+    Judges,                          // Vec<Address> registered judges
+    Submissions,                     // Vec<Submission> in registration order
+    JudgeVote(Address),              // judge -> Vec<BytesN<32>> ranked submission hashes
+    WinningSet,                      // Vec<Address> winners payouts are constrained to
+    Shortlisted(Address),            // participant -> shortlisted for a prize
+    WinnerConfirmDeadline,           // u64 timestamp by which shortlisted winners confirm
+    WinnerAddress(Address),          // participant -> confirmed payout address
+    ConfirmedAddress(Address),       // payout address -> participant that confirmed it
+    SwapRouter,                      // Address of the DEX router adapter
+    ActiveSeason,                    // u32 number of the season payouts are debited from
+    LatestSeason,                    // u32 number of the most recently opened season
+    Season(u32),                     // season number -> SeasonStats
+    SeasonHistory(u32),              // season number -> Vec<PayoutRecord>
+    FrozenRecipient(Address),        // recipient -> pending claims/schedules frozen
+    PendingRetry(u64),               // retry_id -> PendingRetry
+    NextRetryId,                     // u64 next retry_id
+    RetryTotal,                      // i128 total reserved for pending retries
+    DustPolicy,                      // DustPolicy for rounding residue of splits
+}
+
+/// Storage keys for prize tracks, kept apart from `DataKey`, which is at the
+/// 50-variant limit for contract types.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TrackKey {
+    Names,           // Vec<String> names of all tracks
+    Track(String),   // track name -> Track
+    History(String), // track name -> Vec<PayoutRecord>
+    EarmarkReserved, // i128 earmarked sponsor funds not yet spent by their tracks
+    BudgetReserved,  // i128 track budgets not yet paid out, earmarks included
+}
+
+/// Storage keys for per-winner payout settings.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum WinnerKey {
+    AlternatesSet,          // bool, set once any winner designates an alternate address
+    PayoutAddress(Address), // winner -> alternate address their payouts are sent to
+    TeamsSet,               // bool, set once any team is registered
+    Team(Address),          // team entry -> Team members and shares
+}
+
+/// Storage keys for payout-key replay protection and signed permits.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum NonceKey {
+    Required,  // bool, payout-key payouts must carry a nonce
+    Next,      // u64 next nonce the payout key must use
+    PermitKey, // BytesN<32> ed25519 public key that signs payout permits
+}
+
+/// Storage keys for outflow limits, kept apart from the full `DataKey`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LimitKey {
+    MaxBatchSize,                // u32 maximum token transfers per payout batch
+    EpochLimit,                  // EpochLimit cumulative payout cap per epoch
+    EpochOutflow,                // EpochOutflow paid out in the latest epoch
+    OverCapApproval(BytesN<32>), // payout_id -> approved to exceed the epoch cap
+    Tripwire,                    // OutflowTripwire automatic pause configuration
+    TripwireWindow,              // OutflowWindow being measured by the tripwire
+    TripwirePaused,              // bool: the release pause was set by the tripwire
+}
+
+/// Storage keys for the fee ledger, kept apart from the full `DataKey`.
+///
+/// Entries live in persistent storage, one per fee-bearing payout; only the
+/// entry count is kept in instance storage.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FeeKey {
+    Count,       // u64 number of fee ledger entries
+    Record(u64), // index -> FeeRecord
+}
+
+/// Automatically pause payouts when outflow within a window exceeds
+/// `max_bps` of the balance remaining at the window's start.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OutflowTripwire {
+    pub max_bps: u32,
+    /// Window length in seconds.
+    pub window: u64,
+}
+
+/// Outflow measured by the tripwire since `started_at`.
+///
+/// The previous window's outflow still counts, weighted by how much of it
+/// overlaps the trailing `window` seconds, so spending up to the limit on
+/// both sides of a window boundary does not double the limit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OutflowWindow {
+    pub started_at: u64,
+    /// Remaining balance when the window started.
+    pub baseline: i128,
+    pub paid: i128,
+    /// Outflow of the window immediately before this one.
+    pub previous_paid: i128,
+}
+
+/// Cumulative payout cap per fixed-length epoch.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EpochLimit {
+    /// Maximum total debited from the pool per epoch, including fees.
+    pub cap: i128,
+    /// Epoch length in seconds; epochs start at multiples of this duration.
+    pub duration: u64,
+}
+
+/// Amount debited from the pool during one epoch.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EpochOutflow {
+    pub epoch: u64,
+    pub paid: i128,
+}
+
+/// A single payout authorized off-chain by the permit key.
+///
+/// The permit key signs the network id followed by the XDR encoding of this
+/// struct; anyone may submit it with the signature to `execute_signed_payout`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutPermit {
+    /// Escrow contract the permit is for, so it cannot be replayed against
+    /// another deployment sharing the permit key.
+    pub contract: Address,
+    pub program_id: String,
+    pub recipient: Address,
+    pub amount: i128,
+    /// Must equal `get_payout_nonce`; shared with the `_with_nonce` entrypoints.
+    pub nonce: u64,
+    pub expires_at: u64,
+}
+
+/// An additional payout signer registered by the organizer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutSigner {
+    pub signer: Address,
+    /// Maximum cumulative amount (including fees) this signer may pay out.
+    pub spending_cap: i128,
+    pub spent: i128,
+    pub expires_at: u64,
+}
+
+/// A submission entered for judging, identified by its content hash.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Submission {
+    pub hash: BytesN<32>,
+    pub submitter: Address,
+}
+
+/// Budget and payout totals for one numbered season of a recurring program.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeasonStats {
+    pub season: u32,
+    pub budget: i128,
+    /// Total debited from the budget, including fees.
+    pub paid_out: i128,
+    pub payout_count: u32,
+    pub opened_at: u64,
+    pub closed_at: Option<u64>,
+}
+
+/// A lenient batch transfer that failed and is kept reserved for `retry_payout`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingRetry {
+    pub retry_id: u64,
+    pub recipient: Address,
+    /// Amount requested in the original batch.
+    pub amount: i128,
+    pub net: i128,
+    pub fee: i128,
+    /// Amount reserved from the pool (net plus any on-top fee).
+    pub debit: i128,
+    pub created_at: u64,
+}
+
+/// A named prize pool (e.g. "DeFi") with its own budget inside the program.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Track {
+    pub name: String,
+    pub budget: i128,
+    /// Total debited from the budget, including fees.
+    pub paid_out: i128,
+    pub payout_count: u32,
+    /// Key allowed to pay out from this track; the program payout key if `None`.
+    pub payout_key: Option<Address>,
+    /// Sponsor funds locked for this track only; included in `budget`.
+    pub earmarked: i128,
+}
+
+/// Members of a team entry and their payout shares in basis points.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Team {
+    pub members: Vec<Address>,
+    pub shares_bps: Vec<u32>,
+}
+
+/// Who authorizes a payout made through the shared payout paths.
+enum PayoutAuth {
+    /// The program's authorized payout key.
+    PayoutKey,
+    /// The payout key, consuming the given replay-protection nonce.
+    Nonced(u64),
+    /// A permit signed off-chain by the permit key.
+    Permit(PayoutPermit, BytesN<64>),
+    /// A delegated payout signer or session key.
+    Signer(Address),
+    /// The payout key of a track, whose budget is debited.
+    Track(String),
+    /// The payout key, finalizing a proposed distribution.
+    Distribution,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DirectPayoutsSetEvent {
+    pub version: u32,
+    pub enabled: bool,
+    pub organizer: Address,
+}
+
+/// A published distribution that can be amended until `finalize_after`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposedDistribution {
+    pub recipients: Vec<Address>,
+    pub amounts: Vec<i128>,
+    pub total_amount: i128,
+    pub proposed_at: u64,
+    pub finalize_after: u64,
+}
+
+/// A payout held during the clawback window.
+///
+/// The pool has already been debited; `records` are moved into the payout
+/// history once the hold is released.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HeldPayout {
+    pub payout_id: BytesN<32>,
+    pub records: Vec<PayoutRecord>,
+    pub total_amount: i128,
+    pub release_at: u64,
+}
+
+/// Interface expected from an external KYC / eligibility registry contract.
+#[contractclient(name = "EligibilityRegistryClient")]
+pub trait EligibilityRegistry {
+    fn is_eligible(env: Env, account: Address) -> bool;
+}
+
+/// Interface expected from a DEX router adapter (e.g. wrapping Soroswap).
+///
+/// The escrow transfers `amount_in` of `token_in` to the adapter before calling
+/// `swap`; the adapter must deliver `token_out` to `to` and return the amount sent.
+#[contractclient(name = "SwapRouterClient")]
+pub trait SwapRouter {
+    fn swap(
+        env: Env,
+        token_in: Address,
+        token_out: Address,
+        amount_in: i128,
+        min_out: i128,
+        to: Address,
+    ) -> i128;
+}
+
+/// A sponsor's total funding through `lock_program_funds_from`, stamped with
+/// the time of its latest contribution.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SponsorContribution {
+    pub sponsor: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+/// Organizer-configured payout caps. `None` means unlimited.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutLimits {
+    pub max_per_payout: Option<i128>,
+    pub max_per_recipient: Option<i128>,
+}
+
+/// Where the rounding residue of a split distribution goes.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DustPolicy {
+    /// Added to the first recipient's amount (default).
+    FirstRecipient,
+    /// Sent to the configured fee recipient; residues below
+    /// `MIN_DUST_TRANSFER` are left in the pool instead.
+    FeeRecipient,
+    /// Left in the program's remaining balance.
+    LeaveInPool,
+}
+
+/// Lifecycle status of the program.
+///
+/// `Setup` -> `Funded` on the first lock, -> `Active` when the organizer opens
+/// the program, -> `PayoutPhase` on the first payout (or explicitly), ->
+/// `Closed` once the organizer closes it. `Cancelled` is reachable before any
+/// payout, and a closed or fully paid-out program can be `Archived`. See
+/// [`status_transition_allowed`] for the full table.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProgramStatus {
+    Setup,
+    Funded,
+    Active,
+    PayoutPhase,
+    Closed,
+    Cancelled,
+    Archived,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramStatusChangedEvent {
+    pub version: u32,
+    pub program_id: String,
+    pub previous: ProgramStatus,
+    pub status: ProgramStatus,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramClosedEvent {
+    pub version: u32,
+    pub program_id: String,
+    pub organizer: Address,
+    pub refunded_amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramCancelledEvent {
+    pub version: u32,
+    pub program_id: String,
+    pub organizer: Address,
+    pub refunded_amount: i128,
+}
+
+/// Result of comparing the recorded balance with the contract's token holdings.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReconciliationReport {
+    pub token_balance: i128,
+    pub recorded_balance: i128,
+    /// `token_balance - recorded_balance`; negative means the escrow is underfunded.
+    pub discrepancy: i128,
+    pub timestamp: u64,
+}
+
+/// Result of checking the program's accounting and solvency invariants.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvariantReport {
+    pub total_funds: i128,
+    pub total_paid: i128,
+    pub total_refunded: i128,
+    pub remaining_balance: i128,
+    /// `total_funds - total_paid - total_refunded`
+    pub expected_remaining: i128,
+    /// `remaining_balance` plus funds held, claimable or queued for retry.
+    pub obligations: i128,
+    pub token_balance: i128,
+    /// `remaining_balance == expected_remaining`
+    pub accounting_ok: bool,
+    /// `token_balance >= obligations`
+    pub solvent: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BalanceMismatchEvent {
+    pub version: u32,
+    pub program_id: String,
+    pub token_balance: i128,
+    pub recorded_balance: i128,
+    pub discrepancy: i128,
 }
 
 #[contracttype]
@@ -456,6 +1247,64 @@ pub struct ProgramAggregateStats {
     pub released_count: u32,
 }
 
+/// Compact program overview for indexers; omits the payout history.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramSummary {
+    pub program_id: String,
+    pub status: ProgramStatus,
+    pub token_address: Address,
+    pub total_funds: i128,
+    pub remaining_balance: i128,
+    /// Delivered by payouts, excluding held, claimable and retry amounts.
+    pub total_paid_out: i128,
+    pub total_fees_collected: i128,
+    pub held_total: i128,
+    pub claimable_total: i128,
+    pub retry_total: i128,
+    pub payout_count: u32,
+    pub organizer: Address,
+    pub authorized_payout_key: Address,
+    pub active_season: Option<u32>,
+}
+
+/// Per-token accounting report for finance reconciliation.
+///
+/// Only the program token has locked, paid out, refunded and fee totals;
+/// any other token reports just the balance the contract holds.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenAccounting {
+    pub token: Address,
+    pub locked: i128,
+    /// Delivered by payouts, including fees.
+    pub paid_out: i128,
+    /// Debited from the pool but held for clawback.
+    pub held: i128,
+    /// Debited from the pool and reserved for pending claims.
+    pub claimable: i128,
+    /// Debited from the pool and queued for a transfer retry.
+    pub pending_retry: i128,
+    pub refunded: i128,
+    pub fees_collected: i128,
+    pub remaining_balance: i128,
+    /// Actual token balance held by the contract.
+    pub balance: i128,
+}
+
+/// Estimated cost of paying `batch_size` recipients, to guide chunking.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchCostEstimate {
+    pub batch_size: u32,
+    /// `None` when batches are unlimited.
+    pub max_batch_size: Option<u32>,
+    /// Number of batches needed to stay within `max_batch_size`.
+    pub chunks: u32,
+    /// Token transfers performed, including one fee transfer per chunk.
+    pub transfers: u32,
+}
+
 /// Input item for batch program registration.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -518,19 +1367,88 @@ impl ProgramEscrowContract {
         creator: Address,
         initial_liquidity: Option<i128>,
     ) -> ProgramData {
-        Self::initialize_program(env, program_id, authorized_payout_key, token_address, creator, initial_liquidity)
+        Self::initialize_program(
+            env,
+            program_id,
+            authorized_payout_key,
+            token_address,
+            creator,
+            initial_liquidity,
+        )
     }
 
-    pub fn initialize_program(
-        env: Env,
+    /// Initialize a new program escrow with descriptive metadata.
+    ///
+    /// Same as `init_program`; `metadata` is exposed through `get_program_metadata`.
+    ///
+    /// # Panics
+    /// * If `end_time` is before `start_time`
+    pub fn init_program_with_metadata(
+        env: Env,
+        program_id: String,
+        authorized_payout_key: Address,
+        token_address: Address,
+        creator: Address,
+        initial_liquidity: Option<i128>,
+        metadata: ProgramMetadata,
+    ) -> ProgramData {
+        if let (Some(start), Some(end)) = (metadata.start_time, metadata.end_time) {
+            if end < start {
+                panic!("Invalid program dates");
+            }
+        }
+        Self::init_program_internal(
+            env,
+            program_id,
+            authorized_payout_key,
+            token_address,
+            creator,
+            initial_liquidity,
+            Some(metadata),
+        )
+    }
+
+    pub fn initialize_program(
+        env: Env,
+        program_id: String,
+        authorized_payout_key: Address,
+        token_address: Address,
+        creator: Address,
+        initial_liquidity: Option<i128>,
+    ) -> ProgramData {
+        Self::init_program_internal(
+            env,
+            program_id,
+            authorized_payout_key,
+            token_address,
+            creator,
+            initial_liquidity,
+            None,
+        )
+    }
+
+    /// Returns the metadata set at init.
+    ///
+    /// # Panics
+    /// * If the program was initialized without metadata
+    pub fn get_program_metadata(env: Env) -> ProgramMetadata {
+        env.storage()
+            .instance()
+            .get(&DataKey::ProgramMetadata)
+            .unwrap_or_else(|| panic!("Program metadata not set"))
+    }
+
+    fn init_program_internal(
+        env: Env,
         program_id: String,
         authorized_payout_key: Address,
         token_address: Address,
         creator: Address,
         initial_liquidity: Option<i128>,
+        metadata: Option<ProgramMetadata>,
     ) -> ProgramData {
         // Check if program already exists
-        if env.storage().instance().has(&PROGRAM_DATA) {
+        if load_program_data(&env).is_some() {
             panic!("Program already initialized");
         }
 
@@ -541,13 +1459,12 @@ impl ProgramEscrowContract {
         if let Some(amount) = initial_liquidity {
             if amount > 0 {
                 // Transfer initial liquidity from creator to contract
-                let contract_address = env.current_contract_address();
                 let token_client = token::Client::new(&env, &token_address);
                 creator.require_auth();
-                token_client.transfer(&creator, &contract_address, &amount);
-                total_funds = amount;
-                remaining_balance = amount;
-                init_liquidity = amount;
+                let received = Self::pull_tokens(&env, &token_client, &creator, amount);
+                total_funds = received;
+                remaining_balance = received;
+                init_liquidity = received;
             }
         }
 
@@ -562,7 +1479,22 @@ impl ProgramEscrowContract {
         };
 
         // Store program data
-        env.storage().instance().set(&PROGRAM_DATA, &program_data);
+        save_program_data(&env, &program_data);
+        env.storage().instance().set(&DataKey::Organizer, &creator);
+        if let Some(metadata) = metadata {
+            env.storage()
+                .instance()
+                .set(&DataKey::ProgramMetadata, &metadata);
+        }
+        let status = if total_funds > 0 {
+            ProgramStatus::Funded
+        } else {
+            ProgramStatus::Setup
+        };
+        env.storage()
+            .instance()
+            .set(&DataKey::ProgramStatus, &status);
+        env.storage().instance().set(&TOTAL_PAID, &0i128);
         env.storage()
             .instance()
             .set(&SCHEDULES, &Vec::<ProgramReleaseSchedule>::new(&env));
@@ -573,7 +1505,7 @@ impl ProgramEscrowContract {
 
         // Emit ProgramInitialized event
         env.events().publish(
-            (PROGRAM_INITIALIZED,),
+            (PROGRAM_INITIALIZED, program_id.clone()),
             ProgramInitializedEvent {
                 version: EVENT_VERSION_V2,
                 program_id,
@@ -648,6 +1580,7 @@ impl ProgramEscrowContract {
                     payout_fee_rate: 0,
                     fee_recipient: authorized_payout_key.clone(),
                     fee_enabled: false,
+                    fee_on_top: false,
                 };
                 env.storage().instance().set(&FEE_CONFIG, &fee_config);
             }
@@ -661,6 +1594,7 @@ impl ProgramEscrowContract {
                 &DataKey::MultisigConfig(program_id.clone()),
                 &multisig_config,
             );
+            storage::extend_persistent(&env, &DataKey::MultisigConfig(program_id.clone()));
 
             registry.push_back(program_id.clone());
             env.events().publish(
@@ -675,14 +1609,7 @@ impl ProgramEscrowContract {
 
     /// Calculate fee amount based on rate (in basis points)
     fn calculate_fee(amount: i128, fee_rate: i128) -> i128 {
-        if fee_rate == 0 {
-            return 0;
-        }
-        // Fee = (amount * fee_rate) / BASIS_POINTS
-        amount
-            .checked_mul(fee_rate)
-            .and_then(|x| x.checked_div(BASIS_POINTS))
-            .unwrap_or(0)
+        math::fee_of(amount, fee_rate)
     }
 
     /// Get fee configuration (internal helper)
@@ -695,14 +1622,171 @@ impl ProgramEscrowContract {
                 payout_fee_rate: 0,
                 fee_recipient: env.current_contract_address(),
                 fee_enabled: false,
+                fee_on_top: false,
             })
     }
+
+    /// Split a payout amount into (amount to recipient, fee, total debited from the pool).
+    fn split_payout_fee(fee_config: &FeeConfig, amount: i128) -> (i128, i128, i128) {
+        if !fee_config.fee_enabled {
+            return (amount, 0, amount);
+        }
+        let fee = Self::calculate_fee(amount, fee_config.payout_fee_rate);
+        if fee_config.fee_on_top {
+            (amount, fee, amount + fee)
+        } else {
+            (amount - fee, fee, amount)
+        }
+    }
+
+    /// Append a fee ledger entry and bump the running fee total.
+    fn record_fee(
+        env: &Env,
+        fee_config: &FeeConfig,
+        payee: &Address,
+        payout_amount: i128,
+        fee: i128,
+    ) {
+        if fee == 0 {
+            return;
+        }
+        Self::migrate_fee_ledger(env);
+        Self::push_fee_record(
+            env,
+            FeeRecord {
+                payee: payee.clone(),
+                payout_amount,
+                fee_amount: fee,
+                fee_recipient: fee_config.fee_recipient.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        let total: i128 = env.storage().instance().get(&TOTAL_FEES).unwrap_or(0);
+        env.storage().instance().set(&TOTAL_FEES, &(total + fee));
+    }
+
+    fn push_fee_record(env: &Env, record: FeeRecord) {
+        let index: u64 = env.storage().instance().get(&FeeKey::Count).unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&FeeKey::Record(index), &record);
+        storage::extend_persistent(env, &FeeKey::Record(index));
+        env.storage().instance().set(&FeeKey::Count, &(index + 1));
+    }
+
+    /// Move a fee ledger kept as one instance `Vec` by earlier versions into
+    /// per-entry persistent storage.
+    fn migrate_fee_ledger(env: &Env) {
+        let legacy: Option<Vec<FeeRecord>> = env.storage().instance().get(&FEE_LEDGER);
+        if let Some(legacy) = legacy {
+            env.storage().instance().remove(&FEE_LEDGER);
+            for record in legacy.iter() {
+                Self::push_fee_record(env, record);
+            }
+        }
+    }
+
+    /// Update the platform payout fee configuration (admin only).
+    ///
+    /// # Arguments
+    /// * `payout_fee_rate` - Fee in basis points (max 5000)
+    /// * `fee_recipient`   - Address receiving collected fees
+    /// * `fee_on_top`      - Charge fee on top of the payout instead of deducting it
+    /// * `fee_enabled`     - Master switch for payout fees
+    pub fn update_fee_config(
+        env: Env,
+        payout_fee_rate: Option<i128>,
+        fee_recipient: Option<Address>,
+        fee_on_top: Option<bool>,
+        fee_enabled: Option<bool>,
+    ) -> FeeConfig {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("Not initialized"));
+        admin.require_auth();
+
+        let mut fee_config = Self::get_fee_config_internal(&env);
+
+        if let Some(rate) = payout_fee_rate {
+            if !(0..=MAX_FEE_RATE).contains(&rate) {
+                panic!("Invalid fee rate");
+            }
+            fee_config.payout_fee_rate = rate;
+        }
+        if let Some(recipient) = fee_recipient {
+            fee_config.fee_recipient = recipient;
+        }
+        if let Some(on_top) = fee_on_top {
+            fee_config.fee_on_top = on_top;
+        }
+        if let Some(enabled) = fee_enabled {
+            fee_config.fee_enabled = enabled;
+        }
+
+        env.storage().instance().set(&FEE_CONFIG, &fee_config);
+
+        env.events().publish(
+            (FEE_CONFIG,),
+            FeeConfigUpdatedEvent {
+                version: EVENT_VERSION_V2,
+                payout_fee_rate: fee_config.payout_fee_rate,
+                fee_recipient: fee_config.fee_recipient.clone(),
+                fee_enabled: fee_config.fee_enabled,
+                fee_on_top: fee_config.fee_on_top,
+            },
+        );
+
+        fee_config
+    }
+
+    /// Get current fee configuration
+    pub fn get_fee_config(env: Env) -> FeeConfig {
+        Self::get_fee_config_internal(&env)
+    }
+
+    /// Get a page of the platform fee ledger (one entry per fee-bearing
+    /// payout), oldest first.
+    pub fn get_fee_ledger(env: Env, offset: u32, limit: u32) -> Vec<FeeRecord> {
+        let mut results = Vec::new(&env);
+        let legacy: Option<Vec<FeeRecord>> = env.storage().instance().get(&FEE_LEDGER);
+        if let Some(legacy) = legacy {
+            for record in legacy.iter().skip(offset as usize).take(limit as usize) {
+                results.push_back(record);
+            }
+            return results;
+        }
+        let count: u64 = env.storage().instance().get(&FeeKey::Count).unwrap_or(0);
+        let end = count.min(offset as u64 + limit as u64);
+        for index in offset as u64..end {
+            if let Some(record) = env.storage().persistent().get(&FeeKey::Record(index)) {
+                results.push_back(record);
+            }
+        }
+        results
+    }
+
+    /// Number of entries in the platform fee ledger
+    pub fn get_fee_ledger_count(env: Env) -> u64 {
+        let legacy: Option<Vec<FeeRecord>> = env.storage().instance().get(&FEE_LEDGER);
+        match legacy {
+            Some(legacy) => legacy.len() as u64,
+            None => env.storage().instance().get(&FeeKey::Count).unwrap_or(0),
+        }
+    }
+
+    /// Get total platform fees collected from payouts
+    pub fn get_total_fees_collected(env: Env) -> i128 {
+        env.storage().instance().get(&TOTAL_FEES).unwrap_or(0)
+    }
     /// Check if a program exists (legacy single-program check)
     ///
     /// # Returns
     /// * `bool` - True if program exists, false otherwise
     pub fn program_exists(env: Env) -> bool {
-        env.storage().instance().has(&PROGRAM_DATA)
+        load_program_data(&env).is_some()
             || env.storage().instance().has(&PROGRAM_REGISTRY)
     }
 
@@ -717,12 +1801,18 @@ impl ProgramEscrowContract {
 
     /// Lock initial funds into the program escrow
     ///
+    /// The tokens must already have been transferred to the contract; prefer
+    /// `fund_program`, which pulls them in the same call. The escrow must hold
+    /// `amount` beyond what it already accounts for. Only for a token flagged
+    /// with `set_fee_on_transfer` is a short deposit recorded at what arrived.
+    ///
     /// # Arguments
     /// * `amount` - Amount of funds to lock (in native token units)
     ///
     /// # Returns
     /// Updated ProgramData with locked funds
     pub fn lock_program_funds(env: Env, amount: i128) -> ProgramData {
+        Self::assert_program_active(&env);
         if Self::check_paused(&env, symbol_short!("lock")) {
             panic!("Funds Paused");
         }
@@ -731,10 +1821,139 @@ impl ProgramEscrowContract {
             panic!("Amount must be greater than zero");
         }
 
-        let mut program_data: ProgramData = env
-            .storage()
+        let program_data: ProgramData =
+            load_program_data(&env).unwrap_or_else(|| panic!("Program not initialized"));
+        let token_balance = token::Client::new(&env, &program_data.token_address)
+            .balance(&env.current_contract_address());
+        let accounted = program_data.remaining_balance
+            + claim_period::get_reserved_total(&env)
+            + Self::get_held_total(env.clone())
+            + Self::get_retry_total(env.clone());
+        let available = token_balance - accounted;
+        if available <= 0 {
+            panic!("No funds received");
+        }
+        if available < amount && !Self::is_fee_on_transfer(env.clone()) {
+            panic!("Insufficient funds received");
+        }
+
+        Self::record_locked_funds(&env, amount.min(available), None)
+    }
+
+    /// Lock funds contributed by `sponsor` and record the contribution.
+    ///
+    /// Unlike `lock_program_funds`, the tokens are transferred from the sponsor
+    /// into the escrow, so each recorded contribution is backed on-chain. The
+    /// amount recorded is what actually arrived, which is less than `amount`
+    /// for fee-on-transfer tokens. Repeat contributions add to the sponsor's
+    /// existing entry; at most `MAX_SPONSORS` sponsors are recorded.
+    ///
+    /// # Arguments
+    /// * `sponsor` - Funding address; must authorize the transfer
+    /// * `amount`  - Amount to transfer and lock
+    pub fn lock_program_funds_from(env: Env, sponsor: Address, amount: i128) -> ProgramData {
+        Self::assert_program_active(&env);
+        if Self::check_paused(&env, symbol_short!("lock")) {
+            panic!("Funds Paused");
+        }
+
+        if amount <= 0 {
+            panic!("Amount must be greater than zero");
+        }
+
+        sponsor.require_auth();
+
+        let program_data: ProgramData =
+            load_program_data(&env).unwrap_or_else(|| panic!("Program not initialized"));
+        let token_client = token::Client::new(&env, &program_data.token_address);
+        let received = Self::pull_tokens(&env, &token_client, &sponsor, amount);
+
+        // One entry per sponsor; repeat contributions are added to it
+        let mut sponsors = Self::get_sponsors(env.clone());
+        let timestamp = env.ledger().timestamp();
+        match sponsors.iter().position(|c| c.sponsor == sponsor) {
+            Some(index) => {
+                let mut contribution = sponsors.get(index as u32).unwrap();
+                contribution.amount += received;
+                contribution.timestamp = timestamp;
+                sponsors.set(index as u32, contribution);
+            }
+            None => {
+                if sponsors.len() >= MAX_SPONSORS {
+                    panic!("Too many sponsors");
+                }
+                sponsors.push_back(SponsorContribution {
+                    sponsor: sponsor.clone(),
+                    amount: received,
+                    timestamp,
+                });
+            }
+        }
+        env.storage().instance().set(&DataKey::Sponsors, &sponsors);
+
+        Self::record_locked_funds(&env, received, Some(sponsor))
+    }
+
+    /// Top up the program by pulling `amount` from `from` in one call.
+    ///
+    /// The funder authorizes once; the escrow transfers the tokens itself and
+    /// records them, so funds can never be recorded before they arrive.
+    ///
+    /// # Arguments
+    /// * `from`   - Funding address; must authorize the transfer
+    /// * `amount` - Amount to transfer and lock
+    pub fn fund_program(env: Env, from: Address, amount: i128) -> ProgramData {
+        Self::lock_program_funds_from(env, from, amount)
+    }
+
+    /// Returns each sponsor's total contribution, in order of first funding.
+    pub fn get_sponsors(env: Env) -> Vec<SponsorContribution> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Sponsors)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Flag the program token as fee-on-transfer (organizer only).
+    ///
+    /// Payouts then measure the escrow's actual balance change and debit any
+    /// transfer fee the token charged the escrow from the pool. The flag is
+    /// also set automatically when a deposit the escrow pulls arrives short.
+    pub fn set_fee_on_transfer(env: Env, enabled: bool) {
+        Self::get_organizer(env.clone()).require_auth();
+
+        env.storage().instance().set(&FEE_ON_TRANSFER, &enabled);
+        env.events().publish((FEE_ON_TRANSFER_SET,), enabled);
+    }
+
+    /// Returns true if payouts measure balance deltas for a fee-on-transfer token.
+    pub fn is_fee_on_transfer(env: Env) -> bool {
+        env.storage()
             .instance()
-            .get(&PROGRAM_DATA)
+            .get(&FEE_ON_TRANSFER)
+            .unwrap_or(false)
+    }
+
+    /// Transfer `amount` from `from` into the contract and return the amount
+    /// that actually arrived, so fee-on-transfer tokens are recorded at their
+    /// effective value rather than the nominal one. A short delivery flags the
+    /// token so payouts measure their balance deltas too.
+    fn pull_tokens(env: &Env, token_client: &token::Client, from: &Address, amount: i128) -> i128 {
+        let contract_address = env.current_contract_address();
+        let before = token_client.balance(&contract_address);
+        token_client.transfer(from, &contract_address, &amount);
+        let received = token_client.balance(&contract_address) - before;
+        if received <= 0 {
+            panic!("No funds received");
+        }
+        if received < amount {
+            env.storage().instance().set(&FEE_ON_TRANSFER, &true);
+        }
+        received
+    }
+
+    fn record_locked_funds(env: &Env, amount: i128, sponsor: Option<Address>) -> ProgramData {
+        let mut program_data: ProgramData = load_program_data(env)
             .unwrap_or_else(|| panic!("Program not initialized"));
 
         // Update balances
@@ -742,16 +1961,20 @@ impl ProgramEscrowContract {
         program_data.remaining_balance += amount;
 
         // Store updated data
-        env.storage().instance().set(&PROGRAM_DATA, &program_data);
+        save_program_data(env, &program_data);
+        if Self::get_program_status(env.clone()) == ProgramStatus::Setup {
+            Self::set_program_status(env, ProgramStatus::Funded);
+        }
 
         // Emit FundsLocked event
         env.events().publish(
-            (FUNDS_LOCKED,),
+            (FUNDS_LOCKED, program_data.program_id.clone()),
             FundsLockedEvent {
                 version: EVENT_VERSION_V2,
                 program_id: program_data.program_id.clone(),
                 amount,
                 remaining_balance: program_data.remaining_balance,
+                sponsor,
             },
         );
 
@@ -759,347 +1982,3701 @@ impl ProgramEscrowContract {
     }
 
     // ========================================================================
-    // Initialization & Admin
+    // Program Cancellation
     // ========================================================================
 
-    /// Initialize the contract with an admin.
-    /// This must be called before any admin protected functions (like pause) can be used.
-    pub fn initialize_contract(env: Env, admin: Address) {
-        if env.storage().instance().has(&DataKey::Admin) {
-            panic!("Already initialized");
+    /// Cancel the program and refund all locked funds to the organizer.
+    ///
+    /// Only allowed before any payouts have been made; entering the payout
+    /// phase explicitly does not count. After cancellation the program rejects
+    /// locks, payouts, schedules and claims; views keep working.
+    ///
+    /// # Returns
+    /// Updated ProgramData with a zero remaining balance
+    ///
+    /// # Panics
+    /// * If the program is not initialized or already cancelled
+    /// * If any payout has already been made, held or reserved for a claim
+    pub fn cancel_program(env: Env) -> ProgramData {
+        Self::assert_program_active(&env);
+
+        let mut program_data: ProgramData =
+            load_program_data(&env).unwrap_or_else(|| panic!("Program not initialized"));
+
+        let organizer = Self::get_organizer(env.clone());
+        organizer.require_auth();
+
+        if !program_data.payout_history.is_empty()
+            || Self::get_held_total(env.clone()) != 0
+            || Self::get_retry_total(env.clone()) != 0
+            || claim_period::get_reserved_total(&env) != 0
+        {
+            panic!("Cannot cancel after payouts");
         }
-        env.storage().instance().set(&DataKey::Admin, &admin);
-    }
 
-    /// Set or rotate admin. If no admin is set, sets initial admin. If admin exists, current admin must authorize and the new address becomes admin.
-    pub fn set_admin(env: Env, admin: Address) {
-        if env.storage().instance().has(&DataKey::Admin) {
-            let current: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-            current.require_auth();
+        let refunded_amount = program_data.remaining_balance;
+        if refunded_amount > 0 {
+            let token_client = token::Client::new(&env, &program_data.token_address);
+            token_client.transfer(
+                &env.current_contract_address(),
+                &organizer,
+                &refunded_amount,
+            );
+            let refunded: i128 = env.storage().instance().get(&TOTAL_REFUNDED).unwrap_or(0);
+            env.storage()
+                .instance()
+                .set(&TOTAL_REFUNDED, &(refunded + refunded_amount));
         }
-        env.storage().instance().set(&DataKey::Admin, &admin);
-    }
 
-    /// Returns the current admin address, if set.
-    pub fn get_admin(env: Env) -> Option<Address> {
-        env.storage().instance().get(&DataKey::Admin)
-    }
+        program_data.remaining_balance = 0;
+        save_program_data(&env, &program_data);
+        Self::set_program_status(&env, ProgramStatus::Cancelled);
 
-    pub fn get_program_release_schedules(env: Env) -> Vec<ProgramReleaseSchedule> {
-    env.storage()
-        .instance()
-        .get(&SCHEDULES)
-        .unwrap_or_else(|| Vec::new(&env))
-}
+        env.events().publish(
+            (PROGRAM_CANCELLED, program_data.program_id.clone()),
+            ProgramCancelledEvent {
+                version: EVENT_VERSION_V2,
+                program_id: program_data.program_id.clone(),
+                organizer,
+                refunded_amount,
+            },
+        );
 
-    /// Update pause flags (admin only)
-    pub fn set_paused(env: Env, lock: Option<bool>, release: Option<bool>, refund: Option<bool>, reason: Option<String>) {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            panic!("Not initialized");
-        }
+        program_data
+    }
 
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+    /// Returns the organizer (program creator) address.
+    pub fn get_organizer(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Organizer)
+            .unwrap_or_else(|| panic!("Program not initialized"))
+    }
 
-        let mut flags = Self::get_pause_flags(&env);
-        let timestamp = env.ledger().timestamp();
+    /// Returns the current program status.
+    pub fn get_program_status(env: Env) -> ProgramStatus {
+        env.storage()
+            .instance()
+            .get(&DataKey::ProgramStatus)
+            .unwrap_or(ProgramStatus::Active)
+    }
 
-        if reason.is_some() {
-            flags.pause_reason = reason.clone();
+    fn set_program_status(env: &Env, status: ProgramStatus) {
+        let previous = Self::get_program_status(env.clone());
+        if !status_transition_allowed(previous, status) {
+            panic!("Invalid status transition");
         }
+        env.storage()
+            .instance()
+            .set(&DataKey::ProgramStatus, &status);
+        let program_id = load_program_data(env)
+            .map(|data| data.program_id)
+            .unwrap_or_else(|| String::from_str(env, ""));
+        env.events().publish(
+            (PROGRAM_STATUS_CHANGED, program_id.clone()),
+            ProgramStatusChangedEvent {
+                version: EVENT_VERSION_V2,
+                program_id,
+                previous,
+                status,
+            },
+        );
+    }
 
-        if let Some(paused) = lock {
-            flags.lock_paused = paused;
-            env.events().publish(
-                (PAUSE_STATE_CHANGED,),
-                (symbol_short!("lock"), paused, admin.clone(), reason.clone(), timestamp),
-            );
+    /// Panics if the program is closed, cancelled or archived.
+    fn assert_program_active(env: &Env) {
+        match Self::get_program_status(env.clone()) {
+            ProgramStatus::Setup
+            | ProgramStatus::Funded
+            | ProgramStatus::Active
+            | ProgramStatus::PayoutPhase => {}
+            ProgramStatus::Closed => panic!("Program closed"),
+            ProgramStatus::Cancelled => panic!("Program cancelled"),
+            ProgramStatus::Archived => panic!("Program archived"),
         }
+    }
 
-        if let Some(paused) = release {
-            flags.release_paused = paused;
-            env.events().publish(
-                (PAUSE_STATE_CHANGED,),
-                (symbol_short!("release"), paused, admin.clone(), reason.clone(), timestamp),
-            );
+    /// Move a funded or active program into its payout phase after a payout.
+    fn enter_payout_phase(env: &Env) {
+        if matches!(
+            Self::get_program_status(env.clone()),
+            ProgramStatus::Funded | ProgramStatus::Active
+        ) {
+            Self::set_program_status(env, ProgramStatus::PayoutPhase);
         }
+    }
 
-        if let Some(paused) = refund {
-            flags.refund_paused = paused;
-            env.events().publish(
-                (PAUSE_STATE_CHANGED,),
-                (symbol_short!("refund"), paused, admin.clone(), reason.clone(), timestamp),
-            );
+    /// Open a funded program to participants (organizer only).
+    ///
+    /// # Panics
+    /// * If the program is not in the `Funded` state
+    pub fn activate_program(env: Env) {
+        Self::get_organizer(env.clone()).require_auth();
+        if Self::get_program_status(env.clone()) != ProgramStatus::Funded {
+            panic!("Program must be funded to activate");
         }
+        Self::set_program_status(&env, ProgramStatus::Active);
+    }
 
-        let any_paused = flags.lock_paused || flags.release_paused || flags.refund_paused;
-        
-        if any_paused {
-            if flags.paused_at == 0 {
-                flags.paused_at = timestamp;
-            }
-        } else {
-            flags.pause_reason = None;
-            flags.paused_at = 0;
+    /// Enter the payout phase before the first payout (organizer only).
+    ///
+    /// Payouts also move the program into this phase automatically.
+    ///
+    /// # Panics
+    /// * If the program is not `Funded` or `Active`
+    pub fn start_payout_phase(env: Env) {
+        Self::get_organizer(env.clone()).require_auth();
+        if !matches!(
+            Self::get_program_status(env.clone()),
+            ProgramStatus::Funded | ProgramStatus::Active
+        ) {
+            panic!("Program must be funded or active");
         }
-
-        env.storage().instance().set(&DataKey::PauseFlags, &flags);
+        Self::set_program_status(&env, ProgramStatus::PayoutPhase);
     }
 
-    /// Emergency withdraw all program funds (admin only, must have lock_paused = true)
-    pub fn emergency_withdraw(env: Env, target: Address) {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            panic!("Not initialized");
+    /// Close the program, refunding any remaining balance to the organizer
+    /// (organizer only). A closed program rejects locks and payouts but can
+    /// still be archived.
+    ///
+    /// # Panics
+    /// * If the program is still in setup, or already closed, cancelled or archived
+    /// * If funds are still held, claimable or queued for retry
+    /// * If sponsor funds are still earmarked for a track
+    pub fn close_program(env: Env) -> ProgramData {
+        Self::assert_program_active(&env);
+        if Self::get_program_status(env.clone()) == ProgramStatus::Setup {
+            panic!("Program not funded");
+        }
+        let organizer = Self::get_organizer(env.clone());
+        organizer.require_auth();
+
+        if Self::get_held_total(env.clone()) != 0
+            || Self::get_retry_total(env.clone()) != 0
+            || claim_period::get_reserved_total(&env) != 0
+        {
+            panic!("Pending payouts outstanding");
         }
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
 
-        let flags = Self::get_pause_flags(&env);
-        if !flags.lock_paused {
-            panic!("Not paused");
+        let mut program_data: ProgramData =
+            load_program_data(&env).unwrap_or_else(|| panic!("Program not initialized"));
+        let refunded_amount = program_data.remaining_balance;
+        Self::assert_not_earmarked(&env, &program_data, &PayoutAuth::PayoutKey, refunded_amount);
+        if refunded_amount > 0 {
+            let token_client = token::Client::new(&env, &program_data.token_address);
+            token_client.transfer(
+                &env.current_contract_address(),
+                &organizer,
+                &refunded_amount,
+            );
+            let refunded: i128 = env.storage().instance().get(&TOTAL_REFUNDED).unwrap_or(0);
+            env.storage()
+                .instance()
+                .set(&TOTAL_REFUNDED, &(refunded + refunded_amount));
         }
+        program_data.remaining_balance = 0;
+        save_program_data(&env, &program_data);
 
-        let program_data: ProgramData = env.storage().instance().get(&PROGRAM_DATA).unwrap_or_else(|| panic!("Program not initialized"));
-        let token_client = token::TokenClient::new(&env, &program_data.token_address);
-        
-        let contract_address = env.current_contract_address();
-        let balance = token_client.balance(&contract_address);
-        
-        if balance > 0 {
-            token_client.transfer(&contract_address, &target, &balance);
+        Self::set_program_status(&env, ProgramStatus::Closed);
+        env.events().publish(
+            (PROGRAM_CLOSED, program_data.program_id.clone()),
+            ProgramClosedEvent {
+                version: EVENT_VERSION_V2,
+                program_id: program_data.program_id.clone(),
+                organizer,
+                refunded_amount,
+            },
+        );
+        program_data
+    }
+
+    /// Archive a closed or fully paid-out program (organizer only).
+    ///
+    /// Freezes all writes, emits a final summary, and clears the payout,
+    /// release and fee histories so the program stops paying rent on them.
+    /// `get_program_summary` keeps returning the aggregate stats.
+    ///
+    /// # Panics
+    /// * If the program is cancelled or already archived
+    /// * If any balance remains or funds are still held, claimable or queued for retry
+    pub fn archive_program(env: Env) -> ProgramSummary {
+        if Self::get_program_status(env.clone()) != ProgramStatus::Closed {
+            Self::assert_program_active(&env);
+        }
+        Self::get_organizer(env.clone()).require_auth();
+
+        let mut program_data: ProgramData =
+            load_program_data(&env).unwrap_or_else(|| panic!("Program not initialized"));
+        if program_data.remaining_balance != 0
+            || Self::get_held_total(env.clone()) != 0
+            || Self::get_retry_total(env.clone()) != 0
+            || claim_period::get_reserved_total(&env) != 0
+        {
+            panic!("Program not fully paid out");
+        }
+
+        Self::set_program_status(&env, ProgramStatus::Archived);
+        let summary = Self::get_program_summary(env.clone());
+        env.storage().instance().set(&ARCHIVED_SUMMARY, &summary);
+
+        // Backfill the paid total before the history it is derived from goes
+        load_total_paid(&env);
+        program_data.payout_history = Vec::new(&env);
+        save_program_data(&env, &program_data);
+        env.storage()
+            .instance()
+            .set(&RELEASE_HISTORY, &Vec::<ProgramReleaseHistory>::new(&env));
+        // Fee entries are left to expire rather than removed one by one
+        env.storage().instance().remove(&FEE_LEDGER);
+        env.storage().instance().remove(&FeeKey::Count);
+
+        env.events()
+            .publish((PROGRAM_ARCHIVED, program_data.program_id), summary.clone());
+        summary
+    }
+
+    // ========================================================================
+    // Initialization & Admin
+    // ========================================================================
+
+    /// Initialize the contract with an admin.
+    /// This must be called before any admin protected functions (like pause) can be used.
+    pub fn initialize_contract(env: Env, admin: Address) {
+        ownable::init_owner(&env, &DataKey::Admin, &admin).unwrap_or_else(|e| access_panic(e));
+        env.storage()
+            .instance()
+            .set(&DataKey::Version, &CONTRACT_VERSION);
+    }
+
+    /// Set the initial admin. Once an admin exists this only proposes `admin`
+    /// (like `propose_admin`); the rotation takes effect when it calls `accept_admin`.
+    pub fn set_admin(env: Env, admin: Address) {
+        if ownable::owner(&env, &DataKey::Admin).is_some() {
+            Self::propose_admin(env, admin);
+        } else {
+            ownable::init_owner(&env, &DataKey::Admin, &admin).unwrap_or_else(|e| access_panic(e));
+        }
+    }
+
+    /// Returns the current admin address, if set.
+    pub fn get_admin(env: Env) -> Option<Address> {
+        ownable::owner(&env, &DataKey::Admin)
+    }
+
+    /// Propose `new_admin` as the next admin (admin only). The transfer takes
+    /// effect once `new_admin` calls `accept_admin`.
+    pub fn propose_admin(env: Env, new_admin: Address) {
+        ownable::propose_owner(&env, &DataKey::Admin, &new_admin)
+            .unwrap_or_else(|e| access_panic(e));
+        env.events().publish((ADMIN_PROPOSED,), new_admin);
+    }
+
+    /// Become admin (proposed admin only). Panics if no transfer is pending.
+    pub fn accept_admin(env: Env) {
+        let previous =
+            ownable::accept_ownership(&env, &DataKey::Admin).unwrap_or_else(|e| access_panic(e));
+        let admin = Self::get_admin(env.clone()).unwrap();
+        env.events()
+            .publish((ADMIN_TRANSFERRED,), (previous, admin));
+    }
+
+    /// Withdraw a pending admin transfer (admin only).
+    pub fn cancel_admin_transfer(env: Env) {
+        ownable::cancel_ownership_transfer(&env, &DataKey::Admin)
+            .unwrap_or_else(|e| access_panic(e));
+    }
+
+    /// Returns the proposed admin, if a transfer is pending.
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        ownable::pending_owner(&env)
+    }
+
+    // ========================================================================
+    // Upgrade & Version Management
+    // ========================================================================
+
+    /// Upgrades the contract to new WASM code (admin only).
+    ///
+    /// Program state and locked funds stay in place. The current version is
+    /// kept as the previous version; call `set_version` once migrated.
+    ///
+    /// # Arguments
+    /// * `new_wasm_hash` - Hash of the uploaded WASM code (32 bytes)
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("Not initialized"));
+        admin.require_auth();
+
+        let current_version = Self::get_version(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::PreviousVersion, &current_version);
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        env.events().publish(
+            (CONTRACT_UPGRADED,),
+            (new_wasm_hash, current_version, admin),
+        );
+    }
+
+    /// Retrieves the current contract version number (0 if never initialized).
+    pub fn get_version(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::Version).unwrap_or(0)
+    }
+
+    /// Retrieves the version recorded before the last upgrade, if any.
+    pub fn get_previous_version(env: Env) -> Option<u32> {
+        env.storage().instance().get(&DataKey::PreviousVersion)
+    }
+
+    /// Updates the contract version number (admin only).
+    pub fn set_version(env: Env, new_version: u32) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("Not initialized"));
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::Version, &new_version);
+    }
+
+    pub fn get_program_release_schedules(env: Env) -> Vec<ProgramReleaseSchedule> {
+    env.storage()
+        .instance()
+        .get(&SCHEDULES)
+        .unwrap_or_else(|| Vec::new(&env))
+}
+
+    /// Update pause flags (admin only)
+    pub fn set_paused(env: Env, lock: Option<bool>, release: Option<bool>, refund: Option<bool>, reason: Option<String>) {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            panic!("Not initialized");
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let mut flags = Self::get_pause_flags(&env);
+        let timestamp = env.ledger().timestamp();
+
+        if reason.is_some() {
+            flags.pause_reason = reason.clone();
+        }
+
+        if let Some(paused) = lock {
+            flags.lock_paused = paused;
             env.events().publish(
-                (symbol_short!("em_wtd"),),
-                (admin, target.clone(), balance, env.ledger().timestamp()),
+                (PAUSE_STATE_CHANGED,),
+                (symbol_short!("lock"), paused, admin.clone(), reason.clone(), timestamp),
+            );
+        }
+
+        if let Some(paused) = release {
+            flags.release_paused = paused;
+            env.events().publish(
+                (PAUSE_STATE_CHANGED,),
+                (symbol_short!("release"), paused, admin.clone(), reason.clone(), timestamp),
+            );
+        }
+
+        if let Some(paused) = refund {
+            flags.refund_paused = paused;
+            env.events().publish(
+                (PAUSE_STATE_CHANGED,),
+                (symbol_short!("refund"), paused, admin.clone(), reason.clone(), timestamp),
             );
         }
+
+        let any_paused = flags.lock_paused || flags.release_paused || flags.refund_paused;
+        
+        if any_paused {
+            if flags.paused_at == 0 {
+                flags.paused_at = timestamp;
+            }
+        } else {
+            flags.pause_reason = None;
+            flags.paused_at = 0;
+        }
+
+        env.storage().instance().set(&DataKey::PauseFlags, &flags);
     }
 
-    /// Get current pause flags
-    pub fn get_pause_flags(env: &Env) -> PauseFlags {
+    /// Pause all payout outflows (batch, single, scheduled releases and claims).
+    ///
+    /// Callable by the contract admin or the program organizer so a leaked
+    /// payout key can be neutralized in one transaction. Deposits and views
+    /// keep working while payouts are paused.
+    pub fn pause_payouts(env: Env, caller: Address, reason: Option<String>) {
+        Self::set_payouts_paused(&env, &caller, true, reason);
+    }
+
+    /// Resume payout outflows. Callable by the contract admin or the program organizer.
+    pub fn unpause_payouts(env: Env, caller: Address) {
+        Self::set_payouts_paused(&env, &caller, false, None);
+    }
+
+    /// Returns true if payouts are currently paused.
+    pub fn is_payouts_paused(env: Env) -> bool {
+        Self::check_paused(&env, symbol_short!("release"))
+    }
+
+    fn set_payouts_paused(env: &Env, caller: &Address, paused: bool, reason: Option<String>) {
+        Self::require_admin_or_organizer(env, caller);
+
+        let mut flags = Self::get_pause_flags(env);
+        let timestamp = env.ledger().timestamp();
+        flags.release_paused = paused;
+
+        if paused {
+            if reason.is_some() {
+                flags.pause_reason = reason.clone();
+            }
+            if flags.paused_at == 0 {
+                flags.paused_at = timestamp;
+            }
+        } else if !flags.lock_paused && !flags.refund_paused {
+            flags.pause_reason = None;
+            flags.paused_at = 0;
+        }
+        env.storage().instance().remove(&LimitKey::TripwirePaused);
+
+        env.storage().instance().set(&DataKey::PauseFlags, &flags);
+        env.events().publish(
+            (PAUSE_STATE_CHANGED,),
+            (
+                symbol_short!("release"),
+                paused,
+                caller.clone(),
+                reason,
+                timestamp,
+            ),
+        );
+    }
+
+    /// Requires auth from `caller` and checks it is the contract admin or the organizer.
+    fn require_admin_or_organizer(env: &Env, caller: &Address) {
+        let admin: Option<Address> = env.storage().instance().get(&DataKey::Admin);
+        let organizer: Option<Address> = env.storage().instance().get(&DataKey::Organizer);
+        if admin.as_ref() != Some(caller) && organizer.as_ref() != Some(caller) {
+            panic!("Unauthorized: only admin or organizer");
+        }
+        caller.require_auth();
+    }
+
+    /// Panics if payout outflows are paused.
+    fn assert_payouts_not_paused(env: &Env) {
+        if Self::check_paused(env, symbol_short!("release")) {
+            panic!("Funds Paused");
+        }
+    }
+
+    /// Set per-transaction and per-recipient payout caps (organizer only).
+    ///
+    /// # Arguments
+    /// * `max_per_payout`    - Max amount for any single payout item (`None` = unlimited)
+    /// * `max_per_recipient` - Max cumulative amount paid to one recipient (`None` = unlimited)
+    ///
+    /// Cumulative per-recipient totals are only tracked while a per-recipient
+    /// cap is configured, so payouts made before the cap was set do not count.
+    pub fn set_payout_limits(
+        env: Env,
+        max_per_payout: Option<i128>,
+        max_per_recipient: Option<i128>,
+    ) -> PayoutLimits {
+        Self::get_organizer(env.clone()).require_auth();
+
+        if max_per_payout.is_some_and(|v| v <= 0) || max_per_recipient.is_some_and(|v| v <= 0) {
+            panic!("Limits must be greater than zero");
+        }
+
+        let limits = PayoutLimits {
+            max_per_payout,
+            max_per_recipient,
+        };
         env.storage()
             .instance()
-            .get(&DataKey::PauseFlags)
-            .unwrap_or(PauseFlags {
-                lock_paused: false,
-                release_paused: false,
-                refund_paused: false,
-                pause_reason: None,
-                paused_at: 0,
+            .set(&DataKey::PayoutLimits, &limits);
+        env.events()
+            .publish((PAYOUT_LIMITS_SET,), (max_per_payout, max_per_recipient));
+
+        limits
+    }
+
+    /// Get the configured payout caps
+    pub fn get_payout_limits(env: Env) -> PayoutLimits {
+        env.storage()
+            .instance()
+            .get(&DataKey::PayoutLimits)
+            .unwrap_or(PayoutLimits {
+                max_per_payout: None,
+                max_per_recipient: None,
             })
     }
 
-    /// Check if an operation is paused
-    fn check_paused(env: &Env, operation: Symbol) -> bool {
-        let flags = Self::get_pause_flags(env);
-        if operation == symbol_short!("lock") {
-            return flags.lock_paused;
-        } else if operation == symbol_short!("release") {
-            return flags.release_paused;
-        } else if operation == symbol_short!("refund") {
-            return flags.refund_paused;
+    /// Get the cumulative amount paid to a recipient while a per-recipient cap was active
+    pub fn get_recipient_total_paid(env: Env, recipient: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RecipientPaid(recipient))
+            .unwrap_or(0)
+    }
+
+    /// Check `amount` against the payout caps and add it to the recipient's running total.
+    fn enforce_payout_limits(env: &Env, limits: &PayoutLimits, recipient: &Address, amount: i128) {
+        if let Some(max) = limits.max_per_payout {
+            if amount > max {
+                reentrancy_guard::clear_entered(env);
+                panic!("Payout exceeds per-transaction limit");
+            }
         }
-        false
+
+        if let Some(max) = limits.max_per_recipient {
+            let key = DataKey::RecipientPaid(recipient.clone());
+            let paid: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+            let new_total = paid + amount;
+            if new_total > max {
+                reentrancy_guard::clear_entered(env);
+                panic!("Payout exceeds per-recipient limit");
+            }
+            env.storage().persistent().set(&key, &new_total);
+            storage::extend_persistent(env, &key);
+        }
+    }
+
+    /// Undo `enforce_payout_limits` bookkeeping for an item that was not paid.
+    fn release_recipient_limit(
+        env: &Env,
+        limits: &PayoutLimits,
+        recipient: &Address,
+        amount: i128,
+    ) {
+        if limits.max_per_recipient.is_some() {
+            let key = DataKey::RecipientPaid(recipient.clone());
+            let paid: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+            env.storage().persistent().set(&key, &(paid - amount));
+        }
+    }
+
+    /// Set the amount above which a payout needs organizer approval (organizer only).
+    ///
+    /// Pass `None` to disable. For batches the threshold applies to the batch total.
+    pub fn set_large_payout_threshold(env: Env, threshold: Option<i128>) {
+        Self::get_organizer(env.clone()).require_auth();
+
+        match threshold {
+            Some(amount) if amount <= 0 => panic!("Threshold must be greater than zero"),
+            Some(amount) => env
+                .storage()
+                .instance()
+                .set(&DataKey::LargePayoutThreshold, &amount),
+            None => env
+                .storage()
+                .instance()
+                .remove(&DataKey::LargePayoutThreshold),
+        }
+        let program_id = Self::get_program_info(env.clone()).program_id;
+        env.events()
+            .publish((LARGE_PAYOUT_THRESHOLD_SET, program_id), threshold);
+    }
+
+    /// Get the large payout approval threshold, if any
+    pub fn get_large_payout_threshold(env: Env) -> Option<i128> {
+        env.storage().instance().get(&DataKey::LargePayoutThreshold)
+    }
+
+    /// Organizer co-signs a large payout ahead of execution.
+    ///
+    /// The payout key must then submit the payout through `single_payout_with_id`
+    /// or `batch_payout_with_id` using the same `payout_id`, `recipients` and
+    /// `amounts`; the approval does not cover any other payload.
+    pub fn approve_large_payout(
+        env: Env,
+        payout_id: BytesN<32>,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+    ) {
+        let organizer = Self::get_organizer(env.clone());
+        organizer.require_auth();
+
+        if Self::is_payout_processed(env.clone(), payout_id.clone()) {
+            panic!("Payout already processed");
+        }
+
+        let payload_hash = Self::payout_payload_hash(&env, &recipients, &amounts);
+        env.storage().persistent().set(
+            &DataKey::LargePayoutApproval(payout_id.clone()),
+            &payload_hash,
+        );
+        env.events()
+            .publish((LARGE_PAYOUT_APPROVED,), (payout_id, organizer));
+    }
+
+    /// Returns true if the organizer has approved this payout_id.
+    pub fn is_large_payout_approved(env: Env, payout_id: BytesN<32>) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::LargePayoutApproval(payout_id))
     }
 
-    // --- Circuit Breaker & Rate Limit ---
+    /// Hash identifying a payout's recipients and amounts for approvals.
+    fn payout_payload_hash(
+        env: &Env,
+        recipients: &Vec<Address>,
+        amounts: &Vec<i128>,
+    ) -> BytesN<32> {
+        let payload = (recipients.clone(), amounts.clone()).to_xdr(env);
+        env.crypto().sha256(&payload).into()
+    }
+
+    /// Require (and consume) organizer approval when `amount` exceeds the
+    /// threshold. The approval must have been given for this exact payload.
+    fn check_large_payout_approval(
+        env: &Env,
+        payout_id: &Option<BytesN<32>>,
+        recipients: &Vec<Address>,
+        amounts: &Vec<i128>,
+        amount: i128,
+    ) {
+        let threshold: Option<i128> = env.storage().instance().get(&DataKey::LargePayoutThreshold);
+        match threshold {
+            Some(limit) if amount > limit => {}
+            _ => return,
+        }
+
+        let Some(key) = payout_id
+            .as_ref()
+            .map(|id| DataKey::LargePayoutApproval(id.clone()))
+            .filter(|key| env.storage().persistent().has(key))
+        else {
+            reentrancy_guard::clear_entered(env);
+            panic!("Large payout requires organizer approval");
+        };
+        let approved_hash: BytesN<32> = env.storage().persistent().get(&key).unwrap();
+        if approved_hash != Self::payout_payload_hash(env, recipients, amounts) {
+            reentrancy_guard::clear_entered(env);
+            panic!("Large payout approval does not match payout");
+        }
+        env.storage().persistent().remove(&key);
+    }
+
+    /// Cap the total paid out per epoch (organizer only), limiting the loss
+    /// from a compromised payout key to one epoch's budget.
+    ///
+    /// Payouts beyond the cap wait for the next epoch unless the organizer
+    /// approves their `payout_id` with `approve_over_cap_payout`. Pass `None`
+    /// to disable.
+    pub fn set_epoch_limit(env: Env, limit: Option<EpochLimit>) {
+        Self::get_organizer(env.clone()).require_auth();
+
+        match &limit {
+            Some(l) if l.cap <= 0 || l.duration == 0 => {
+                panic!("Epoch cap and duration must be greater than zero")
+            }
+            Some(l) => env.storage().instance().set(&LimitKey::EpochLimit, l),
+            None => env.storage().instance().remove(&LimitKey::EpochLimit),
+        }
+        let program_id = Self::get_program_info(env.clone()).program_id;
+        env.events().publish((EPOCH_LIMIT_SET, program_id), limit);
+    }
+
+    /// Get the per-epoch payout cap, if any
+    pub fn get_epoch_limit(env: Env) -> Option<EpochLimit> {
+        env.storage().instance().get(&LimitKey::EpochLimit)
+    }
+
+    /// Get the amount debited from the pool so far in the current epoch.
+    pub fn get_epoch_outflow(env: Env) -> i128 {
+        let Some(limit) = Self::get_epoch_limit(env.clone()) else {
+            return 0;
+        };
+        let epoch = env.ledger().timestamp() / limit.duration;
+        env.storage()
+            .instance()
+            .get::<_, EpochOutflow>(&LimitKey::EpochOutflow)
+            .filter(|outflow| outflow.epoch == epoch)
+            .map_or(0, |outflow| outflow.paid)
+    }
+
+    /// Organizer co-signs a payout that would exceed the epoch cap.
+    ///
+    /// The payout key must then submit it through `single_payout_with_id` or
+    /// `batch_payout_with_id` using the same `payout_id`.
+    pub fn approve_over_cap_payout(env: Env, payout_id: BytesN<32>) {
+        let organizer = Self::get_organizer(env.clone());
+        organizer.require_auth();
+
+        if Self::is_payout_processed(env.clone(), payout_id.clone()) {
+            panic!("Payout already processed");
+        }
+
+        env.storage()
+            .persistent()
+            .set(&LimitKey::OverCapApproval(payout_id.clone()), &true);
+        env.events()
+            .publish((OVER_CAP_APPROVED,), (payout_id, organizer));
+    }
+
+    /// Add `amount` to the current epoch's outflow, requiring (and consuming)
+    /// organizer approval if it would exceed the cap.
+    ///
+    /// Charged from `validate_payouts`, so every path that debits the pool
+    /// (direct, claimable and scheduled payouts) counts against the cap.
+    fn charge_epoch_outflow(env: &Env, payout_id: &Option<BytesN<32>>, amount: i128) {
+        let Some(limit) = Self::get_epoch_limit(env.clone()) else {
+            return;
+        };
+
+        let paid = Self::get_epoch_outflow(env.clone()) + amount;
+        if paid > limit.cap {
+            let approval = payout_id
+                .as_ref()
+                .map(|id| LimitKey::OverCapApproval(id.clone()))
+                .filter(|key| env.storage().persistent().has(key));
+            let Some(key) = approval else {
+                reentrancy_guard::clear_entered(env);
+                panic!("Epoch payout cap exceeded");
+            };
+            env.storage().persistent().remove(&key);
+        }
+        env.storage().instance().set(
+            &LimitKey::EpochOutflow,
+            &EpochOutflow {
+                epoch: env.ledger().timestamp() / limit.duration,
+                paid,
+            },
+        );
+    }
+
+    /// Configure the outflow tripwire (organizer only). Pass `None` to disable.
+    ///
+    /// The payout that pushes a window's outflow past `max_bps` of the
+    /// window's starting balance still completes, after which payouts stay
+    /// paused until the organizer calls `reset_outflow_tripwire`.
+    pub fn set_outflow_tripwire(env: Env, tripwire: Option<OutflowTripwire>) {
+        Self::get_organizer(env.clone()).require_auth();
+
+        match &tripwire {
+            Some(t) if t.max_bps == 0 || t.max_bps as i128 > BASIS_POINTS || t.window == 0 => {
+                panic!("Invalid tripwire configuration")
+            }
+            Some(t) => env.storage().instance().set(&LimitKey::Tripwire, t),
+            None => env.storage().instance().remove(&LimitKey::Tripwire),
+        }
+        env.storage().instance().remove(&LimitKey::TripwireWindow);
+        let program_id = Self::get_program_info(env.clone()).program_id;
+        env.events().publish((TRIPWIRE_SET, program_id), tripwire);
+    }
+
+    /// Get the outflow tripwire configuration, if any
+    pub fn get_outflow_tripwire(env: Env) -> Option<OutflowTripwire> {
+        env.storage().instance().get(&LimitKey::Tripwire)
+    }
+
+    /// Get the outflow window currently measured by the tripwire, if any
+    pub fn get_outflow_window(env: Env) -> Option<OutflowWindow> {
+        env.storage().instance().get(&LimitKey::TripwireWindow)
+    }
+
+    /// Start a fresh outflow window and lift the payout pause if the tripwire
+    /// set it (organizer only). Pauses set by an admin or organizer stay.
+    pub fn reset_outflow_tripwire(env: Env) {
+        let organizer = Self::get_organizer(env.clone());
+        organizer.require_auth();
+        env.storage().instance().remove(&LimitKey::TripwireWindow);
+
+        if env.storage().instance().has(&LimitKey::TripwirePaused) {
+            env.storage().instance().remove(&LimitKey::TripwirePaused);
+            let mut flags = Self::get_pause_flags(&env);
+            flags.release_paused = false;
+            if flags.pause_reason == Some(String::from_str(&env, TRIPWIRE_PAUSE_REASON)) {
+                flags.pause_reason = None;
+            }
+            if !flags.lock_paused && !flags.refund_paused {
+                flags.paused_at = 0;
+            }
+            env.storage().instance().set(&DataKey::PauseFlags, &flags);
+        }
+        env.events().publish((TRIPWIRE_RESET,), organizer);
+    }
+
+    /// Add `amount` to the tripwire window and pause payouts if the trailing
+    /// outflow now exceeds the configured share of the window's starting balance.
+    fn record_tripwire_outflow(env: &Env, program_data: &ProgramData, amount: i128) {
+        let Some(tripwire) = Self::get_outflow_tripwire(env.clone()) else {
+            return;
+        };
+
+        let now = env.ledger().timestamp();
+        let mut window = match Self::get_outflow_window(env.clone()) {
+            Some(w) if now < w.started_at + tripwire.window => w,
+            // The next window carries this one's outflow into the trailing measure
+            Some(w) if now < w.started_at + 2 * tripwire.window => OutflowWindow {
+                started_at: w.started_at + tripwire.window,
+                baseline: program_data.remaining_balance,
+                paid: 0,
+                previous_paid: w.paid,
+            },
+            _ => OutflowWindow {
+                started_at: now,
+                baseline: program_data.remaining_balance,
+                paid: 0,
+                previous_paid: 0,
+            },
+        };
+        window.paid += amount;
+        env.storage()
+            .instance()
+            .set(&LimitKey::TripwireWindow, &window);
+
+        let overlap = (window.started_at + tripwire.window - now) as i128;
+        let trailing = window.paid + window.previous_paid * overlap / tripwire.window as i128;
+        if trailing * BASIS_POINTS <= window.baseline * tripwire.max_bps as i128 {
+            return;
+        }
+
+        let mut flags = Self::get_pause_flags(env);
+        if !flags.release_paused {
+            flags.release_paused = true;
+            env.storage()
+                .instance()
+                .set(&LimitKey::TripwirePaused, &true);
+        }
+        if flags.pause_reason.is_none() {
+            flags.pause_reason = Some(String::from_str(env, TRIPWIRE_PAUSE_REASON));
+        }
+        if flags.paused_at == 0 {
+            flags.paused_at = now;
+        }
+        env.storage().instance().set(&DataKey::PauseFlags, &flags);
+        env.events().publish(
+            (TRIPWIRE_TRIPPED, program_data.program_id.clone()),
+            (trailing, window.baseline),
+        );
+    }
+
+    /// Restrict payouts to recipients approved by an external registry (organizer only).
+    ///
+    /// The registry must implement `is_eligible(account) -> bool`. Pass `None`
+    /// to allow any recipient again.
+    pub fn set_eligibility_registry(env: Env, registry: Option<Address>) {
+        Self::get_organizer(env.clone()).require_auth();
+
+        match &registry {
+            Some(address) => env
+                .storage()
+                .instance()
+                .set(&DataKey::EligibilityRegistry, address),
+            None => env
+                .storage()
+                .instance()
+                .remove(&DataKey::EligibilityRegistry),
+        }
+        env.events().publish((ELIGIBILITY_REGISTRY_SET,), registry);
+    }
+
+    /// Get the configured eligibility registry, if any
+    pub fn get_eligibility_registry(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::EligibilityRegistry)
+    }
+
+    /// Set the DEX router adapter used by `single_payout_in` (organizer only).
+    /// Pass `None` to disable swapped payouts.
+    pub fn set_swap_router(env: Env, router: Option<Address>) {
+        Self::get_organizer(env.clone()).require_auth();
+
+        match &router {
+            Some(address) => env.storage().instance().set(&DataKey::SwapRouter, address),
+            None => env.storage().instance().remove(&DataKey::SwapRouter),
+        }
+        env.events().publish((SWAP_ROUTER_SET,), router);
+    }
+
+    /// Get the configured swap router adapter, if any
+    pub fn get_swap_router(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::SwapRouter)
+    }
+
+    /// Swap `amount_in` of the program token through the router and deliver
+    /// the output to `recipient`, panicking if less than `min_out` arrives.
+    fn swap_to_recipient(
+        env: &Env,
+        program_data: &ProgramData,
+        asset_out: &Address,
+        amount_in: i128,
+        min_out: i128,
+        recipient: &Address,
+    ) {
+        let router = Self::get_swap_router(env.clone()).unwrap_or_else(|| {
+            reentrancy_guard::clear_entered(env);
+            panic!("Swap router not set")
+        });
+
+        let token_in = &program_data.token_address;
+        token::Client::new(env, token_in).transfer(
+            &env.current_contract_address(),
+            &router,
+            &amount_in,
+        );
+        let out_client = token::Client::new(env, asset_out);
+        let balance_before = out_client.balance(recipient);
+        SwapRouterClient::new(env, &router)
+            .swap(token_in, asset_out, &amount_in, &min_out, recipient);
+        // Trust the token balance rather than the router's reported amount
+        let amount_out = out_client.balance(recipient) - balance_before;
+        if amount_out < min_out {
+            reentrancy_guard::clear_entered(env);
+            panic!("Slippage exceeded");
+        }
+
+        env.events().publish(
+            (
+                PAYOUT_SWAPPED,
+                program_data.program_id.clone(),
+                recipient.clone(),
+            ),
+            (recipient.clone(), asset_out.clone(), amount_in, amount_out),
+        );
+    }
+
+    /// Register or replace a delegated payout signer (organizer only).
+    ///
+    /// # Arguments
+    /// * `signer`       - Address allowed to call the `delegated_*` payout entrypoints
+    /// * `spending_cap` - Maximum cumulative amount the signer may pay out
+    /// * `expires_at`   - Ledger timestamp after which the signer is rejected
+    pub fn add_payout_signer(env: Env, signer: Address, spending_cap: i128, expires_at: u64) {
+        Self::get_organizer(env.clone()).require_auth();
+
+        if spending_cap <= 0 {
+            panic!("Spending cap must be greater than zero");
+        }
+        if expires_at <= env.ledger().timestamp() {
+            panic!("Expiry must be in the future");
+        }
+
+        roles::grant_role(&env, &PAYOUT_SIGNER_ROLE, &signer);
+        env.storage().persistent().set(
+            &DataKey::PayoutSigner(signer.clone()),
+            &PayoutSigner {
+                signer: signer.clone(),
+                spending_cap,
+                spent: 0,
+                expires_at,
+            },
+        );
+        storage::extend_persistent(&env, &DataKey::PayoutSigner(signer.clone()));
+        env.events()
+            .publish((PAYOUT_SIGNER_SET,), (signer, spending_cap, expires_at));
+    }
+
+    /// Revoke a delegated payout signer (organizer only).
+    pub fn remove_payout_signer(env: Env, signer: Address) {
+        Self::get_organizer(env.clone()).require_auth();
+
+        roles::revoke_role(&env, &PAYOUT_SIGNER_ROLE, &signer);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::PayoutSigner(signer.clone()));
+        env.events().publish((PAYOUT_SIGNER_REMOVED,), signer);
+    }
+
+    /// Issue a short-lived session key for the payout backend (organizer only).
+    ///
+    /// The session key calls the `delegated_*` payout entrypoints in place of
+    /// the long-lived payout key, may pay out at most `max_total`, and stops
+    /// working at `expires_at`, which must be within one day. Revoke early with
+    /// `remove_payout_signer`.
+    pub fn authorize_session(env: Env, key: Address, expires_at: u64, max_total: i128) {
+        if expires_at > env.ledger().timestamp() + MAX_SESSION_DURATION {
+            panic!("Session duration too long");
+        }
+        Self::add_payout_signer(env, key, max_total, expires_at);
+    }
+
+    /// Get a delegated payout signer, if registered
+    pub fn get_payout_signer(env: Env, signer: Address) -> Option<PayoutSigner> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PayoutSigner(signer))
+    }
+
+    /// Require auth from the payout key, a registered, unexpired delegate, or a track's key.
+    fn require_payout_auth(env: &Env, program_data: &ProgramData, auth: &PayoutAuth) {
+        let signer = match auth {
+            PayoutAuth::PayoutKey => {
+                Self::assert_payout_nonce_not_required(env);
+                program_data.authorized_payout_key.require_auth();
+                return;
+            }
+            PayoutAuth::Nonced(nonce) => {
+                program_data.authorized_payout_key.require_auth();
+                Self::consume_payout_nonce(env, *nonce);
+                return;
+            }
+            PayoutAuth::Distribution => {
+                program_data.authorized_payout_key.require_auth();
+                return;
+            }
+            PayoutAuth::Permit(permit, signature) => {
+                Self::verify_payout_permit(env, program_data, permit, signature);
+                return;
+            }
+            PayoutAuth::Track(name) => {
+                match Self::load_track(env, name).payout_key {
+                    Some(track_key) => track_key.require_auth(),
+                    None => {
+                        Self::assert_payout_nonce_not_required(env);
+                        program_data.authorized_payout_key.require_auth();
+                    }
+                }
+                return;
+            }
+            PayoutAuth::Signer(signer) => signer,
+        };
+
+        if roles::require_role(env, &PAYOUT_SIGNER_ROLE, signer).is_err() {
+            reentrancy_guard::clear_entered(env);
+            panic!("Unauthorized: not a payout signer");
+        }
+        let entry = Self::get_payout_signer(env.clone(), signer.clone()).unwrap();
+        if env.ledger().timestamp() > entry.expires_at {
+            reentrancy_guard::clear_entered(env);
+            panic!("Payout signer expired");
+        }
+    }
+
+    /// Panics if payout-key payouts must go through the `_with_nonce` entrypoints.
+    fn assert_payout_nonce_not_required(env: &Env) {
+        if Self::is_payout_nonce_required(env.clone()) {
+            reentrancy_guard::clear_entered(env);
+            panic!("Payout nonce required");
+        }
+    }
+
+    /// Add `amount` to a delegate's spent total, enforcing its cap.
+    fn charge_signer_cap(env: &Env, auth: &PayoutAuth, amount: i128) {
+        let PayoutAuth::Signer(signer) = auth else {
+            return;
+        };
+
+        let key = DataKey::PayoutSigner(signer.clone());
+        let mut entry: PayoutSigner = env.storage().persistent().get(&key).unwrap();
+        if entry.spent + amount > entry.spending_cap {
+            reentrancy_guard::clear_entered(env);
+            panic!("Payout exceeds signer spending cap");
+        }
+        entry.spent += amount;
+        env.storage().persistent().set(&key, &entry);
+        storage::extend_persistent(env, &key);
+    }
+
+    /// Block a recipient from receiving any further payouts (organizer only).
+    pub fn block_recipient(env: Env, recipient: Address) {
+        let organizer = Self::get_organizer(env.clone());
+        organizer.require_auth();
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::BlockedRecipient(recipient.clone()), &true);
+        env.events()
+            .publish((RECIPIENT_BLOCKED,), (recipient, organizer));
+    }
+
+    /// Lift a block placed with `block_recipient` (organizer only).
+    pub fn unblock_recipient(env: Env, recipient: Address) {
+        let organizer = Self::get_organizer(env.clone());
+        organizer.require_auth();
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::BlockedRecipient(recipient.clone()));
+        env.events()
+            .publish((RECIPIENT_UNBLOCKED,), (recipient, organizer));
+    }
+
+    /// Returns true if the organizer has blocked this recipient.
+    pub fn is_recipient_blocked(env: Env, recipient: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::BlockedRecipient(recipient))
+    }
+
+    /// Freeze a recipient's pending claims and release schedules (organizer only).
+    ///
+    /// Unlike `block_recipient`, this only holds back payouts already owed to
+    /// the recipient, e.g. while a submission is under dispute. Frozen
+    /// schedules are skipped by `trigger_program_releases` and resume once
+    /// unfrozen.
+    pub fn freeze_recipient(env: Env, recipient: Address) {
+        let organizer = Self::get_organizer(env.clone());
+        organizer.require_auth();
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::FrozenRecipient(recipient.clone()), &true);
+        env.events()
+            .publish((RECIPIENT_FROZEN,), (recipient, organizer));
+    }
+
+    /// Lift a freeze placed with `freeze_recipient` (organizer only).
+    pub fn unfreeze_recipient(env: Env, recipient: Address) {
+        let organizer = Self::get_organizer(env.clone());
+        organizer.require_auth();
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::FrozenRecipient(recipient.clone()));
+        env.events()
+            .publish((RECIPIENT_UNFROZEN,), (recipient, organizer));
+    }
+
+    /// Returns true if the recipient's pending payouts are frozen.
+    pub fn is_recipient_frozen(env: Env, recipient: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::FrozenRecipient(recipient))
+    }
+
+    /// Panics if `recipient`'s pending payouts are frozen.
+    fn assert_recipient_not_frozen(env: &Env, recipient: &Address) {
+        if Self::is_recipient_frozen(env.clone(), recipient.clone()) {
+            reentrancy_guard::clear_entered(env);
+            panic!("Recipient is frozen");
+        }
+    }
+
+    /// Panics if `recipient` has been blocked by the organizer.
+    fn assert_recipient_not_blocked(env: &Env, recipient: &Address) {
+        if Self::is_recipient_blocked(env.clone(), recipient.clone()) {
+            reentrancy_guard::clear_entered(env);
+            panic!("Recipient is blocked");
+        }
+    }
+
+    /// Panics unless `recipient` is eligible according to `registry` (if set).
+    fn assert_recipient_eligible(env: &Env, registry: &Option<Address>, recipient: &Address) {
+        if let Some(registry) = registry {
+            if !EligibilityRegistryClient::new(env, registry).is_eligible(recipient) {
+                reentrancy_guard::clear_entered(env);
+                panic!("Recipient not eligible");
+            }
+        }
+    }
+
+    /// Emergency withdraw all program funds (admin only, must have lock_paused = true)
+    pub fn emergency_withdraw(env: Env, target: Address) {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            panic!("Not initialized");
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let flags = Self::get_pause_flags(&env);
+        if !flags.lock_paused {
+            panic!("Not paused");
+        }
+
+        let program_data: ProgramData =
+            load_program_data(&env).unwrap_or_else(|| panic!("Program not initialized"));
+        let token_client = token::TokenClient::new(&env, &program_data.token_address);
+        
+        let contract_address = env.current_contract_address();
+        let balance = token_client.balance(&contract_address);
+        
+        if balance > 0 {
+            token_client.transfer(&contract_address, &target, &balance);
+            env.events().publish(
+                (symbol_short!("em_wtd"),),
+                (admin, target.clone(), balance, env.ledger().timestamp()),
+            );
+        }
+    }
+
+    /// Get current pause flags
+    pub fn get_pause_flags(env: &Env) -> PauseFlags {
+        env.storage()
+            .instance()
+            .get(&DataKey::PauseFlags)
+            .unwrap_or(PauseFlags {
+                lock_paused: false,
+                release_paused: false,
+                refund_paused: false,
+                pause_reason: None,
+                paused_at: 0,
+            })
+    }
+
+    /// Check if an operation is paused
+    fn check_paused(env: &Env, operation: Symbol) -> bool {
+        let flags = Self::get_pause_flags(env);
+        if operation == symbol_short!("lock") {
+            return flags.lock_paused;
+        } else if operation == symbol_short!("release") {
+            return flags.release_paused;
+        } else if operation == symbol_short!("refund") {
+            return flags.refund_paused;
+        }
+        false
+    }
+
+    // --- Circuit Breaker & Rate Limit ---
+
+    pub fn set_circuit_admin(env: Env, new_admin: Address, caller: Option<Address>) {
+        error_recovery::set_circuit_admin(&env, new_admin, caller);
+    }
+
+    pub fn get_circuit_admin(env: Env) -> Option<Address> {
+        error_recovery::get_circuit_admin(&env)
+    }
+
+    pub fn reset_circuit_breaker(env: Env, caller: Address) {
+        caller.require_auth();
+        let admin = error_recovery::get_circuit_admin(&env).expect("Circuit admin not set");
+        if caller != admin {
+            panic!("Unauthorized: only circuit admin can reset");
+        }
+        error_recovery::reset_circuit_breaker(&env, &admin);
+    }
+
+    pub fn configure_circuit_breaker(
+        env: Env,
+        caller: Address,
+        _threshold: u32,
+        _lookback: u32,
+        _cooldown: u32,
+    ) {
+        caller.require_auth();
+        let admin = error_recovery::get_circuit_admin(&env).expect("Circuit admin not set");
+        if caller != admin {
+            panic!("Unauthorized: only circuit admin can configure");
+        }
+        // Logic to update config in storage would go here
+    }
+
+    pub fn update_rate_limit_config(
+        env: Env,
+        window_size: u64,
+        max_operations: u32,
+        cooldown_period: u64,
+    ) {
+        // Only admin can update rate limit config
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let config = RateLimitConfig {
+            window_size,
+            max_operations,
+            cooldown_period,
+        };
+        env.storage().instance().set(&DataKey::RateLimitConfig, &config);
+    }
+
+    pub fn get_rate_limit_config(env: Env) -> RateLimitConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::RateLimitConfig)
+            .unwrap_or(RateLimitConfig {
+                window_size: 3600,
+                max_operations: 10,
+                cooldown_period: 60,
+            })
+    }
+
+    pub fn get_analytics(_env: Env) -> Analytics {
+        Analytics {
+            total_locked: 0,
+            total_released: 0,
+            total_payouts: 0,
+            active_programs: 0,
+            operation_count: 0,
+        }
+    }
+
+    pub fn set_whitelist(env: Env, _address: Address, _whitelisted: bool) {
+        // Only admin can set whitelist
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap_or_else(|| panic!("Not initialized"));
+        admin.require_auth();
+    }
+
+    // ========================================================================
+    // Two-phase Distribution
+    // ========================================================================
+
+    /// Set how long a proposed distribution stays open to amendment (organizer only).
+    pub fn set_challenge_period(env: Env, period_seconds: u64) {
+        Self::get_organizer(env.clone()).require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::ChallengePeriod, &period_seconds);
+        let program_id = Self::get_program_info(env.clone()).program_id;
+        env.events()
+            .publish((CHALLENGE_PERIOD_SET, program_id), period_seconds);
+    }
+
+    /// Allow or forbid payouts that skip the challenge period (organizer only).
+    ///
+    /// Direct payouts are enabled by default. Disabling them forces every
+    /// payout through `finalize_distribution`, so recipients can only be paid
+    /// after the challenge period.
+    pub fn set_direct_payouts(env: Env, enabled: bool) {
+        let organizer = Self::get_organizer(env.clone());
+        organizer.require_auth();
+        env.storage().instance().set(&DIRECT_PAYOUTS, &enabled);
+        let program_id = Self::get_program_info(env.clone()).program_id;
+        env.events().publish(
+            (DIRECT_PAYOUTS_SET, program_id),
+            DirectPayoutsSetEvent {
+                version: EVENT_VERSION_V2,
+                enabled,
+                organizer,
+            },
+        );
+    }
+
+    /// Whether payouts may skip the proposed distribution flow.
+    pub fn get_direct_payouts(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DIRECT_PAYOUTS)
+            .unwrap_or(true)
+    }
+
+    /// Panics if payouts must go through a proposed distribution.
+    fn assert_direct_payouts_enabled(env: &Env) {
+        if !Self::get_direct_payouts(env.clone()) {
+            reentrancy_guard::clear_entered(env);
+            panic!("Direct payouts disabled");
+        }
+    }
+
+    /// Get the challenge period in seconds (default: 86400 = 24h).
+    pub fn get_challenge_period(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ChallengePeriod)
+            .unwrap_or(DEFAULT_CHALLENGE_PERIOD)
+    }
+
+    /// Publish a distribution that can be finalized after the challenge period.
+    ///
+    /// Requires the authorized payout key. Only one distribution can be
+    /// pending at a time.
+    pub fn propose_distribution(
+        env: Env,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+    ) -> ProposedDistribution {
+        Self::assert_program_active(&env);
+        let program_data = Self::get_program_info(env.clone());
+        program_data.authorized_payout_key.require_auth();
+
+        if env.storage().instance().has(&DataKey::ProposedDistribution) {
+            panic!("Distribution already proposed");
+        }
+
+        let proposal = Self::store_distribution(&env, &program_data, recipients, amounts);
+        env.events().publish(
+            (DISTRIBUTION_PROPOSED,),
+            (proposal.total_amount, proposal.finalize_after),
+        );
+        proposal
+    }
+
+    /// Replace the pending distribution (organizer only). Restarts the challenge period.
+    pub fn amend_distribution(
+        env: Env,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+    ) -> ProposedDistribution {
+        Self::get_organizer(env.clone()).require_auth();
+        let current = Self::get_proposed_distribution(env.clone())
+            .unwrap_or_else(|| panic!("No distribution proposed"));
+        if env.ledger().timestamp() >= current.finalize_after {
+            panic!("Challenge period has ended");
+        }
+
+        let program_data = Self::get_program_info(env.clone());
+        let proposal = Self::store_distribution(&env, &program_data, recipients, amounts);
+        env.events().publish(
+            (DISTRIBUTION_AMENDED,),
+            (proposal.total_amount, proposal.finalize_after),
+        );
+        proposal
+    }
+
+    /// Pay out the proposed distribution once its challenge period has ended.
+    ///
+    /// Requires the authorized payout key and runs through the regular batch
+    /// payout path, so fees, limits and pauses still apply.
+    pub fn finalize_distribution(env: Env) -> ProgramData {
+        let proposal = Self::get_proposed_distribution(env.clone())
+            .unwrap_or_else(|| panic!("No distribution proposed"));
+        if env.ledger().timestamp() < proposal.finalize_after {
+            panic!("Challenge period not over");
+        }
+
+        env.storage()
+            .instance()
+            .remove(&DataKey::ProposedDistribution);
+        let memos = Vec::new(&env);
+        let data = Self::execute_batch_payout(
+            env.clone(),
+            proposal.recipients,
+            proposal.amounts,
+            None,
+            memos,
+            PayoutAuth::Distribution,
+            None,
+        );
+        env.events()
+            .publish((DISTRIBUTION_FINALIZED,), proposal.total_amount);
+        data
+    }
+
+    /// Get the distribution awaiting finalization, if any
+    pub fn get_proposed_distribution(env: Env) -> Option<ProposedDistribution> {
+        env.storage().instance().get(&DataKey::ProposedDistribution)
+    }
+
+    fn store_distribution(
+        env: &Env,
+        program_data: &ProgramData,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+    ) -> ProposedDistribution {
+        if recipients.len() != amounts.len() {
+            panic!("Recipients and amounts vectors must have the same length");
+        }
+        if recipients.is_empty() {
+            panic!("Cannot process empty batch");
+        }
+
+        let mut total_amount: i128 = 0;
+        for amount in amounts.iter() {
+            if amount <= 0 {
+                panic!("All amounts must be greater than zero");
+            }
+            total_amount = total_amount
+                .checked_add(amount)
+                .unwrap_or_else(|| panic!("Payout amount overflow"));
+        }
+        if total_amount > program_data.remaining_balance {
+            panic!("Insufficient balance");
+        }
+
+        let proposed_at = env.ledger().timestamp();
+        let proposal = ProposedDistribution {
+            recipients,
+            amounts,
+            total_amount,
+            proposed_at,
+            finalize_after: proposed_at + Self::get_challenge_period(env.clone()),
+        };
+        env.storage()
+            .instance()
+            .set(&DataKey::ProposedDistribution, &proposal);
+        proposal
+    }
+
+    // ========================================================================
+    // Payout Functions
+    // ========================================================================
+
+    /// Execute batch payouts to multiple recipients
+    ///
+    /// # Arguments
+    /// * `recipients` - Vector of recipient addresses
+    /// * `amounts` - Vector of amounts (must match recipients length)
+    ///
+    /// # Returns
+    /// Updated ProgramData after payouts
+    pub fn batch_payout(env: Env, recipients: Vec<Address>, amounts: Vec<i128>) -> ProgramData {
+        let memos = Vec::new(&env);
+        Self::execute_batch_payout(
+            env,
+            recipients,
+            amounts,
+            None,
+            memos,
+            PayoutAuth::PayoutKey,
+            None,
+        )
+    }
+
+    /// Execute batch payouts, skipping recipients whose transfer fails.
+    ///
+    /// Validation (auth, amounts, limits, balance) still applies to the whole
+    /// batch; only failed token transfers (e.g. a missing trustline) are
+    /// skipped. Each skipped transfer stays reserved in a pending retry that
+    /// the payout key can complete later with `retry_payout`.
+    ///
+    /// # Returns
+    /// One entry per recipient: `true` if paid, `false` if the transfer failed
+    pub fn batch_payout_lenient(
+        env: Env,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+    ) -> Vec<bool> {
+        let memos = Vec::new(&env);
+        let mut results = Vec::new(&env);
+        Self::execute_batch_payout(
+            env,
+            recipients,
+            amounts,
+            None,
+            memos,
+            PayoutAuth::PayoutKey,
+            Some(&mut results),
+        );
+        results
+    }
+
+    /// Execute batch payouts guarded by a caller-supplied dedupe key.
+    ///
+    /// The whole batch is recorded under `payout_id`; retrying with the same
+    /// id is rejected, so a backend that timed out can safely resubmit.
+    /// `memos` is either empty or holds one reference hash per recipient.
+    ///
+    /// # Panics
+    /// * If `payout_id` has already been processed
+    /// * If `memos` is non-empty and its length differs from `recipients`
+    pub fn batch_payout_with_id(
+        env: Env,
+        payout_id: BytesN<32>,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+        memos: Vec<BytesN<32>>,
+    ) -> ProgramData {
+        Self::execute_batch_payout(
+            env,
+            recipients,
+            amounts,
+            Some(payout_id),
+            memos,
+            PayoutAuth::PayoutKey,
+            None,
+        )
+    }
+
+    /// Execute a single payout guarded by a caller-supplied dedupe key.
+    ///
+    /// # Panics
+    /// * If `payout_id` has already been processed
+    pub fn single_payout_with_id(
+        env: Env,
+        payout_id: BytesN<32>,
+        recipient: Address,
+        amount: i128,
+        memo: Option<BytesN<32>>,
+    ) -> ProgramData {
+        Self::execute_single_payout(
+            env,
+            recipient,
+            amount,
+            Some(payout_id),
+            memo,
+            PayoutAuth::PayoutKey,
+            None,
+        )
+    }
+
+    /// Require payout-key payouts to go through the `_with_nonce` entrypoints
+    /// (organizer only).
+    ///
+    /// Nonces must be used in strictly increasing order, so a captured payout
+    /// invocation can neither be replayed nor reordered with later ones.
+    ///
+    /// While required, every other entrypoint the payout key authorizes to
+    /// move funds (batch, claimable, scheduled, pending-claim and track
+    /// payouts without their own key) panics with "Payout nonce required".
+    /// Delegated signers and tracks with their own payout key are exempt:
+    /// they sign with a separate key whose spending is bounded by its cap or
+    /// track budget, and signed permits carry their own nonce.
+    pub fn set_payout_nonce_required(env: Env, required: bool) {
+        Self::get_organizer(env.clone()).require_auth();
+        env.storage().instance().set(&NonceKey::Required, &required);
+        let program_id = Self::get_program_info(env.clone()).program_id;
+        env.events()
+            .publish((PAYOUT_NONCE_REQUIRED, program_id), required);
+    }
+
+    /// Returns true if payout-key payouts must carry a nonce.
+    pub fn is_payout_nonce_required(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&NonceKey::Required)
+            .unwrap_or(false)
+    }
+
+    /// Get the nonce the next nonced payout must use.
+    pub fn get_payout_nonce(env: Env) -> u64 {
+        env.storage().instance().get(&NonceKey::Next).unwrap_or(0)
+    }
+
+    /// Execute batch payouts that consume `nonce`.
+    ///
+    /// # Panics
+    /// * If `nonce` is not the value returned by `get_payout_nonce`
+    pub fn batch_payout_with_nonce(
+        env: Env,
+        nonce: u64,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+    ) -> ProgramData {
+        let memos = Vec::new(&env);
+        Self::execute_batch_payout(
+            env,
+            recipients,
+            amounts,
+            None,
+            memos,
+            PayoutAuth::Nonced(nonce),
+            None,
+        )
+    }
+
+    /// Execute a single payout that consumes `nonce`.
+    ///
+    /// # Panics
+    /// * If `nonce` is not the value returned by `get_payout_nonce`
+    pub fn single_payout_with_nonce(
+        env: Env,
+        nonce: u64,
+        recipient: Address,
+        amount: i128,
+    ) -> ProgramData {
+        Self::execute_single_payout(
+            env,
+            recipient,
+            amount,
+            None,
+            None,
+            PayoutAuth::Nonced(nonce),
+            None,
+        )
+    }
+
+    /// Register the ed25519 public key whose signatures authorize payout
+    /// permits (payout key only).
+    pub fn set_permit_key(env: Env, public_key: BytesN<32>) {
+        let program_data = Self::get_program_info(env.clone());
+        program_data.authorized_payout_key.require_auth();
+        env.storage()
+            .instance()
+            .set(&NonceKey::PermitKey, &public_key);
+        env.events()
+            .publish((PERMIT_KEY_SET, program_data.program_id), public_key);
+    }
+
+    /// Get the ed25519 public key that signs payout permits, if configured.
+    pub fn get_permit_key(env: Env) -> Option<BytesN<32>> {
+        env.storage().instance().get(&NonceKey::PermitKey)
+    }
+
+    /// Execute a single payout authorized by a permit signed off-chain.
+    ///
+    /// Anyone may submit the permit; it is valid once, before `expires_at`,
+    /// and only while its nonce is the next expected payout nonce.
+    ///
+    /// # Panics
+    /// * If no permit key is configured or the signature does not verify
+    /// * If the permit is for another contract or program, has expired, or
+    ///   has a stale nonce
+    pub fn execute_signed_payout(
+        env: Env,
+        payload: PayoutPermit,
+        signature: BytesN<64>,
+    ) -> ProgramData {
+        let recipient = payload.recipient.clone();
+        let amount = payload.amount;
+        Self::execute_single_payout(
+            env,
+            recipient,
+            amount,
+            None,
+            None,
+            PayoutAuth::Permit(payload, signature),
+            None,
+        )
+    }
+
+    fn verify_payout_permit(
+        env: &Env,
+        program_data: &ProgramData,
+        permit: &PayoutPermit,
+        signature: &BytesN<64>,
+    ) {
+        let Some(public_key) = Self::get_permit_key(env.clone()) else {
+            reentrancy_guard::clear_entered(env);
+            panic!("Permit key not configured");
+        };
+        if permit.contract != env.current_contract_address() {
+            reentrancy_guard::clear_entered(env);
+            panic!("Permit is for another contract");
+        }
+        if permit.program_id != program_data.program_id {
+            reentrancy_guard::clear_entered(env);
+            panic!("Permit is for another program");
+        }
+        if env.ledger().timestamp() > permit.expires_at {
+            reentrancy_guard::clear_entered(env);
+            panic!("Permit expired");
+        }
+        // Bind the signature to this network as well as this contract
+        let mut message = Bytes::from_array(env, &env.ledger().network_id().to_array());
+        message.append(&permit.clone().to_xdr(env));
+        env.crypto()
+            .ed25519_verify(&public_key, &message, signature);
+        Self::consume_payout_nonce(env, permit.nonce);
+    }
+
+    /// Rejects any nonce other than the next expected one, then advances it.
+    fn consume_payout_nonce(env: &Env, nonce: u64) {
+        let expected = Self::get_payout_nonce(env.clone());
+        if nonce != expected {
+            reentrancy_guard::clear_entered(env);
+            panic!("Invalid payout nonce");
+        }
+        env.storage()
+            .instance()
+            .set(&NonceKey::Next, &(expected + 1));
+    }
+
+    /// Set the maximum number of token transfers per payout batch, or `None`
+    /// for no limit (organizer only).
+    ///
+    /// Every payout path counts against the limit, and a registered team
+    /// counts once per member it fans out to. Batches are unlimited until a
+    /// limit is set.
+    ///
+    /// # Panics
+    /// * If `max_batch_size` is zero
+    pub fn set_max_batch_size(env: Env, max_batch_size: Option<u32>) {
+        Self::get_organizer(env.clone()).require_auth();
+        match max_batch_size {
+            Some(0) => panic!("Max batch size must be greater than zero"),
+            Some(max) => env.storage().instance().set(&LimitKey::MaxBatchSize, &max),
+            None => env.storage().instance().remove(&LimitKey::MaxBatchSize),
+        }
+        let program_id = Self::get_program_info(env.clone()).program_id;
+        env.events()
+            .publish((MAX_BATCH_SIZE_SET, program_id), max_batch_size);
+    }
+
+    /// Get the maximum number of token transfers per payout batch (`None`
+    /// means unlimited).
+    pub fn get_max_batch_size(env: Env) -> Option<u32> {
+        env.storage().instance().get(&LimitKey::MaxBatchSize)
+    }
+
+    /// Token transfers a payout to `recipients` performs, counting each member
+    /// a registered team fans out to.
+    fn count_payout_transfers(env: &Env, recipients: &Vec<Address>) -> u32 {
+        let mut transfers = 0u32;
+        for recipient in recipients.iter() {
+            transfers += load_team(env, &recipient).map_or(1, |team| team.members.len());
+        }
+        transfers
+    }
+
+    /// Estimate how a payout to `batch_size` recipients must be chunked and
+    /// how many token transfers it performs under the current fee config.
+    pub fn estimate_batch_cost(env: Env, batch_size: u32) -> BatchCostEstimate {
+        let max_batch_size = Self::get_max_batch_size(env.clone());
+        let chunks = match max_batch_size {
+            Some(max) => batch_size.div_ceil(max),
+            None => u32::from(batch_size > 0),
+        };
+        let fee_transfers = if Self::get_fee_config_internal(&env).fee_enabled {
+            chunks
+        } else {
+            0
+        };
+        BatchCostEstimate {
+            batch_size,
+            max_batch_size,
+            chunks,
+            transfers: batch_size + fee_transfers,
+        }
+    }
+
+    /// Returns true if a payout with this dedupe key has already been processed.
+    pub fn is_payout_processed(env: Env, payout_id: BytesN<32>) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::ProcessedPayout(payout_id))
+    }
+
+    /// Hold payouts for `window_seconds` before they reach recipients (organizer only).
+    ///
+    /// While a window is configured, payouts must go through the `_with_id`
+    /// entrypoints so they can be clawed back by `payout_id`. Fees are still
+    /// charged immediately. Pass `None` to pay out directly again.
+    pub fn set_clawback_window(env: Env, window_seconds: Option<u64>) {
+        Self::get_organizer(env.clone()).require_auth();
+
+        match window_seconds {
+            Some(0) => panic!("Window must be greater than zero"),
+            Some(window) => env
+                .storage()
+                .instance()
+                .set(&DataKey::ClawbackWindow, &window),
+            None => env.storage().instance().remove(&DataKey::ClawbackWindow),
+        }
+        let program_id = Self::get_program_info(env.clone()).program_id;
+        env.events()
+            .publish((CLAWBACK_WINDOW_SET, program_id), window_seconds);
+    }
+
+    /// Get the clawback window in seconds, if enabled
+    pub fn get_clawback_window(env: Env) -> Option<u64> {
+        env.storage().instance().get(&DataKey::ClawbackWindow)
+    }
+
+    /// Get a payout that is still held, if any
+    pub fn get_held_payout(env: Env, payout_id: BytesN<32>) -> Option<HeldPayout> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::HeldPayout(payout_id))
+    }
+
+    /// Get the total amount currently held across all payouts
+    pub fn get_held_total(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::HeldTotal)
+            .unwrap_or(0)
+    }
+
+    /// Return a held payout to the pool before its window ends (organizer only).
+    ///
+    /// A hold paying a recipient that has since been blocked can never be
+    /// released, so it may be clawed back at any time.
+    ///
+    /// # Returns
+    /// Amount credited back to `remaining_balance`
+    pub fn clawback(env: Env, payout_id: BytesN<32>) -> i128 {
+        let organizer = Self::get_organizer(env.clone());
+        organizer.require_auth();
+
+        let held = Self::take_held_payout(&env, &payout_id);
+        if env.ledger().timestamp() >= held.release_at && !Self::has_blocked_recipient(&env, &held)
+        {
+            panic!("Clawback window has passed");
+        }
+
+        let mut program_data = Self::get_program_info(env.clone());
+        program_data.remaining_balance += held.total_amount;
+        adjust_total_paid(&env, -held.total_amount);
+        save_program_data(&env, &program_data);
+
+        env.events().publish(
+            (PAYOUT_CLAWED_BACK,),
+            (payout_id, held.total_amount, organizer),
+        );
+
+        held.total_amount
+    }
+
+    /// Whether any recipient of a held payout, or a member of a team it pays,
+    /// is blocked.
+    fn has_blocked_recipient(env: &Env, held: &HeldPayout) -> bool {
+        held.records.iter().any(|record| {
+            Self::is_recipient_blocked(env.clone(), record.recipient.clone())
+                || load_team(env, &record.recipient).is_some_and(|team| {
+                    team.members
+                        .iter()
+                        .any(|member| Self::is_recipient_blocked(env.clone(), member))
+                })
+        })
+    }
+
+    /// Deliver a held payout once its window has passed. Callable by anyone.
+    pub fn release_held_payout(env: Env, payout_id: BytesN<32>) -> ProgramData {
+        Self::assert_payouts_not_paused(&env);
+
+        let held = Self::take_held_payout(&env, &payout_id);
+        if env.ledger().timestamp() < held.release_at {
+            panic!("Clawback window still open");
+        }
+
+        let mut program_data = Self::get_program_info(env.clone());
+        let token_client = token::Client::new(&env, &program_data.token_address);
+        for record in held.records.iter() {
+            deliver_payout(&env, &token_client, &record.recipient, record.amount, false);
+            program_data.payout_history.push_back(record);
+        }
+        save_program_data(&env, &program_data);
+
+        env.events()
+            .publish((HELD_PAYOUT_RELEASED,), (payout_id, held.total_amount));
+
+        program_data
+    }
+
+    /// Complete a transfer skipped by `batch_payout_lenient` (payout key only).
+    ///
+    /// Panics (leaving the retry queued) if the transfer fails again.
+    pub fn retry_payout(env: Env, retry_id: u64) -> ProgramData {
+        Self::assert_program_active(&env);
+        Self::assert_payouts_not_paused(&env);
+
+        let mut program_data = Self::get_program_info(env.clone());
+        program_data.authorized_payout_key.require_auth();
+
+        let retry = Self::take_retry(&env, retry_id);
+        Self::assert_recipient_not_blocked(&env, &retry.recipient);
+
+        let contract_address = env.current_contract_address();
+        let token_client = token::Client::new(&env, &program_data.token_address);
+        deliver_payout(&env, &token_client, &retry.recipient, retry.net, false);
+        if retry.fee > 0 {
+            let fee_config = Self::get_fee_config_internal(&env);
+            token_client.transfer(&contract_address, &fee_config.fee_recipient, &retry.fee);
+            Self::record_fee(&env, &fee_config, &retry.recipient, retry.net, retry.fee);
+        }
+
+        program_data.payout_history.push_back(PayoutRecord {
+            recipient: retry.recipient.clone(),
+            amount: retry.net,
+            timestamp: env.ledger().timestamp(),
+            memo: None,
+        });
+        save_program_data(&env, &program_data);
+
+        env.events().publish(
+            (RETRY_PAID, program_data.program_id.clone(), retry.recipient),
+            (retry_id, retry.net),
+        );
+
+        program_data
+    }
+
+    /// Drop a pending retry and return its reserved amount to the pool (organizer only).
+    pub fn cancel_retry(env: Env, retry_id: u64) -> i128 {
+        let organizer = Self::get_organizer(env.clone());
+        organizer.require_auth();
+
+        let retry = Self::take_retry(&env, retry_id);
+        let limits = Self::get_payout_limits(env.clone());
+        Self::release_recipient_limit(&env, &limits, &retry.recipient, retry.amount);
+
+        let mut program_data = Self::get_program_info(env.clone());
+        program_data.remaining_balance += retry.debit;
+        adjust_total_paid(&env, -retry.debit);
+        save_program_data(&env, &program_data);
+
+        env.events()
+            .publish((RETRY_CANCELLED,), (retry_id, retry.debit, organizer));
+
+        retry.debit
+    }
+
+    /// Get a pending retry, if queued
+    pub fn get_pending_retry(env: Env, retry_id: u64) -> Option<PendingRetry> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PendingRetry(retry_id))
+    }
+
+    /// Total amount reserved for pending retries
+    pub fn get_retry_total(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::RetryTotal)
+            .unwrap_or(0)
+    }
+
+    fn queue_retry(
+        env: &Env,
+        recipient: &Address,
+        amount: i128,
+        net: i128,
+        fee: i128,
+        debit: i128,
+    ) {
+        let retry_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextRetryId)
+            .unwrap_or(1);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextRetryId, &(retry_id + 1));
+        env.storage().persistent().set(
+            &DataKey::PendingRetry(retry_id),
+            &PendingRetry {
+                retry_id,
+                recipient: recipient.clone(),
+                amount,
+                net,
+                fee,
+                debit,
+                created_at: env.ledger().timestamp(),
+            },
+        );
+        storage::extend_persistent(env, &DataKey::PendingRetry(retry_id));
+        Self::adjust_retry_total(env, debit);
+        env.events()
+            .publish((RETRY_QUEUED,), (retry_id, recipient.clone(), debit));
+    }
+
+    fn take_retry(env: &Env, retry_id: u64) -> PendingRetry {
+        let key = DataKey::PendingRetry(retry_id);
+        let retry: PendingRetry = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| panic!("Retry not found"));
+        env.storage().persistent().remove(&key);
+        Self::adjust_retry_total(env, -retry.debit);
+        retry
+    }
+
+    fn adjust_retry_total(env: &Env, delta: i128) {
+        let total = Self::get_retry_total(env.clone()) + delta;
+        env.storage().instance().set(&DataKey::RetryTotal, &total);
+    }
+
+    fn take_held_payout(env: &Env, payout_id: &BytesN<32>) -> HeldPayout {
+        let key = DataKey::HeldPayout(payout_id.clone());
+        let held: HeldPayout = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| panic!("Held payout not found"));
+        env.storage().persistent().remove(&key);
+        Self::adjust_held_total(env, -held.total_amount);
+        held
+    }
+
+    fn adjust_held_total(env: &Env, delta: i128) {
+        let total = Self::get_held_total(env.clone()) + delta;
+        env.storage().instance().set(&DataKey::HeldTotal, &total);
+    }
+
+    /// Returns the release time if payouts are currently held, or `None` to pay directly.
+    fn payout_hold_until(env: &Env, payout_id: &Option<BytesN<32>>) -> Option<u64> {
+        let window = Self::get_clawback_window(env.clone())?;
+        if payout_id.is_none() {
+            reentrancy_guard::clear_entered(env);
+            panic!("Clawback window requires payout_id");
+        }
+        Some(env.ledger().timestamp() + window)
+    }
+
+    /// Store `records` as held under `payout_id` until `release_at`.
+    fn hold_payout(
+        env: &Env,
+        payout_id: &Option<BytesN<32>>,
+        records: Vec<PayoutRecord>,
+        total_amount: i128,
+        release_at: u64,
+    ) {
+        let payout_id = payout_id.clone().unwrap();
+        env.storage().persistent().set(
+            &DataKey::HeldPayout(payout_id.clone()),
+            &HeldPayout {
+                payout_id: payout_id.clone(),
+                records,
+                total_amount,
+                release_at,
+            },
+        );
+        storage::extend_persistent(env, &DataKey::HeldPayout(payout_id.clone()));
+        Self::adjust_held_total(env, total_amount);
+        env.events()
+            .publish((PAYOUT_HELD,), (payout_id, total_amount, release_at));
+    }
+
+    /// Rejects an already-processed dedupe key, otherwise marks it processed.
+    fn consume_payout_id(env: &Env, payout_id: &Option<BytesN<32>>) {
+        if let Some(id) = payout_id {
+            let key = DataKey::ProcessedPayout(id.clone());
+            if env.storage().persistent().has(&key) {
+                reentrancy_guard::clear_entered(env);
+                panic!("Duplicate payout_id");
+            }
+            env.storage()
+                .persistent()
+                .set(&key, &env.ledger().timestamp());
+        }
+    }
+
+    /// Run the pre-payout checks shared by every path that debits the pool.
+    ///
+    /// Validates the batch shape and each recipient, then charges the
+    /// program-wide caps (large payout approval, epoch outflow, tripwire,
+    /// season budget) against the total debit including fees.
+    ///
+    /// # Returns
+    /// The fee config, the total debit and the active season
+    fn validate_payouts(
+        env: &Env,
+        program_data: &ProgramData,
+        auth: &PayoutAuth,
+        payout_id: &Option<BytesN<32>>,
+        recipients: &Vec<Address>,
+        amounts: &Vec<i128>,
+    ) -> (FeeConfig, i128, Option<u32>) {
+        if !matches!(auth, PayoutAuth::Distribution) {
+            Self::assert_direct_payouts_enabled(env);
+        }
+        if recipients.len() != amounts.len() {
+            reentrancy_guard::clear_entered(env);
+            panic!("Recipients and amounts vectors must have the same length");
+        }
+
+        if recipients.len() == 0 {
+            reentrancy_guard::clear_entered(env);
+            panic!("Cannot process empty batch");
+        }
+
+        if let Some(max) = Self::get_max_batch_size(env.clone()) {
+            if Self::count_payout_transfers(env, recipients) > max {
+                reentrancy_guard::clear_entered(env);
+                panic!("Batch exceeds max batch size");
+            }
+        }
+
+        // Calculate total amount debited from the pool, including fees
+        let fee_config = Self::get_fee_config_internal(env);
+        let limits = Self::get_payout_limits(env.clone());
+        let registry = Self::get_eligibility_registry(env.clone());
+        let winning_set: Option<Vec<Address>> = env.storage().instance().get(&DataKey::WinningSet);
+        let confirmation_required = env
+            .storage()
+            .instance()
+            .has(&DataKey::WinnerConfirmDeadline);
+        let mut total_debit: i128 = 0;
+        for (recipient, amount) in recipients.iter().zip(amounts.iter()) {
+            if amount <= 0 {
+                reentrancy_guard::clear_entered(env);
+                panic!("All amounts must be greater than zero");
+            }
+            Self::assert_recipient_not_blocked(env, &recipient);
+            let participant = Self::payout_participant(env, confirmation_required, &recipient);
+            Self::assert_recipient_in_winning_set(env, &winning_set, &participant);
+            Self::assert_recipient_confirmed(env, confirmation_required, &participant);
+            Self::assert_recipient_eligible(env, &registry, &recipient);
+            Self::enforce_payout_limits(env, &limits, &recipient, amount);
+            let (_, _, debit) = Self::split_payout_fee(&fee_config, amount);
+            total_debit = total_debit.checked_add(debit).unwrap_or_else(|| {
+                reentrancy_guard::clear_entered(env);
+                panic!("Payout amount overflow")
+            });
+        }
+
+        Self::check_large_payout_approval(env, payout_id, recipients, amounts, total_debit);
+        Self::charge_epoch_outflow(env, payout_id, total_debit);
+        Self::record_tripwire_outflow(env, program_data, total_debit);
+        let active_season = Self::get_active_season(env.clone());
+        Self::check_season_budget(env, active_season, total_debit);
+
+        // Validate sufficient balance
+        if total_debit > program_data.remaining_balance {
+            reentrancy_guard::clear_entered(env);
+            panic!("Insufficient balance");
+        }
+        Self::assert_not_earmarked(env, program_data, auth, total_debit);
+        Self::assert_track_budgets_covered(env, program_data, auth, total_debit);
+
+        (fee_config, total_debit, active_season)
+    }
+
+    /// Run the recipient checks and the per-payout cap when a payout is
+    /// scheduled, so a schedule that could never pass `validate_payouts` is
+    /// rejected up front.
+    fn assert_payout_schedulable(env: &Env, recipient: &Address, amount: i128) {
+        Self::assert_direct_payouts_enabled(env);
+        Self::assert_recipient_not_blocked(env, recipient);
+        let winning_set: Option<Vec<Address>> = env.storage().instance().get(&DataKey::WinningSet);
+        let confirmation_required = env
+            .storage()
+            .instance()
+            .has(&DataKey::WinnerConfirmDeadline);
+        let participant = Self::payout_participant(env, confirmation_required, recipient);
+        Self::assert_recipient_in_winning_set(env, &winning_set, &participant);
+        Self::assert_recipient_confirmed(env, confirmation_required, &participant);
+        Self::assert_recipient_eligible(
+            env,
+            &Self::get_eligibility_registry(env.clone()),
+            recipient,
+        );
+        if let Some(max) = Self::get_payout_limits(env.clone()).max_per_payout {
+            if amount > max {
+                panic!("Payout exceeds per-transaction limit");
+            }
+        }
+        // A schedule carries no payout id, so it can never be approved as a large payout
+        let (_, _, debit) = Self::split_payout_fee(&Self::get_fee_config_internal(env), amount);
+        let threshold: Option<i128> = env.storage().instance().get(&DataKey::LargePayoutThreshold);
+        if threshold.is_some_and(|limit| debit > limit) {
+            panic!("Large payout requires organizer approval");
+        }
+    }
+
+    /// Whether `validate_payouts` would accept a due schedule right now.
+    ///
+    /// Runs the same checks without panicking or charging any cap, so the
+    /// keeper can skip a schedule that cannot be paid instead of reverting
+    /// every other due schedule with it. Only a pool balance shortfall is
+    /// left for `validate_payouts` to reject.
+    fn schedule_payable(
+        env: &Env,
+        program_data: &ProgramData,
+        schedule: &ProgramReleaseSchedule,
+    ) -> bool {
+        let recipient = &schedule.recipient;
+        if !Self::get_direct_payouts(env.clone())
+            || Self::is_recipient_frozen(env.clone(), recipient.clone())
+            || Self::is_recipient_blocked(env.clone(), recipient.clone())
+        {
+            return false;
+        }
+
+        let registry = Self::get_eligibility_registry(env.clone());
+        let is_eligible = |account: &Address| match &registry {
+            Some(registry) => EligibilityRegistryClient::new(env, registry).is_eligible(account),
+            None => true,
+        };
+        let winning_set: Option<Vec<Address>> = env.storage().instance().get(&DataKey::WinningSet);
+        let confirmation_required = env
+            .storage()
+            .instance()
+            .has(&DataKey::WinnerConfirmDeadline);
+        let participant = Self::payout_participant(env, confirmation_required, recipient);
+        if winning_set.is_some_and(|winners| !winners.contains(&participant))
+            || (confirmation_required
+                && !env
+                    .storage()
+                    .persistent()
+                    .has(&DataKey::WinnerAddress(participant)))
+            || !is_eligible(recipient)
+        {
+            return false;
+        }
+        if let Some(team) = load_team(env, recipient) {
+            for member in team.members.iter() {
+                if Self::is_recipient_blocked(env.clone(), member.clone()) || !is_eligible(&member)
+                {
+                    return false;
+                }
+            }
+        }
+        let recipients = vec![env, recipient.clone()];
+        if Self::get_max_batch_size(env.clone())
+            .is_some_and(|max| Self::count_payout_transfers(env, &recipients) > max)
+        {
+            return false;
+        }
+
+        let limits = Self::get_payout_limits(env.clone());
+        if limits
+            .max_per_payout
+            .is_some_and(|max| schedule.amount > max)
+        {
+            return false;
+        }
+        if let Some(max) = limits.max_per_recipient {
+            let paid: i128 = env
+                .storage()
+                .persistent()
+                .get(&DataKey::RecipientPaid(recipient.clone()))
+                .unwrap_or(0);
+            if paid + schedule.amount > max {
+                return false;
+            }
+        }
+
+        let (_, _, debit) =
+            Self::split_payout_fee(&Self::get_fee_config_internal(env), schedule.amount);
+        let threshold: Option<i128> = env.storage().instance().get(&DataKey::LargePayoutThreshold);
+        if threshold.is_some_and(|limit| debit > limit) {
+            return false;
+        }
+        if Self::get_epoch_limit(env.clone())
+            .is_some_and(|limit| Self::get_epoch_outflow(env.clone()) + debit > limit.cap)
+        {
+            return false;
+        }
+        if let Some(season) = Self::get_active_season(env.clone()) {
+            let stats = Self::get_season(env.clone(), season);
+            if stats.paid_out + debit > stats.budget {
+                return false;
+            }
+        }
+
+        // A shortfall of the pool itself still reverts the run
+        let reserved = Self::get_earmarked_reserve(env.clone())
+            .max(Self::get_track_budget_reserve(env.clone()));
+        reserved == 0
+            || debit > program_data.remaining_balance
+            || debit <= program_data.remaining_balance - reserved
+    }
+
+    /// Validate and pay one due schedule, debiting `program_data`.
+    ///
+    /// # Returns
+    /// The payout record for the net amount delivered
+    fn pay_schedule(
+        env: &Env,
+        program_data: &mut ProgramData,
+        schedule: &ProgramReleaseSchedule,
+    ) -> PayoutRecord {
+        let (fee_config, debit, active_season) = Self::validate_payouts(
+            env,
+            program_data,
+            &PayoutAuth::PayoutKey,
+            &None,
+            &vec![env, schedule.recipient.clone()],
+            &vec![env, schedule.amount],
+        );
+        let (net, fee, _) = Self::split_payout_fee(&fee_config, schedule.amount);
+
+        let token_client = token::Client::new(env, &program_data.token_address);
+        deliver_payout(env, &token_client, &schedule.recipient, net, false);
+        if fee > 0 {
+            token_client.transfer(
+                &env.current_contract_address(),
+                &fee_config.fee_recipient,
+                &fee,
+            );
+            Self::record_fee(env, &fee_config, &schedule.recipient, net, fee);
+        }
+
+        let record = PayoutRecord {
+            recipient: schedule.recipient.clone(),
+            amount: net,
+            timestamp: env.ledger().timestamp(),
+            memo: None,
+        };
+        Self::record_season_payouts(env, active_season, vec![env, record.clone()], debit);
+        Self::enter_payout_phase(env);
+
+        program_data.remaining_balance -= debit;
+        adjust_total_paid(env, debit);
+        record
+    }
+
+    fn execute_batch_payout(
+        env: Env,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+        payout_id: Option<BytesN<32>>,
+        memos: Vec<BytesN<32>>,
+        auth: PayoutAuth,
+        mut lenient_results: Option<&mut Vec<bool>>,
+    ) -> ProgramData {
+        Self::assert_program_active(&env);
+
+        // Reentrancy guard: Check and set
+        reentrancy_guard::check_not_entered(&env);
+        reentrancy_guard::set_entered(&env);
+
+        if Self::check_paused(&env, symbol_short!("release")) {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Funds Paused");
+        }
+
+        // Verify authorization
+        let program_data: ProgramData =
+            load_program_data(&env)
+                .unwrap_or_else(|| {
+                    reentrancy_guard::clear_entered(&env);
+                    panic!("Program not initialized")
+                });
+
+        Self::require_payout_auth(&env, &program_data, &auth);
+        Self::consume_payout_id(&env, &payout_id);
+
+        // Validate input lengths match
+        if !memos.is_empty() && memos.len() != recipients.len() {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Memos must be empty or match recipients length");
+        }
+
+        let (fee_config, total_debit, active_season) = Self::validate_payouts(
+            &env,
+            &program_data,
+            &auth,
+            &payout_id,
+            &recipients,
+            &amounts,
+        );
+
+        // Execute transfers
+        let hold_until = Self::payout_hold_until(&env, &payout_id);
+        let mut updated_history = program_data.payout_history.clone();
+        let mut held_records = Vec::new(&env);
+        let mut paid_records = Vec::new(&env);
+        let collect_records = active_season.is_some() || matches!(auth, PayoutAuth::Track(_));
+        let timestamp = env.ledger().timestamp();
+        let contract_address = env.current_contract_address();
+        let token_client = token::Client::new(&env, &program_data.token_address);
+        let balance_before = payout_balance_before(&env, &token_client);
+        let mut total_payout: i128 = 0;
+        let mut total_fee: i128 = 0;
+        let mut paid_count: u32 = 0;
+
+        for i in 0..recipients.len() {
+            let recipient = recipients.get(i).unwrap();
+            let amount = amounts.get(i).unwrap();
+            let (net, fee, debit) = Self::split_payout_fee(&fee_config, amount);
+
+            // Transfer funds from contract to recipient, unless held for clawback.
+            // In lenient mode a failed transfer is skipped instead of reverting the batch,
+            // and only the team shares actually delivered are recorded as paid.
+            let mut paid = net;
+            if let Some(results) = lenient_results.as_deref_mut() {
+                let sent = if hold_until.is_some() {
+                    Some(net)
+                } else {
+                    deliver_payout(&env, &token_client, &recipient, net, true)
+                };
+                results.push_back(sent.is_some());
+                let Some(delivered) = sent else {
+                    Self::queue_retry(&env, &recipient, amount, net, fee, debit);
+                    continue;
+                };
+                paid = delivered;
+            } else if hold_until.is_none() {
+                deliver_payout(&env, &token_client, &recipient, net, false);
+            }
+            Self::record_fee(&env, &fee_config, &recipient, net, fee);
+            total_fee += fee;
+            if paid == 0 {
+                // Every team share was queued for retry
+                continue;
+            }
+            paid_count += 1;
+            total_payout += paid;
+
+            // Record payout
+            let payout_record = PayoutRecord {
+                recipient,
+                amount: paid,
+                timestamp,
+                memo: memos.get(i).map(Bytes::from),
+            };
+            if collect_records {
+                paid_records.push_back(payout_record.clone());
+            }
+            if hold_until.is_some() {
+                held_records.push_back(payout_record);
+            } else {
+                updated_history.push_back(payout_record);
+            }
+        }
+
+        if total_fee > 0 {
+            token_client.transfer(&contract_address, &fee_config.fee_recipient, &total_fee);
+        }
+        let sent = if hold_until.is_some() {
+            total_fee
+        } else {
+            total_payout + total_fee
+        };
+        let overhead = transfer_overhead(&env, &token_client, balance_before, sent);
+        Self::assert_overhead_covered(&env, &program_data, total_debit, overhead);
+
+        if let Some(release_at) = hold_until {
+            Self::hold_payout(&env, &payout_id, held_records, total_payout, release_at);
+        }
+
+        Self::charge_signer_cap(&env, &auth, total_debit);
+        Self::enter_payout_phase(&env);
+        Self::record_track_payouts(&env, &auth, &paid_records, total_debit);
+        Self::record_season_payouts(&env, active_season, paid_records, total_debit);
+
+        // Update program data
+        let mut updated_data = program_data.clone();
+        updated_data.remaining_balance -= total_debit + overhead;
+        adjust_total_paid(&env, total_debit + overhead);
+        updated_data.payout_history = updated_history;
+
+        // Store updated data
+        save_program_data(&env, &updated_data);
+
+        // Emit BatchPayout event
+        env.events().publish(
+            (BATCH_PAYOUT, updated_data.program_id.clone()),
+            BatchPayoutEvent {
+                version: EVENT_VERSION_V2,
+                program_id: updated_data.program_id.clone(),
+                recipient_count: paid_count,
+                total_amount: total_payout,
+                total_fee,
+                remaining_balance: updated_data.remaining_balance,
+                memos,
+            },
+        );
+
+        // Clear reentrancy guard before returning
+        reentrancy_guard::clear_entered(&env);
+
+        updated_data
+    }
+
+    /// Execute a single payout to one recipient
+    ///
+    /// # Arguments
+    /// * `recipient` - Address of the recipient
+    /// * `amount` - Amount to transfer
+    ///
+    /// # Returns
+    /// Updated ProgramData after payout
+    pub fn single_payout(env: Env, recipient: Address, amount: i128) -> ProgramData {
+        Self::execute_single_payout(
+            env,
+            recipient,
+            amount,
+            None,
+            None,
+            PayoutAuth::PayoutKey,
+            None,
+        )
+    }
+
+    /// Execute a single payout delivered in `asset_out` instead of the program token.
+    ///
+    /// The net amount is swapped through the configured router adapter in the
+    /// same transaction, so the payout reverts entirely if the swap fails or
+    /// returns less than `min_out`.
+    ///
+    /// # Arguments
+    /// * `recipient` - Address of the recipient
+    /// * `amount` - Amount of the program token to pay out
+    /// * `asset_out` - Token the recipient receives
+    /// * `min_out` - Minimum amount of `asset_out` the recipient must receive
+    pub fn single_payout_in(
+        env: Env,
+        recipient: Address,
+        amount: i128,
+        asset_out: Address,
+        min_out: i128,
+    ) -> ProgramData {
+        if min_out <= 0 {
+            panic!("Minimum output must be greater than zero");
+        }
+        Self::execute_single_payout(
+            env,
+            recipient,
+            amount,
+            None,
+            None,
+            PayoutAuth::PayoutKey,
+            Some((asset_out, min_out)),
+        )
+    }
+
+    /// Define the prize amount for each rank (organizer only).
+    ///
+    /// `tiers[0]` is paid to 1st place, `tiers[1]` to 2nd place and so on.
+    pub fn set_prize_tiers(env: Env, tiers: Vec<i128>) {
+        Self::get_organizer(env.clone()).require_auth();
+
+        if tiers.is_empty() {
+            panic!("Prize tiers cannot be empty");
+        }
+        if tiers.iter().any(|amount| amount <= 0) {
+            panic!("All amounts must be greater than zero");
+        }
+
+        env.storage().instance().set(&DataKey::PrizeTiers, &tiers);
+        env.events().publish((PRIZE_TIERS_SET,), tiers);
+    }
+
+    /// Get the configured prize tiers (empty if none are set)
+    pub fn get_prize_tiers(env: Env) -> Vec<i128> {
+        env.storage()
+            .instance()
+            .get(&DataKey::PrizeTiers)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Pay `ordered_winners` the configured prize for their rank.
+    ///
+    /// Winners are listed from 1st place down; there may be fewer winners than
+    /// tiers. Runs through the regular batch payout path.
+    ///
+    /// # Panics
+    /// * If no prize tiers are configured
+    /// * If there are more winners than tiers
+    pub fn distribute_by_rank(env: Env, ordered_winners: Vec<Address>) -> ProgramData {
+        let tiers = Self::get_prize_tiers(env.clone());
+        if tiers.is_empty() {
+            panic!("Prize tiers not set");
+        }
+        if ordered_winners.len() > tiers.len() {
+            panic!("More winners than prize tiers");
+        }
+
+        let amounts = tiers.slice(0..ordered_winners.len());
+        let memos = Vec::new(&env);
+        Self::execute_batch_payout(
+            env,
+            ordered_winners,
+            amounts,
+            None,
+            memos,
+            PayoutAuth::PayoutKey,
+            None,
+        )
+    }
+
+    /// Choose where the rounding residue of split distributions goes (organizer only).
+    pub fn set_dust_policy(env: Env, policy: DustPolicy) {
+        Self::get_organizer(env.clone()).require_auth();
+
+        env.storage().instance().set(&DataKey::DustPolicy, &policy);
+        env.events().publish((DUST_POLICY_SET,), policy);
+    }
+
+    /// Get the dust policy (defaults to `DustPolicy::FirstRecipient`)
+    pub fn get_dust_policy(env: Env) -> DustPolicy {
+        env.storage()
+            .instance()
+            .get(&DataKey::DustPolicy)
+            .unwrap_or(DustPolicy::FirstRecipient)
+    }
+
+    /// Split the exact remaining balance among `recipients`.
+    ///
+    /// With empty `shares_bps` the balance is split evenly; otherwise each
+    /// recipient gets `remaining * share / 10_000`, and shares must sum to
+    /// 10_000. Amounts are rounded down and the leftover stroops (fewer than
+    /// the number of recipients) are handled by the dust policy, so the pool
+    /// reaches zero unless the policy is `LeaveInPool`, or `FeeRecipient` with
+    /// less than `MIN_DUST_TRANSFER` of dust.
+    ///
+    /// # Panics
+    /// * If on-top payout fees are enabled (the pool could not reach zero)
+    /// * If shares are given but do not match recipients or sum to 10_000
+    /// * If any recipient's amount rounds down to zero
+    pub fn distribute_remainder(
+        env: Env,
+        recipients: Vec<Address>,
+        shares_bps: Vec<u32>,
+    ) -> ProgramData {
+        let remaining = Self::get_remaining_balance(env.clone());
+        Self::execute_split(env, remaining, recipients, shares_bps)
+    }
+
+    /// Split `total` among `recipients` by `shares_bps` (summing to 10_000).
+    ///
+    /// Amounts are rounded down and the residue is handled by the dust policy.
+    pub fn distribute_percentages(
+        env: Env,
+        total: i128,
+        recipients: Vec<Address>,
+        shares_bps: Vec<u32>,
+    ) -> ProgramData {
+        if total <= 0 {
+            panic!("Amount must be greater than zero");
+        }
+        if shares_bps.is_empty() {
+            panic!("Shares must match recipients length");
+        }
+        Self::execute_split(env, total, recipients, shares_bps)
+    }
+
+    fn execute_split(
+        env: Env,
+        total: i128,
+        recipients: Vec<Address>,
+        shares_bps: Vec<u32>,
+    ) -> ProgramData {
+        let fee_config = Self::get_fee_config_internal(&env);
+        if fee_config.fee_enabled && fee_config.fee_on_top {
+            panic!("Split distribution does not support on-top fees");
+        }
+        if recipients.is_empty() {
+            panic!("Cannot process empty batch");
+        }
+
+        let policy = Self::get_dust_policy(env.clone());
+        if policy == DustPolicy::FeeRecipient
+            && fee_config.fee_recipient == env.current_contract_address()
+        {
+            panic!("Fee recipient not configured");
+        }
+
+        let (mut amounts, dust) = Self::split_amounts(&env, total, recipients.len(), &shares_bps);
+        if policy == DustPolicy::FirstRecipient {
+            amounts.set(0, amounts.get(0).unwrap() + dust);
+        }
+
+        let memos = Vec::new(&env);
+        let mut data = Self::execute_batch_payout(
+            env.clone(),
+            recipients,
+            amounts,
+            None,
+            memos,
+            PayoutAuth::PayoutKey,
+            None,
+        );
+
+        if policy == DustPolicy::FeeRecipient && dust >= MIN_DUST_TRANSFER {
+            Self::charge_epoch_outflow(&env, &None, dust);
+            Self::record_tripwire_outflow(&env, &data, dust);
+            token::Client::new(&env, &data.token_address).transfer(
+                &env.current_contract_address(),
+                &fee_config.fee_recipient,
+                &dust,
+            );
+            data.remaining_balance -= dust;
+            adjust_total_paid(&env, dust);
+            save_program_data(&env, &data);
+            env.events()
+                .publish((DUST_PAID,), (fee_config.fee_recipient, dust));
+        }
+        data
+    }
+
+    /// Split `total` into `count` rounded-down amounts (evenly, or by
+    /// `shares_bps`) and return them with the undistributed dust.
+    fn split_amounts(
+        env: &Env,
+        total: i128,
+        count: u32,
+        shares_bps: &Vec<u32>,
+    ) -> (Vec<i128>, i128) {
+        if shares_bps.is_empty() {
+            let mut amounts = Vec::new(env);
+            for _ in 0..count {
+                amounts.push_back(total / count as i128);
+            }
+            if total / (count as i128) <= 0 {
+                panic!("Amount too small to split");
+            }
+            return (amounts, total % count as i128);
+        }
+
+        if shares_bps.len() != count {
+            panic!("Shares must match recipients length");
+        }
+        let (amounts, dust) =
+            math::split_bps(env, total, shares_bps).unwrap_or_else(|e| common_panic(e));
+        if amounts.iter().any(|amount| amount <= 0) {
+            panic!("Amount too small to split");
+        }
+        (amounts, dust)
+    }
+
+    // ========================================================================
+    // Seasons
+    // ========================================================================
+
+    /// Open season `season` with its own payout budget (organizer only).
+    ///
+    /// While a season is active every payout is debited from its budget and
+    /// recorded in its history. Opening a season closes the previous one.
+    ///
+    /// # Panics
+    /// * If `season` is not greater than the last opened season
+    /// * If `budget` is not positive or exceeds the remaining balance
+    pub fn open_season(env: Env, season: u32, budget: i128) -> SeasonStats {
+        Self::get_organizer(env.clone()).require_auth();
+
+        let latest: Option<u32> = env.storage().instance().get(&DataKey::LatestSeason);
+        if latest.is_some_and(|latest| season <= latest) {
+            panic!("Season number must increase");
+        }
+        if budget <= 0 {
+            panic!("Budget must be greater than zero");
+        }
+        if budget > Self::get_remaining_balance(env.clone()) {
+            panic!("Season budget exceeds remaining balance");
+        }
+
+        if Self::get_active_season(env.clone()).is_some() {
+            Self::close_active_season(&env);
+        }
+
+        let stats = SeasonStats {
+            season,
+            budget,
+            paid_out: 0,
+            payout_count: 0,
+            opened_at: env.ledger().timestamp(),
+            closed_at: None,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Season(season), &stats);
+        env.storage()
+            .instance()
+            .set(&DataKey::ActiveSeason, &season);
+        env.storage()
+            .instance()
+            .set(&DataKey::LatestSeason, &season);
+        env.events().publish((SEASON_OPENED,), (season, budget));
+        stats
+    }
+
+    /// Close the active season (organizer only). Later payouts are not
+    /// tied to any season until the next one is opened.
+    pub fn close_season(env: Env) -> SeasonStats {
+        Self::get_organizer(env.clone()).require_auth();
+        if Self::get_active_season(env.clone()).is_none() {
+            panic!("No active season");
+        }
+        Self::close_active_season(&env)
+    }
+
+    /// Get the number of the active season, if any
+    pub fn get_active_season(env: Env) -> Option<u32> {
+        env.storage().instance().get(&DataKey::ActiveSeason)
+    }
+
+    /// Get budget and payout totals for a season
+    pub fn get_season(env: Env, season: u32) -> SeasonStats {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Season(season))
+            .unwrap_or_else(|| panic!("Season not found"))
+    }
+
+    /// Get the payouts made during a season
+    pub fn get_season_history(env: Env, season: u32) -> Vec<PayoutRecord> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SeasonHistory(season))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    fn close_active_season(env: &Env) -> SeasonStats {
+        let season: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ActiveSeason)
+            .unwrap();
+        let mut stats = Self::get_season(env.clone(), season);
+        stats.closed_at = Some(env.ledger().timestamp());
+        env.storage()
+            .persistent()
+            .set(&DataKey::Season(season), &stats);
+        env.storage().instance().remove(&DataKey::ActiveSeason);
+        env.events()
+            .publish((SEASON_CLOSED,), (season, stats.paid_out));
+        stats
+    }
+
+    /// Panics if debiting `debit` would overrun the active season's budget.
+    fn check_season_budget(env: &Env, active_season: Option<u32>, debit: i128) {
+        if let Some(season) = active_season {
+            let stats = Self::get_season(env.clone(), season);
+            if stats.paid_out + debit > stats.budget {
+                reentrancy_guard::clear_entered(env);
+                panic!("Season budget exceeded");
+            }
+        }
+    }
+
+    fn record_season_payouts(
+        env: &Env,
+        active_season: Option<u32>,
+        records: Vec<PayoutRecord>,
+        debit: i128,
+    ) {
+        let Some(season) = active_season else {
+            return;
+        };
+        let mut stats = Self::get_season(env.clone(), season);
+        stats.paid_out += debit;
+        stats.payout_count += records.len();
+        env.storage()
+            .persistent()
+            .set(&DataKey::Season(season), &stats);
+
+        let mut history = Self::get_season_history(env.clone(), season);
+        history.append(&records);
+        env.storage()
+            .persistent()
+            .set(&DataKey::SeasonHistory(season), &history);
+    }
+
+    // ========================================================================
+    // Tracks
+    // ========================================================================
+
+    /// Create a named prize track with its own budget (organizer only).
+    ///
+    /// Payouts made with `track_batch_payout` / `track_single_payout` are
+    /// debited from the track's budget and recorded in its history. If
+    /// `payout_key` is set it authorizes the track's payouts instead of the
+    /// program payout key.
+    ///
+    /// Unspent track budgets stay reserved in the pool: other tracks and
+    /// regular payouts cannot spend them.
+    ///
+    /// # Panics
+    /// * If the track already exists or `MAX_TRACKS` tracks exist
+    /// * If the unspent budgets of all tracks would exceed the remaining balance
+    pub fn create_track(
+        env: Env,
+        name: String,
+        budget: i128,
+        payout_key: Option<Address>,
+    ) -> Track {
+        Self::assert_program_active(&env);
+        Self::get_organizer(env.clone()).require_auth();
+
+        if budget <= 0 {
+            panic!("Budget must be greater than zero");
+        }
+        let mut names = Self::get_tracks(env.clone());
+        if names.contains(&name) {
+            panic!("Track already exists");
+        }
+        if names.len() >= MAX_TRACKS {
+            panic!("Too many tracks");
+        }
+
+        let committed = Self::get_track_budget_reserve(env.clone()) + budget;
+        if committed > Self::get_remaining_balance(env.clone()) {
+            panic!("Track budgets exceed remaining balance");
+        }
+        env.storage()
+            .instance()
+            .set(&TrackKey::BudgetReserved, &committed);
+
+        let track = Track {
+            name: name.clone(),
+            budget,
+            paid_out: 0,
+            payout_count: 0,
+            payout_key,
+            earmarked: 0,
+        };
+        env.storage()
+            .persistent()
+            .set(&TrackKey::Track(name.clone()), &track);
+        names.push_back(name.clone());
+        env.storage().instance().set(&TrackKey::Names, &names);
+        let program_id = Self::get_program_info(env.clone()).program_id;
+        env.events().publish(
+            (TRACK_CREATED, program_id, name),
+            (budget, track.payout_key.clone()),
+        );
+        track
+    }
+
+    /// Lock sponsor funds earmarked for a single track.
+    ///
+    /// The amount is added to the track's budget and can only be paid out by
+    /// that track: other tracks and regular payouts cannot spend it. Track
+    /// payouts draw on earmarked funds first.
+    pub fn lock_track_funds_from(
+        env: Env,
+        sponsor: Address,
+        track: String,
+        amount: i128,
+    ) -> ProgramData {
+        Self::assert_program_active(&env);
+        let mut entry = Self::load_track(&env, &track);
+        let before = Self::get_remaining_balance(env.clone());
+        let data = Self::lock_program_funds_from(env.clone(), sponsor.clone(), amount);
+        let received = data.remaining_balance - before;
+
+        entry.budget += received;
+        entry.earmarked += received;
+        env.storage()
+            .persistent()
+            .set(&TrackKey::Track(track.clone()), &entry);
+        let reserved = Self::get_earmarked_reserve(env.clone()) + received;
+        env.storage()
+            .instance()
+            .set(&TrackKey::EarmarkReserved, &reserved);
+        let budgets = Self::get_track_budget_reserve(env.clone()) + received;
+        env.storage()
+            .instance()
+            .set(&TrackKey::BudgetReserved, &budgets);
+
+        env.events().publish(
+            (TRACK_EARMARKED, data.program_id.clone(), track),
+            (sponsor, received),
+        );
+        data
+    }
+
+    /// Total earmarked sponsor funds not yet paid out by their tracks
+    pub fn get_earmarked_reserve(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&TrackKey::EarmarkReserved)
+            .unwrap_or(0)
+    }
+
+    /// Total track budgets not yet paid out by their tracks
+    pub fn get_track_budget_reserve(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&TrackKey::BudgetReserved)
+            .unwrap_or(0)
+    }
+
+    /// Panics if paying `debit` would spend another track's unspent budget.
+    fn assert_track_budgets_covered(
+        env: &Env,
+        program_data: &ProgramData,
+        auth: &PayoutAuth,
+        debit: i128,
+    ) {
+        let reserved = Self::get_track_budget_reserve(env.clone());
+        if reserved == 0 {
+            return;
+        }
+        let own_reserve = match auth {
+            PayoutAuth::Track(name) => {
+                let track = Self::load_track(env, name);
+                (track.budget - track.paid_out).max(0)
+            }
+            _ => 0,
+        };
+        if debit > program_data.remaining_balance - reserved + own_reserve {
+            reentrancy_guard::clear_entered(env);
+            panic!("Funds reserved for track budgets");
+        }
+    }
+
+    /// Panics if paying `debit` would spend funds earmarked for another track.
+    fn assert_not_earmarked(env: &Env, program_data: &ProgramData, auth: &PayoutAuth, debit: i128) {
+        let reserved = Self::get_earmarked_reserve(env.clone());
+        if reserved == 0 {
+            return;
+        }
+        let own_reserve = match auth {
+            PayoutAuth::Track(name) => {
+                let track = Self::load_track(env, name);
+                (track.earmarked - track.paid_out).max(0)
+            }
+            _ => 0,
+        };
+        if debit > program_data.remaining_balance - reserved + own_reserve {
+            reentrancy_guard::clear_entered(env);
+            panic!("Funds earmarked for another track");
+        }
+    }
+
+    /// Panics if the extra amount a fee-on-transfer token took during a payout
+    /// cannot be covered by the pool.
+    fn assert_overhead_covered(env: &Env, program_data: &ProgramData, debit: i128, overhead: i128) {
+        if overhead > 0 && debit + overhead > program_data.remaining_balance {
+            reentrancy_guard::clear_entered(env);
+            panic!("Insufficient balance");
+        }
+    }
+
+    /// Get the names of all tracks
+    pub fn get_tracks(env: Env) -> Vec<String> {
+        env.storage()
+            .instance()
+            .get(&TrackKey::Names)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Get a track's budget and payout totals
+    pub fn get_track(env: Env, name: String) -> Track {
+        Self::load_track(&env, &name)
+    }
+
+    /// Get the payouts made from a track
+    pub fn get_track_history(env: Env, name: String) -> Vec<PayoutRecord> {
+        env.storage()
+            .persistent()
+            .get(&TrackKey::History(name))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Execute batch payouts from a track's budget.
+    pub fn track_batch_payout(
+        env: Env,
+        track: String,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+    ) -> ProgramData {
+        let memos = Vec::new(&env);
+        Self::execute_batch_payout(
+            env,
+            recipients,
+            amounts,
+            None,
+            memos,
+            PayoutAuth::Track(track),
+            None,
+        )
+    }
+
+    /// Execute a single payout from a track's budget.
+    pub fn track_single_payout(
+        env: Env,
+        track: String,
+        recipient: Address,
+        amount: i128,
+    ) -> ProgramData {
+        Self::execute_single_payout(
+            env,
+            recipient,
+            amount,
+            None,
+            None,
+            PayoutAuth::Track(track),
+            None,
+        )
+    }
+
+    fn load_track(env: &Env, name: &String) -> Track {
+        env.storage()
+            .persistent()
+            .get(&TrackKey::Track(name.clone()))
+            .unwrap_or_else(|| {
+                reentrancy_guard::clear_entered(env);
+                panic!("Track not found")
+            })
+    }
+
+    /// Debit a track payout from its budget and append it to the track history.
+    fn record_track_payouts(
+        env: &Env,
+        auth: &PayoutAuth,
+        records: &Vec<PayoutRecord>,
+        debit: i128,
+    ) {
+        let PayoutAuth::Track(name) = auth else {
+            return;
+        };
+        let mut track = Self::load_track(env, name);
+        if track.paid_out + debit > track.budget {
+            reentrancy_guard::clear_entered(env);
+            panic!("Track budget exceeded");
+        }
+        let spent_earmark = (track.earmarked - track.paid_out).max(0).min(debit);
+        if spent_earmark > 0 {
+            let reserved = Self::get_earmarked_reserve(env.clone()) - spent_earmark;
+            env.storage()
+                .instance()
+                .set(&TrackKey::EarmarkReserved, &reserved);
+        }
+        let budgets = Self::get_track_budget_reserve(env.clone()) - debit;
+        env.storage()
+            .instance()
+            .set(&TrackKey::BudgetReserved, &budgets);
+        track.paid_out += debit;
+        track.payout_count += records.len();
+        env.storage()
+            .persistent()
+            .set(&TrackKey::Track(name.clone()), &track);
+
+        let mut history = Self::get_track_history(env.clone(), name.clone());
+        history.append(records);
+        env.storage()
+            .persistent()
+            .set(&TrackKey::History(name.clone()), &history);
+    }
+
+    // ========================================================================
+    // Judging
+    // ========================================================================
+
+    /// Register a judge who may rank submissions (organizer only). At most
+    /// `MAX_JUDGES` judges may be registered.
+    pub fn add_judge(env: Env, judge: Address) {
+        Self::get_organizer(env.clone()).require_auth();
+        Self::assert_judging_open(&env);
+
+        if roles::has_role(&env, &JUDGE_ROLE, &judge) {
+            panic!("Judge already registered");
+        }
+        let mut judges = Self::get_judges(env.clone());
+        if judges.len() >= MAX_JUDGES {
+            panic!("Too many judges");
+        }
+        roles::grant_role(&env, &JUDGE_ROLE, &judge);
+        // The list is kept for tallying; the role gates voting
+        judges.push_back(judge.clone());
+        env.storage().instance().set(&DataKey::Judges, &judges);
+        env.events().publish((JUDGE_ADDED,), judge);
+    }
+
+    /// Remove a judge and discard their vote (organizer only).
+    pub fn remove_judge(env: Env, judge: Address) {
+        Self::get_organizer(env.clone()).require_auth();
+        Self::assert_judging_open(&env);
+
+        if !roles::has_role(&env, &JUDGE_ROLE, &judge) {
+            panic!("Not a judge");
+        }
+        roles::revoke_role(&env, &JUDGE_ROLE, &judge);
+        let mut judges = Self::get_judges(env.clone());
+        if let Some(index) = judges.first_index_of(&judge) {
+            judges.remove(index);
+        }
+        env.storage().instance().set(&DataKey::Judges, &judges);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::JudgeVote(judge.clone()));
+        env.events().publish((JUDGE_REMOVED,), judge);
+    }
+
+    /// Get all registered judges
+    pub fn get_judges(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Judges)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Enter a submission for judging. The submitter must authorize and is
+    /// the address paid if the submission wins. At most `MAX_SUBMISSIONS`
+    /// submissions are accepted.
+    pub fn register_submission(env: Env, submitter: Address, hash: BytesN<32>) {
+        submitter.require_auth();
+        Self::assert_judging_open(&env);
+
+        let mut submissions = Self::get_submissions(env.clone());
+        if submissions.iter().any(|s| s.hash == hash) {
+            panic!("Submission already registered");
+        }
+        if submissions.len() >= MAX_SUBMISSIONS {
+            panic!("Too many submissions");
+        }
+        submissions.push_back(Submission {
+            hash: hash.clone(),
+            submitter: submitter.clone(),
+        });
+        env.storage()
+            .instance()
+            .set(&DataKey::Submissions, &submissions);
+        env.events()
+            .publish((SUBMISSION_REGISTERED,), (hash, submitter));
+    }
+
+    /// Get all submissions in registration order
+    pub fn get_submissions(env: Env) -> Vec<Submission> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Submissions)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Cast (or replace) a judge's ranked vote, best submission first.
+    ///
+    /// # Panics
+    /// * If `judge` is not registered
+    /// * If the ranking is empty, repeats a hash or names an unknown submission
+    pub fn cast_vote(env: Env, judge: Address, ranking: Vec<BytesN<32>>) {
+        Self::assert_judging_open(&env);
+        roles::require_role(&env, &JUDGE_ROLE, &judge).unwrap_or_else(|_| panic!("Not a judge"));
+        if ranking.is_empty() {
+            panic!("Ranking cannot be empty");
+        }
+
+        let submissions = Self::get_submissions(env.clone());
+        for (i, hash) in ranking.iter().enumerate() {
+            if !submissions.iter().any(|s| s.hash == hash) {
+                panic!("Unknown submission");
+            }
+            if ranking.slice(0..i as u32).contains(&hash) {
+                panic!("Duplicate submission in ranking");
+            }
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::JudgeVote(judge.clone()), &ranking);
+        env.events().publish((VOTE_CAST,), (judge, ranking));
+    }
+
+    /// Get a judge's ranked vote (empty if the judge has not voted)
+    pub fn get_judge_vote(env: Env, judge: Address) -> Vec<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::JudgeVote(judge))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Tally judge votes and fix the winning set (organizer only).
+    ///
+    /// Votes are scored as a Borda count: in a ranking of `n` submissions the
+    /// first earns `n` points, the second `n - 1` and so on. Ties go to the
+    /// earlier registered submission. Once finalized, payouts may only go to
+    /// submitters in the winning set.
+    ///
+    /// # Panics
+    /// * If winners were already finalized
+    /// * If `winner_count` is zero or exceeds the number of submissions
+    /// * If no judge has voted
+    pub fn finalize_judging(env: Env, winner_count: u32) -> Vec<Address> {
+        Self::get_organizer(env.clone()).require_auth();
+        Self::assert_judging_open(&env);
+
+        let submissions = Self::get_submissions(env.clone());
+        if winner_count == 0 || winner_count > submissions.len() {
+            panic!("Invalid winner count");
+        }
+
+        let mut scores: Vec<u32> = Vec::new(&env);
+        for _ in submissions.iter() {
+            scores.push_back(0);
+        }
+        let mut votes_cast = 0u32;
+        for judge in Self::get_judges(env.clone()).iter() {
+            let ranking = Self::get_judge_vote(env.clone(), judge);
+            if ranking.is_empty() {
+                continue;
+            }
+            votes_cast += 1;
+            let n = ranking.len();
+            for (rank, hash) in ranking.iter().enumerate() {
+                let index = submissions.iter().position(|s| s.hash == hash).unwrap() as u32;
+                scores.set(index, scores.get(index).unwrap() + n - rank as u32);
+            }
+        }
+        if votes_cast == 0 {
+            panic!("No votes cast");
+        }
+
+        let mut picked: Vec<bool> = Vec::new(&env);
+        for _ in submissions.iter() {
+            picked.push_back(false);
+        }
+        let mut winners: Vec<Address> = Vec::new(&env);
+        for _ in 0..winner_count {
+            let mut best: Option<u32> = None;
+            for i in 0..scores.len() {
+                if picked.get(i).unwrap() {
+                    continue;
+                }
+                if best.is_none_or(|b| scores.get(i).unwrap() > scores.get(b).unwrap()) {
+                    best = Some(i);
+                }
+            }
+            let best = best.unwrap();
+            picked.set(best, true);
+            winners.push_back(submissions.get(best).unwrap().submitter);
+        }
 
-    pub fn set_circuit_admin(env: Env, new_admin: Address, caller: Option<Address>) {
-        error_recovery::set_circuit_admin(&env, new_admin, caller);
+        env.storage().instance().set(&DataKey::WinningSet, &winners);
+        env.events().publish((WINNERS_FINALIZED,), winners.clone());
+        winners
     }
 
-    pub fn get_circuit_admin(env: Env) -> Option<Address> {
-        error_recovery::get_circuit_admin(&env)
+    /// Get the finalized winners in rank order (empty until judging is finalized)
+    pub fn get_winning_set(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::WinningSet)
+            .unwrap_or_else(|| Vec::new(&env))
     }
 
-    pub fn reset_circuit_breaker(env: Env, caller: Address) {
-        caller.require_auth();
-        let admin = error_recovery::get_circuit_admin(&env).expect("Circuit admin not set");
-        if caller != admin {
-            panic!("Unauthorized: only circuit admin can reset");
+    // ========================================================================
+    // Winner Address Confirmation
+    // ========================================================================
+
+    /// Shortlist participants who must confirm a payout address before
+    /// `confirm_by` (organizer only).
+    ///
+    /// Once any participant is shortlisted, payouts may only go to participants
+    /// who confirmed an address through `register_winner_address`. A payout
+    /// naming either the participant or that address is delivered to the
+    /// confirmed address.
+    pub fn shortlist_winners(env: Env, participants: Vec<Address>, confirm_by: u64) {
+        Self::get_organizer(env.clone()).require_auth();
+
+        if confirm_by <= env.ledger().timestamp() {
+            panic!("Confirmation deadline must be in the future");
         }
-        error_recovery::reset_circuit_breaker(&env, &admin);
-    }
 
-    pub fn configure_circuit_breaker(
-        env: Env,
-        caller: Address,
-        _threshold: u32,
-        _lookback: u32,
-        _cooldown: u32,
-    ) {
-        caller.require_auth();
-        let admin = error_recovery::get_circuit_admin(&env).expect("Circuit admin not set");
-        if caller != admin {
-            panic!("Unauthorized: only circuit admin can configure");
+        for participant in participants.iter() {
+            env.storage()
+                .persistent()
+                .set(&DataKey::Shortlisted(participant), &true);
         }
-        // Logic to update config in storage would go here
+        env.storage()
+            .instance()
+            .set(&DataKey::WinnerConfirmDeadline, &confirm_by);
+        env.events()
+            .publish((WINNERS_SHORTLISTED,), (participants, confirm_by));
     }
 
-    pub fn update_rate_limit_config(
+    /// Confirm the address a shortlisted participant wants to be paid to.
+    ///
+    /// Both the participant and the payout address must authorize, which proves
+    /// the participant controls the address. Calling again replaces the
+    /// previously confirmed address.
+    ///
+    /// # Panics
+    /// * If `program_id` does not match this program
+    /// * If the participant is not shortlisted
+    /// * If the confirmation deadline has passed
+    pub fn register_winner_address(
         env: Env,
-        window_size: u64,
-        max_operations: u32,
-        cooldown_period: u64,
+        program_id: String,
+        participant: Address,
+        payout_address: Address,
     ) {
-        // Only admin can update rate limit config
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-
-        let config = RateLimitConfig {
-            window_size,
-            max_operations,
-            cooldown_period,
-        };
-        env.storage().instance().set(&DataKey::RateLimitConfig, &config);
-    }
+        participant.require_auth();
+        payout_address.require_auth();
 
-    pub fn get_rate_limit_config(env: Env) -> RateLimitConfig {
-        env.storage()
+        let program_data =
+            load_program_data(&env).unwrap_or_else(|| panic!("Program not initialized"));
+        if program_data.program_id != program_id {
+            panic!("Program not found");
+        }
+        if !Self::is_shortlisted(env.clone(), participant.clone()) {
+            panic!("Participant not shortlisted");
+        }
+        let deadline: u64 = env
+            .storage()
             .instance()
-            .get(&DataKey::RateLimitConfig)
-            .unwrap_or(RateLimitConfig {
-                window_size: 3600,
-                max_operations: 10,
-                cooldown_period: 60,
-            })
-    }
-
-    pub fn get_analytics(_env: Env) -> Analytics {
-        Analytics {
-            total_locked: 0,
-            total_released: 0,
-            total_payouts: 0,
-            active_programs: 0,
-            operation_count: 0,
+            .get(&DataKey::WinnerConfirmDeadline)
+            .unwrap();
+        if env.ledger().timestamp() > deadline {
+            panic!("Registration window closed");
         }
-    }
 
-    pub fn set_whitelist(env: Env, _address: Address, _whitelisted: bool) {
-        // Only admin can set whitelist
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap_or_else(|| panic!("Not initialized"));
-        admin.require_auth();
+        if let Some(previous) = Self::get_winner_address(env.clone(), participant.clone()) {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::ConfirmedAddress(previous));
+        }
+        env.storage().persistent().set(
+            &DataKey::WinnerAddress(participant.clone()),
+            &payout_address,
+        );
+        env.storage().persistent().set(
+            &DataKey::ConfirmedAddress(payout_address.clone()),
+            &participant,
+        );
+        env.events().publish(
+            (
+                WINNER_ADDRESS_REGISTERED,
+                program_id.clone(),
+                participant.clone(),
+            ),
+            (program_id, participant, payout_address),
+        );
     }
- // ========================================================================
-    // Payout Functions
-    // ========================================================================
 
-    /// Execute batch payouts to multiple recipients
-    ///
-    /// # Arguments
-    /// * `recipients` - Vector of recipient addresses
-    /// * `amounts` - Vector of amounts (must match recipients length)
+    /// Send all future payouts for `winner` to `alternate` instead, e.g. a team
+    /// treasury. Only the winner can set this, and only once they are in the
+    /// finalized winning set or shortlisted. Setting the winner's own address
+    /// clears the alternate.
     ///
-    /// # Returns
-    /// Updated ProgramData after payouts
-    pub fn batch_payout(env: Env, recipients: Vec<Address>, amounts: Vec<i128>) -> ProgramData {
-        // Reentrancy guard: Check and set
-        reentrancy_guard::check_not_entered(&env);
-        reentrancy_guard::set_entered(&env);
+    /// # Panics
+    /// * If `winner` is not an approved winner
+    /// * If the program is closed, cancelled or archived, or `winner` is frozen
+    pub fn set_payout_address(env: Env, winner: Address, alternate: Address) {
+        Self::assert_program_active(&env);
+        winner.require_auth();
+        Self::assert_recipient_not_frozen(&env, &winner);
 
-        if Self::check_paused(&env, symbol_short!("release")) {
-            reentrancy_guard::clear_entered(&env);
-            panic!("Funds Paused");
+        let program_data = Self::get_program_info(env.clone());
+        let in_winning_set = Self::get_winning_set(env.clone()).contains(&winner);
+        if !in_winning_set && !Self::is_shortlisted(env.clone(), winner.clone()) {
+            panic!("Not an approved winner");
         }
 
-        // Verify authorization
-        let program_data: ProgramData =
+        let key = WinnerKey::PayoutAddress(winner.clone());
+        if alternate == winner {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &alternate);
+            storage::extend_persistent(&env, &key);
             env.storage()
                 .instance()
-                .get(&PROGRAM_DATA)
-                .unwrap_or_else(|| {
-                    reentrancy_guard::clear_entered(&env);
-                    panic!("Program not initialized")
-                });
+                .set(&WinnerKey::AlternatesSet, &true);
+        }
+        env.events().publish(
+            (PAYOUT_ADDRESS_SET, program_data.program_id, winner.clone()),
+            (winner, alternate),
+        );
+    }
 
-        program_data.authorized_payout_key.require_auth();
+    /// Get the alternate address a winner's payouts are sent to, if any.
+    pub fn get_payout_address(env: Env, winner: Address) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&WinnerKey::PayoutAddress(winner))
+    }
 
-        // Validate input lengths match
-        if recipients.len() != amounts.len() {
-            reentrancy_guard::clear_entered(&env);
-            panic!("Recipients and amounts vectors must have the same length");
-        }
+    /// Register `team` as a team entry whose payouts are split among `members`
+    /// by `shares_bps`. Registering again replaces the previous members.
+    ///
+    /// A batch row or single payout to `team` then fans out into one transfer
+    /// per member instead of going to the team address.
+    ///
+    /// # Panics
+    /// * If the program is closed, cancelled or archived, or `team` is frozen
+    /// * If there are no members or more than `MAX_TEAM_MEMBERS`
+    /// * If `shares_bps` does not match `members` or sum to 10000
+    /// * If a member has a zero share or is listed twice
+    pub fn register_team(env: Env, team: Address, members: Vec<Address>, shares_bps: Vec<u32>) {
+        Self::assert_program_active(&env);
+        team.require_auth();
+        Self::assert_recipient_not_frozen(&env, &team);
 
-        if recipients.len() == 0 {
-            reentrancy_guard::clear_entered(&env);
-            panic!("Cannot process empty batch");
+        let program_data = Self::get_program_info(env.clone());
+        if members.is_empty() || members.len() > MAX_TEAM_MEMBERS {
+            panic!("Invalid team size");
         }
-
-        // Calculate total payout amount
-        let mut total_payout: i128 = 0;
-        for amount in amounts.iter() {
-            if amount <= 0 {
-                reentrancy_guard::clear_entered(&env);
-                panic!("All amounts must be greater than zero");
+        if shares_bps.len() != members.len() {
+            panic!("Shares must match members length");
+        }
+        if shares_bps.iter().map(|bps| bps as i128).sum::<i128>() != BASIS_POINTS {
+            panic!("Shares must sum to 10000 basis points");
+        }
+        if shares_bps.iter().any(|bps| bps == 0) {
+            panic!("Member shares must be greater than zero");
+        }
+        for i in 1..members.len() {
+            if members.slice(0..i).contains(members.get(i).unwrap()) {
+                panic!("Duplicate team member");
             }
-            total_payout = total_payout.checked_add(amount).unwrap_or_else(|| {
-                reentrancy_guard::clear_entered(&env);
-                panic!("Payout amount overflow")
-            });
         }
 
-        // Validate sufficient balance
-        if total_payout > program_data.remaining_balance {
-            reentrancy_guard::clear_entered(&env);
-            panic!("Insufficient balance");
-        }
+        env.storage().persistent().set(
+            &WinnerKey::Team(team.clone()),
+            &Team {
+                members: members.clone(),
+                shares_bps: shares_bps.clone(),
+            },
+        );
+        storage::extend_persistent(&env, &WinnerKey::Team(team.clone()));
+        env.storage().instance().set(&WinnerKey::TeamsSet, &true);
+        env.events().publish(
+            (TEAM_REGISTERED, program_data.program_id, team.clone()),
+            (team, members, shares_bps),
+        );
+    }
 
-        // Execute transfers
-        let mut updated_history = program_data.payout_history.clone();
-        let timestamp = env.ledger().timestamp();
-        let contract_address = env.current_contract_address();
-        let token_client = token::Client::new(&env, &program_data.token_address);
+    /// Get the members and shares registered for a team entry, if any.
+    pub fn get_team(env: Env, team: Address) -> Option<Team> {
+        load_team(&env, &team)
+    }
 
-        for i in 0..recipients.len() {
-            let recipient = recipients.get(i).unwrap();
-            let amount = amounts.get(i).unwrap();
+    /// Returns true if the participant has been shortlisted.
+    pub fn is_shortlisted(env: Env, participant: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Shortlisted(participant))
+    }
 
-            // Transfer funds from contract to recipient
-            token_client.transfer(&contract_address, &recipient, &amount);
+    /// Get the payout address a participant has confirmed, if any.
+    pub fn get_winner_address(env: Env, participant: Address) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::WinnerAddress(participant))
+    }
 
-            // Record payout
-            let payout_record = PayoutRecord {
-                recipient,
-                amount,
-                timestamp,
-            };
-            updated_history.push_back(payout_record);
+    /// Participant a payout to `recipient` is for: the participant who
+    /// confirmed `recipient` as their payout address, otherwise `recipient`.
+    /// The winning set and confirmation checks run against the participant.
+    fn payout_participant(env: &Env, confirmation_required: bool, recipient: &Address) -> Address {
+        if !confirmation_required {
+            return recipient.clone();
         }
+        env.storage()
+            .persistent()
+            .get(&DataKey::ConfirmedAddress(recipient.clone()))
+            .unwrap_or_else(|| recipient.clone())
+    }
 
-        // Update program data
-        let mut updated_data = program_data.clone();
-        updated_data.remaining_balance -= total_payout;
-        updated_data.payout_history = updated_history;
+    /// Panics if winners are shortlisted and `participant` has not confirmed a
+    /// payout address.
+    fn assert_recipient_confirmed(env: &Env, confirmation_required: bool, participant: &Address) {
+        if confirmation_required
+            && !env
+                .storage()
+                .persistent()
+                .has(&DataKey::WinnerAddress(participant.clone()))
+        {
+            reentrancy_guard::clear_entered(env);
+            panic!("Recipient address not confirmed");
+        }
+    }
 
-        // Store updated data
-        env.storage().instance().set(&PROGRAM_DATA, &updated_data);
+    fn assert_judging_open(env: &Env) {
+        if env.storage().instance().has(&DataKey::WinningSet) {
+            panic!("Winners already finalized");
+        }
+    }
 
-        // Emit BatchPayout event
-        env.events().publish(
-            (BATCH_PAYOUT,),
-            BatchPayoutEvent {
-                version: EVENT_VERSION_V2,
-                program_id: updated_data.program_id.clone(),
-                recipient_count: recipients.len() as u32,
-                total_amount: total_payout,
-                remaining_balance: updated_data.remaining_balance,
-            },
-        );
+    /// Panics if a winning set is finalized and `recipient` is not in it.
+    fn assert_recipient_in_winning_set(
+        env: &Env,
+        winning_set: &Option<Vec<Address>>,
+        recipient: &Address,
+    ) {
+        if let Some(winners) = winning_set {
+            if !winners.contains(recipient) {
+                reentrancy_guard::clear_entered(env);
+                panic!("Recipient not in winning set");
+            }
+        }
+    }
 
-        // Clear reentrancy guard before returning
-        reentrancy_guard::clear_entered(&env);
+    /// Execute batch payouts authorized by a delegated payout signer.
+    ///
+    /// The batch total (including fees) counts against the signer's spending cap.
+    pub fn delegated_batch_payout(
+        env: Env,
+        signer: Address,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+    ) -> ProgramData {
+        let memos = Vec::new(&env);
+        Self::execute_batch_payout(
+            env,
+            recipients,
+            amounts,
+            None,
+            memos,
+            PayoutAuth::Signer(signer),
+            None,
+        )
+    }
 
-        updated_data
+    /// Execute a single payout authorized by a delegated payout signer.
+    pub fn delegated_single_payout(
+        env: Env,
+        signer: Address,
+        recipient: Address,
+        amount: i128,
+    ) -> ProgramData {
+        Self::execute_single_payout(
+            env,
+            recipient,
+            amount,
+            None,
+            None,
+            PayoutAuth::Signer(signer),
+            None,
+        )
     }
 
-    /// Execute a single payout to one recipient
-    ///
-    /// # Arguments
-    /// * `recipient` - Address of the recipient
-    /// * `amount` - Amount to transfer
-    ///
-    /// # Returns
-    /// Updated ProgramData after payout
-    pub fn single_payout(env: Env, recipient: Address, amount: i128) -> ProgramData {
+    fn execute_single_payout(
+        env: Env,
+        recipient: Address,
+        amount: i128,
+        payout_id: Option<BytesN<32>>,
+        memo: Option<BytesN<32>>,
+        auth: PayoutAuth,
+        swap: Option<(Address, i128)>,
+    ) -> ProgramData {
+        Self::assert_program_active(&env);
+
         // Reentrancy guard: Check and set
         reentrancy_guard::check_not_entered(&env);
         reentrancy_guard::set_entered(&env);
@@ -1111,15 +5688,14 @@ impl ProgramEscrowContract {
 
         // Verify authorization
         let program_data: ProgramData =
-            env.storage()
-                .instance()
-                .get(&PROGRAM_DATA)
+            load_program_data(&env)
                 .unwrap_or_else(|| {
                     reentrancy_guard::clear_entered(&env);
                     panic!("Program not initialized")
                 });
 
-        program_data.authorized_payout_key.require_auth();
+        Self::require_payout_auth(&env, &program_data, &auth);
+        Self::consume_payout_id(&env, &payout_id);
 
         // Validate amount
         if amount <= 0 {
@@ -1127,63 +5703,203 @@ impl ProgramEscrowContract {
             panic!("Amount must be greater than zero");
         }
 
-        // Validate sufficient balance
-        if amount > program_data.remaining_balance {
-            reentrancy_guard::clear_entered(&env);
-            panic!("Insufficient balance");
-        }
+        let (fee_config, debit, active_season) = Self::validate_payouts(
+            &env,
+            &program_data,
+            &auth,
+            &payout_id,
+            &vec![&env, recipient.clone()],
+            &vec![&env, amount],
+        );
+        let (net, fee, _) = Self::split_payout_fee(&fee_config, amount);
+        Self::charge_signer_cap(&env, &auth, debit);
 
-        // Transfer funds from contract to recipient
+        // Transfer funds from contract to recipient, unless held for clawback
+        let hold_until = Self::payout_hold_until(&env, &payout_id);
         let contract_address = env.current_contract_address();
         let token_client = token::Client::new(&env, &program_data.token_address);
-        token_client.transfer(&contract_address, &recipient, &amount);
+        let balance_before = payout_balance_before(&env, &token_client);
+        match (&hold_until, &swap) {
+            (None, None) => {
+                deliver_payout(&env, &token_client, &recipient, net, false);
+            }
+            (None, Some((asset_out, min_out))) => {
+                if load_team(&env, &recipient).is_some() {
+                    reentrancy_guard::clear_entered(&env);
+                    panic!("Swapped payouts cannot be split across a team");
+                }
+                Self::swap_to_recipient(
+                    &env,
+                    &program_data,
+                    asset_out,
+                    net,
+                    *min_out,
+                    &payout_destination(&env, &recipient),
+                )
+            }
+            (Some(_), None) => {}
+            (Some(_), Some(_)) => {
+                reentrancy_guard::clear_entered(&env);
+                panic!("Swapped payouts cannot be held");
+            }
+        }
+        if fee > 0 {
+            token_client.transfer(&contract_address, &fee_config.fee_recipient, &fee);
+            Self::record_fee(&env, &fee_config, &recipient, net, fee);
+        }
+        let sent = if hold_until.is_some() { fee } else { debit };
+        let overhead = transfer_overhead(&env, &token_client, balance_before, sent);
+        Self::assert_overhead_covered(&env, &program_data, debit, overhead);
 
         // Record payout
         let timestamp = env.ledger().timestamp();
         let payout_record = PayoutRecord {
             recipient: recipient.clone(),
-            amount,
+            amount: net,
             timestamp,
+            memo: memo.clone().map(Bytes::from),
         };
 
+        let paid_records = vec![&env, payout_record.clone()];
+        Self::record_track_payouts(&env, &auth, &paid_records, debit);
+        Self::record_season_payouts(&env, active_season, paid_records, debit);
+        Self::enter_payout_phase(&env);
+
         let mut updated_history = program_data.payout_history.clone();
-        updated_history.push_back(payout_record);
+        match hold_until {
+            Some(release_at) => Self::hold_payout(
+                &env,
+                &payout_id,
+                vec![&env, payout_record],
+                net,
+                release_at,
+            ),
+            None => updated_history.push_back(payout_record),
+        }
 
         // Update program data
         let mut updated_data = program_data.clone();
-        updated_data.remaining_balance -= amount;
+        updated_data.remaining_balance -= debit + overhead;
+        adjust_total_paid(&env, debit + overhead);
         updated_data.payout_history = updated_history;
 
         // Store updated data
-        env.storage().instance().set(&PROGRAM_DATA, &updated_data);
+        save_program_data(&env, &updated_data);
 
         // Emit Payout event
         env.events().publish(
-            (PAYOUT,),
+            (PAYOUT, updated_data.program_id.clone(), recipient.clone()),
             PayoutEvent {
                 version: EVENT_VERSION_V2,
                 program_id: updated_data.program_id.clone(),
                 recipient,
-                amount,
+                amount: net,
+                fee,
                 remaining_balance: updated_data.remaining_balance,
+                memo: memo.map(Bytes::from),
             },
         );
 
-        // Clear reentrancy guard before returning
-        reentrancy_guard::clear_entered(&env);
+        // Clear reentrancy guard before returning
+        reentrancy_guard::clear_entered(&env);
+
+        updated_data
+    }
+
+    /// Get program information
+    ///
+    /// # Returns
+    /// ProgramData containing all program information
+    pub fn get_program_info(env: Env) -> ProgramData {
+        load_program_data(&env).unwrap_or_else(|| panic!("Program not initialized"))
+    }
+
+    /// Get locked, paid out, refunded and fee totals plus the current balance
+    /// for `token`. Payouts still held, claimable or queued for retry are
+    /// reported separately from `paid_out`.
+    pub fn get_accounting(env: Env, token: Address) -> TokenAccounting {
+        let program_data: ProgramData =
+            load_program_data(&env).unwrap_or_else(|| panic!("Program not initialized"));
+        let balance = token::Client::new(&env, &token).balance(&env.current_contract_address());
+
+        if token != program_data.token_address {
+            return TokenAccounting {
+                token,
+                locked: 0,
+                paid_out: 0,
+                held: 0,
+                claimable: 0,
+                pending_retry: 0,
+                refunded: 0,
+                fees_collected: 0,
+                remaining_balance: 0,
+                balance,
+            };
+        }
+
+        let refunded: i128 = env.storage().instance().get(&TOTAL_REFUNDED).unwrap_or(0);
+        let held = Self::get_held_total(env.clone());
+        let claimable = claim_period::get_reserved_total(&env);
+        let pending_retry = Self::get_retry_total(env.clone());
+        let debited = program_data.total_funds - program_data.remaining_balance - refunded;
+        TokenAccounting {
+            token,
+            locked: program_data.total_funds,
+            paid_out: debited - held - claimable - pending_retry,
+            held,
+            claimable,
+            pending_retry,
+            refunded,
+            fees_collected: Self::get_total_fees_collected(env.clone()),
+            remaining_balance: program_data.remaining_balance,
+            balance,
+        }
+    }
 
-        updated_data
+    /// Get a compact program overview without the payout history.
+    ///
+    /// Archived programs return the summary recorded when they were archived.
+    pub fn get_program_summary(env: Env) -> ProgramSummary {
+        if let Some(summary) = env.storage().instance().get(&ARCHIVED_SUMMARY) {
+            return summary;
+        }
+        let program_data: ProgramData =
+            load_program_data(&env).unwrap_or_else(|| panic!("Program not initialized"));
+        let refunded: i128 = env.storage().instance().get(&TOTAL_REFUNDED).unwrap_or(0);
+        let held_total = Self::get_held_total(env.clone());
+        let claimable_total = claim_period::get_reserved_total(&env);
+        let retry_total = Self::get_retry_total(env.clone());
+        let debited = program_data.total_funds - program_data.remaining_balance - refunded;
+
+        ProgramSummary {
+            program_id: program_data.program_id,
+            status: Self::get_program_status(env.clone()),
+            token_address: program_data.token_address,
+            total_funds: program_data.total_funds,
+            remaining_balance: program_data.remaining_balance,
+            total_paid_out: debited - held_total - claimable_total - retry_total,
+            total_fees_collected: Self::get_total_fees_collected(env.clone()),
+            held_total,
+            claimable_total,
+            retry_total,
+            payout_count: program_data.payout_history.len(),
+            organizer: Self::get_organizer(env.clone()),
+            authorized_payout_key: program_data.authorized_payout_key,
+            active_season: Self::get_active_season(env),
+        }
     }
 
-    /// Get program information
+    /// Extend the storage TTL of the program and contract instance. Callable by anyone.
     ///
-    /// # Returns
-    /// ProgramData containing all program information
-    pub fn get_program_info(env: Env) -> ProgramData {
-        env.storage()
-            .instance()
-            .get(&PROGRAM_DATA)
-            .unwrap_or_else(|| panic!("Program not initialized"))
+    /// Keeps long-running programs from being archived between the funding and
+    /// payout phases, even when no other calls touch the contract.
+    pub fn bump_ttl(env: Env, program_id: String) {
+        let program_data =
+            load_program_data(&env).unwrap_or_else(|| panic!("Program not initialized"));
+        if program_data.program_id != program_id {
+            panic!("Program not found");
+        }
+        storage::extend_instance(&env);
     }
 
     /// Get remaining balance
@@ -1191,33 +5907,148 @@ impl ProgramEscrowContract {
     /// # Returns
     /// Current remaining balance
     pub fn get_remaining_balance(env: Env) -> i128 {
-        let program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&PROGRAM_DATA)
+        let program_data: ProgramData = load_program_data(&env)
             .unwrap_or_else(|| panic!("Program not initialized"));
 
         program_data.remaining_balance
     }
 
+    /// Compare the contract's actual token balance with `remaining_balance`.
+    ///
+    /// Emits a `BalMism` event when the two differ.
+    ///
+    /// # Returns
+    /// Report with both balances and the signed discrepancy
+    pub fn reconcile(env: Env) -> ReconciliationReport {
+        let program_data: ProgramData =
+            load_program_data(&env).unwrap_or_else(|| panic!("Program not initialized"));
+
+        let token_client = token::Client::new(&env, &program_data.token_address);
+        let token_balance = token_client.balance(&env.current_contract_address());
+        // Funds reserved for claims, held payouts and retries are still on hand
+        let recorded_balance = program_data.remaining_balance
+            + claim_period::get_reserved_total(&env)
+            + Self::get_held_total(env.clone())
+            + Self::get_retry_total(env.clone());
+        let discrepancy = token_balance - recorded_balance;
+
+        if discrepancy != 0 {
+            env.events().publish(
+                (BALANCE_MISMATCH, program_data.program_id.clone()),
+                BalanceMismatchEvent {
+                    version: EVENT_VERSION_V2,
+                    program_id: program_data.program_id,
+                    token_balance,
+                    recorded_balance,
+                    discrepancy,
+                },
+            );
+        }
+
+        ReconciliationReport {
+            token_balance,
+            recorded_balance,
+            discrepancy,
+            timestamp: env.ledger().timestamp(),
+        }
+    }
+
+    /// Check that `remaining_balance == total_funds - total_paid - total_refunded`
+    /// and that the token balance covers `remaining_balance` plus all reserved
+    /// payouts. Read-only, so monitoring can call it after every upgrade.
+    pub fn assert_invariants(env: Env) -> InvariantReport {
+        let program_data: ProgramData =
+            load_program_data(&env).unwrap_or_else(|| panic!("Program not initialized"));
+
+        let total_paid = load_total_paid(&env);
+        let total_refunded: i128 = env.storage().instance().get(&TOTAL_REFUNDED).unwrap_or(0);
+        let expected_remaining = program_data.total_funds - total_paid - total_refunded;
+        let obligations = program_data.remaining_balance
+            + claim_period::get_reserved_total(&env)
+            + Self::get_held_total(env.clone())
+            + Self::get_retry_total(env.clone());
+        let token_balance = token::Client::new(&env, &program_data.token_address)
+            .balance(&env.current_contract_address());
+
+        InvariantReport {
+            total_funds: program_data.total_funds,
+            total_paid,
+            total_refunded,
+            remaining_balance: program_data.remaining_balance,
+            expected_remaining,
+            obligations,
+            token_balance,
+            accounting_ok: program_data.remaining_balance == expected_remaining,
+            solvent: token_balance >= obligations,
+        }
+    }
+
+    /// Transfer tokens the escrow holds beyond its obligations to `recipient`.
+    ///
+    /// For the program token the surplus is the balance above `remaining_balance`
+    /// plus funds reserved for pending claims and held payouts. Any other token
+    /// is swept in full.
+    ///
+    /// # Returns
+    /// Amount swept
+    ///
+    /// # Panics
+    /// * If the caller is not the organizer
+    /// * If there is no surplus
+    pub fn sweep_surplus(env: Env, token: Address, recipient: Address) -> i128 {
+        let program_data: ProgramData =
+            load_program_data(&env).unwrap_or_else(|| panic!("Program not initialized"));
+
+        let organizer = Self::get_organizer(env.clone());
+        organizer.require_auth();
+
+        let token_client = token::Client::new(&env, &token);
+        let contract_address = env.current_contract_address();
+        let balance = token_client.balance(&contract_address);
+        let surplus = if token == program_data.token_address {
+            balance
+                - program_data.remaining_balance
+                - claim_period::get_reserved_total(&env)
+                - Self::get_held_total(env.clone())
+                - Self::get_retry_total(env.clone())
+        } else {
+            balance
+        };
+
+        if surplus <= 0 {
+            panic!("No surplus to sweep");
+        }
+
+        token_client.transfer(&contract_address, &recipient, &surplus);
+
+        env.events()
+            .publish((SURPLUS_SWEPT,), (token, recipient, surplus, organizer));
+
+        surplus
+    }
+
     /// Create a release schedule entry that can be triggered at/after `release_timestamp`.
+    ///
+    /// At most `MAX_SCHEDULES` schedules are stored; once full, released ones
+    /// are pruned (they stay in the release history) to make room.
    pub fn create_program_release_schedule(
     env: Env,
     recipient: Address,
     amount: i128,
     release_timestamp: u64,
 ) -> ProgramReleaseSchedule {
-    let program_data: ProgramData = env
-        .storage()
-        .instance()
-        .get(&PROGRAM_DATA)
+    Self::assert_program_active(&env);
+
+    let program_data: ProgramData = load_program_data(&env)
         .unwrap_or_else(|| panic!("Program not initialized"));
 
+    Self::assert_payout_nonce_not_required(&env);
     program_data.authorized_payout_key.require_auth();
 
     if amount <= 0 {
         panic!("Amount must be greater than zero");
     }
+    Self::assert_payout_schedulable(&env, &recipient, amount);
 
     let mut schedules: Vec<ProgramReleaseSchedule> = env
         .storage()
@@ -1229,6 +6060,19 @@ impl ProgramEscrowContract {
         .instance()
         .get(&NEXT_SCHEDULE_ID)
         .unwrap_or(1_u64);
+    if schedules.len() >= MAX_SCHEDULES {
+        // Released schedules live on in the release history
+        let mut pending = Vec::new(&env);
+        for existing in schedules.iter() {
+            if !existing.released {
+                pending.push_back(existing);
+            }
+        }
+        schedules = pending;
+        if schedules.len() >= MAX_SCHEDULES {
+            panic!("Too many pending schedules");
+        }
+    }
 
     let schedule = ProgramReleaseSchedule {
         schedule_id,
@@ -1251,19 +6095,104 @@ impl ProgramEscrowContract {
 
     /// Trigger all due schedules where `now >= release_timestamp`.
     pub fn trigger_program_releases(env: Env) -> u32 {
+        Self::release_due_schedules(env, true)
+    }
+
+    /// Schedule a payout that anyone may execute once `not_before` has passed.
+    ///
+    /// Unlike `create_program_release_schedule`, the amount must be covered by
+    /// the remaining balance not already committed to other pending schedules.
+    ///
+    /// # Arguments
+    /// * `recipient`  - Address receiving the payout
+    /// * `amount`     - Amount to pay
+    /// * `not_before` - Ledger timestamp from which the payout may be executed
+    pub fn schedule_payout(
+        env: Env,
+        recipient: Address,
+        amount: i128,
+        not_before: u64,
+    ) -> ProgramReleaseSchedule {
+        let program_data = Self::get_program_info(env.clone());
+        let committed = Self::get_total_scheduled_amount(env.clone());
+        if amount > 0 && committed + amount > program_data.remaining_balance {
+            panic!("Insufficient uncommitted balance");
+        }
+        Self::assert_not_earmarked(
+            &env,
+            &program_data,
+            &PayoutAuth::PayoutKey,
+            committed + amount,
+        );
+        Self::assert_track_budgets_covered(
+            &env,
+            &program_data,
+            &PayoutAuth::PayoutKey,
+            committed + amount,
+        );
+        Self::create_program_release_schedule(env, recipient, amount, not_before)
+    }
+
+    /// Permissionless keeper entrypoint: release every schedule that is due.
+    ///
+    /// # Returns
+    /// Number of schedules released
+    pub fn execute_due_payouts(env: Env) -> u32 {
+        Self::release_due_schedules(env, false)
+    }
+
+    /// Remove a pending release schedule (organizer only).
+    ///
+    /// Frees the amount it committed, e.g. for a schedule the keeper keeps
+    /// skipping because its recipient was blocked after it was created.
+    ///
+    /// # Returns
+    /// The cancelled schedule
+    pub fn cancel_schedule(env: Env, schedule_id: u64) -> ProgramReleaseSchedule {
+        let organizer = Self::get_organizer(env.clone());
+        organizer.require_auth();
+
+        let mut schedules = Self::get_release_schedules(env.clone());
+        let index = schedules
+            .iter()
+            .position(|s| s.schedule_id == schedule_id)
+            .unwrap_or_else(|| panic!("Schedule not found")) as u32;
+        let schedule = schedules.get(index).unwrap();
+        if schedule.released {
+            panic!("Schedule already released");
+        }
+        schedules.remove(index);
+        env.storage().instance().set(&SCHEDULES, &schedules);
+
+        let program_id = Self::get_program_info(env.clone()).program_id;
+        env.events().publish(
+            (SCHEDULE_CANCELLED, program_id),
+            (
+                schedule_id,
+                schedule.recipient.clone(),
+                schedule.amount,
+                organizer,
+            ),
+        );
+        schedule
+    }
+
+    fn release_due_schedules(env: Env, require_payout_key: bool) -> u32 {
+        Self::assert_program_active(&env);
+        Self::assert_payouts_not_paused(&env);
+
         // Reentrancy guard: Check and set
         reentrancy_guard::check_not_entered(&env);
         reentrancy_guard::set_entered(&env);
 
-        let mut program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&PROGRAM_DATA)
+        let mut program_data: ProgramData = load_program_data(&env)
             .unwrap_or_else(|| {
                 reentrancy_guard::clear_entered(&env);
                 panic!("Program not initialized")
             });
-        program_data.authorized_payout_key.require_auth();
+        if require_payout_key {
+            program_data.authorized_payout_key.require_auth();
+        }
 
         let mut schedules: Vec<ProgramReleaseSchedule> = env
             .storage()
@@ -1278,7 +6207,6 @@ impl ProgramEscrowContract {
 
         let now = env.ledger().timestamp();
         let contract_address = env.current_contract_address();
-        let token_client = token::Client::new(&env, &program_data.token_address);
         let mut released_count: u32 = 0;
 
         for i in 0..schedules.len() {
@@ -1286,24 +6214,24 @@ impl ProgramEscrowContract {
             if schedule.released || now < schedule.release_timestamp {
                 continue;
             }
-
-            if schedule.amount > program_data.remaining_balance {
-                reentrancy_guard::clear_entered(&env);
-                panic!("Insufficient balance");
+            // A tripwire hit by an earlier schedule pauses releases for the rest of the run
+            if Self::get_pause_flags(&env).release_paused {
+                break;
+            }
+            if !Self::schedule_payable(&env, &program_data, &schedule) {
+                env.events().publish(
+                    (SCHEDULE_SKIPPED, program_data.program_id.clone()),
+                    schedule.schedule_id,
+                );
+                continue;
             }
 
-            token_client.transfer(&contract_address, &schedule.recipient, &schedule.amount);
+            let record = Self::pay_schedule(&env, &mut program_data, &schedule);
             schedule.released = true;
             schedule.released_at = Some(now);
             schedule.released_by = Some(contract_address.clone());
             schedules.set(i, schedule.clone());
-
-            program_data.remaining_balance -= schedule.amount;
-            program_data.payout_history.push_back(PayoutRecord {
-                recipient: schedule.recipient.clone(),
-                amount: schedule.amount,
-                timestamp: now,
-            });
+            program_data.payout_history.push_back(record);
             release_history.push_back(ProgramReleaseHistory {
                 schedule_id: schedule.schedule_id,
                 recipient: schedule.recipient,
@@ -1314,11 +6242,9 @@ impl ProgramEscrowContract {
             released_count += 1;
         }
 
-        env.storage().instance().set(&PROGRAM_DATA, &program_data);
+        save_program_data(&env, &program_data);
         env.storage().instance().set(&SCHEDULES, &schedules);
-        env.storage()
-            .instance()
-            .set(&RELEASE_HISTORY, &release_history);
+        save_release_history(&env, release_history);
 
         // Clear reentrancy guard before returning
         reentrancy_guard::clear_entered(&env);
@@ -1367,10 +6293,7 @@ impl ProgramEscrowContract {
         offset: u32,
         limit: u32,
     ) -> Vec<PayoutRecord> {
-        let program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&PROGRAM_DATA)
+        let program_data: ProgramData = load_program_data(&env)
             .unwrap_or_else(|| panic!("Program not initialized"));
         let history = program_data.payout_history;
         let mut results = Vec::new(&env);
@@ -1402,10 +6325,7 @@ impl ProgramEscrowContract {
         offset: u32,
         limit: u32,
     ) -> Vec<PayoutRecord> {
-        let program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&PROGRAM_DATA)
+        let program_data: ProgramData = load_program_data(&env)
             .unwrap_or_else(|| panic!("Program not initialized"));
         let history = program_data.payout_history;
         let mut results = Vec::new(&env);
@@ -1437,10 +6357,7 @@ impl ProgramEscrowContract {
         offset: u32,
         limit: u32,
     ) -> Vec<PayoutRecord> {
-        let program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&PROGRAM_DATA)
+        let program_data: ProgramData = load_program_data(&env)
             .unwrap_or_else(|| panic!("Program not initialized"));
         let history = program_data.payout_history;
         let mut results = Vec::new(&env);
@@ -1565,10 +6482,7 @@ impl ProgramEscrowContract {
 
     /// Get aggregate statistics for the program
   pub fn get_program_aggregate_stats(env: Env) -> ProgramAggregateStats {
-    let program_data: ProgramData = env
-        .storage()
-        .instance()
-        .get(&PROGRAM_DATA)
+    let program_data: ProgramData = load_program_data(&env)
         .unwrap_or_else(|| panic!("Program not initialized"));
     let schedules: Vec<ProgramReleaseSchedule> = env
         .storage()
@@ -1608,10 +6522,7 @@ impl ProgramEscrowContract {
         offset: u32,
         limit: u32,
     ) -> Vec<PayoutRecord> {
-        let program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&PROGRAM_DATA)
+        let program_data: ProgramData = load_program_data(&env)
             .unwrap_or_else(|| panic!("Program not initialized"));
         let history = program_data.payout_history;
         let mut results = Vec::new(&env);
@@ -1691,7 +6602,7 @@ impl ProgramEscrowContract {
     }
 
     pub fn get_program_count(env: Env) -> u32 {
-        if env.storage().instance().has(&PROGRAM_DATA) {
+        if load_program_data(&env).is_some() {
             1
         } else {
             0
@@ -1700,7 +6611,7 @@ impl ProgramEscrowContract {
 
     pub fn list_programs(env: Env) -> Vec<ProgramData> {
         let mut results = Vec::new(&env);
-        if env.storage().instance().has(&PROGRAM_DATA) {
+        if load_program_data(&env).is_some() {
             results.push_back(Self::get_program_info(env.clone()));
         }
         results
@@ -1732,8 +6643,10 @@ impl ProgramEscrowContract {
     }
 
     pub fn release_program_schedule_manual(env: Env, schedule_id: u64) {
+        Self::assert_program_active(&env);
+        Self::assert_payouts_not_paused(&env);
         let mut schedules = Self::get_release_schedules(env.clone());
-        let program_data = Self::get_program_info(env.clone());
+        let mut program_data = Self::get_program_info(env.clone());
         
         program_data.authorized_payout_key.require_auth();
 
@@ -1748,11 +6661,9 @@ impl ProgramEscrowContract {
                 if s.released {
                     panic!("Already released");
                 }
-                
-                // Transfer funds
-                let token_client = token::Client::new(&env, &program_data.token_address);
-                token_client.transfer(&env.current_contract_address(), &s.recipient, &s.amount);
-                
+                Self::assert_recipient_not_frozen(&env, &s.recipient);
+                Self::pay_schedule(&env, &mut program_data, &s);
+
                 s.released = true;
                 s.released_at = Some(now);
                 s.released_by = Some(caller.clone());
@@ -1771,11 +6682,7 @@ impl ProgramEscrowContract {
 
         // Write to release history
         if let Some(s) = released_schedule {
-            let mut updated_program_data = program_data.clone();
-            updated_program_data.remaining_balance -= s.amount;
-            env.storage()
-                .instance()
-                .set(&PROGRAM_DATA, &updated_program_data);
+            save_program_data(&env, &program_data);
 
             let mut history: Vec<ProgramReleaseHistory> = env.storage()
                 .instance()
@@ -1788,13 +6695,15 @@ impl ProgramEscrowContract {
                 released_at: now,
                 release_type: ReleaseType::Manual,
             });
-            env.storage().instance().set(&RELEASE_HISTORY, &history);
+            save_release_history(&env, history);
         }
     }
 
     pub fn release_prog_schedule_automatic(env: Env, schedule_id: u64) {
+        Self::assert_program_active(&env);
+        Self::assert_payouts_not_paused(&env);
         let mut schedules = Self::get_release_schedules(env.clone());
-        let program_data = Self::get_program_info(env.clone());
+        let mut program_data = Self::get_program_info(env.clone());
         let now = env.ledger().timestamp();
         let mut released_schedule: Option<ProgramReleaseSchedule> = None;
 
@@ -1808,11 +6717,9 @@ impl ProgramEscrowContract {
                 if now < s.release_timestamp {
                     panic!("Not yet due");
                 }
-                
-                // Transfer funds
-                let token_client = token::Client::new(&env, &program_data.token_address);
-                token_client.transfer(&env.current_contract_address(), &s.recipient, &s.amount);
-                
+                Self::assert_recipient_not_frozen(&env, &s.recipient);
+                Self::pay_schedule(&env, &mut program_data, &s);
+
                 s.released = true;
                 s.released_at = Some(now);
                 s.released_by = Some(env.current_contract_address());
@@ -1831,11 +6738,7 @@ impl ProgramEscrowContract {
 
         // Write to release history
         if let Some(s) = released_schedule {
-            let mut updated_program_data = program_data.clone();
-            updated_program_data.remaining_balance -= s.amount;
-            env.storage()
-                .instance()
-                .set(&PROGRAM_DATA, &updated_program_data);
+            save_program_data(&env, &program_data);
 
             let mut history: Vec<ProgramReleaseHistory> = env.storage()
                 .instance()
@@ -1848,7 +6751,7 @@ impl ProgramEscrowContract {
                 released_at: now,
                 release_type: ReleaseType::Automatic,
             });
-            env.storage().instance().set(&RELEASE_HISTORY, &history);
+            save_release_history(&env, history);
         }
     }
 
@@ -1859,10 +6762,107 @@ impl ProgramEscrowContract {
         amount: i128,
         claim_deadline: u64,
     ) -> u64 {
+        Self::assert_program_active(&env);
+        Self::assert_payout_nonce_not_required(&env);
+        Self::assert_direct_payouts_enabled(&env);
+        Self::assert_recipient_eligible(
+            &env,
+            &Self::get_eligibility_registry(env.clone()),
+            &recipient,
+        );
         claim_period::create_pending_claim(&env, &program_id, &recipient, amount, claim_deadline)
     }
 
+    /// Pay a batch as claimable balances instead of direct transfers.
+    ///
+    /// Each amount is reserved as a pending claim the recipient pulls with
+    /// `execute_claim` before `expires_at`, so a recipient without a trustline
+    /// cannot fail the batch. Unclaimed amounts return to the pool through
+    /// `reclaim_expired_claim`. The batch goes through the same checks as
+    /// `batch_payout`, and fees are paid out when the claims are created.
+    ///
+    /// # Returns
+    /// The claim id created for each recipient, in order
+    pub fn batch_payout_claimable(
+        env: Env,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+        expires_at: u64,
+    ) -> Vec<u64> {
+        Self::assert_program_active(&env);
+        Self::assert_payouts_not_paused(&env);
+
+        reentrancy_guard::check_not_entered(&env);
+        reentrancy_guard::set_entered(&env);
+
+        let mut program_data: ProgramData = load_program_data(&env).unwrap_or_else(|| {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Program not initialized")
+        });
+        let auth = PayoutAuth::PayoutKey;
+        Self::require_payout_auth(&env, &program_data, &auth);
+
+        if expires_at <= env.ledger().timestamp() {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Claim deadline must be in the future");
+        }
+
+        let (fee_config, total_debit, active_season) =
+            Self::validate_payouts(&env, &program_data, &auth, &None, &recipients, &amounts);
+
+        // Reserve each net amount as a claim; fees are settled up front
+        let program_id = program_data.program_id.clone();
+        let timestamp = env.ledger().timestamp();
+        let mut claim_ids = Vec::new(&env);
+        let mut claim_records = Vec::new(&env);
+        let mut total_fee: i128 = 0;
+        for (recipient, amount) in recipients.iter().zip(amounts.iter()) {
+            let (net, fee, _) = Self::split_payout_fee(&fee_config, amount);
+            claim_ids.push_back(claim_period::reserve_claim(
+                &env,
+                &mut program_data,
+                &program_id,
+                &recipient,
+                net,
+                expires_at,
+            ));
+            Self::record_fee(&env, &fee_config, &recipient, net, fee);
+            total_fee += fee;
+            claim_records.push_back(PayoutRecord {
+                recipient,
+                amount: net,
+                timestamp,
+                memo: None,
+            });
+        }
+
+        if total_fee > 0 {
+            let token_client = token::Client::new(&env, &program_data.token_address);
+            token_client.transfer(
+                &env.current_contract_address(),
+                &fee_config.fee_recipient,
+                &total_fee,
+            );
+            program_data.remaining_balance -= total_fee;
+            adjust_total_paid(&env, total_fee);
+            save_program_data(&env, &program_data);
+        }
+
+        Self::enter_payout_phase(&env);
+        Self::record_season_payouts(&env, active_season, claim_records, total_debit);
+
+        reentrancy_guard::clear_entered(&env);
+        claim_ids
+    }
+
+    /// Return an expired, unclaimed claim to the pool. Callable by anyone.
+    pub fn reclaim_expired_claim(env: Env, program_id: String, claim_id: u64) -> i128 {
+        claim_period::reclaim_expired_claim(&env, &program_id, claim_id)
+    }
+
     pub fn execute_claim(env: Env, program_id: String, claim_id: u64, recipient: Address) {
+        Self::assert_payouts_not_paused(&env);
+        Self::assert_recipient_not_frozen(&env, &recipient);
         claim_period::execute_claim(&env, &program_id, claim_id, &recipient)
     }
 
@@ -1889,6 +6889,150 @@ mod test;
 #[cfg(test)]
 mod test_pause;
 
+#[cfg(test)]
+mod test_cancel_program;
+
+#[cfg(test)]
+mod test_payout_pause;
+
+#[cfg(test)]
+mod test_payout_idempotency;
+
+#[cfg(test)]
+mod test_payout_memo;
+
+#[cfg(test)]
+mod test_payout_fees;
+
+#[cfg(test)]
+mod test_payout_limits;
+
+#[cfg(test)]
+mod test_large_payout_approval;
+
+#[cfg(test)]
+mod test_scheduled_payouts;
+
+#[cfg(test)]
+mod test_reconcile;
+
+#[cfg(test)]
+mod test_sweep_surplus;
+
+#[cfg(test)]
+mod test_sponsors;
+
+#[cfg(test)]
+mod test_eligibility_registry;
+
+#[cfg(test)]
+mod test_clawback;
+
+#[cfg(test)]
+mod test_distribution_dispute;
+
+#[cfg(test)]
+mod test_upgrade;
+
+#[cfg(test)]
+mod test_storage_ttl;
+
+#[cfg(test)]
+mod test_program_metadata;
+
+#[cfg(test)]
+mod test_recipient_blocklist;
+
+#[cfg(test)]
+mod test_payout_signers;
+
+#[cfg(test)]
+mod test_lenient_batch_payout;
+
+#[cfg(test)]
+mod test_prize_tiers;
+
+#[cfg(test)]
+mod test_judging;
+
+#[cfg(test)]
+mod test_winner_addresses;
+
+#[cfg(test)]
+mod test_swap_payout;
+
+#[cfg(test)]
+mod test_seasons;
+
+#[cfg(test)]
+mod test_session_keys;
+
+#[cfg(test)]
+mod test_program_summary;
+
+#[cfg(test)]
+mod test_event_topics;
+
+#[cfg(test)]
+mod test_recipient_freeze;
+
+#[cfg(test)]
+mod test_retry_queue;
+
+#[cfg(test)]
+mod test_claimable_payouts;
+
+#[cfg(test)]
+mod test_distribute_remainder;
+
+#[cfg(test)]
+mod test_dust_policy;
+
+#[cfg(test)]
+mod test_tracks;
+
+#[cfg(test)]
+mod test_track_earmarks;
+
+#[cfg(test)]
+mod test_payout_address;
+
+#[cfg(test)]
+mod test_team_payouts;
+
+#[cfg(test)]
+mod test_payout_nonce;
+
+#[cfg(test)]
+mod test_signed_payouts;
+
+#[cfg(test)]
+mod test_max_batch_size;
+
+#[cfg(test)]
+mod test_epoch_limits;
+
+#[cfg(test)]
+mod test_outflow_tripwire;
+
+#[cfg(test)]
+mod test_archive_program;
+
+#[cfg(test)]
+mod test_token_accounting;
+
+#[cfg(test)]
+mod test_fund_program;
+
+#[cfg(test)]
+mod test_program_lifecycle;
+
+#[cfg(test)]
+mod test_invariants;
+
 #[cfg(test)]
 #[cfg(any())]
 mod rbac_tests;
+
+#[cfg(test)]
+mod test_fee_on_transfer;
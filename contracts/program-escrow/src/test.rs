@@ -77,9 +77,11 @@ fn test_init_program_and_event() {
 #[test]
 fn test_lock_program_funds_multi_step_balance() {
     let env = Env::default();
-    let (client, _admin, _token, _token_admin) = setup_program(&env, 0);
+    let (client, _admin, _token, token_admin) = setup_program(&env, 0);
 
+    token_admin.mint(&client.address, &10_000);
     client.lock_program_funds(&10_000);
+    token_admin.mint(&client.address, &5_000);
     client.lock_program_funds(&5_000);
     assert_eq!(client.get_remaining_balance(), 15_000);
     assert_eq!(client.get_program_info().total_funds, 15_000);
@@ -654,7 +656,7 @@ fn test_anti_abuse_whitelist_bypass() {
 // Admin rotation and config updates (Issue #465)
 // =============================================================================
 
-/// Admin can be set and rotated; new admin is persisted.
+/// Admin can be set and rotated; the new admin takes over once it accepts.
 #[test]
 fn test_admin_rotation() {
     let env = Env::default();
@@ -669,6 +671,10 @@ fn test_admin_rotation() {
     assert_eq!(client.get_admin(), Some(admin.clone()));
 
     client.set_admin(&new_admin);
+    assert_eq!(client.get_admin(), Some(admin));
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+
+    client.accept_admin();
     assert_eq!(client.get_admin(), Some(new_admin));
 }
 
@@ -685,6 +691,7 @@ fn test_new_admin_can_update_config() {
 
     client.set_admin(&admin);
     client.set_admin(&new_admin);
+    client.accept_admin();
 
     client.update_rate_limit_config(&3600, &10, &30);
 
@@ -694,6 +701,69 @@ fn test_new_admin_can_update_config() {
     assert_eq!(config.cooldown_period, 30);
 }
 
+/// Two-step transfer: the proposed admin takes over only once it accepts.
+#[test]
+fn test_two_step_admin_transfer() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize_contract(&admin);
+    client.propose_admin(&new_admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+    assert_eq!(client.get_admin(), Some(admin.clone()));
+
+    client.accept_admin();
+    assert!(env.auths().iter().any(|(a, _)| *a == new_admin));
+    assert_eq!(client.get_admin(), Some(new_admin));
+    assert_eq!(client.get_pending_admin(), None);
+}
+
+/// A cancelled transfer cannot be accepted.
+#[test]
+#[should_panic(expected = "No pending admin")]
+fn test_accept_cancelled_admin_transfer_panics() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize_contract(&admin);
+    client.propose_admin(&new_admin);
+    client.cancel_admin_transfer();
+    client.accept_admin();
+}
+
+/// Rotating through `set_admin` replaces a pending transfer rather than
+/// bypassing it.
+#[test]
+fn test_set_admin_replaces_pending_admin() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let proposed = Address::generate(&env);
+    let rotated = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize_contract(&admin);
+    client.propose_admin(&proposed);
+    client.set_admin(&rotated);
+    assert_eq!(client.get_admin(), Some(admin));
+    assert_eq!(client.get_pending_admin(), Some(rotated.clone()));
+
+    client.accept_admin();
+    assert_eq!(client.get_admin(), Some(rotated));
+}
+
 /// Non-admin cannot update rate limit config.
 #[test]
 #[should_panic]
@@ -0,0 +1,68 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+fn setup_program(
+    env: &Env,
+    initial_amount: i128,
+) -> (ProgramEscrowContractClient<'static>, token::Client<'static>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    (client, token_client)
+}
+
+#[test]
+fn test_archive_compacts_history_and_keeps_stats() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+
+    client.batch_payout(
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 6_000i128, 4_000i128],
+    );
+    let summary = client.archive_program();
+
+    assert_eq!(summary.status, ProgramStatus::Archived);
+    assert_eq!(summary.payout_count, 2);
+    assert_eq!(summary.total_paid_out, 10_000);
+    assert_eq!(client.get_program_info().payout_history.len(), 0);
+    assert_eq!(client.get_program_summary(), summary);
+}
+
+#[test]
+#[should_panic(expected = "Program archived")]
+fn test_archived_program_rejects_writes() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 1_000);
+
+    client.single_payout(&Address::generate(&env), &1_000);
+    client.archive_program();
+    client.lock_program_funds(&500);
+}
+
+#[test]
+#[should_panic(expected = "Program not fully paid out")]
+fn test_cannot_archive_with_remaining_balance() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 1_000);
+
+    client.single_payout(&Address::generate(&env), &400);
+    client.archive_program();
+}
@@ -0,0 +1,130 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+fn setup_program(
+    env: &Env,
+    initial_amount: i128,
+) -> (
+    ProgramEscrowContractClient<'static>,
+    Address,
+    Address,
+    token::Client<'static>,
+) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    if initial_amount > 0 {
+        token_admin_client.mint(&client.address, &initial_amount);
+        client.lock_program_funds(&initial_amount);
+    }
+
+    (client, organizer, payout_key, token_client)
+}
+
+#[test]
+fn test_cancel_program_refunds_organizer() {
+    let env = Env::default();
+    let (client, organizer, _payout_key, token_client) = setup_program(&env, 50_000);
+
+    assert_eq!(client.get_organizer(), organizer);
+    assert_eq!(client.get_program_status(), ProgramStatus::Funded);
+
+    let data = client.cancel_program();
+
+    assert_eq!(data.remaining_balance, 0);
+    assert_eq!(token_client.balance(&organizer), 50_000);
+    assert_eq!(token_client.balance(&client.address), 0);
+    assert_eq!(client.get_program_status(), ProgramStatus::Cancelled);
+}
+
+#[test]
+fn test_cancel_program_with_zero_balance() {
+    let env = Env::default();
+    let (client, organizer, _payout_key, token_client) = setup_program(&env, 0);
+
+    client.cancel_program();
+
+    assert_eq!(token_client.balance(&organizer), 0);
+    assert_eq!(client.get_program_status(), ProgramStatus::Cancelled);
+}
+
+#[test]
+#[should_panic(expected = "Cannot cancel after payouts")]
+fn test_cancel_program_after_payout_rejected() {
+    let env = Env::default();
+    let (client, _organizer, _payout_key, _token_client) = setup_program(&env, 50_000);
+
+    client.single_payout(&Address::generate(&env), &1_000);
+    client.cancel_program();
+}
+
+#[test]
+#[should_panic(expected = "Program cancelled")]
+fn test_cancel_program_twice_rejected() {
+    let env = Env::default();
+    let (client, _organizer, _payout_key, _token_client) = setup_program(&env, 50_000);
+
+    client.cancel_program();
+    client.cancel_program();
+}
+
+#[test]
+#[should_panic(expected = "Program cancelled")]
+fn test_lock_after_cancel_rejected() {
+    let env = Env::default();
+    let (client, _organizer, _payout_key, _token_client) = setup_program(&env, 50_000);
+
+    client.cancel_program();
+    client.lock_program_funds(&1_000);
+}
+
+#[test]
+#[should_panic(expected = "Program cancelled")]
+fn test_batch_payout_after_cancel_rejected() {
+    let env = Env::default();
+    let (client, _organizer, _payout_key, _token_client) = setup_program(&env, 50_000);
+
+    client.cancel_program();
+    client.batch_payout(&vec![&env, Address::generate(&env)], &vec![&env, 1_000]);
+}
+
+#[test]
+fn test_views_work_after_cancel() {
+    let env = Env::default();
+    let (client, _organizer, _payout_key, _token_client) = setup_program(&env, 50_000);
+
+    client.cancel_program();
+
+    let info = client.get_program_info();
+    assert_eq!(info.total_funds, 50_000);
+    assert_eq!(client.get_remaining_balance(), 0);
+}
+
+#[test]
+fn test_cancel_program_adds_to_refunded_total() {
+    let env = Env::default();
+    let (client, _organizer, _payout_key, token_client) = setup_program(&env, 50_000);
+
+    // An earlier refund already recorded for this program
+    env.as_contract(&client.address, || {
+        env.storage().instance().set(&TOTAL_REFUNDED, &5_000i128);
+    });
+    client.cancel_program();
+
+    let accounting = client.get_accounting(&token_client.address);
+    assert_eq!(accounting.refunded, 55_000);
+}
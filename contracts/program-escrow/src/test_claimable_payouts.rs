@@ -0,0 +1,97 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+
+fn setup_program(
+    env: &Env,
+    initial_amount: i128,
+) -> (ProgramEscrowContractClient<'static>, token::Client<'static>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    (client, token_client)
+}
+
+#[test]
+fn test_claimable_batch_is_pulled_by_recipients() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+    let expires_at = env.ledger().timestamp() + 1_000;
+
+    let ids = client.batch_payout_claimable(
+        &vec![&env, r1.clone(), r2.clone()],
+        &vec![&env, 1_000i128, 2_000i128],
+        &expires_at,
+    );
+
+    assert_eq!(ids.len(), 2);
+    assert_eq!(client.get_remaining_balance(), 7_000);
+    assert_eq!(token_client.balance(&r1), 0);
+
+    client.execute_claim(&program_id, &ids.get(0).unwrap(), &r1);
+    assert_eq!(token_client.balance(&r1), 1_000);
+    assert_eq!(client.reconcile().discrepancy, 0);
+}
+
+#[test]
+fn test_expired_claim_reclaimed_to_pool() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let now = env.ledger().timestamp();
+
+    let ids = client.batch_payout_claimable(
+        &vec![&env, Address::generate(&env)],
+        &vec![&env, 1_000i128],
+        &(now + 1_000),
+    );
+    let claim_id = ids.get(0).unwrap();
+
+    assert!(client
+        .try_reclaim_expired_claim(&program_id, &claim_id)
+        .is_err());
+
+    env.ledger().set_timestamp(now + 1_001);
+    assert_eq!(client.reclaim_expired_claim(&program_id, &claim_id), 1_000);
+    assert_eq!(client.get_remaining_balance(), 10_000);
+    assert_eq!(
+        client.get_claim(&program_id, &claim_id).status,
+        ClaimStatus::Cancelled
+    );
+}
+
+#[test]
+#[should_panic(expected = "Payout exceeds per-transaction limit")]
+fn test_claimable_batch_respects_payout_limits() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    client.set_payout_limits(&Some(1_000), &None);
+
+    client.batch_payout_claimable(
+        &vec![&env, Address::generate(&env)],
+        &vec![&env, 1_001i128],
+        &(env.ledger().timestamp() + 1_000),
+    );
+}
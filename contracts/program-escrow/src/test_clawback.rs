@@ -0,0 +1,124 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, BytesN, Env, String,
+};
+
+fn setup_program(
+    env: &Env,
+    initial_amount: i128,
+) -> (ProgramEscrowContractClient<'static>, token::Client<'static>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+    client.set_clawback_window(&Some(3_600));
+
+    (client, token_client)
+}
+
+#[test]
+fn test_payout_held_then_released_after_window() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+    let payout_id = BytesN::from_array(&env, &[1u8; 32]);
+    let now = env.ledger().timestamp();
+
+    let data = client.single_payout_with_id(&payout_id, &recipient, &2_000, &None);
+
+    assert_eq!(token_client.balance(&recipient), 0);
+    assert_eq!(data.remaining_balance, 8_000);
+    assert_eq!(data.payout_history.len(), 0);
+    assert_eq!(client.get_held_total(), 2_000);
+    assert_eq!(
+        client.get_held_payout(&payout_id).unwrap().release_at,
+        now + 3_600
+    );
+
+    assert!(client.try_release_held_payout(&payout_id).is_err());
+
+    env.ledger().set_timestamp(now + 3_600);
+    let data = client.release_held_payout(&payout_id);
+
+    assert_eq!(token_client.balance(&recipient), 2_000);
+    assert_eq!(data.payout_history.len(), 1);
+    assert_eq!(client.get_held_total(), 0);
+    assert_eq!(client.get_held_payout(&payout_id), None);
+}
+
+#[test]
+fn test_clawback_returns_batch_to_pool() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+    let payout_id = BytesN::from_array(&env, &[2u8; 32]);
+
+    client.batch_payout_with_id(
+        &payout_id,
+        &vec![&env, r1.clone(), r2.clone()],
+        &vec![&env, 1_000i128, 4_000i128],
+        &vec![&env],
+    );
+    assert_eq!(client.get_remaining_balance(), 5_000);
+
+    let returned = client.clawback(&payout_id);
+
+    assert_eq!(returned, 5_000);
+    assert_eq!(client.get_remaining_balance(), 10_000);
+    assert_eq!(client.get_held_total(), 0);
+    assert_eq!(token_client.balance(&r1), 0);
+    assert_eq!(token_client.balance(&r2), 0);
+    assert!(client.try_release_held_payout(&payout_id).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Clawback window has passed")]
+fn test_clawback_after_window_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let payout_id = BytesN::from_array(&env, &[3u8; 32]);
+    let now = env.ledger().timestamp();
+
+    client.single_payout_with_id(&payout_id, &Address::generate(&env), &1_000, &None);
+    env.ledger().set_timestamp(now + 3_600);
+
+    client.clawback(&payout_id);
+}
+
+#[test]
+#[should_panic(expected = "Clawback window requires payout_id")]
+fn test_payout_without_id_rejected_in_clawback_mode() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+
+    client.single_payout(&Address::generate(&env), &1_000);
+}
+
+#[test]
+fn test_disabling_window_pays_directly() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+
+    client.set_clawback_window(&None);
+    client.single_payout(&recipient, &1_000);
+
+    assert_eq!(token_client.balance(&recipient), 1_000);
+}
@@ -0,0 +1,78 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+fn setup_program(
+    env: &Env,
+    initial_amount: i128,
+) -> (ProgramEscrowContractClient<'static>, token::Client<'static>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    (client, token_client)
+}
+
+#[test]
+fn test_even_split_gives_dust_to_first_recipient() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+    let r3 = Address::generate(&env);
+
+    let data = client.distribute_remainder(
+        &vec![&env, r1.clone(), r2.clone(), r3.clone()],
+        &Vec::new(&env),
+    );
+
+    assert_eq!(token_client.balance(&r1), 3_334);
+    assert_eq!(token_client.balance(&r2), 3_333);
+    assert_eq!(token_client.balance(&r3), 3_333);
+    assert_eq!(data.remaining_balance, 0);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_bps_split_empties_pool() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_001);
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+
+    client.distribute_remainder(
+        &vec![&env, r1.clone(), r2.clone()],
+        &vec![&env, 7_000u32, 3_000u32],
+    );
+
+    assert_eq!(token_client.balance(&r1), 7_001);
+    assert_eq!(token_client.balance(&r2), 3_000);
+    assert_eq!(client.get_remaining_balance(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Shares must sum to 10000 basis points")]
+fn test_bps_not_summing_to_whole_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+
+    client.distribute_remainder(
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 5_000u32, 4_000u32],
+    );
+}
@@ -0,0 +1,168 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+
+fn setup_program(
+    env: &Env,
+    initial_amount: i128,
+) -> (ProgramEscrowContractClient<'static>, token::Client<'static>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+    client.set_challenge_period(&1_000);
+
+    (client, token_client)
+}
+
+#[test]
+fn test_distribution_finalizes_after_challenge_period() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+    let now = env.ledger().timestamp();
+
+    let proposal = client.propose_distribution(
+        &vec![&env, r1.clone(), r2.clone()],
+        &vec![&env, 3_000i128, 2_000i128],
+    );
+    assert_eq!(proposal.total_amount, 5_000);
+    assert_eq!(proposal.finalize_after, now + 1_000);
+
+    assert!(client.try_finalize_distribution().is_err());
+    assert_eq!(token_client.balance(&r1), 0);
+
+    env.ledger().set_timestamp(now + 1_000);
+    let data = client.finalize_distribution();
+
+    assert_eq!(token_client.balance(&r1), 3_000);
+    assert_eq!(token_client.balance(&r2), 2_000);
+    assert_eq!(data.remaining_balance, 5_000);
+    assert_eq!(client.get_proposed_distribution(), None);
+}
+
+#[test]
+fn test_organizer_amendment_replaces_list_and_restarts_period() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let disqualified = Address::generate(&env);
+    let winner = Address::generate(&env);
+    let now = env.ledger().timestamp();
+
+    client.propose_distribution(&vec![&env, disqualified.clone()], &vec![&env, 4_000i128]);
+
+    env.ledger().set_timestamp(now + 500);
+    let amended = client.amend_distribution(&vec![&env, winner.clone()], &vec![&env, 4_000i128]);
+    assert_eq!(amended.finalize_after, now + 1_500);
+
+    env.ledger().set_timestamp(now + 1_000);
+    assert!(client.try_finalize_distribution().is_err());
+
+    env.ledger().set_timestamp(now + 1_500);
+    client.finalize_distribution();
+
+    assert_eq!(token_client.balance(&winner), 4_000);
+    assert_eq!(token_client.balance(&disqualified), 0);
+}
+
+#[test]
+#[should_panic(expected = "Challenge period has ended")]
+fn test_amend_after_challenge_period_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let now = env.ledger().timestamp();
+
+    client.propose_distribution(&vec![&env, Address::generate(&env)], &vec![&env, 1_000i128]);
+    env.ledger().set_timestamp(now + 1_000);
+
+    client.amend_distribution(&vec![&env, Address::generate(&env)], &vec![&env, 1_000i128]);
+}
+
+#[test]
+#[should_panic(expected = "Distribution already proposed")]
+fn test_second_proposal_rejected_while_pending() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+
+    client.propose_distribution(&vec![&env, Address::generate(&env)], &vec![&env, 1_000i128]);
+    client.propose_distribution(&vec![&env, Address::generate(&env)], &vec![&env, 1_000i128]);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient balance")]
+fn test_proposal_exceeding_balance_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+
+    client.propose_distribution(
+        &vec![&env, Address::generate(&env)],
+        &vec![&env, 10_001i128],
+    );
+}
+
+#[test]
+fn test_direct_payouts_enabled_by_default() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+
+    assert!(client.get_direct_payouts());
+    client.single_payout(&recipient, &1_000);
+
+    assert_eq!(token_client.balance(&recipient), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Direct payouts disabled")]
+fn test_disabled_direct_payouts_reject_single_payout() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+
+    client.set_direct_payouts(&false);
+    assert!(!client.get_direct_payouts());
+    client.single_payout(&Address::generate(&env), &1_000);
+}
+
+#[test]
+#[should_panic(expected = "Direct payouts disabled")]
+fn test_disabled_direct_payouts_reject_schedule() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let now = env.ledger().timestamp();
+
+    client.set_direct_payouts(&false);
+    client.create_program_release_schedule(&Address::generate(&env), &1_000, &now);
+}
+
+#[test]
+fn test_disabled_direct_payouts_still_finalize_distribution() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+    let now = env.ledger().timestamp();
+
+    client.set_direct_payouts(&false);
+    client.propose_distribution(&vec![&env, recipient.clone()], &vec![&env, 1_000i128]);
+    env.ledger().set_timestamp(now + 1_000);
+    client.finalize_distribution();
+
+    assert_eq!(token_client.balance(&recipient), 1_000);
+}
@@ -0,0 +1,119 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+fn setup_program(
+    env: &Env,
+    initial_amount: i128,
+) -> (ProgramEscrowContractClient<'static>, token::Client<'static>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    client.initialize_contract(&admin);
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &admin, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    (client, token_client)
+}
+
+#[test]
+fn test_default_policy_gives_dust_to_first_recipient() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+
+    assert_eq!(client.get_dust_policy(), DustPolicy::FirstRecipient);
+    client.distribute_percentages(
+        &1_001,
+        &vec![&env, r1.clone(), r2.clone()],
+        &vec![&env, 5_000u32, 5_000u32],
+    );
+
+    assert_eq!(token_client.balance(&r1), 501);
+    assert_eq!(token_client.balance(&r2), 500);
+    assert_eq!(client.get_remaining_balance(), 8_999);
+}
+
+#[test]
+fn test_leave_in_pool_keeps_dust() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let recipients = vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+
+    client.set_dust_policy(&DustPolicy::LeaveInPool);
+    client.distribute_remainder(&recipients, &Vec::new(&env));
+
+    for recipient in recipients.iter() {
+        assert_eq!(token_client.balance(&recipient), 3_333);
+    }
+    assert_eq!(client.get_remaining_balance(), 1);
+}
+
+#[test]
+fn test_fee_recipient_skips_dust_below_minimum() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let fee_recipient = Address::generate(&env);
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+    let r3 = Address::generate(&env);
+    client.update_fee_config(&None, &Some(fee_recipient.clone()), &None, &None);
+
+    client.set_dust_policy(&DustPolicy::FeeRecipient);
+    client.distribute_remainder(&vec![&env, r1.clone(), r2, r3], &Vec::new(&env));
+
+    // One stroop of dust is not worth a transfer
+    assert_eq!(token_client.balance(&r1), 3_333);
+    assert_eq!(token_client.balance(&fee_recipient), 0);
+    assert_eq!(client.get_remaining_balance(), 1);
+    assert_eq!(client.reconcile().discrepancy, 0);
+}
+
+#[test]
+fn test_fee_recipient_receives_dust_above_minimum() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_019);
+    let fee_recipient = Address::generate(&env);
+    client.update_fee_config(&None, &Some(fee_recipient.clone()), &None, &None);
+    let mut recipients = Vec::new(&env);
+    for _ in 0..20 {
+        recipients.push_back(Address::generate(&env));
+    }
+
+    client.set_dust_policy(&DustPolicy::FeeRecipient);
+    client.distribute_remainder(&recipients, &Vec::new(&env));
+
+    assert_eq!(token_client.balance(&recipients.get(0).unwrap()), 500);
+    assert_eq!(token_client.balance(&fee_recipient), 19);
+    assert_eq!(client.get_remaining_balance(), 0);
+    assert_eq!(client.reconcile().discrepancy, 0);
+}
+
+#[test]
+#[should_panic(expected = "Fee recipient not configured")]
+fn test_fee_recipient_policy_requires_recipient() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+
+    client.set_dust_policy(&DustPolicy::FeeRecipient);
+    client.distribute_remainder(&vec![&env, Address::generate(&env)], &Vec::new(&env));
+}
@@ -0,0 +1,126 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl, testutils::Address as _, token, vec, Address, Env, String,
+};
+
+#[contract]
+pub struct MockRegistry;
+
+#[contractimpl]
+impl MockRegistry {
+    pub fn approve(env: Env, account: Address) {
+        env.storage().persistent().set(&account, &true);
+    }
+
+    pub fn is_eligible(env: Env, account: Address) -> bool {
+        env.storage().persistent().get(&account).unwrap_or(false)
+    }
+}
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    registry: MockRegistryClient<'static>,
+    token: token::Client<'static>,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+    let registry_id = env.register_contract(None, MockRegistry);
+    let registry = MockRegistryClient::new(env, &registry_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(env));
+    let token = token::Client::new(env, &sac.address());
+    let token_admin = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin.mint(&client.address, &100_000);
+    client.lock_program_funds(&100_000);
+    client.set_eligibility_registry(&Some(registry_id));
+
+    Setup {
+        client,
+        registry,
+        token,
+    }
+}
+
+#[test]
+fn test_eligible_recipient_is_paid() {
+    let env = Env::default();
+    let s = setup(&env);
+    let recipient = Address::generate(&env);
+    s.registry.approve(&recipient);
+
+    s.client.single_payout(&recipient, &1_000);
+    assert_eq!(s.token.balance(&recipient), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Recipient not eligible")]
+fn test_ineligible_single_recipient_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.single_payout(&Address::generate(&env), &1_000);
+}
+
+#[test]
+fn test_batch_rejected_if_any_recipient_ineligible() {
+    let env = Env::default();
+    let s = setup(&env);
+    let verified = Address::generate(&env);
+    s.registry.approve(&verified);
+
+    let result = s.client.try_batch_payout(
+        &vec![&env, verified.clone(), Address::generate(&env)],
+        &vec![&env, 1_000i128, 1_000i128],
+    );
+
+    assert!(result.is_err());
+    assert_eq!(s.token.balance(&verified), 0);
+    assert_eq!(s.client.get_remaining_balance(), 100_000);
+}
+
+#[test]
+fn test_scheduled_release_checks_eligibility() {
+    let env = Env::default();
+    let s = setup(&env);
+    let now = env.ledger().timestamp();
+    let recipient = Address::generate(&env);
+
+    assert!(s
+        .client
+        .try_schedule_payout(&recipient, &1_000, &now)
+        .is_err());
+
+    s.registry.approve(&recipient);
+    s.client.schedule_payout(&recipient, &1_000, &now);
+
+    // Eligibility is checked again when the schedule executes
+    let stricter = env.register_contract(None, MockRegistry);
+    s.client.set_eligibility_registry(&Some(stricter));
+    assert_eq!(s.client.execute_due_payouts(), 0);
+    assert_eq!(s.token.balance(&recipient), 0);
+}
+
+#[test]
+fn test_clearing_registry_allows_any_recipient() {
+    let env = Env::default();
+    let s = setup(&env);
+    let recipient = Address::generate(&env);
+
+    s.client.set_eligibility_registry(&None);
+    assert_eq!(s.client.get_eligibility_registry(), None);
+
+    s.client.single_payout(&recipient, &1_000);
+    assert_eq!(s.token.balance(&recipient), 1_000);
+}
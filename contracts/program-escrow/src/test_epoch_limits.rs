@@ -0,0 +1,108 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+
+fn setup_program(
+    env: &Env,
+    initial_amount: i128,
+) -> (ProgramEscrowContractClient<'static>, token::Client<'static>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    (client, token_client)
+}
+
+fn daily_cap(cap: i128) -> Option<EpochLimit> {
+    Some(EpochLimit {
+        cap,
+        duration: 86_400,
+    })
+}
+
+#[test]
+fn test_cap_resets_next_epoch() {
+    let env = Env::default();
+    env.ledger().set_timestamp(86_400 * 10);
+    let (client, token_client) = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+
+    client.set_epoch_limit(&daily_cap(3_000));
+    client.batch_payout(
+        &vec![&env, recipient.clone(), recipient.clone()],
+        &vec![&env, 1_000i128, 2_000i128],
+    );
+    assert_eq!(client.get_epoch_outflow(), 3_000);
+    assert!(client.try_single_payout(&recipient, &1).is_err());
+
+    env.ledger().set_timestamp(86_400 * 11);
+    assert_eq!(client.get_epoch_outflow(), 0);
+    client.single_payout(&recipient, &3_000);
+
+    assert_eq!(token_client.balance(&recipient), 6_000);
+}
+
+#[test]
+fn test_organizer_approves_over_cap_payout() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+    let payout_id = BytesN::from_array(&env, &[9; 32]);
+
+    client.set_epoch_limit(&daily_cap(1_000));
+    client.approve_over_cap_payout(&payout_id);
+    client.single_payout_with_id(&payout_id, &recipient, &5_000, &None);
+
+    assert_eq!(token_client.balance(&recipient), 5_000);
+    assert_eq!(client.get_epoch_outflow(), 5_000);
+}
+
+#[test]
+#[should_panic(expected = "Epoch payout cap exceeded")]
+fn test_payout_over_cap_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+
+    client.set_epoch_limit(&daily_cap(1_000));
+    client.single_payout(&Address::generate(&env), &600);
+    client.single_payout(&Address::generate(&env), &600);
+}
+
+#[test]
+fn test_claimable_and_scheduled_payouts_count_toward_cap() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+    let now = env.ledger().timestamp();
+
+    client.set_epoch_limit(&daily_cap(3_000));
+    client.batch_payout_claimable(
+        &vec![&env, recipient.clone()],
+        &vec![&env, 2_000i128],
+        &(now + 1_000),
+    );
+    assert_eq!(client.get_epoch_outflow(), 2_000);
+
+    client.schedule_payout(&recipient, &1_500, &now);
+    assert_eq!(client.execute_due_payouts(), 0);
+    assert_eq!(token_client.balance(&recipient), 0);
+    assert_eq!(client.get_epoch_outflow(), 2_000);
+}
@@ -0,0 +1,98 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    token, vec, Address, Env, IntoVal, String, Val,
+};
+
+fn setup_program(env: &Env, initial_amount: i128) -> ProgramEscrowContractClient<'static> {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(env));
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token::StellarAssetClient::new(env, &sac.address()).mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    client
+}
+
+#[test]
+fn test_payout_topics_include_program_and_recipient() {
+    let env = Env::default();
+    let client = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+
+    client.single_payout(&recipient, &1_000);
+
+    let (_, topics, _) = env.events().all().last().unwrap();
+    let expected: Vec<Val> =
+        (PAYOUT, String::from_str(&env, "hack-2026"), recipient).into_val(&env);
+    assert_eq!(topics, expected);
+}
+
+#[test]
+fn test_batch_and_lock_topics_include_program() {
+    let env = Env::default();
+    let client = setup_program(&env, 10_000);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    let (_, topics, _) = env.events().all().last().unwrap();
+    let expected: Vec<Val> = (FUNDS_LOCKED, program_id.clone()).into_val(&env);
+    assert_eq!(topics, expected);
+
+    client.batch_payout(&vec![&env, Address::generate(&env)], &vec![&env, 1_000i128]);
+
+    let (_, topics, _) = env.events().all().last().unwrap();
+    let expected: Vec<Val> = (BATCH_PAYOUT, program_id).into_val(&env);
+    assert_eq!(topics, expected);
+}
+
+#[test]
+fn test_config_setters_emit_program_topic() {
+    let env = Env::default();
+    let client = setup_program(&env, 10_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let expected: Vec<Val> = (CLAWBACK_WINDOW_SET, program_id.clone()).into_val(&env);
+
+    client.set_clawback_window(&Some(3_600));
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(topics, expected);
+
+    client.set_large_payout_threshold(&Some(5_000));
+    let (_, topics, _) = env.events().all().last().unwrap();
+    let expected: Vec<Val> = (LARGE_PAYOUT_THRESHOLD_SET, program_id.clone()).into_val(&env);
+    assert_eq!(topics, expected);
+
+    client.set_challenge_period(&600);
+    let (_, topics, _) = env.events().all().last().unwrap();
+    let expected: Vec<Val> = (CHALLENGE_PERIOD_SET, program_id.clone()).into_val(&env);
+    assert_eq!(topics, expected);
+
+    client.set_max_batch_size(&Some(10));
+    let (_, topics, _) = env.events().all().last().unwrap();
+    let expected: Vec<Val> = (MAX_BATCH_SIZE_SET, program_id).into_val(&env);
+    assert_eq!(topics, expected);
+}
+
+#[test]
+fn test_track_topics_include_program_and_track() {
+    let env = Env::default();
+    let client = setup_program(&env, 10_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let track = String::from_str(&env, "defi");
+
+    client.create_track(&track, &2_000, &None);
+
+    let (_, topics, _) = env.events().all().last().unwrap();
+    let expected: Vec<Val> = (TRACK_CREATED, program_id, track).into_val(&env);
+    assert_eq!(topics, expected);
+}
@@ -0,0 +1,203 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, vec, Address, Env, String};
+
+/// Token that charges `fee_bps` on every transfer. By default the recipient
+/// receives the amount minus the fee; with `sender_pays` set the sender is
+/// debited the amount plus the fee instead.
+#[contract]
+pub struct FeeToken;
+
+#[contractimpl]
+impl FeeToken {
+    pub fn configure(env: Env, fee_bps: i128, sender_pays: bool) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("fee"), &fee_bps);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("sender"), &sender_pays);
+    }
+
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), to.clone());
+        env.storage().persistent().set(&to, &(balance + amount));
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage().persistent().get(&id).unwrap_or(0)
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        let fee_bps: i128 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("fee"))
+            .unwrap_or(0);
+        let sender_pays: bool = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("sender"))
+            .unwrap_or(false);
+        let fee = amount * fee_bps / 10_000;
+        let (debit, credit) = if sender_pays {
+            (amount + fee, amount)
+        } else {
+            (amount, amount - fee)
+        };
+        let from_balance = Self::balance(env.clone(), from.clone());
+        if from_balance < debit {
+            panic!("insufficient balance");
+        }
+        env.storage()
+            .persistent()
+            .set(&from, &(from_balance - debit));
+        let to_balance = Self::balance(env.clone(), to.clone());
+        env.storage().persistent().set(&to, &(to_balance + credit));
+    }
+}
+
+fn setup_program(
+    env: &Env,
+    fee_bps: i128,
+    sender_pays: bool,
+) -> (
+    ProgramEscrowContractClient<'static>,
+    FeeTokenClient<'static>,
+) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+    let token_id = env.register_contract(None, FeeToken);
+    let token_client = FeeTokenClient::new(env, &token_id);
+    token_client.configure(&fee_bps, &sender_pays);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &token_id, &organizer, &None);
+
+    (client, token_client)
+}
+
+#[test]
+fn test_funding_records_amount_actually_received() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 100, false);
+    let funder = Address::generate(&env);
+    token_client.mint(&funder, &10_000);
+
+    let data = client.fund_program(&funder, &10_000);
+
+    assert_eq!(data.total_funds, 9_900);
+    assert_eq!(data.remaining_balance, 9_900);
+    assert_eq!(client.get_sponsors().get(0).unwrap().amount, 9_900);
+    assert!(client.is_fee_on_transfer());
+    assert_eq!(token_client.balance(&client.address), 9_900);
+    let report = client.assert_invariants();
+    assert!(report.accounting_ok);
+    assert!(report.solvent);
+}
+
+#[test]
+fn test_initial_liquidity_records_amount_actually_received() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let token_id = env.register_contract(None, FeeToken);
+    let token_client = FeeTokenClient::new(&env, &token_id);
+    token_client.configure(&200, &false);
+    let organizer = Address::generate(&env);
+    token_client.mint(&organizer, &5_000);
+
+    let data = client.init_program(
+        &String::from_str(&env, "hack-2026"),
+        &Address::generate(&env),
+        &token_id,
+        &organizer,
+        &Some(5_000),
+    );
+
+    assert_eq!(data.total_funds, 4_900);
+    assert_eq!(data.remaining_balance, 4_900);
+    assert_eq!(token_client.balance(&client.address), 4_900);
+}
+
+#[test]
+fn test_payouts_debit_transfer_fees_charged_to_escrow() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 100, true);
+    token_client.mint(&client.address, &10_000);
+    client.lock_program_funds(&10_000);
+    client.set_fee_on_transfer(&true);
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+
+    client.single_payout(&r1, &1_000);
+    assert_eq!(token_client.balance(&r1), 1_000);
+    assert_eq!(client.get_remaining_balance(), 8_990);
+
+    client.batch_payout(
+        &vec![&env, r1.clone(), r2.clone()],
+        &vec![&env, 2_000i128, 3_000i128],
+    );
+    assert_eq!(token_client.balance(&r2), 3_000);
+    assert_eq!(client.get_remaining_balance(), 3_940);
+    assert_eq!(token_client.balance(&client.address), 3_940);
+    assert!(client.assert_invariants().accounting_ok);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient balance")]
+fn test_payout_rejected_when_pool_cannot_cover_transfer_fee() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 100, true);
+    token_client.mint(&client.address, &20_000);
+    client.lock_program_funds(&10_000);
+    client.set_fee_on_transfer(&true);
+
+    // The token has the funds, but the pool cannot absorb the 100 fee
+    client.single_payout(&Address::generate(&env), &10_000);
+}
+
+#[test]
+fn test_legacy_lock_records_only_what_arrived() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 100, false);
+    let funder = Address::generate(&env);
+    token_client.mint(&funder, &10_000);
+
+    // The deposit arrives short before the nominal amount is locked
+    client.set_fee_on_transfer(&true);
+    token_client.transfer(&funder, &client.address, &10_000);
+    let data = client.lock_program_funds(&10_000);
+
+    assert_eq!(data.remaining_balance, 9_900);
+    assert!(client.is_fee_on_transfer());
+    assert_eq!(client.reconcile().discrepancy, 0);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient funds received")]
+fn test_legacy_lock_short_deposit_rejected_without_flag() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 100, false);
+    let funder = Address::generate(&env);
+    token_client.mint(&funder, &10_000);
+
+    token_client.transfer(&funder, &client.address, &10_000);
+    client.lock_program_funds(&10_000);
+}
+
+#[test]
+#[should_panic(expected = "No funds received")]
+fn test_legacy_lock_without_deposit_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 100, false);
+
+    client.lock_program_funds(&1_000);
+}
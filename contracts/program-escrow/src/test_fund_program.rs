@@ -0,0 +1,89 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, MockAuth, MockAuthInvoke},
+    token, Address, Env, IntoVal, String,
+};
+
+fn setup_program(
+    env: &Env,
+) -> (
+    ProgramEscrowContractClient<'static>,
+    token::Client<'static>,
+    token::StellarAssetClient<'static>,
+) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    (client, token_client, token_admin_client)
+}
+
+#[test]
+fn test_fund_program_pulls_tokens_and_records() {
+    let env = Env::default();
+    let (client, token_client, token_admin_client) = setup_program(&env);
+    let funder = Address::generate(&env);
+    token_admin_client.mint(&funder, &5_000);
+
+    let data = client.fund_program(&funder, &3_000);
+
+    assert_eq!(data.remaining_balance, 3_000);
+    assert_eq!(token_client.balance(&funder), 2_000);
+    assert_eq!(token_client.balance(&client.address), 3_000);
+    assert_eq!(client.get_sponsors().get(0).unwrap().sponsor, funder);
+}
+
+#[test]
+fn test_fund_program_requires_funder_auth() {
+    let env = Env::default();
+    let (client, token_client, token_admin_client) = setup_program(&env);
+    let funder = Address::generate(&env);
+    token_admin_client.mint(&funder, &5_000);
+
+    let stranger = Address::generate(&env);
+    env.mock_auths(&[MockAuth {
+        address: &stranger,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "fund_program",
+            args: (funder.clone(), 3_000i128).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+
+    assert!(client.try_fund_program(&funder, &3_000).is_err());
+    assert_eq!(token_client.balance(&funder), 5_000);
+}
+
+#[test]
+#[should_panic(expected = "Amount must be greater than zero")]
+fn test_fund_program_rejects_zero() {
+    let env = Env::default();
+    let (client, _token_client, _token_admin_client) = setup_program(&env);
+
+    client.fund_program(&Address::generate(&env), &0);
+}
+
+#[test]
+fn test_underfunded_lock_rejected_for_standard_token() {
+    let env = Env::default();
+    let (client, _token_client, token_admin_client) = setup_program(&env);
+    token_admin_client.mint(&client.address, &5_000);
+
+    assert!(client.try_lock_program_funds(&10_000).is_err());
+    assert!(!client.is_fee_on_transfer());
+    assert_eq!(client.get_remaining_balance(), 0);
+}
@@ -0,0 +1,112 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+
+fn setup_program(
+    env: &Env,
+    initial_amount: i128,
+) -> (ProgramEscrowContractClient<'static>, token::Client<'static>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    client.initialize_contract(&admin);
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &admin, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    (client, token_client)
+}
+
+#[test]
+fn test_invariants_hold_across_payout_paths() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let now = env.ledger().timestamp();
+
+    client.single_payout(&Address::generate(&env), &1_000);
+    client.batch_payout(
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 500i128, 1_500i128],
+    );
+    let ids = client.batch_payout_claimable(
+        &vec![&env, Address::generate(&env)],
+        &vec![&env, 2_000i128],
+        &(now + 100),
+    );
+    env.ledger().set_timestamp(now + 101);
+    client.reclaim_expired_claim(&program_id, &ids.get(0).unwrap());
+
+    let report = client.assert_invariants();
+    assert!(report.accounting_ok);
+    assert!(report.solvent);
+    assert_eq!(report.total_paid, 3_000);
+    assert_eq!(report.remaining_balance, 7_000);
+    assert_eq!(report.token_balance, 7_000);
+}
+
+#[test]
+fn test_invariants_include_refunds() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+
+    client.single_payout(&Address::generate(&env), &4_000);
+    client.close_program();
+
+    let report = client.assert_invariants();
+    assert!(report.accounting_ok);
+    assert_eq!(report.total_refunded, 6_000);
+    assert_eq!(report.expected_remaining, 0);
+}
+
+#[test]
+fn test_invariants_detect_insolvency() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+
+    client.set_paused(&Some(true), &None, &None, &None);
+    client.emergency_withdraw(&Address::generate(&env));
+
+    let report = client.assert_invariants();
+    assert!(report.accounting_ok);
+    assert!(!report.solvent);
+    assert_eq!(report.obligations, 10_000);
+    assert_eq!(report.token_balance, 0);
+}
+
+#[test]
+fn test_total_paid_backfilled_for_legacy_program() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+
+    client.batch_payout(
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 1_500i128, 2_500i128],
+    );
+    // Programs created before the running total existed have no entry
+    env.as_contract(&client.address, || {
+        env.storage().instance().remove(&TOTAL_PAID);
+    });
+
+    let report = client.assert_invariants();
+    assert!(report.accounting_ok);
+    assert_eq!(report.total_paid, 4_000);
+
+    client.single_payout(&Address::generate(&env), &1_000);
+    assert_eq!(client.assert_invariants().total_paid, 5_000);
+}
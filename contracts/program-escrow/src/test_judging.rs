@@ -0,0 +1,119 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, BytesN, Env, String};
+
+fn setup_program(
+    env: &Env,
+    initial_amount: i128,
+) -> (ProgramEscrowContractClient<'static>, token::Client<'static>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    (client, token_client)
+}
+
+#[test]
+fn test_borda_count_picks_winners() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 20_000);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    let (a, b, c) = (
+        BytesN::from_array(&env, &[1; 32]),
+        BytesN::from_array(&env, &[2; 32]),
+        BytesN::from_array(&env, &[3; 32]),
+    );
+    client.register_submission(&alice, &a);
+    client.register_submission(&bob, &b);
+    client.register_submission(&carol, &c);
+
+    let j1 = Address::generate(&env);
+    let j2 = Address::generate(&env);
+    client.add_judge(&j1);
+    client.add_judge(&j2);
+    // Scores: b = 3 + 3, a = 2 + 1, c = 1 + 2 (tie goes to the earlier entry)
+    client.cast_vote(&j1, &vec![&env, b.clone(), a.clone(), c.clone()]);
+    client.cast_vote(&j2, &vec![&env, b.clone(), c.clone(), a.clone()]);
+
+    let winners = client.finalize_judging(&2);
+    assert_eq!(winners, vec![&env, bob.clone(), alice.clone()]);
+    assert_eq!(client.get_winning_set(), winners);
+
+    client.set_prize_tiers(&vec![&env, 5_000i128, 3_000i128]);
+    client.distribute_by_rank(&winners);
+    assert_eq!(token_client.balance(&bob), 5_000);
+    assert_eq!(token_client.balance(&alice), 3_000);
+}
+
+#[test]
+#[should_panic(expected = "Recipient not in winning set")]
+fn test_payout_outside_winning_set_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 20_000);
+    let alice = Address::generate(&env);
+    let judge = Address::generate(&env);
+    let hash = BytesN::from_array(&env, &[1; 32]);
+
+    client.register_submission(&alice, &hash);
+    client.add_judge(&judge);
+    client.cast_vote(&judge, &vec![&env, hash]);
+    client.finalize_judging(&1);
+
+    client.single_payout(&Address::generate(&env), &1_000);
+}
+
+#[test]
+#[should_panic(expected = "Not a judge")]
+fn test_non_judge_cannot_vote() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 20_000);
+    let hash = BytesN::from_array(&env, &[1; 32]);
+    client.register_submission(&Address::generate(&env), &hash);
+
+    client.cast_vote(&Address::generate(&env), &vec![&env, hash]);
+}
+
+#[test]
+#[should_panic(expected = "Duplicate submission in ranking")]
+fn test_duplicate_ranking_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 20_000);
+    let judge = Address::generate(&env);
+    let hash = BytesN::from_array(&env, &[1; 32]);
+    client.register_submission(&Address::generate(&env), &hash);
+    client.add_judge(&judge);
+
+    client.cast_vote(&judge, &vec![&env, hash.clone(), hash]);
+}
+
+#[test]
+#[should_panic(expected = "Winners already finalized")]
+fn test_vote_after_finalize_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 20_000);
+    let judge = Address::generate(&env);
+    let hash = BytesN::from_array(&env, &[1; 32]);
+    client.register_submission(&Address::generate(&env), &hash);
+    client.add_judge(&judge);
+    client.cast_vote(&judge, &vec![&env, hash.clone()]);
+    client.finalize_judging(&1);
+
+    client.cast_vote(&judge, &vec![&env, hash]);
+}
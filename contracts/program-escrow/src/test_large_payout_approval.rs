@@ -0,0 +1,133 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, BytesN, Env, String};
+
+fn setup_program(
+    env: &Env,
+    initial_amount: i128,
+) -> (ProgramEscrowContractClient<'static>, token::Client<'static>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+    client.set_large_payout_threshold(&Some(10_000));
+
+    (client, token_client)
+}
+
+#[test]
+fn test_payout_below_threshold_needs_no_approval() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 100_000);
+    let recipient = Address::generate(&env);
+
+    client.single_payout(&recipient, &10_000);
+    assert_eq!(token_client.balance(&recipient), 10_000);
+}
+
+#[test]
+#[should_panic(expected = "Large payout requires organizer approval")]
+fn test_large_single_payout_without_approval_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 100_000);
+
+    client.single_payout(&Address::generate(&env), &10_001);
+}
+
+#[test]
+#[should_panic(expected = "Large payout requires organizer approval")]
+fn test_large_payout_with_unapproved_id_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 100_000);
+    let payout_id = BytesN::from_array(&env, &[1u8; 32]);
+
+    client.single_payout_with_id(&payout_id, &Address::generate(&env), &50_000, &None);
+}
+
+#[test]
+fn test_approved_large_payout_executes_and_consumes_approval() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 100_000);
+    let recipient = Address::generate(&env);
+    let payout_id = BytesN::from_array(&env, &[1u8; 32]);
+
+    client.approve_large_payout(
+        &payout_id,
+        &vec![&env, recipient.clone()],
+        &vec![&env, 50_000i128],
+    );
+    assert!(client.is_large_payout_approved(&payout_id));
+
+    client.single_payout_with_id(&payout_id, &recipient, &50_000, &None);
+
+    assert_eq!(token_client.balance(&recipient), 50_000);
+    assert!(!client.is_large_payout_approved(&payout_id));
+}
+
+#[test]
+fn test_batch_threshold_applies_to_total() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 100_000);
+    let recipients = vec![&env, Address::generate(&env), Address::generate(&env)];
+    let amounts = vec![&env, 6_000i128, 6_000i128];
+
+    let result = client.try_batch_payout(&recipients, &amounts);
+    assert!(result.is_err());
+
+    let payout_id = BytesN::from_array(&env, &[2u8; 32]);
+    client.approve_large_payout(&payout_id, &recipients, &amounts);
+    let data = client.batch_payout_with_id(&payout_id, &recipients, &amounts, &vec![&env]);
+    assert_eq!(data.remaining_balance, 88_000);
+}
+
+#[test]
+fn test_disabling_threshold_allows_large_payouts() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 100_000);
+
+    client.set_large_payout_threshold(&None);
+    assert_eq!(client.get_large_payout_threshold(), None);
+
+    client.single_payout(&Address::generate(&env), &50_000);
+}
+
+#[test]
+fn test_approval_only_covers_the_approved_payload() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 100_000);
+    let recipient = Address::generate(&env);
+    let attacker = Address::generate(&env);
+    let payout_id = BytesN::from_array(&env, &[3u8; 32]);
+
+    client.approve_large_payout(
+        &payout_id,
+        &vec![&env, recipient.clone()],
+        &vec![&env, 20_000i128],
+    );
+
+    assert!(client
+        .try_single_payout_with_id(&payout_id, &attacker, &20_000, &None)
+        .is_err());
+    assert!(client
+        .try_single_payout_with_id(&payout_id, &recipient, &90_000, &None)
+        .is_err());
+    assert!(client.is_large_payout_approved(&payout_id));
+
+    client.single_payout_with_id(&payout_id, &recipient, &20_000, &None);
+    assert_eq!(token_client.balance(&recipient), 20_000);
+    assert_eq!(token_client.balance(&attacker), 0);
+}
@@ -0,0 +1,160 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events, IssuerFlags},
+    token, vec, Address, Env, String, TryFromVal,
+};
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    token: token::Client<'static>,
+    token_admin: token::StellarAssetClient<'static>,
+}
+
+fn setup(env: &Env, initial_amount: i128) -> Setup {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(env));
+    let token = token::Client::new(env, &sac.address());
+    let token_admin = token::StellarAssetClient::new(env, &sac.address());
+    sac.issuer().set_flag(IssuerFlags::RevocableFlag);
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    Setup {
+        client,
+        token,
+        token_admin,
+    }
+}
+
+#[test]
+fn test_lenient_batch_skips_failed_transfer() {
+    let env = Env::default();
+    let s = setup(&env, 10_000);
+    let r1 = Address::generate(&env);
+    let bad = Address::generate(&env);
+    let r3 = Address::generate(&env);
+    // A deauthorized balance makes the token transfer fail.
+    s.token_admin.set_authorized(&bad, &false);
+
+    let results = s.client.batch_payout_lenient(
+        &vec![&env, r1.clone(), bad.clone(), r3.clone()],
+        &vec![&env, 1_000i128, 2_000i128, 3_000i128],
+    );
+
+    assert_eq!(results, vec![&env, true, false, true]);
+    assert_eq!(s.token.balance(&r1), 1_000);
+    assert_eq!(s.token.balance(&bad), 0);
+    assert_eq!(s.token.balance(&r3), 3_000);
+
+    let data = s.client.get_program_info();
+    // The skipped 2_000 stays reserved for a retry
+    assert_eq!(data.remaining_balance, 4_000);
+    assert_eq!(s.client.get_retry_total(), 2_000);
+    assert_eq!(data.payout_history.len(), 2);
+    assert_eq!(s.client.reconcile().discrepancy, 0);
+
+    let (_, _, payload) = env.events().all().last().unwrap();
+    let event = BatchPayoutEvent::try_from_val(&env, &payload).unwrap();
+    assert_eq!(event.recipient_count, 2);
+    assert_eq!(event.total_amount, 4_000);
+}
+
+#[test]
+fn test_lenient_batch_all_success() {
+    let env = Env::default();
+    let s = setup(&env, 10_000);
+
+    let results = s.client.batch_payout_lenient(
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 1_000i128, 2_000i128],
+    );
+
+    assert_eq!(results, vec![&env, true, true]);
+    assert_eq!(s.client.get_remaining_balance(), 7_000);
+}
+
+#[test]
+fn test_strict_batch_reverts_on_failed_transfer() {
+    let env = Env::default();
+    let s = setup(&env, 10_000);
+    let bad = Address::generate(&env);
+    s.token_admin.set_authorized(&bad, &false);
+
+    let result = s.client.try_batch_payout(
+        &vec![&env, Address::generate(&env), bad],
+        &vec![&env, 1_000i128, 2_000i128],
+    );
+
+    assert!(result.is_err());
+    assert_eq!(s.client.get_remaining_balance(), 10_000);
+}
+
+#[test]
+fn test_lenient_batch_queues_failed_team_member() {
+    let env = Env::default();
+    let s = setup(&env, 10_000);
+    let team = Address::generate(&env);
+    let good = Address::generate(&env);
+    let bad = Address::generate(&env);
+    s.token_admin.set_authorized(&bad, &false);
+    s.client.register_team(
+        &team,
+        &vec![&env, good.clone(), bad.clone()],
+        &vec![&env, 6_000u32, 4_000u32],
+    );
+
+    let results = s
+        .client
+        .batch_payout_lenient(&vec![&env, team], &vec![&env, 1_000i128]);
+
+    assert_eq!(results, vec![&env, true]);
+    assert_eq!(s.token.balance(&good), 600);
+    assert_eq!(s.token.balance(&bad), 0);
+    assert_eq!(s.client.get_retry_total(), 400);
+    assert_eq!(s.client.get_pending_retry(&1).unwrap().recipient, bad);
+    assert_eq!(s.client.reconcile().discrepancy, 0);
+
+    // Only the delivered share is recorded; the retry records the rest
+    let (_, _, payload) = env.events().all().last().unwrap();
+    let event = BatchPayoutEvent::try_from_val(&env, &payload).unwrap();
+    assert_eq!(event.total_amount, 600);
+    let history = s.client.get_program_info().payout_history;
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.get(0).unwrap().amount, 600);
+
+    s.token_admin.set_authorized(&bad, &true);
+    let history = s.client.retry_payout(&1).payout_history;
+    assert_eq!(s.token.balance(&bad), 400);
+    assert_eq!(history.iter().map(|record| record.amount).sum::<i128>(), 1_000);
+}
+
+#[test]
+fn test_lenient_batch_team_with_every_member_failing() {
+    let env = Env::default();
+    let s = setup(&env, 10_000);
+    let team = Address::generate(&env);
+    let bad = Address::generate(&env);
+    s.token_admin.set_authorized(&bad, &false);
+    s.client.register_team(&team, &vec![&env, bad.clone()], &vec![&env, 10_000u32]);
+
+    let results = s
+        .client
+        .batch_payout_lenient(&vec![&env, team], &vec![&env, 1_000i128]);
+
+    assert_eq!(results, vec![&env, true]);
+    assert_eq!(s.client.get_retry_total(), 1_000);
+    assert_eq!(s.client.get_program_info().payout_history.len(), 0);
+    assert_eq!(s.client.reconcile().discrepancy, 0);
+}
@@ -0,0 +1,126 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+fn setup_program(
+    env: &Env,
+    initial_amount: i128,
+) -> (ProgramEscrowContractClient<'static>, token::Client<'static>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    (client, token_client)
+}
+
+#[test]
+fn test_default_max_batch_size_and_estimate() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+
+    assert_eq!(client.get_max_batch_size(), None);
+    assert_eq!(client.estimate_batch_cost(&60).chunks, 1);
+    client.set_max_batch_size(&Some(25));
+
+    let estimate = client.estimate_batch_cost(&60);
+    assert_eq!(estimate.max_batch_size, Some(25));
+    assert_eq!(estimate.chunks, 3);
+    assert_eq!(estimate.transfers, 60);
+}
+
+#[test]
+fn test_batch_within_limit_succeeds() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+
+    client.set_max_batch_size(&Some(2));
+    client.batch_payout(
+        &vec![&env, r1.clone(), r2.clone()],
+        &vec![&env, 100i128, 200i128],
+    );
+
+    assert_eq!(token_client.balance(&r2), 200);
+}
+
+#[test]
+#[should_panic(expected = "Batch exceeds max batch size")]
+fn test_oversized_batch_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+
+    client.set_max_batch_size(&Some(2));
+    client.batch_payout(
+        &vec![
+            &env,
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+        ],
+        &vec![&env, 100i128, 100i128, 100i128],
+    );
+}
+
+#[test]
+fn test_clearing_limit_allows_any_batch() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+
+    client.set_max_batch_size(&Some(1));
+    client.set_max_batch_size(&None);
+    assert_eq!(client.get_max_batch_size(), None);
+
+    client.batch_payout(
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 100i128, 100i128],
+    );
+}
+
+#[test]
+#[should_panic(expected = "Batch exceeds max batch size")]
+fn test_team_fan_out_counts_against_limit() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let team = Address::generate(&env);
+    client.register_team(
+        &team,
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 5_000u32, 5_000u32],
+    );
+
+    client.set_max_batch_size(&Some(2));
+    client.batch_payout(
+        &vec![&env, team, Address::generate(&env)],
+        &vec![&env, 100i128, 100i128],
+    );
+}
+
+#[test]
+#[should_panic(expected = "Batch exceeds max batch size")]
+fn test_claimable_batch_respects_limit() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+
+    client.set_max_batch_size(&Some(1));
+    client.batch_payout_claimable(
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 100i128, 100i128],
+        &(env.ledger().timestamp() + 100),
+    );
+}
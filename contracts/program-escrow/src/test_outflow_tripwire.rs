@@ -0,0 +1,129 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+fn setup_program(
+    env: &Env,
+    initial_amount: i128,
+) -> (ProgramEscrowContractClient<'static>, token::Client<'static>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    (client, token_client)
+}
+
+fn tripwire(max_bps: u32) -> Option<OutflowTripwire> {
+    Some(OutflowTripwire {
+        max_bps,
+        window: 3_600,
+    })
+}
+
+#[test]
+fn test_tripwire_pauses_after_threshold_crossed() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+
+    client.set_outflow_tripwire(&tripwire(2_000));
+    client.single_payout(&recipient, &1_500);
+    assert!(!client.is_payouts_paused());
+
+    // Crossing 20% of the starting balance completes, then trips the pause
+    client.single_payout(&recipient, &1_000);
+    assert!(client.is_payouts_paused());
+    assert_eq!(token_client.balance(&recipient), 2_500);
+    assert!(client.try_single_payout(&recipient, &1).is_err());
+
+    client.reset_outflow_tripwire();
+    assert!(!client.is_payouts_paused());
+    client.single_payout(&recipient, &500);
+    assert_eq!(client.get_outflow_window().unwrap().baseline, 7_500);
+}
+
+#[test]
+fn test_window_rolls_over() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+
+    client.set_outflow_tripwire(&tripwire(2_000));
+    client.single_payout(&recipient, &1_500);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 7_200);
+    client.single_payout(&recipient, &1_500);
+
+    assert!(!client.is_payouts_paused());
+    let window = client.get_outflow_window().unwrap();
+    assert_eq!(window.baseline, 8_500);
+    assert_eq!(window.paid, 1_500);
+    assert_eq!(window.previous_paid, 0);
+}
+
+#[test]
+fn test_outflow_across_window_boundary_trips() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+    let start = env.ledger().timestamp();
+
+    client.set_outflow_tripwire(&tripwire(2_000));
+    client.single_payout(&recipient, &100);
+    env.ledger().set_timestamp(start + 3_599);
+    client.single_payout(&recipient, &1_400);
+
+    // A fixed window would allow another full 20% right after the boundary
+    env.ledger().set_timestamp(start + 3_600);
+    client.single_payout(&recipient, &1_500);
+
+    assert!(client.is_payouts_paused());
+    assert_eq!(client.get_outflow_window().unwrap().previous_paid, 1_500);
+}
+
+#[test]
+fn test_trip_keeps_existing_pause_and_reset_only_lifts_its_own() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+    let organizer = client.get_organizer();
+    let reason = String::from_str(&env, "Incident review");
+
+    client.set_outflow_tripwire(&tripwire(2_000));
+    client.single_payout(&recipient, &2_500);
+    assert!(client.is_payouts_paused());
+    client.reset_outflow_tripwire();
+    assert!(!client.is_payouts_paused());
+
+    client.pause_payouts(&organizer, &Some(reason.clone()));
+    client.reset_outflow_tripwire();
+    assert!(client.is_payouts_paused());
+    assert_eq!(client.get_pause_flags().pause_reason, Some(reason));
+}
+
+#[test]
+#[should_panic(expected = "Invalid tripwire configuration")]
+fn test_tripwire_rejects_zero_threshold() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+
+    client.set_outflow_tripwire(&tripwire(0));
+}
@@ -243,7 +243,7 @@ fn test_set_paused_emits_events() {
 fn test_operations_resume_after_unpause() {
     let env = Env::default();
     env.mock_all_auths();
-    let (contract, _admin, _payout_key, _token) = setup_program_with_admin(&env);
+    let (contract, _admin, _payout_key, token) = setup_program_with_admin(&env);
 
     // Pause
     contract.set_paused(&Some(true), &None, &None, &None);
@@ -252,6 +252,7 @@ fn test_operations_resume_after_unpause() {
     contract.set_paused(&Some(false), &None, &None, &None);
     
     // Should succeed now
+    token::StellarAssetClient::new(&env, &token.address).mint(&contract.address, &1000);
     contract.lock_program_funds(&1000);
 }
 
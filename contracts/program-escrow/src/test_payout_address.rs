@@ -0,0 +1,145 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+
+fn setup_program(
+    env: &Env,
+    initial_amount: i128,
+) -> (ProgramEscrowContractClient<'static>, token::Client<'static>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    (client, token_client)
+}
+
+fn finalize_single_winner(env: &Env, client: &ProgramEscrowContractClient) -> Address {
+    let judge = Address::generate(env);
+    let winner = Address::generate(env);
+    let hash = BytesN::from_array(env, &[1; 32]);
+    client.add_judge(&judge);
+    client.register_submission(&winner, &hash);
+    client.cast_vote(&judge, &Vec::from_array(env, [hash]));
+    client.finalize_judging(&1);
+    winner
+}
+
+#[test]
+fn test_winner_payout_sent_to_alternate() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let winner = finalize_single_winner(&env, &client);
+    let treasury = Address::generate(&env);
+
+    client.set_payout_address(&winner, &treasury);
+    assert_eq!(client.get_payout_address(&winner), Some(treasury.clone()));
+
+    client.batch_payout(&vec![&env, winner.clone()], &vec![&env, 4_000i128]);
+
+    assert_eq!(token_client.balance(&treasury), 4_000);
+    assert_eq!(token_client.balance(&winner), 0);
+    let history = client.get_program_info().payout_history;
+    assert_eq!(history.get(0).unwrap().recipient, winner);
+}
+
+#[test]
+fn test_setting_own_address_clears_alternate() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let winner = finalize_single_winner(&env, &client);
+
+    client.set_payout_address(&winner, &Address::generate(&env));
+    client.set_payout_address(&winner, &winner);
+    assert_eq!(client.get_payout_address(&winner), None);
+
+    client.single_payout(&winner, &1_000);
+    assert_eq!(token_client.balance(&winner), 1_000);
+}
+
+#[test]
+fn test_shortlisted_participant_may_set_alternate() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let participant = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    client.shortlist_winners(
+        &vec![&env, participant.clone()],
+        &(env.ledger().timestamp() + 1_000),
+    );
+    client.set_payout_address(&participant, &treasury);
+
+    assert_eq!(client.get_payout_address(&participant), Some(treasury));
+}
+
+#[test]
+#[should_panic(expected = "Not an approved winner")]
+fn test_non_winner_cannot_set_alternate() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    finalize_single_winner(&env, &client);
+
+    client.set_payout_address(&Address::generate(&env), &Address::generate(&env));
+}
+
+#[test]
+#[should_panic(expected = "Recipient is blocked")]
+fn test_blocked_alternate_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let winner = finalize_single_winner(&env, &client);
+    let treasury = Address::generate(&env);
+
+    client.set_payout_address(&winner, &treasury);
+    client.block_recipient(&treasury);
+
+    client.single_payout(&winner, &1_000);
+}
+
+#[test]
+fn test_held_payout_released_to_alternate() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let winner = finalize_single_winner(&env, &client);
+    let treasury = Address::generate(&env);
+    let payout_id = BytesN::from_array(&env, &[3; 32]);
+
+    client.set_payout_address(&winner, &treasury);
+    client.set_clawback_window(&Some(60));
+    client.single_payout_with_id(&payout_id, &winner, &2_000, &None);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 60);
+    client.release_held_payout(&payout_id);
+
+    assert_eq!(token_client.balance(&treasury), 2_000);
+    assert_eq!(token_client.balance(&winner), 0);
+}
+
+#[test]
+#[should_panic(expected = "Recipient is frozen")]
+fn test_frozen_winner_cannot_redirect_payouts() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let winner = finalize_single_winner(&env, &client);
+    client.freeze_recipient(&winner);
+
+    client.set_payout_address(&winner, &Address::generate(&env));
+}
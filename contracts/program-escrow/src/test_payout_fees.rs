@@ -0,0 +1,221 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    token, vec, Address, Env, String, TryFromVal,
+};
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    token: token::Client<'static>,
+    fee_recipient: Address,
+}
+
+fn setup(env: &Env, initial_amount: i128, rate: i128, on_top: bool) -> Setup {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let fee_recipient = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    client.initialize_contract(&admin);
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &admin, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    client.update_fee_config(
+        &Some(rate),
+        &Some(fee_recipient.clone()),
+        &Some(on_top),
+        &Some(true),
+    );
+
+    Setup {
+        client,
+        token,
+        fee_recipient,
+    }
+}
+
+#[test]
+fn test_fee_deducted_from_single_payout() {
+    let env = Env::default();
+    let s = setup(&env, 100_000, 250, false);
+    let recipient = Address::generate(&env);
+
+    let data = s.client.single_payout(&recipient, &10_000);
+
+    assert_eq!(s.token.balance(&recipient), 9_750);
+    assert_eq!(s.token.balance(&s.fee_recipient), 250);
+    assert_eq!(data.remaining_balance, 90_000);
+    assert_eq!(data.payout_history.get(0).unwrap().amount, 9_750);
+
+    let (_, _, payload) = env.events().all().last().unwrap();
+    let event = PayoutEvent::try_from_val(&env, &payload).unwrap();
+    assert_eq!(event.amount, 9_750);
+    assert_eq!(event.fee, 250);
+}
+
+#[test]
+fn test_fee_on_top_of_single_payout() {
+    let env = Env::default();
+    let s = setup(&env, 100_000, 250, true);
+    let recipient = Address::generate(&env);
+
+    let data = s.client.single_payout(&recipient, &10_000);
+
+    assert_eq!(s.token.balance(&recipient), 10_000);
+    assert_eq!(s.token.balance(&s.fee_recipient), 250);
+    assert_eq!(data.remaining_balance, 89_750);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient balance")]
+fn test_fee_on_top_counts_against_balance() {
+    let env = Env::default();
+    let s = setup(&env, 10_000, 100, true);
+
+    s.client.single_payout(&Address::generate(&env), &10_000);
+}
+
+#[test]
+fn test_batch_payout_fees_and_ledger() {
+    let env = Env::default();
+    let s = setup(&env, 100_000, 1_000, false);
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+
+    let data = s.client.batch_payout(
+        &vec![&env, r1.clone(), r2.clone()],
+        &vec![&env, 20_000i128, 10_000i128],
+    );
+
+    assert_eq!(s.token.balance(&r1), 18_000);
+    assert_eq!(s.token.balance(&r2), 9_000);
+    assert_eq!(s.token.balance(&s.fee_recipient), 3_000);
+    assert_eq!(data.remaining_balance, 70_000);
+
+    let (_, _, payload) = env.events().all().last().unwrap();
+    let event = BatchPayoutEvent::try_from_val(&env, &payload).unwrap();
+    assert_eq!(event.total_amount, 27_000);
+    assert_eq!(event.total_fee, 3_000);
+
+    let ledger = s.client.get_fee_ledger(&0, &10);
+    assert_eq!(ledger.len(), 2);
+    assert_eq!(s.client.get_fee_ledger_count(), 2);
+    assert_eq!(ledger.get(0).unwrap().payee, r1);
+    assert_eq!(ledger.get(0).unwrap().fee_amount, 2_000);
+    assert_eq!(ledger.get(1).unwrap().fee_amount, 1_000);
+    assert_eq!(s.client.get_total_fees_collected(), 3_000);
+}
+
+#[test]
+fn test_disabled_fee_pays_full_amount() {
+    let env = Env::default();
+    let s = setup(&env, 100_000, 1_000, false);
+    s.client
+        .update_fee_config(&None, &None, &None, &Some(false));
+    let recipient = Address::generate(&env);
+
+    s.client.single_payout(&recipient, &10_000);
+
+    assert_eq!(s.token.balance(&recipient), 10_000);
+    assert_eq!(s.client.get_fee_ledger_count(), 0);
+    assert_eq!(s.client.get_total_fees_collected(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Invalid fee rate")]
+fn test_fee_rate_above_max_rejected() {
+    let env = Env::default();
+    let s = setup(&env, 100_000, 0, false);
+
+    s.client
+        .update_fee_config(&Some(5_001), &None, &None, &None);
+}
+
+#[test]
+fn test_fee_charged_on_claimable_batch() {
+    let env = Env::default();
+    let s = setup(&env, 100_000, 1_000, false);
+    let program_id = String::from_str(&env, "hack-2026");
+    let recipient = Address::generate(&env);
+    let expires_at = env.ledger().timestamp() + 1_000;
+
+    let ids = s
+        .client
+        .batch_payout_claimable(&vec![&env, recipient.clone()], &vec![&env, 10_000i128], &expires_at);
+
+    assert_eq!(s.token.balance(&s.fee_recipient), 1_000);
+    assert_eq!(s.client.get_claim(&program_id, &ids.get(0).unwrap()).amount, 9_000);
+    assert_eq!(s.client.get_remaining_balance(), 90_000);
+
+    s.client.execute_claim(&program_id, &ids.get(0).unwrap(), &recipient);
+    assert_eq!(s.token.balance(&recipient), 9_000);
+    assert_eq!(s.client.get_total_fees_collected(), 1_000);
+}
+
+#[test]
+fn test_fee_ledger_is_paged() {
+    let env = Env::default();
+    let s = setup(&env, 100_000, 1_000, false);
+    let recipients = vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+
+    s.client
+        .batch_payout(&recipients, &vec![&env, 1_000i128, 2_000i128, 3_000i128]);
+
+    let page = s.client.get_fee_ledger(&1, &5);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().payee, recipients.get(1).unwrap());
+    assert_eq!(page.get(1).unwrap().fee_amount, 300);
+    assert_eq!(s.client.get_fee_ledger(&3, &5).len(), 0);
+}
+
+#[test]
+fn test_legacy_fee_ledger_migrated_on_next_fee() {
+    let env = Env::default();
+    let s = setup(&env, 100_000, 1_000, false);
+    let earlier = Address::generate(&env);
+
+    // Earlier versions kept the whole ledger as one instance Vec
+    env.as_contract(&s.client.address, || {
+        let legacy = vec![
+            &env,
+            FeeRecord {
+                payee: earlier.clone(),
+                payout_amount: 5_000,
+                fee_amount: 500,
+                fee_recipient: s.fee_recipient.clone(),
+                timestamp: 0,
+            },
+        ];
+        env.storage().instance().set(&FEE_LEDGER, &legacy);
+    });
+    assert_eq!(s.client.get_fee_ledger_count(), 1);
+
+    s.client.single_payout(&Address::generate(&env), &1_000);
+
+    assert_eq!(s.client.get_fee_ledger_count(), 2);
+    let ledger = s.client.get_fee_ledger(&0, &10);
+    assert_eq!(ledger.get(0).unwrap().payee, earlier);
+    assert_eq!(ledger.get(1).unwrap().fee_amount, 100);
+    let legacy_left = env.as_contract(&s.client.address, || {
+        env.storage().instance().has(&FEE_LEDGER)
+    });
+    assert!(!legacy_left);
+}
@@ -0,0 +1,124 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, BytesN, Env, String};
+
+fn setup_program(
+    env: &Env,
+    initial_amount: i128,
+) -> (ProgramEscrowContractClient<'static>, token::Client<'static>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    (client, token_client)
+}
+
+#[test]
+fn test_single_payout_with_id_marks_processed() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+    let payout_id = BytesN::from_array(&env, &[1u8; 32]);
+
+    assert!(!client.is_payout_processed(&payout_id));
+    let data = client.single_payout_with_id(&payout_id, &recipient, &2_500, &None);
+
+    assert!(client.is_payout_processed(&payout_id));
+    assert_eq!(data.remaining_balance, 7_500);
+    assert_eq!(token_client.balance(&recipient), 2_500);
+}
+
+#[test]
+#[should_panic(expected = "Duplicate payout_id")]
+fn test_single_payout_retry_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+    let payout_id = BytesN::from_array(&env, &[1u8; 32]);
+
+    client.single_payout_with_id(&payout_id, &recipient, &2_500, &None);
+    client.single_payout_with_id(&payout_id, &recipient, &2_500, &None);
+}
+
+#[test]
+#[should_panic(expected = "Duplicate payout_id")]
+fn test_batch_payout_retry_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let recipients = vec![&env, Address::generate(&env), Address::generate(&env)];
+    let amounts = vec![&env, 1_000i128, 2_000i128];
+    let payout_id = BytesN::from_array(&env, &[7u8; 32]);
+
+    client.batch_payout_with_id(&payout_id, &recipients, &amounts, &vec![&env]);
+    client.batch_payout_with_id(&payout_id, &recipients, &amounts, &vec![&env]);
+}
+
+#[test]
+#[should_panic(expected = "Duplicate payout_id")]
+fn test_ids_shared_between_single_and_batch() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+    let payout_id = BytesN::from_array(&env, &[3u8; 32]);
+
+    client.single_payout_with_id(&payout_id, &recipient, &1_000, &None);
+    client.batch_payout_with_id(
+        &payout_id,
+        &vec![&env, recipient],
+        &vec![&env, 1_000i128],
+        &vec![&env],
+    );
+}
+
+#[test]
+fn test_distinct_ids_both_processed() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+
+    client.single_payout_with_id(
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &recipient,
+        &1_000,
+        &None,
+    );
+    client.single_payout_with_id(
+        &BytesN::from_array(&env, &[2u8; 32]),
+        &recipient,
+        &1_000,
+        &None,
+    );
+
+    assert_eq!(token_client.balance(&recipient), 2_000);
+    assert_eq!(client.get_remaining_balance(), 8_000);
+}
+
+#[test]
+fn test_failed_payout_does_not_consume_id() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+    let payout_id = BytesN::from_array(&env, &[9u8; 32]);
+
+    let result = client.try_single_payout_with_id(&payout_id, &recipient, &50_000, &None);
+    assert!(result.is_err());
+    assert!(!client.is_payout_processed(&payout_id));
+
+    client.single_payout_with_id(&payout_id, &recipient, &5_000, &None);
+    assert!(client.is_payout_processed(&payout_id));
+}
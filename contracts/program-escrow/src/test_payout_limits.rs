@@ -0,0 +1,99 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+fn setup_program(env: &Env, initial_amount: i128) -> ProgramEscrowContractClient<'static> {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    client
+}
+
+#[test]
+fn test_default_limits_are_unlimited() {
+    let env = Env::default();
+    let client = setup_program(&env, 100_000);
+
+    let limits = client.get_payout_limits();
+    assert_eq!(limits.max_per_payout, None);
+    assert_eq!(limits.max_per_recipient, None);
+
+    client.single_payout(&Address::generate(&env), &100_000);
+}
+
+#[test]
+#[should_panic(expected = "Payout exceeds per-transaction limit")]
+fn test_single_payout_over_transaction_cap_rejected() {
+    let env = Env::default();
+    let client = setup_program(&env, 100_000);
+    client.set_payout_limits(&Some(5_000), &None);
+
+    client.single_payout(&Address::generate(&env), &5_001);
+}
+
+#[test]
+#[should_panic(expected = "Payout exceeds per-transaction limit")]
+fn test_batch_item_over_transaction_cap_rejected() {
+    let env = Env::default();
+    let client = setup_program(&env, 100_000);
+    client.set_payout_limits(&Some(5_000), &None);
+
+    client.batch_payout(
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 5_000i128, 6_000i128],
+    );
+}
+
+#[test]
+fn test_recipient_cumulative_cap_tracks_both_paths() {
+    let env = Env::default();
+    let client = setup_program(&env, 100_000);
+    client.set_payout_limits(&None, &Some(10_000));
+    let recipient = Address::generate(&env);
+
+    client.single_payout(&recipient, &4_000);
+    client.batch_payout(&vec![&env, recipient.clone()], &vec![&env, 6_000i128]);
+
+    assert_eq!(client.get_recipient_total_paid(&recipient), 10_000);
+
+    let result = client.try_single_payout(&recipient, &1);
+    assert!(result.is_err());
+}
+
+#[test]
+#[should_panic(expected = "Payout exceeds per-recipient limit")]
+fn test_duplicate_recipient_in_batch_counts_cumulatively() {
+    let env = Env::default();
+    let client = setup_program(&env, 100_000);
+    client.set_payout_limits(&None, &Some(10_000));
+    let recipient = Address::generate(&env);
+
+    client.batch_payout(
+        &vec![&env, recipient.clone(), recipient],
+        &vec![&env, 6_000i128, 6_000i128],
+    );
+}
+
+#[test]
+#[should_panic(expected = "Limits must be greater than zero")]
+fn test_zero_limit_rejected() {
+    let env = Env::default();
+    let client = setup_program(&env, 100_000);
+
+    client.set_payout_limits(&Some(0), &None);
+}
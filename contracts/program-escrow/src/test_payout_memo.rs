@@ -0,0 +1,103 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    token, vec, Address, Bytes, BytesN, Env, String, TryFromVal,
+};
+
+fn setup_program(env: &Env, initial_amount: i128) -> ProgramEscrowContractClient<'static> {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    client
+}
+
+#[test]
+fn test_single_payout_memo_recorded_and_emitted() {
+    let env = Env::default();
+    let client = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+    let memo = BytesN::from_array(&env, &[0xabu8; 32]);
+
+    let data = client.single_payout_with_id(
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &recipient,
+        &1_000,
+        &Some(memo.clone()),
+    );
+
+    let record = data.payout_history.get(0).unwrap();
+    assert_eq!(record.memo, Some(Bytes::from(memo.clone())));
+
+    let (_, _, payload) = env.events().all().last().unwrap();
+    let event = PayoutEvent::try_from_val(&env, &payload).unwrap();
+    assert_eq!(event.memo, Some(Bytes::from(memo)));
+}
+
+#[test]
+fn test_batch_payout_memos_attached_per_recipient() {
+    let env = Env::default();
+    let client = setup_program(&env, 10_000);
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+    let m1 = BytesN::from_array(&env, &[1u8; 32]);
+    let m2 = BytesN::from_array(&env, &[2u8; 32]);
+
+    let data = client.batch_payout_with_id(
+        &BytesN::from_array(&env, &[9u8; 32]),
+        &vec![&env, r1, r2],
+        &vec![&env, 1_000i128, 2_000i128],
+        &vec![&env, m1.clone(), m2.clone()],
+    );
+
+    assert_eq!(
+        data.payout_history.get(0).unwrap().memo,
+        Some(Bytes::from(m1.clone()))
+    );
+    assert_eq!(
+        data.payout_history.get(1).unwrap().memo,
+        Some(Bytes::from(m2.clone()))
+    );
+
+    let (_, _, payload) = env.events().all().last().unwrap();
+    let event = BatchPayoutEvent::try_from_val(&env, &payload).unwrap();
+    assert_eq!(event.memos, vec![&env, m1, m2]);
+}
+
+#[test]
+fn test_plain_payout_has_no_memo() {
+    let env = Env::default();
+    let client = setup_program(&env, 10_000);
+
+    let data = client.single_payout(&Address::generate(&env), &1_000);
+    assert_eq!(data.payout_history.get(0).unwrap().memo, None);
+}
+
+#[test]
+#[should_panic(expected = "Memos must be empty or match recipients length")]
+fn test_batch_memo_length_mismatch_rejected() {
+    let env = Env::default();
+    let client = setup_program(&env, 10_000);
+
+    client.batch_payout_with_id(
+        &BytesN::from_array(&env, &[9u8; 32]),
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 1_000i128, 2_000i128],
+        &vec![&env, BytesN::from_array(&env, &[1u8; 32])],
+    );
+}
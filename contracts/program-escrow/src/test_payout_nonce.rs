@@ -0,0 +1,100 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+fn setup_program(
+    env: &Env,
+    initial_amount: i128,
+) -> (ProgramEscrowContractClient<'static>, token::Client<'static>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    (client, token_client)
+}
+
+#[test]
+fn test_nonces_advance_and_reject_replay() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+
+    assert_eq!(client.get_payout_nonce(), 0);
+    client.single_payout_with_nonce(&0, &recipient, &1_000);
+    client.batch_payout_with_nonce(&1, &vec![&env, recipient.clone()], &vec![&env, 500i128]);
+    assert_eq!(client.get_payout_nonce(), 2);
+
+    // Replaying an old nonce or skipping ahead is rejected
+    assert!(client
+        .try_single_payout_with_nonce(&1, &recipient, &1_000)
+        .is_err());
+    assert!(client
+        .try_single_payout_with_nonce(&3, &recipient, &1_000)
+        .is_err());
+
+    assert_eq!(token_client.balance(&recipient), 1_500);
+    assert_eq!(client.get_payout_nonce(), 2);
+}
+
+#[test]
+#[should_panic(expected = "Payout nonce required")]
+fn test_required_nonce_blocks_plain_payouts() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+
+    client.set_payout_nonce_required(&true);
+    assert!(client.is_payout_nonce_required());
+    client.single_payout(&Address::generate(&env), &1_000);
+}
+
+#[test]
+#[should_panic(expected = "Invalid payout nonce")]
+fn test_reused_nonce_panics() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+
+    client.set_payout_nonce_required(&true);
+    client.single_payout_with_nonce(&0, &recipient, &1_000);
+    client.single_payout_with_nonce(&0, &recipient, &1_000);
+}
+
+#[test]
+fn test_required_nonce_covers_payout_key_fallbacks() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+    let now = env.ledger().timestamp();
+    let track = String::from_str(&env, "defi");
+    client.create_track(&track, &2_000, &None);
+    client.set_payout_nonce_required(&true);
+
+    // Tracks without their own key fall back to the payout key
+    assert!(client
+        .try_track_single_payout(&track, &recipient, &500)
+        .is_err());
+    assert!(client
+        .try_schedule_payout(&recipient, &500, &now)
+        .is_err());
+
+    // Delegated signers are exempt and stay bounded by their cap
+    let signer = Address::generate(&env);
+    client.add_payout_signer(&signer, &1_000, &(now + 1_000));
+    client.delegated_single_payout(&signer, &recipient, &500);
+    assert_eq!(token_client.balance(&recipient), 500);
+}
@@ -0,0 +1,131 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token, vec, Address, Env, String,
+};
+
+fn setup_program(
+    env: &Env,
+    initial_amount: i128,
+) -> (
+    ProgramEscrowContractClient<'static>,
+    Address,
+    Address,
+    token::Client<'static>,
+) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    if initial_amount > 0 {
+        token_admin_client.mint(&client.address, &initial_amount);
+        client.lock_program_funds(&initial_amount);
+    }
+
+    (client, organizer, payout_key, token_client)
+}
+
+#[test]
+fn test_organizer_can_pause_and_unpause_payouts() {
+    let env = Env::default();
+    let (client, organizer, _payout_key, token_client) = setup_program(&env, 10_000);
+
+    assert!(!client.is_payouts_paused());
+
+    let events_before = env.events().all().len();
+    client.pause_payouts(&organizer, &Some(String::from_str(&env, "key leak")));
+    assert!(client.is_payouts_paused());
+    assert_eq!(env.events().all().len(), events_before + 1);
+
+    let flags = client.get_pause_flags();
+    assert!(flags.release_paused);
+    assert!(!flags.lock_paused);
+    assert_eq!(flags.pause_reason, Some(String::from_str(&env, "key leak")));
+
+    client.unpause_payouts(&organizer);
+    assert!(!client.is_payouts_paused());
+    assert_eq!(client.get_pause_flags().pause_reason, None);
+
+    let recipient = Address::generate(&env);
+    client.single_payout(&recipient, &1_000);
+    assert_eq!(token_client.balance(&recipient), 1_000);
+}
+
+#[test]
+fn test_admin_can_pause_payouts() {
+    let env = Env::default();
+    let (client, _organizer, _payout_key, _token_client) = setup_program(&env, 10_000);
+    let admin = Address::generate(&env);
+    client.initialize_contract(&admin);
+
+    client.pause_payouts(&admin, &None);
+    assert!(client.is_payouts_paused());
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: only admin or organizer")]
+fn test_payout_key_cannot_pause_payouts() {
+    let env = Env::default();
+    let (client, _organizer, payout_key, _token_client) = setup_program(&env, 10_000);
+
+    client.pause_payouts(&payout_key, &None);
+}
+
+#[test]
+#[should_panic(expected = "Funds Paused")]
+fn test_single_payout_blocked_while_paused() {
+    let env = Env::default();
+    let (client, organizer, _payout_key, _token_client) = setup_program(&env, 10_000);
+
+    client.pause_payouts(&organizer, &None);
+    client.single_payout(&Address::generate(&env), &1_000);
+}
+
+#[test]
+#[should_panic(expected = "Funds Paused")]
+fn test_batch_payout_blocked_while_paused() {
+    let env = Env::default();
+    let (client, organizer, _payout_key, _token_client) = setup_program(&env, 10_000);
+
+    client.pause_payouts(&organizer, &None);
+    client.batch_payout(&vec![&env, Address::generate(&env)], &vec![&env, 1_000]);
+}
+
+#[test]
+#[should_panic(expected = "Funds Paused")]
+fn test_scheduled_release_blocked_while_paused() {
+    let env = Env::default();
+    let (client, organizer, _payout_key, _token_client) = setup_program(&env, 10_000);
+
+    client.create_program_release_schedule(&Address::generate(&env), &1_000, &100);
+    env.ledger().with_mut(|li| li.timestamp = 200);
+
+    client.pause_payouts(&organizer, &None);
+    client.trigger_program_releases();
+}
+
+#[test]
+fn test_deposits_and_views_work_while_paused() {
+    let env = Env::default();
+    let (client, organizer, _payout_key, token_client) = setup_program(&env, 10_000);
+
+    client.pause_payouts(&organizer, &None);
+
+    token::StellarAssetClient::new(&env, &token_client.address).mint(&client.address, &5_000);
+    let data = client.lock_program_funds(&5_000);
+    assert_eq!(data.remaining_balance, 15_000);
+    assert_eq!(client.get_remaining_balance(), 15_000);
+}
@@ -0,0 +1,94 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+
+fn setup_program(
+    env: &Env,
+    initial_amount: i128,
+) -> (ProgramEscrowContractClient<'static>, token::Client<'static>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    (client, token_client)
+}
+
+#[test]
+fn test_delegate_pays_out_within_cap() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 100_000);
+    let signer = Address::generate(&env);
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+    let expires_at = env.ledger().timestamp() + 1_000;
+
+    client.add_payout_signer(&signer, &10_000, &expires_at);
+    client.delegated_single_payout(&signer, &r1, &4_000);
+    assert_eq!(env.auths()[0].0, signer);
+
+    client.delegated_batch_payout(&signer, &vec![&env, r2.clone()], &vec![&env, 6_000i128]);
+
+    assert_eq!(token_client.balance(&r1), 4_000);
+    assert_eq!(token_client.balance(&r2), 6_000);
+    assert_eq!(client.get_payout_signer(&signer).unwrap().spent, 10_000);
+}
+
+#[test]
+#[should_panic(expected = "Payout exceeds signer spending cap")]
+fn test_delegate_cap_is_cumulative() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 100_000);
+    let signer = Address::generate(&env);
+    let expires_at = env.ledger().timestamp() + 1_000;
+
+    client.add_payout_signer(&signer, &10_000, &expires_at);
+    client.delegated_single_payout(&signer, &Address::generate(&env), &8_000);
+    client.delegated_single_payout(&signer, &Address::generate(&env), &2_001);
+}
+
+#[test]
+#[should_panic(expected = "Payout signer expired")]
+fn test_expired_delegate_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 100_000);
+    let signer = Address::generate(&env);
+    let now = env.ledger().timestamp();
+
+    client.add_payout_signer(&signer, &10_000, &(now + 1_000));
+    env.ledger().set_timestamp(now + 1_001);
+
+    client.delegated_single_payout(&signer, &Address::generate(&env), &1_000);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: not a payout signer")]
+fn test_removed_delegate_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 100_000);
+    let signer = Address::generate(&env);
+    let expires_at = env.ledger().timestamp() + 1_000;
+
+    client.add_payout_signer(&signer, &10_000, &expires_at);
+    client.remove_payout_signer(&signer);
+    assert_eq!(client.get_payout_signer(&signer), None);
+
+    client.delegated_single_payout(&signer, &Address::generate(&env), &1_000);
+}
@@ -0,0 +1,91 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+fn setup_program(
+    env: &Env,
+    initial_amount: i128,
+) -> (ProgramEscrowContractClient<'static>, token::Client<'static>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    (client, token_client)
+}
+
+#[test]
+fn test_distribute_by_rank_pays_tiers_in_order() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 20_000);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let third = Address::generate(&env);
+
+    client.set_prize_tiers(&vec![&env, 5_000i128, 3_000i128, 2_000i128]);
+    let data = client.distribute_by_rank(&vec![&env, first.clone(), second.clone(), third.clone()]);
+
+    assert_eq!(token_client.balance(&first), 5_000);
+    assert_eq!(token_client.balance(&second), 3_000);
+    assert_eq!(token_client.balance(&third), 2_000);
+    assert_eq!(data.remaining_balance, 10_000);
+}
+
+#[test]
+fn test_fewer_winners_than_tiers_pays_top_ranks() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 20_000);
+    let first = Address::generate(&env);
+
+    client.set_prize_tiers(&vec![&env, 5_000i128, 3_000i128]);
+    client.distribute_by_rank(&vec![&env, first.clone()]);
+
+    assert_eq!(token_client.balance(&first), 5_000);
+    assert_eq!(client.get_remaining_balance(), 15_000);
+}
+
+#[test]
+#[should_panic(expected = "More winners than prize tiers")]
+fn test_more_winners_than_tiers_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 20_000);
+
+    client.set_prize_tiers(&vec![&env, 5_000i128]);
+    client.distribute_by_rank(&vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+    ]);
+}
+
+#[test]
+#[should_panic(expected = "Prize tiers not set")]
+fn test_distribute_without_tiers_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 20_000);
+
+    client.distribute_by_rank(&vec![&env, Address::generate(&env)]);
+}
+
+#[test]
+#[should_panic(expected = "All amounts must be greater than zero")]
+fn test_zero_tier_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 20_000);
+
+    client.set_prize_tiers(&vec![&env, 5_000i128, 0i128]);
+}
@@ -0,0 +1,224 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+fn setup_program(
+    env: &Env,
+) -> (
+    ProgramEscrowContractClient<'static>,
+    Address,
+    token::Client<'static>,
+    token::StellarAssetClient<'static>,
+) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    (client, organizer, token_client, token_admin_client)
+}
+
+#[test]
+fn test_status_follows_program_lifecycle() {
+    let env = Env::default();
+    let (client, organizer, token_client, token_admin_client) = setup_program(&env);
+    assert_eq!(client.get_program_status(), ProgramStatus::Setup);
+
+    token_admin_client.mint(&client.address, &10_000);
+    client.lock_program_funds(&10_000);
+    assert_eq!(client.get_program_status(), ProgramStatus::Funded);
+
+    client.activate_program();
+    assert_eq!(client.get_program_status(), ProgramStatus::Active);
+
+    client.single_payout(&Address::generate(&env), &4_000);
+    assert_eq!(client.get_program_status(), ProgramStatus::PayoutPhase);
+
+    let data = client.close_program();
+    assert_eq!(data.remaining_balance, 0);
+    assert_eq!(token_client.balance(&organizer), 6_000);
+    assert_eq!(client.get_program_status(), ProgramStatus::Closed);
+
+    client.archive_program();
+    assert_eq!(client.get_program_status(), ProgramStatus::Archived);
+}
+
+#[test]
+#[should_panic(expected = "Program closed")]
+fn test_closed_program_rejects_payouts() {
+    let env = Env::default();
+    let (client, _organizer, _token_client, token_admin_client) = setup_program(&env);
+    token_admin_client.mint(&client.address, &10_000);
+    client.lock_program_funds(&10_000);
+
+    client.start_payout_phase();
+    client.close_program();
+    client.single_payout(&Address::generate(&env), &1);
+}
+
+#[test]
+#[should_panic(expected = "Program must be funded to activate")]
+fn test_cannot_activate_unfunded_program() {
+    let env = Env::default();
+    let (client, _organizer, _token_client, _token_admin_client) = setup_program(&env);
+
+    client.activate_program();
+}
+
+#[test]
+fn test_transition_table_rejects_every_illegal_move() {
+    use ProgramStatus::*;
+    let all = [Setup, Funded, Active, PayoutPhase, Closed, Cancelled, Archived];
+    let legal = [
+        (Setup, Funded),
+        (Setup, Cancelled),
+        (Setup, Archived),
+        (Funded, Active),
+        (Funded, PayoutPhase),
+        (Funded, Closed),
+        (Funded, Cancelled),
+        (Funded, Archived),
+        (Active, PayoutPhase),
+        (Active, Closed),
+        (Active, Cancelled),
+        (Active, Archived),
+        (PayoutPhase, Closed),
+        (PayoutPhase, Cancelled),
+        (PayoutPhase, Archived),
+        (Closed, Archived),
+    ];
+    for from in all {
+        for to in all {
+            assert_eq!(
+                status_transition_allowed(from, to),
+                legal.contains(&(from, to)),
+                "{:?} -> {:?}",
+                from,
+                to
+            );
+        }
+    }
+}
+
+#[test]
+fn test_cancel_allowed_after_explicit_payout_phase() {
+    let env = Env::default();
+    let (client, organizer, token_client, token_admin_client) = setup_program(&env);
+    token_admin_client.mint(&client.address, &10_000);
+    client.lock_program_funds(&10_000);
+
+    client.start_payout_phase();
+    client.cancel_program();
+
+    assert_eq!(client.get_program_status(), ProgramStatus::Cancelled);
+    assert_eq!(token_client.balance(&organizer), 10_000);
+}
+
+#[test]
+#[should_panic(expected = "Program must be funded to activate")]
+fn test_cannot_activate_twice() {
+    let env = Env::default();
+    let (client, _organizer, _token_client, token_admin_client) = setup_program(&env);
+    token_admin_client.mint(&client.address, &10_000);
+    client.lock_program_funds(&10_000);
+
+    client.activate_program();
+    client.activate_program();
+}
+
+#[test]
+#[should_panic(expected = "Program must be funded or active")]
+fn test_cannot_restart_payout_phase() {
+    let env = Env::default();
+    let (client, _organizer, _token_client, token_admin_client) = setup_program(&env);
+    token_admin_client.mint(&client.address, &10_000);
+    client.lock_program_funds(&10_000);
+
+    client.start_payout_phase();
+    client.start_payout_phase();
+}
+
+#[test]
+#[should_panic(expected = "Program must be funded or active")]
+fn test_cannot_start_payout_phase_in_setup() {
+    let env = Env::default();
+    let (client, _organizer, _token_client, _token_admin_client) = setup_program(&env);
+
+    client.start_payout_phase();
+}
+
+#[test]
+#[should_panic(expected = "Program not funded")]
+fn test_cannot_close_in_setup() {
+    let env = Env::default();
+    let (client, _organizer, _token_client, _token_admin_client) = setup_program(&env);
+
+    client.close_program();
+}
+
+#[test]
+#[should_panic(expected = "Program closed")]
+fn test_cannot_cancel_closed_program() {
+    let env = Env::default();
+    let (client, _organizer, _token_client, token_admin_client) = setup_program(&env);
+    token_admin_client.mint(&client.address, &10_000);
+    client.lock_program_funds(&10_000);
+
+    client.close_program();
+    client.cancel_program();
+}
+
+#[test]
+#[should_panic(expected = "Program must be funded to activate")]
+fn test_cannot_reactivate_closed_program() {
+    let env = Env::default();
+    let (client, _organizer, _token_client, token_admin_client) = setup_program(&env);
+    token_admin_client.mint(&client.address, &10_000);
+    client.lock_program_funds(&10_000);
+
+    client.close_program();
+    client.activate_program();
+}
+
+#[test]
+#[should_panic(expected = "Program cancelled")]
+fn test_cannot_archive_cancelled_program() {
+    let env = Env::default();
+    let (client, _organizer, _token_client, _token_admin_client) = setup_program(&env);
+
+    client.cancel_program();
+    client.archive_program();
+}
+
+#[test]
+#[should_panic(expected = "Program cancelled")]
+fn test_cannot_close_cancelled_program() {
+    let env = Env::default();
+    let (client, _organizer, _token_client, token_admin_client) = setup_program(&env);
+    token_admin_client.mint(&client.address, &10_000);
+    client.lock_program_funds(&10_000);
+
+    client.cancel_program();
+    client.close_program();
+}
+
+#[test]
+#[should_panic(expected = "Program archived")]
+fn test_cannot_close_archived_program() {
+    let env = Env::default();
+    let (client, _organizer, _token_client, _token_admin_client) = setup_program(&env);
+
+    client.archive_program();
+    client.close_program();
+}
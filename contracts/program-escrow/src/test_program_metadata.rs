@@ -0,0 +1,79 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, Bytes, Env, String};
+
+fn register(env: &Env) -> ProgramEscrowContractClient<'static> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    ProgramEscrowContractClient::new(env, &contract_id)
+}
+
+fn sample_metadata(env: &Env, organizer: &Address) -> ProgramMetadata {
+    ProgramMetadata {
+        display_name: String::from_str(env, "Stellar Hack 2026"),
+        organizer: organizer.clone(),
+        url_hash: Some(Bytes::from_array(env, &[0x11u8; 32])),
+        start_time: Some(1_000),
+        end_time: Some(2_000),
+    }
+}
+
+#[test]
+fn test_metadata_set_at_init_and_readable() {
+    let env = Env::default();
+    let client = register(&env);
+    let organizer = Address::generate(&env);
+    let token = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    let metadata = sample_metadata(&env, &organizer);
+
+    let data = client.init_program_with_metadata(
+        &String::from_str(&env, "hack-2026"),
+        &Address::generate(&env),
+        &token.address(),
+        &organizer,
+        &None,
+        &metadata,
+    );
+
+    assert_eq!(data.program_id, String::from_str(&env, "hack-2026"));
+    assert_eq!(client.get_program_metadata(), metadata);
+    assert_eq!(client.get_organizer(), organizer);
+}
+
+#[test]
+fn test_plain_init_has_no_metadata() {
+    let env = Env::default();
+    let client = register(&env);
+    let token = env.register_stellar_asset_contract_v2(Address::generate(&env));
+
+    client.init_program(
+        &String::from_str(&env, "hack-2026"),
+        &Address::generate(&env),
+        &token.address(),
+        &Address::generate(&env),
+        &None,
+    );
+
+    assert!(client.try_get_program_metadata().is_err());
+}
+
+#[test]
+#[should_panic(expected = "Invalid program dates")]
+fn test_end_before_start_rejected() {
+    let env = Env::default();
+    let client = register(&env);
+    let organizer = Address::generate(&env);
+    let token = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    let mut metadata = sample_metadata(&env, &organizer);
+    metadata.end_time = Some(500);
+
+    client.init_program_with_metadata(
+        &String::from_str(&env, "hack-2026"),
+        &Address::generate(&env),
+        &token.address(),
+        &organizer,
+        &None,
+        &metadata,
+    );
+}
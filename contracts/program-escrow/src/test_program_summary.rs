@@ -0,0 +1,53 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+#[test]
+fn test_program_summary_reflects_payouts() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let organizer = Address::generate(&env);
+    let payout_key = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(&env));
+
+    let program_id = String::from_str(&env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+    token::StellarAssetClient::new(&env, &sac.address()).mint(&client.address, &10_000);
+    client.lock_program_funds(&10_000);
+
+    client.batch_payout(
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 1_000i128, 2_000i128],
+    );
+
+    let summary = client.get_program_summary();
+    assert_eq!(summary.program_id, program_id);
+    assert_eq!(summary.status, ProgramStatus::PayoutPhase);
+    assert_eq!(summary.token_address, sac.address());
+    assert_eq!(summary.total_funds, 10_000);
+    assert_eq!(summary.remaining_balance, 7_000);
+    assert_eq!(summary.total_paid_out, 3_000);
+    assert_eq!(summary.total_fees_collected, 0);
+    assert_eq!(summary.held_total, 0);
+    assert_eq!(summary.claimable_total, 0);
+    assert_eq!(summary.retry_total, 0);
+    assert_eq!(summary.payout_count, 2);
+    assert_eq!(summary.organizer, organizer);
+    assert_eq!(summary.authorized_payout_key, payout_key);
+    assert_eq!(summary.active_season, None);
+
+    // Claimable payouts are not counted as paid out until claimed
+    client.batch_payout_claimable(
+        &vec![&env, Address::generate(&env)],
+        &vec![&env, 500i128],
+        &(env.ledger().timestamp() + 1_000),
+    );
+    let summary = client.get_program_summary();
+    assert_eq!(summary.total_paid_out, 3_000);
+    assert_eq!(summary.claimable_total, 500);
+    assert_eq!(summary.remaining_balance, 6_500);
+}
@@ -0,0 +1,146 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, BytesN, Env, String,
+};
+
+fn setup_program(
+    env: &Env,
+    initial_amount: i128,
+) -> (ProgramEscrowContractClient<'static>, token::Client<'static>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    (client, token_client)
+}
+
+#[test]
+#[should_panic(expected = "Recipient is blocked")]
+fn test_single_payout_to_blocked_recipient_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+
+    client.block_recipient(&recipient);
+    assert!(client.is_recipient_blocked(&recipient));
+
+    client.single_payout(&recipient, &1_000);
+}
+
+#[test]
+fn test_batch_with_blocked_recipient_rejected() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let ok = Address::generate(&env);
+    let blocked = Address::generate(&env);
+    client.block_recipient(&blocked);
+
+    let result = client.try_batch_payout(
+        &vec![&env, ok.clone(), blocked],
+        &vec![&env, 1_000i128, 1_000i128],
+    );
+
+    assert!(result.is_err());
+    assert_eq!(token_client.balance(&ok), 0);
+    assert_eq!(client.get_remaining_balance(), 10_000);
+}
+
+#[test]
+fn test_unblocked_recipient_can_be_paid() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+
+    client.block_recipient(&recipient);
+    client.unblock_recipient(&recipient);
+    assert!(!client.is_recipient_blocked(&recipient));
+
+    client.single_payout(&recipient, &1_000);
+    assert_eq!(token_client.balance(&recipient), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Recipient is blocked")]
+fn test_claim_by_recipient_blocked_after_creation_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    let ids = client.batch_payout_claimable(
+        &vec![&env, recipient.clone()],
+        &vec![&env, 2_000i128],
+        &(env.ledger().timestamp() + 100),
+    );
+    client.block_recipient(&recipient);
+
+    client.execute_claim(&program_id, &ids.get(0).unwrap(), &recipient);
+}
+
+#[test]
+#[should_panic(expected = "Recipient is blocked")]
+fn test_held_payout_to_recipient_blocked_during_window_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+    let payout_id = BytesN::from_array(&env, &[1u8; 32]);
+    let now = env.ledger().timestamp();
+
+    client.set_clawback_window(&Some(3_600));
+    client.single_payout_with_id(&payout_id, &recipient, &2_000, &None);
+    client.block_recipient(&recipient);
+
+    env.ledger().set_timestamp(now + 3_600);
+    client.release_held_payout(&payout_id);
+}
+
+#[test]
+fn test_held_payout_to_blocked_recipient_clawed_back_after_window() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+    let payout_id = BytesN::from_array(&env, &[1u8; 32]);
+    let now = env.ledger().timestamp();
+
+    client.set_clawback_window(&Some(3_600));
+    client.single_payout_with_id(&payout_id, &recipient, &2_000, &None);
+    client.block_recipient(&recipient);
+
+    env.ledger().set_timestamp(now + 3_600);
+    assert_eq!(client.clawback(&payout_id), 2_000);
+    assert_eq!(client.get_held_total(), 0);
+    assert_eq!(client.get_remaining_balance(), 10_000);
+    assert_eq!(token_client.balance(&recipient), 0);
+}
+
+#[test]
+#[should_panic(expected = "Clawback window has passed")]
+fn test_held_payout_to_allowed_recipient_not_clawed_back_after_window() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let payout_id = BytesN::from_array(&env, &[1u8; 32]);
+    let now = env.ledger().timestamp();
+
+    client.set_clawback_window(&Some(3_600));
+    client.single_payout_with_id(&payout_id, &Address::generate(&env), &2_000, &None);
+
+    env.ledger().set_timestamp(now + 3_600);
+    client.clawback(&payout_id);
+}
@@ -0,0 +1,84 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+fn setup_program(
+    env: &Env,
+    initial_amount: i128,
+) -> (ProgramEscrowContractClient<'static>, token::Client<'static>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    (client, token_client)
+}
+
+#[test]
+fn test_frozen_schedule_skipped_until_unfrozen() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let frozen = Address::generate(&env);
+    let other = Address::generate(&env);
+    let now = env.ledger().timestamp();
+
+    client.create_program_release_schedule(&frozen, &1_000, &(now + 100));
+    client.create_program_release_schedule(&other, &2_000, &(now + 100));
+    client.freeze_recipient(&frozen);
+    assert!(client.is_recipient_frozen(&frozen));
+    env.ledger().set_timestamp(now + 100);
+
+    assert_eq!(client.trigger_program_releases(), 1);
+    assert_eq!(token_client.balance(&frozen), 0);
+    assert_eq!(token_client.balance(&other), 2_000);
+
+    client.unfreeze_recipient(&frozen);
+    assert_eq!(client.trigger_program_releases(), 1);
+    assert_eq!(token_client.balance(&frozen), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Recipient is frozen")]
+fn test_frozen_recipient_cannot_execute_claim() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+    let program_id = String::from_str(&env, "hack-2026");
+    let deadline = env.ledger().timestamp() + 1_000;
+
+    let claim_id = client.create_pending_claim(&program_id, &recipient, &1_000, &deadline);
+    client.freeze_recipient(&recipient);
+
+    client.execute_claim(&program_id, &claim_id, &recipient);
+}
+
+#[test]
+#[should_panic(expected = "Recipient is frozen")]
+fn test_frozen_recipient_manual_release_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+    let release_at = env.ledger().timestamp() + 100;
+
+    let schedule = client.create_program_release_schedule(&recipient, &1_000, &release_at);
+    client.freeze_recipient(&recipient);
+
+    client.release_program_schedule_manual(&schedule.schedule_id);
+}
@@ -0,0 +1,81 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    token, Address, Env, String, TryFromVal,
+};
+
+fn setup_program(
+    env: &Env,
+    initial_amount: i128,
+) -> (
+    ProgramEscrowContractClient<'static>,
+    token::StellarAssetClient<'static>,
+) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    (client, token_admin_client)
+}
+
+#[test]
+fn test_reconcile_balanced() {
+    let env = Env::default();
+    let (client, _token_admin) = setup_program(&env, 10_000);
+
+    client.single_payout(&Address::generate(&env), &2_500);
+    let events_before = env.events().all().len();
+    let report = client.reconcile();
+
+    assert_eq!(report.token_balance, 7_500);
+    assert_eq!(report.recorded_balance, 7_500);
+    assert_eq!(report.discrepancy, 0);
+    assert_eq!(env.events().all().len(), events_before);
+}
+
+#[test]
+fn test_reconcile_reports_surplus() {
+    let env = Env::default();
+    let (client, token_admin) = setup_program(&env, 10_000);
+
+    token_admin.mint(&client.address, &500);
+    let report = client.reconcile();
+
+    assert_eq!(report.discrepancy, 500);
+    let (_, _, payload) = env.events().all().last().unwrap();
+    let event = BalanceMismatchEvent::try_from_val(&env, &payload).unwrap();
+    assert_eq!(event.token_balance, 10_500);
+    assert_eq!(event.recorded_balance, 10_000);
+    assert_eq!(event.discrepancy, 500);
+}
+
+#[test]
+fn test_reconcile_reports_shortfall() {
+    let env = Env::default();
+    let (client, _token_admin) = setup_program(&env, 10_000);
+
+    // An emergency withdrawal moves tokens out without touching the books
+    client.initialize_contract(&Address::generate(&env));
+    client.set_paused(&Some(true), &None, &None, &None);
+    client.emergency_withdraw(&Address::generate(&env));
+    let report = client.reconcile();
+
+    assert_eq!(report.token_balance, 0);
+    assert_eq!(report.recorded_balance, 10_000);
+    assert_eq!(report.discrepancy, -10_000);
+}
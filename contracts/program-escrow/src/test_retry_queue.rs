@@ -0,0 +1,98 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, IssuerFlags},
+    token, vec, Address, Env, String,
+};
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    token: token::Client<'static>,
+    token_admin: token::StellarAssetClient<'static>,
+}
+
+fn setup(env: &Env, initial_amount: i128) -> Setup {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(env));
+    let token = token::Client::new(env, &sac.address());
+    let token_admin = token::StellarAssetClient::new(env, &sac.address());
+    sac.issuer().set_flag(IssuerFlags::RevocableFlag);
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    Setup {
+        client,
+        token,
+        token_admin,
+    }
+}
+
+#[test]
+fn test_failed_transfer_is_retried_after_fix() {
+    let env = Env::default();
+    let s = setup(&env, 10_000);
+    let bad = Address::generate(&env);
+    s.token_admin.set_authorized(&bad, &false);
+
+    s.client
+        .batch_payout_lenient(&vec![&env, bad.clone()], &vec![&env, 2_000i128]);
+
+    let retry = s.client.get_pending_retry(&1).unwrap();
+    assert_eq!(retry.recipient, bad);
+    assert_eq!(retry.debit, 2_000);
+    assert_eq!(s.client.get_remaining_balance(), 8_000);
+
+    // Retrying before the recipient is fixed fails and keeps the entry
+    assert!(s.client.try_retry_payout(&1).is_err());
+    assert!(s.client.get_pending_retry(&1).is_some());
+
+    s.token_admin.set_authorized(&bad, &true);
+    let data = s.client.retry_payout(&1);
+
+    assert_eq!(s.token.balance(&bad), 2_000);
+    assert_eq!(data.payout_history.len(), 1);
+    assert_eq!(s.client.get_pending_retry(&1), None);
+    assert_eq!(s.client.get_retry_total(), 0);
+    assert_eq!(s.client.reconcile().discrepancy, 0);
+}
+
+#[test]
+fn test_cancel_retry_returns_funds_to_pool() {
+    let env = Env::default();
+    let s = setup(&env, 10_000);
+    let bad = Address::generate(&env);
+    s.token_admin.set_authorized(&bad, &false);
+
+    s.client
+        .batch_payout_lenient(&vec![&env, bad], &vec![&env, 2_000i128]);
+
+    assert_eq!(s.client.cancel_retry(&1), 2_000);
+    assert_eq!(s.client.get_remaining_balance(), 10_000);
+    assert_eq!(s.client.get_retry_total(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Retry not found")]
+fn test_retry_cannot_be_paid_twice() {
+    let env = Env::default();
+    let s = setup(&env, 10_000);
+    let bad = Address::generate(&env);
+    s.token_admin.set_authorized(&bad, &false);
+    s.client
+        .batch_payout_lenient(&vec![&env, bad.clone()], &vec![&env, 2_000i128]);
+    s.token_admin.set_authorized(&bad, &true);
+
+    s.client.retry_payout(&1);
+    s.client.retry_payout(&1);
+}
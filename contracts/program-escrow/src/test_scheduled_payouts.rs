@@ -0,0 +1,188 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+fn setup_program(
+    env: &Env,
+    initial_amount: i128,
+) -> (ProgramEscrowContractClient<'static>, token::Client<'static>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    (client, token_client)
+}
+
+#[test]
+fn test_scheduled_payout_not_executed_before_timestamp() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+    let now = env.ledger().timestamp();
+
+    client.schedule_payout(&recipient, &4_000, &(now + 100));
+
+    assert_eq!(client.execute_due_payouts(), 0);
+    assert_eq!(token_client.balance(&recipient), 0);
+}
+
+#[test]
+fn test_anyone_can_execute_due_payouts() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+    let now = env.ledger().timestamp();
+
+    client.schedule_payout(&r1, &4_000, &(now + 100));
+    client.schedule_payout(&r2, &3_000, &(now + 200));
+
+    env.ledger().set_timestamp(now + 150);
+    // Keeper call carries no auth at all.
+    env.set_auths(&[]);
+    assert_eq!(client.execute_due_payouts(), 1);
+    assert_eq!(token_client.balance(&r1), 4_000);
+    assert_eq!(token_client.balance(&r2), 0);
+
+    env.ledger().set_timestamp(now + 200);
+    assert_eq!(client.execute_due_payouts(), 1);
+    assert_eq!(token_client.balance(&r2), 3_000);
+    assert_eq!(client.get_remaining_balance(), 3_000);
+    assert_eq!(client.execute_due_payouts(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient uncommitted balance")]
+fn test_schedule_payout_cannot_overcommit_balance() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let now = env.ledger().timestamp();
+
+    client.schedule_payout(&Address::generate(&env), &6_000, &(now + 100));
+    client.schedule_payout(&Address::generate(&env), &5_000, &(now + 100));
+}
+
+#[test]
+#[should_panic(expected = "Funds Paused")]
+fn test_execute_due_payouts_respects_pause() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let now = env.ledger().timestamp();
+    let organizer = client.get_organizer();
+
+    client.schedule_payout(&Address::generate(&env), &1_000, &now);
+    client.pause_payouts(&organizer, &None);
+
+    client.execute_due_payouts();
+}
+
+#[test]
+#[should_panic(expected = "Recipient is blocked")]
+fn test_schedule_payout_rejects_blocked_recipient() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+    client.block_recipient(&recipient);
+
+    client.schedule_payout(&recipient, &1_000, &(env.ledger().timestamp() + 100));
+}
+
+#[test]
+fn test_execute_due_payouts_skips_schedule_over_payout_limits() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+    let now = env.ledger().timestamp();
+    client.set_payout_limits(&None, &Some(5_000));
+
+    client.schedule_payout(&recipient, &3_000, &now);
+    client.schedule_payout(&recipient, &3_000, &now);
+
+    assert_eq!(client.execute_due_payouts(), 1);
+    assert_eq!(token_client.balance(&recipient), 3_000);
+    assert_eq!(client.get_total_scheduled_amount(), 3_000);
+}
+
+#[test]
+fn test_blocked_schedule_does_not_stall_later_schedules() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let blocked = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let now = env.ledger().timestamp();
+
+    let stuck = client.schedule_payout(&blocked, &2_000, &now);
+    client.schedule_payout(&recipient, &3_000, &now);
+    client.block_recipient(&blocked);
+
+    assert_eq!(client.execute_due_payouts(), 1);
+    assert_eq!(token_client.balance(&recipient), 3_000);
+    assert_eq!(token_client.balance(&blocked), 0);
+
+    let cancelled = client.cancel_schedule(&stuck.schedule_id);
+    assert_eq!(cancelled.amount, 2_000);
+    assert_eq!(client.get_total_scheduled_amount(), 0);
+    assert_eq!(client.execute_due_payouts(), 0);
+}
+
+#[test]
+fn test_execute_due_payouts_skips_schedule_over_epoch_cap() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+    let now = env.ledger().timestamp();
+    client.set_epoch_limit(&Some(EpochLimit { cap: 4_000, duration: 1_000 }));
+
+    client.schedule_payout(&r1, &3_000, &now);
+    client.schedule_payout(&r2, &3_000, &now);
+
+    assert_eq!(client.execute_due_payouts(), 1);
+    assert_eq!(token_client.balance(&r2), 0);
+
+    // The skipped schedule is paid once the next epoch starts
+    env.ledger().set_timestamp(now + 1_000);
+    assert_eq!(client.execute_due_payouts(), 1);
+    assert_eq!(token_client.balance(&r2), 3_000);
+}
+
+#[test]
+#[should_panic(expected = "Large payout requires organizer approval")]
+fn test_schedule_payout_rejects_large_payout() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    client.set_large_payout_threshold(&Some(5_000));
+
+    client.schedule_payout(&Address::generate(&env), &6_000, &(env.ledger().timestamp() + 100));
+}
+
+#[test]
+#[should_panic(expected = "Schedule already released")]
+fn test_cancel_released_schedule_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let now = env.ledger().timestamp();
+
+    let schedule = client.schedule_payout(&Address::generate(&env), &1_000, &now);
+    client.execute_due_payouts();
+
+    client.cancel_schedule(&schedule.schedule_id);
+}
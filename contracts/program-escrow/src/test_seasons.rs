@@ -0,0 +1,96 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+fn setup_program(
+    env: &Env,
+    initial_amount: i128,
+) -> (ProgramEscrowContractClient<'static>, token::Client<'static>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    (client, token_client)
+}
+
+#[test]
+fn test_payouts_are_tracked_per_season() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 20_000);
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+
+    client.open_season(&1, &5_000);
+    client.batch_payout(
+        &vec![&env, r1.clone(), r2.clone()],
+        &vec![&env, 1_000i128, 2_000i128],
+    );
+
+    client.open_season(&2, &8_000);
+    client.single_payout(&r1, &4_000);
+
+    let season_one = client.get_season(&1);
+    assert_eq!(season_one.paid_out, 3_000);
+    assert_eq!(season_one.payout_count, 2);
+    assert!(season_one.closed_at.is_some());
+    assert_eq!(client.get_season_history(&1).len(), 2);
+
+    let season_two = client.get_season(&2);
+    assert_eq!(season_two.paid_out, 4_000);
+    assert_eq!(season_two.closed_at, None);
+    assert_eq!(client.get_season_history(&2).get(0).unwrap().recipient, r1);
+    assert_eq!(client.get_active_season(), Some(2));
+    assert_eq!(client.get_remaining_balance(), 13_000);
+}
+
+#[test]
+#[should_panic(expected = "Season budget exceeded")]
+fn test_payout_over_season_budget_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 20_000);
+
+    client.open_season(&1, &5_000);
+    client.single_payout(&Address::generate(&env), &4_000);
+    client.single_payout(&Address::generate(&env), &1_001);
+}
+
+#[test]
+#[should_panic(expected = "Season number must increase")]
+fn test_season_numbers_must_increase() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 20_000);
+
+    client.open_season(&2, &5_000);
+    client.close_season();
+    client.open_season(&1, &5_000);
+}
+
+#[test]
+fn test_payouts_outside_season_use_program_balance() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 20_000);
+    let recipient = Address::generate(&env);
+
+    client.open_season(&1, &1_000);
+    client.close_season();
+    assert_eq!(client.get_active_season(), None);
+
+    client.single_payout(&recipient, &5_000);
+    assert_eq!(token_client.balance(&recipient), 5_000);
+    assert_eq!(client.get_season(&1).paid_out, 0);
+}
@@ -0,0 +1,84 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+fn setup_program(
+    env: &Env,
+    initial_amount: i128,
+) -> (ProgramEscrowContractClient<'static>, token::Client<'static>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    (client, token_client)
+}
+
+#[test]
+fn test_session_key_pays_out() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let session = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let expires_at = env.ledger().timestamp() + 3_600;
+
+    client.authorize_session(&session, &expires_at, &5_000);
+    client.delegated_single_payout(&session, &recipient, &2_000);
+
+    assert_eq!(env.auths()[0].0, session);
+    assert_eq!(token_client.balance(&recipient), 2_000);
+    assert_eq!(client.get_payout_signer(&session).unwrap().spent, 2_000);
+}
+
+#[test]
+#[should_panic(expected = "Payout signer expired")]
+fn test_session_key_invalid_after_expiry() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let session = Address::generate(&env);
+    let now = env.ledger().timestamp();
+
+    client.authorize_session(&session, &(now + 3_600), &5_000);
+    env.ledger().set_timestamp(now + 3_601);
+
+    client.delegated_single_payout(&session, &Address::generate(&env), &1_000);
+}
+
+#[test]
+#[should_panic(expected = "Payout exceeds signer spending cap")]
+fn test_session_key_limited_to_max_total() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let session = Address::generate(&env);
+    let expires_at = env.ledger().timestamp() + 3_600;
+
+    client.authorize_session(&session, &expires_at, &5_000);
+    client.delegated_single_payout(&session, &Address::generate(&env), &5_001);
+}
+
+#[test]
+#[should_panic(expected = "Session duration too long")]
+fn test_long_lived_session_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let expires_at = env.ledger().timestamp() + MAX_SESSION_DURATION + 1;
+
+    client.authorize_session(&Address::generate(&env), &expires_at, &5_000);
+}
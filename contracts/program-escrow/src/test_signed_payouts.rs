@@ -0,0 +1,143 @@
+#![cfg(test)]
+
+extern crate std;
+
+use super::*;
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+fn setup_program(
+    env: &Env,
+    initial_amount: i128,
+) -> (ProgramEscrowContractClient<'static>, token::Client<'static>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    (client, token_client)
+}
+
+fn register_permit_key(env: &Env, client: &ProgramEscrowContractClient) -> SigningKey {
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+    let public_key = BytesN::from_array(env, &signing_key.verifying_key().to_bytes());
+    client.set_permit_key(&public_key);
+    signing_key
+}
+
+fn permit(
+    env: &Env,
+    client: &ProgramEscrowContractClient,
+    recipient: &Address,
+    amount: i128,
+    nonce: u64,
+) -> PayoutPermit {
+    PayoutPermit {
+        contract: client.address.clone(),
+        program_id: String::from_str(env, "hack-2026"),
+        recipient: recipient.clone(),
+        amount,
+        nonce,
+        expires_at: env.ledger().timestamp() + 600,
+    }
+}
+
+fn sign(env: &Env, signing_key: &SigningKey, permit: &PayoutPermit) -> BytesN<64> {
+    let mut message: std::vec::Vec<u8> = env.ledger().network_id().to_array().to_vec();
+    message.extend(permit.clone().to_xdr(env).iter());
+    BytesN::from_array(env, &signing_key.sign(&message).to_bytes())
+}
+
+#[test]
+fn test_signed_permit_pays_once() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let signing_key = register_permit_key(&env, &client);
+    let recipient = Address::generate(&env);
+
+    let payload = permit(&env, &client, &recipient, 2_500, 0);
+    let signature = sign(&env, &signing_key, &payload);
+    client.execute_signed_payout(&payload, &signature);
+
+    assert_eq!(token_client.balance(&recipient), 2_500);
+    assert_eq!(client.get_payout_nonce(), 1);
+    assert!(client
+        .try_execute_signed_payout(&payload, &signature)
+        .is_err());
+}
+
+#[test]
+fn test_tampered_permit_rejected() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let signing_key = register_permit_key(&env, &client);
+    let recipient = Address::generate(&env);
+
+    let payload = permit(&env, &client, &recipient, 100, 0);
+    let signature = sign(&env, &signing_key, &payload);
+    let mut tampered = payload.clone();
+    tampered.amount = 9_000;
+
+    assert!(client
+        .try_execute_signed_payout(&tampered, &signature)
+        .is_err());
+    assert_eq!(token_client.balance(&recipient), 0);
+}
+
+#[test]
+#[should_panic(expected = "Permit expired")]
+fn test_expired_permit_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let signing_key = register_permit_key(&env, &client);
+
+    let payload = permit(&env, &client, &Address::generate(&env), 100, 0);
+    let signature = sign(&env, &signing_key, &payload);
+    env.ledger().set_timestamp(payload.expires_at + 1);
+
+    client.execute_signed_payout(&payload, &signature);
+}
+
+#[test]
+fn test_permit_cannot_be_replayed_on_another_contract() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let (other, _) = setup_program(&env, 10_000);
+    let signing_key = register_permit_key(&env, &client);
+    register_permit_key(&env, &other);
+    let recipient = Address::generate(&env);
+
+    // Same program id, permit key and nonce on both deployments
+    let payload = permit(&env, &client, &recipient, 1_000, 0);
+    let signature = sign(&env, &signing_key, &payload);
+    assert!(other
+        .try_execute_signed_payout(&payload, &signature)
+        .is_err());
+
+    // Re-addressing the permit invalidates the signature
+    let mut readdressed = payload.clone();
+    readdressed.contract = other.address.clone();
+    assert!(other
+        .try_execute_signed_payout(&readdressed, &signature)
+        .is_err());
+
+    assert_eq!(token_client.balance(&recipient), 0);
+    client.execute_signed_payout(&payload, &signature);
+    assert_eq!(token_client.balance(&recipient), 1_000);
+}
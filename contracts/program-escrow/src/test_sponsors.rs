@@ -0,0 +1,99 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    token, Address, Env, String, TryFromVal,
+};
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    token: token::Client<'static>,
+    token_admin: token::StellarAssetClient<'static>,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(env));
+    let token = token::Client::new(env, &sac.address());
+    let token_admin = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    Setup {
+        client,
+        token,
+        token_admin,
+    }
+}
+
+#[test]
+fn test_sponsor_contributions_recorded_in_order() {
+    let env = Env::default();
+    let s = setup(&env);
+    let sponsor_a = Address::generate(&env);
+    let sponsor_b = Address::generate(&env);
+    s.token_admin.mint(&sponsor_a, &5_000);
+    s.token_admin.mint(&sponsor_b, &3_000);
+
+    s.client.lock_program_funds_from(&sponsor_a, &5_000);
+    let data = s.client.lock_program_funds_from(&sponsor_b, &3_000);
+
+    assert_eq!(data.remaining_balance, 8_000);
+    assert_eq!(s.token.balance(&s.client.address), 8_000);
+    assert_eq!(s.token.balance(&sponsor_a), 0);
+
+    let sponsors = s.client.get_sponsors();
+    assert_eq!(sponsors.len(), 2);
+    assert_eq!(sponsors.get(0).unwrap().sponsor, sponsor_a);
+    assert_eq!(sponsors.get(0).unwrap().amount, 5_000);
+    assert_eq!(sponsors.get(1).unwrap().sponsor, sponsor_b);
+    assert_eq!(sponsors.get(1).unwrap().amount, 3_000);
+    assert_eq!(s.client.reconcile().discrepancy, 0);
+}
+
+#[test]
+fn test_sponsor_attributed_in_lock_event() {
+    let env = Env::default();
+    let s = setup(&env);
+    let sponsor = Address::generate(&env);
+    s.token_admin.mint(&sponsor, &1_000);
+
+    s.client.lock_program_funds_from(&sponsor, &1_000);
+
+    let (_, _, payload) = env.events().all().last().unwrap();
+    let event = FundsLockedEvent::try_from_val(&env, &payload).unwrap();
+    assert_eq!(event.amount, 1_000);
+    assert_eq!(event.sponsor, Some(sponsor));
+}
+
+#[test]
+fn test_plain_lock_has_no_sponsor() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.token_admin.mint(&s.client.address, &1_000);
+
+    s.client.lock_program_funds(&1_000);
+
+    let (_, _, payload) = env.events().all().last().unwrap();
+    let event = FundsLockedEvent::try_from_val(&env, &payload).unwrap();
+    assert_eq!(event.sponsor, None);
+    assert_eq!(s.client.get_sponsors().len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Amount must be greater than zero")]
+fn test_zero_sponsor_contribution_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client
+        .lock_program_funds_from(&Address::generate(&env), &0);
+}
@@ -0,0 +1,166 @@
+#![cfg(test)]
+
+use super::*;
+use grainlify_common::storage::{PERSISTENT_TTL_EXTEND_TO, PERSISTENT_TTL_THRESHOLD};
+use soroban_sdk::{
+    testutils::{
+        storage::{Instance as _, Persistent as _},
+        Address as _, Ledger,
+    },
+    token, Address, Env, String,
+};
+
+fn setup_program(env: &Env) -> ProgramEscrowContractClient<'static> {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(env));
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin_client.mint(&client.address, &10_000);
+    client.lock_program_funds(&10_000);
+
+    client
+}
+
+fn program_data_ttl(env: &Env, client: &ProgramEscrowContractClient) -> u32 {
+    env.as_contract(&client.address, || {
+        env.storage().persistent().get_ttl(&PROGRAM_DATA)
+    })
+}
+
+#[test]
+fn test_program_data_stored_persistently_with_extended_ttl() {
+    let env = Env::default();
+    let client = setup_program(&env);
+
+    let stored = env.as_contract(&client.address, || {
+        env.storage().persistent().has(&PROGRAM_DATA)
+    });
+    assert!(stored);
+    assert_eq!(program_data_ttl(&env, &client), PERSISTENT_TTL_EXTEND_TO);
+}
+
+#[test]
+fn test_bump_ttl_extends_program_and_instance() {
+    let env = Env::default();
+    let client = setup_program(&env);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    env.ledger().with_mut(|li| li.sequence_number += 300_000);
+    assert!(program_data_ttl(&env, &client) < PERSISTENT_TTL_THRESHOLD);
+
+    // Anyone may bump; no auths are required.
+    env.set_auths(&[]);
+    client.bump_ttl(&program_id);
+
+    assert_eq!(program_data_ttl(&env, &client), PERSISTENT_TTL_EXTEND_TO);
+    let instance_ttl = env.as_contract(&client.address, || env.storage().instance().get_ttl());
+    assert_eq!(instance_ttl, PERSISTENT_TTL_EXTEND_TO);
+}
+
+#[test]
+fn test_program_write_refreshes_ttl() {
+    let env = Env::default();
+    // Keep the token's entries alive across the jump; locking reads its balance
+    env.ledger().with_mut(|li| li.min_persistent_entry_ttl = 1_000_000);
+    let client = setup_program(&env);
+    let token = client.get_program_info().token_address;
+    token::StellarAssetClient::new(&env, &token).mint(&client.address, &1_000);
+
+    env.ledger().with_mut(|li| li.sequence_number += 800_000);
+    assert!(program_data_ttl(&env, &client) < PERSISTENT_TTL_THRESHOLD);
+    client.lock_program_funds(&1_000);
+
+    assert_eq!(program_data_ttl(&env, &client), PERSISTENT_TTL_EXTEND_TO);
+}
+
+#[test]
+#[should_panic(expected = "Program not found")]
+fn test_bump_ttl_unknown_program_rejected() {
+    let env = Env::default();
+    let client = setup_program(&env);
+
+    client.bump_ttl(&String::from_str(&env, "other"));
+}
+
+#[test]
+fn test_legacy_instance_program_data_migrated_on_read() {
+    let env = Env::default();
+    let client = setup_program(&env);
+
+    // Move the record back to where deployments before the move kept it
+    env.as_contract(&client.address, || {
+        let data: ProgramData = env.storage().persistent().get(&PROGRAM_DATA).unwrap();
+        env.storage().persistent().remove(&PROGRAM_DATA);
+        env.storage().instance().set(&PROGRAM_DATA, &data);
+    });
+
+    assert_eq!(client.get_remaining_balance(), 10_000);
+    let (in_instance, in_persistent) = env.as_contract(&client.address, || {
+        (
+            env.storage().instance().has(&PROGRAM_DATA),
+            env.storage().persistent().has(&PROGRAM_DATA),
+        )
+    });
+    assert!(!in_instance);
+    assert!(in_persistent);
+    assert_eq!(program_data_ttl(&env, &client), PERSISTENT_TTL_EXTEND_TO);
+}
+
+#[test]
+fn test_new_persistent_records_get_extended_ttl() {
+    let env = Env::default();
+    let client = setup_program(&env);
+    let signer = Address::generate(&env);
+
+    client.add_payout_signer(&signer, &1_000, &(env.ledger().timestamp() + 1_000));
+
+    let ttl = env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .get_ttl(&DataKey::PayoutSigner(signer.clone()))
+    });
+    assert_eq!(ttl, PERSISTENT_TTL_EXTEND_TO);
+}
+
+#[test]
+fn test_release_history_keeps_most_recent_entries() {
+    let env = Env::default();
+    let client = setup_program(&env);
+    env.budget().reset_unlimited();
+    let now = env.ledger().timestamp();
+
+    for _ in 0..2 {
+        for _ in 0..60 {
+            client.schedule_payout(&Address::generate(&env), &1, &now);
+        }
+        client.execute_due_payouts();
+    }
+
+    let history = client.get_program_release_history();
+    assert_eq!(history.len(), MAX_RELEASE_HISTORY);
+    assert_eq!(history.get(0).unwrap().schedule_id, 21);
+    // The first round was pruned from the full schedule list
+    assert_eq!(client.get_all_prog_release_schedules().len(), 60);
+}
+
+#[test]
+#[should_panic(expected = "Too many pending schedules")]
+fn test_pending_schedules_are_capped() {
+    let env = Env::default();
+    let client = setup_program(&env);
+    env.budget().reset_unlimited();
+    let later = env.ledger().timestamp() + 1_000;
+
+    for _ in 0..=MAX_SCHEDULES {
+        client.schedule_payout(&Address::generate(&env), &1, &later);
+    }
+}
@@ -0,0 +1,124 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, token, Address, Env, String};
+
+/// Router adapter that pays `amount_in * rate_bps / 10_000` of `token_out`
+/// from its own balance, ignoring `min_out` so the escrow's check is exercised.
+#[contract]
+pub struct MockRouter;
+
+#[contractimpl]
+impl MockRouter {
+    pub fn set_rate(env: Env, rate_bps: i128) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("rate"), &rate_bps);
+    }
+
+    pub fn swap(
+        env: Env,
+        _token_in: Address,
+        token_out: Address,
+        amount_in: i128,
+        _min_out: i128,
+        to: Address,
+    ) -> i128 {
+        let rate: i128 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("rate"))
+            .unwrap();
+        let amount_out = amount_in * rate / 10_000;
+        token::Client::new(&env, &token_out).transfer(
+            &env.current_contract_address(),
+            &to,
+            &amount_out,
+        );
+        amount_out
+    }
+}
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    token: token::Client<'static>,
+    asset_out: token::Client<'static>,
+    router: MockRouterClient<'static>,
+}
+
+fn setup(env: &Env, initial_amount: i128) -> Setup {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(env));
+    let token = token::Client::new(env, &sac.address());
+    let out_sac = env.register_stellar_asset_contract_v2(Address::generate(env));
+    let asset_out = token::Client::new(env, &out_sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token::StellarAssetClient::new(env, &sac.address()).mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    let router_id = env.register_contract(None, MockRouter);
+    let router = MockRouterClient::new(env, &router_id);
+    token::StellarAssetClient::new(env, &out_sac.address()).mint(&router_id, &1_000_000);
+    client.set_swap_router(&Some(router_id));
+
+    Setup {
+        client,
+        token,
+        asset_out,
+        router,
+    }
+}
+
+#[test]
+fn test_single_payout_in_delivers_swapped_asset() {
+    let env = Env::default();
+    let s = setup(&env, 10_000);
+    let recipient = Address::generate(&env);
+    s.router.set_rate(&20_000);
+
+    let data = s
+        .client
+        .single_payout_in(&recipient, &1_000, &s.asset_out.address, &1_900);
+
+    assert_eq!(s.asset_out.balance(&recipient), 2_000);
+    assert_eq!(s.token.balance(&recipient), 0);
+    assert_eq!(s.token.balance(&s.router.address), 1_000);
+    assert_eq!(data.remaining_balance, 9_000);
+}
+
+#[test]
+fn test_slippage_reverts_payout() {
+    let env = Env::default();
+    let s = setup(&env, 10_000);
+    let recipient = Address::generate(&env);
+    s.router.set_rate(&5_000);
+
+    let result = s
+        .client
+        .try_single_payout_in(&recipient, &1_000, &s.asset_out.address, &900);
+
+    assert!(result.is_err());
+    assert_eq!(s.asset_out.balance(&recipient), 0);
+    assert_eq!(s.token.balance(&s.client.address), 10_000);
+    assert_eq!(s.client.get_remaining_balance(), 10_000);
+}
+
+#[test]
+#[should_panic(expected = "Swap router not set")]
+fn test_swap_without_router_rejected() {
+    let env = Env::default();
+    let s = setup(&env, 10_000);
+    s.client.set_swap_router(&None);
+
+    s.client
+        .single_payout_in(&Address::generate(&env), &1_000, &s.asset_out.address, &1);
+}
@@ -0,0 +1,98 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    token: token::Client<'static>,
+    token_admin: token::StellarAssetClient<'static>,
+}
+
+fn setup(env: &Env, initial_amount: i128) -> Setup {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(env));
+    let token = token::Client::new(env, &sac.address());
+    let token_admin = token::StellarAssetClient::new(env, &sac.address());
+
+    client.initialize_contract(&organizer);
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    Setup {
+        client,
+        token,
+        token_admin,
+    }
+}
+
+#[test]
+fn test_sweep_program_token_surplus_only() {
+    let env = Env::default();
+    let s = setup(&env, 10_000);
+    let recipient = Address::generate(&env);
+
+    s.token_admin.mint(&s.client.address, &750);
+    let swept = s.client.sweep_surplus(&s.token.address, &recipient);
+
+    assert_eq!(swept, 750);
+    assert_eq!(s.token.balance(&recipient), 750);
+    assert_eq!(s.token.balance(&s.client.address), 10_000);
+    assert_eq!(s.client.get_remaining_balance(), 10_000);
+}
+
+#[test]
+fn test_sweep_foreign_token_in_full() {
+    let env = Env::default();
+    let s = setup(&env, 10_000);
+    let recipient = Address::generate(&env);
+
+    let other = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    token::StellarAssetClient::new(&env, &other.address()).mint(&s.client.address, &300);
+
+    let swept = s.client.sweep_surplus(&other.address(), &recipient);
+
+    assert_eq!(swept, 300);
+    assert_eq!(
+        token::Client::new(&env, &other.address()).balance(&recipient),
+        300
+    );
+}
+
+#[test]
+fn test_sweep_keeps_funds_reserved_for_pending_claims() {
+    let env = Env::default();
+    let s = setup(&env, 10_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let deadline = env.ledger().timestamp() + 1_000;
+
+    s.client
+        .create_pending_claim(&program_id, &Address::generate(&env), &4_000, &deadline);
+    s.token_admin.mint(&s.client.address, &100);
+
+    let swept = s
+        .client
+        .sweep_surplus(&s.token.address, &Address::generate(&env));
+
+    assert_eq!(swept, 100);
+    assert_eq!(s.token.balance(&s.client.address), 10_000);
+}
+
+#[test]
+#[should_panic(expected = "No surplus to sweep")]
+fn test_sweep_without_surplus_rejected() {
+    let env = Env::default();
+    let s = setup(&env, 10_000);
+
+    s.client
+        .sweep_surplus(&s.token.address, &Address::generate(&env));
+}
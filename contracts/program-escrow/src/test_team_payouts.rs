@@ -0,0 +1,139 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+fn setup_program(
+    env: &Env,
+    initial_amount: i128,
+) -> (ProgramEscrowContractClient<'static>, token::Client<'static>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    (client, token_client)
+}
+
+#[test]
+fn test_team_entry_fans_out_to_members() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let team = Address::generate(&env);
+    let m1 = Address::generate(&env);
+    let m2 = Address::generate(&env);
+    let m3 = Address::generate(&env);
+    let solo = Address::generate(&env);
+
+    client.register_team(
+        &team,
+        &vec![&env, m1.clone(), m2.clone(), m3.clone()],
+        &vec![&env, 5_000u32, 2_500u32, 2_500u32],
+    );
+    client.batch_payout(
+        &vec![&env, team.clone(), solo.clone()],
+        &vec![&env, 3_001i128, 1_000i128],
+    );
+
+    assert_eq!(token_client.balance(&m1), 1_501);
+    assert_eq!(token_client.balance(&m2), 750);
+    assert_eq!(token_client.balance(&m3), 750);
+    assert_eq!(token_client.balance(&team), 0);
+    assert_eq!(token_client.balance(&solo), 1_000);
+    assert_eq!(client.get_remaining_balance(), 5_999);
+}
+
+#[test]
+fn test_single_payout_to_team_and_reregistration() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let team = Address::generate(&env);
+    let m1 = Address::generate(&env);
+    let m2 = Address::generate(&env);
+
+    client.register_team(
+        &team,
+        &vec![&env, m1.clone(), m2.clone()],
+        &vec![&env, 5_000u32, 5_000u32],
+    );
+    client.register_team(&team, &vec![&env, m2.clone()], &vec![&env, 10_000u32]);
+    client.single_payout(&team, &2_000);
+
+    assert_eq!(
+        client.get_team(&team).unwrap().members,
+        vec![&env, m2.clone()]
+    );
+    assert_eq!(token_client.balance(&m1), 0);
+    assert_eq!(token_client.balance(&m2), 2_000);
+}
+
+#[test]
+#[should_panic(expected = "Shares must sum to 10000 basis points")]
+fn test_team_shares_must_sum_to_whole() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+
+    client.register_team(
+        &Address::generate(&env),
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 6_000u32, 3_000u32],
+    );
+}
+
+#[test]
+#[should_panic(expected = "Duplicate team member")]
+fn test_duplicate_team_member_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let member = Address::generate(&env);
+
+    client.register_team(
+        &Address::generate(&env),
+        &vec![&env, member.clone(), member],
+        &vec![&env, 5_000u32, 5_000u32],
+    );
+}
+
+#[test]
+#[should_panic(expected = "Member shares must be greater than zero")]
+fn test_zero_share_team_member_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+
+    client.register_team(
+        &Address::generate(&env),
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 10_000u32, 0u32],
+    );
+}
+
+#[test]
+#[should_panic(expected = "Recipient is blocked")]
+fn test_blocked_team_member_rejects_payout() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let team = Address::generate(&env);
+    let blocked = Address::generate(&env);
+
+    client.register_team(
+        &team,
+        &vec![&env, Address::generate(&env), blocked.clone()],
+        &vec![&env, 5_000u32, 5_000u32],
+    );
+    client.block_recipient(&blocked);
+
+    client.single_payout(&team, &2_000);
+}
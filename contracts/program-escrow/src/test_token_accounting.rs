@@ -0,0 +1,91 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, BytesN, Env, String};
+
+fn setup_program(
+    env: &Env,
+    initial_amount: i128,
+) -> (ProgramEscrowContractClient<'static>, token::Client<'static>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    (client, token_client)
+}
+
+#[test]
+fn test_accounting_for_program_token() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    client.single_payout(&Address::generate(&env), &3_000);
+
+    let report = client.get_accounting(&token_client.address);
+    assert_eq!(report.locked, 10_000);
+    assert_eq!(report.paid_out, 3_000);
+    assert_eq!(report.refunded, 0);
+    assert_eq!(report.remaining_balance, 7_000);
+    assert_eq!(report.balance, 7_000);
+}
+
+#[test]
+fn test_accounting_records_refund_on_cancel() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    client.cancel_program();
+
+    let report = client.get_accounting(&token_client.address);
+    assert_eq!(report.refunded, 10_000);
+    assert_eq!(report.paid_out, 0);
+    assert_eq!(client.get_program_summary().total_paid_out, 0);
+}
+
+#[test]
+fn test_accounting_for_stray_token() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let other = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    token::StellarAssetClient::new(&env, &other.address()).mint(&client.address, &250);
+
+    let report = client.get_accounting(&other.address());
+    assert_eq!(report.locked, 0);
+    assert_eq!(report.balance, 250);
+}
+
+#[test]
+fn test_accounting_separates_pending_payouts() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let payout_id = BytesN::from_array(&env, &[4u8; 32]);
+
+    client.single_payout(&Address::generate(&env), &1_000);
+    client.batch_payout_claimable(
+        &vec![&env, Address::generate(&env)],
+        &vec![&env, 2_000i128],
+        &(env.ledger().timestamp() + 1_000),
+    );
+    client.set_clawback_window(&Some(3_600));
+    client.single_payout_with_id(&payout_id, &Address::generate(&env), &3_000, &None);
+
+    let report = client.get_accounting(&token_client.address);
+    assert_eq!(report.paid_out, 1_000);
+    assert_eq!(report.claimable, 2_000);
+    assert_eq!(report.held, 3_000);
+    assert_eq!(report.pending_retry, 0);
+    assert_eq!(report.remaining_balance, 4_000);
+    assert_eq!(report.balance, 9_000);
+}
@@ -0,0 +1,123 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+fn setup_program(
+    env: &Env,
+    initial_amount: i128,
+) -> (
+    ProgramEscrowContractClient<'static>,
+    token::Client<'static>,
+    token::StellarAssetClient<'static>,
+) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    (client, token_client, token_admin_client)
+}
+
+#[test]
+fn test_earmarked_funds_only_fund_their_track() {
+    let env = Env::default();
+    let (client, token_client, token_admin_client) = setup_program(&env, 10_000);
+    let defi = String::from_str(&env, "DeFi");
+    let sponsor = Address::generate(&env);
+    let winner = Address::generate(&env);
+    token_admin_client.mint(&sponsor, &5_000);
+
+    client.create_track(&defi, &1_000, &None);
+    client.lock_track_funds_from(&sponsor, &defi, &5_000);
+
+    let track = client.get_track(&defi);
+    assert_eq!(track.earmarked, 5_000);
+    assert_eq!(track.budget, 6_000);
+    assert_eq!(client.get_earmarked_reserve(), 5_000);
+    assert_eq!(client.get_sponsors().len(), 1);
+
+    // Regular payouts cannot reach the sponsor's money or the track's budget
+    let result = client.try_single_payout(&Address::generate(&env), &9_001);
+    assert!(result.is_err());
+    client.single_payout(&Address::generate(&env), &9_000);
+
+    client.track_single_payout(&defi, &winner, &5_000);
+    assert_eq!(token_client.balance(&winner), 5_000);
+    assert_eq!(client.get_earmarked_reserve(), 0);
+    assert_eq!(client.get_track_budget_reserve(), 1_000);
+    assert_eq!(client.get_remaining_balance(), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Funds earmarked for another track")]
+fn test_regular_payout_cannot_spend_earmark() {
+    let env = Env::default();
+    let (client, _token_client, token_admin_client) = setup_program(&env, 2_000);
+    let defi = String::from_str(&env, "DeFi");
+    let sponsor = Address::generate(&env);
+    token_admin_client.mint(&sponsor, &5_000);
+
+    client.create_track(&defi, &1_000, &None);
+    client.lock_track_funds_from(&sponsor, &defi, &5_000);
+
+    // 7_000 remains, but only 2_000 of it is not earmarked for DeFi
+    client.single_payout(&Address::generate(&env), &2_001);
+}
+
+#[test]
+#[should_panic(expected = "Track budget exceeded")]
+fn test_track_cannot_exceed_earmarked_budget() {
+    let env = Env::default();
+    let (client, _token_client, token_admin_client) = setup_program(&env, 10_000);
+    let defi = String::from_str(&env, "DeFi");
+    let sponsor = Address::generate(&env);
+    token_admin_client.mint(&sponsor, &5_000);
+
+    client.create_track(&defi, &1, &None);
+    client.lock_track_funds_from(&sponsor, &defi, &5_000);
+
+    client.track_single_payout(&defi, &Address::generate(&env), &5_002);
+}
+
+#[test]
+fn test_earmarks_hold_against_claimable_schedules_and_close() {
+    let env = Env::default();
+    let (client, _token_client, token_admin_client) = setup_program(&env, 2_000);
+    let defi = String::from_str(&env, "DeFi");
+    let sponsor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let now = env.ledger().timestamp();
+    token_admin_client.mint(&sponsor, &5_000);
+
+    client.create_track(&defi, &1_000, &None);
+    client.lock_track_funds_from(&sponsor, &defi, &5_000);
+
+    assert!(client
+        .try_batch_payout_claimable(
+            &soroban_sdk::vec![&env, recipient.clone()],
+            &soroban_sdk::vec![&env, 2_001i128],
+            &(now + 1_000),
+        )
+        .is_err());
+    assert!(client
+        .try_schedule_payout(&recipient, &2_001, &(now + 100))
+        .is_err());
+    assert!(client.try_close_program().is_err());
+
+    client.schedule_payout(&recipient, &1_000, &(now + 100));
+    assert_eq!(client.get_earmarked_reserve(), 5_000);
+}
@@ -0,0 +1,155 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, MockAuth, MockAuthInvoke},
+    token, vec, Address, Env, IntoVal, String,
+};
+
+fn setup_program(
+    env: &Env,
+    initial_amount: i128,
+) -> (ProgramEscrowContractClient<'static>, token::Client<'static>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    (client, token_client)
+}
+
+#[test]
+fn test_track_payouts_have_separate_accounting() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 20_000);
+    let defi = String::from_str(&env, "DeFi");
+    let ai = String::from_str(&env, "AI");
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+
+    client.create_track(&defi, &8_000, &None);
+    client.create_track(&ai, &5_000, &None);
+    client.track_batch_payout(
+        &defi,
+        &vec![&env, r1.clone(), r2.clone()],
+        &vec![&env, 3_000i128, 1_000i128],
+    );
+    client.track_single_payout(&ai, &r1, &2_000);
+
+    assert_eq!(client.get_tracks(), vec![&env, defi.clone(), ai.clone()]);
+    let defi_track = client.get_track(&defi);
+    assert_eq!(defi_track.paid_out, 4_000);
+    assert_eq!(defi_track.payout_count, 2);
+    assert_eq!(client.get_track_history(&defi).len(), 2);
+    assert_eq!(client.get_track(&ai).paid_out, 2_000);
+    assert_eq!(token_client.balance(&r1), 5_000);
+    assert_eq!(client.get_remaining_balance(), 14_000);
+}
+
+#[test]
+#[should_panic(expected = "Track budget exceeded")]
+fn test_track_budget_enforced() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 20_000);
+    let defi = String::from_str(&env, "DeFi");
+
+    client.create_track(&defi, &1_000, &None);
+    client.track_single_payout(&defi, &Address::generate(&env), &1_001);
+}
+
+#[test]
+#[should_panic(expected = "Track budgets exceed remaining balance")]
+fn test_track_budgets_cannot_exceed_pool() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+
+    client.create_track(&String::from_str(&env, "DeFi"), &6_000, &None);
+    client.create_track(&String::from_str(&env, "AI"), &5_000, &None);
+}
+
+#[test]
+fn test_track_payout_key_authorizes_track_payouts() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let defi = String::from_str(&env, "DeFi");
+    let track_key = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    client.create_track(&defi, &5_000, &Some(track_key.clone()));
+
+    env.mock_auths(&[MockAuth {
+        address: &track_key,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "track_single_payout",
+            args: (defi.clone(), recipient.clone(), 1_000i128).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    client.track_single_payout(&defi, &recipient, &1_000);
+
+    assert_eq!(token_client.balance(&recipient), 1_000);
+}
+
+#[test]
+fn test_track_budgets_stay_reserved() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let defi = String::from_str(&env, "DeFi");
+    let ai = String::from_str(&env, "AI");
+
+    client.create_track(&defi, &6_000, &None);
+    client.create_track(&ai, &2_000, &None);
+    assert_eq!(client.get_track_budget_reserve(), 8_000);
+
+    // Neither regular payouts nor another track can dip into DeFi's budget
+    assert!(client
+        .try_single_payout(&Address::generate(&env), &2_001)
+        .is_err());
+    client.single_payout(&Address::generate(&env), &2_000);
+    client.track_single_payout(&ai, &Address::generate(&env), &2_000);
+    assert!(client
+        .try_single_payout(&Address::generate(&env), &1)
+        .is_err());
+
+    client.track_single_payout(&defi, &Address::generate(&env), &6_000);
+    assert_eq!(client.get_track_budget_reserve(), 0);
+    assert_eq!(client.get_remaining_balance(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Too many tracks")]
+fn test_track_count_is_capped() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let names = [
+        "t00", "t01", "t02", "t03", "t04", "t05", "t06", "t07", "t08", "t09", "t10", "t11",
+        "t12", "t13", "t14", "t15", "t16", "t17", "t18", "t19", "t20",
+    ];
+
+    for name in names {
+        client.create_track(&String::from_str(&env, name), &1, &None);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Program closed")]
+fn test_closed_program_rejects_new_tracks() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    client.close_program();
+
+    client.create_track(&String::from_str(&env, "DeFi"), &1_000, &None);
+}
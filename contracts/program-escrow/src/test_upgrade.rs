@@ -0,0 +1,61 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, Address) {
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+    (client, admin)
+}
+
+#[test]
+fn test_version_recorded_on_initialize() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+
+    assert_eq!(client.get_version(), CONTRACT_VERSION);
+    assert_eq!(client.get_previous_version(), None);
+}
+
+#[test]
+fn test_admin_can_set_version() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup(&env);
+
+    client.set_version(&2);
+    assert_eq!(client.get_version(), 2);
+}
+
+#[test]
+fn test_set_version_requires_admin_auth() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+
+    assert!(client.try_set_version(&2).is_err());
+    assert_eq!(client.get_version(), CONTRACT_VERSION);
+}
+
+#[test]
+fn test_upgrade_requires_admin_auth() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+
+    let result = client.try_upgrade(&BytesN::from_array(&env, &[7u8; 32]));
+    assert!(result.is_err());
+    assert_eq!(client.get_previous_version(), None);
+}
+
+#[test]
+#[should_panic(expected = "Not initialized")]
+fn test_upgrade_without_admin_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    client.upgrade(&BytesN::from_array(&env, &[7u8; 32]));
+}
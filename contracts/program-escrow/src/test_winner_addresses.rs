@@ -0,0 +1,161 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+
+fn setup_program(
+    env: &Env,
+    initial_amount: i128,
+) -> (ProgramEscrowContractClient<'static>, token::Client<'static>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    let token_client = token::Client::new(env, &sac.address());
+    let token_admin_client = token::StellarAssetClient::new(env, &sac.address());
+
+    let program_id = String::from_str(env, "hack-2026");
+    client.init_program(&program_id, &payout_key, &sac.address(), &organizer, &None);
+
+    token_admin_client.mint(&client.address, &initial_amount);
+    client.lock_program_funds(&initial_amount);
+
+    (client, token_client)
+}
+
+#[test]
+fn test_payout_to_confirmed_address() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let participant = Address::generate(&env);
+    let wallet = Address::generate(&env);
+    let confirm_by = env.ledger().timestamp() + 1_000;
+
+    client.shortlist_winners(&vec![&env, participant.clone()], &confirm_by);
+    assert!(client.is_shortlisted(&participant));
+    client.register_winner_address(&String::from_str(&env, "hack-2026"), &participant, &wallet);
+    assert_eq!(
+        client.get_winner_address(&participant),
+        Some(wallet.clone())
+    );
+
+    client.single_payout(&wallet, &1_000);
+    assert_eq!(token_client.balance(&wallet), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Recipient address not confirmed")]
+fn test_payout_to_unconfirmed_address_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let participant = Address::generate(&env);
+    let confirm_by = env.ledger().timestamp() + 1_000;
+
+    client.shortlist_winners(&vec![&env, participant.clone()], &confirm_by);
+    client.single_payout(&participant, &1_000);
+}
+
+#[test]
+fn test_reregistering_replaces_confirmed_address() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let participant = Address::generate(&env);
+    let old_wallet = Address::generate(&env);
+    let new_wallet = Address::generate(&env);
+    let program_id = String::from_str(&env, "hack-2026");
+    let confirm_by = env.ledger().timestamp() + 1_000;
+
+    client.shortlist_winners(&vec![&env, participant.clone()], &confirm_by);
+    client.register_winner_address(&program_id, &participant, &old_wallet);
+    client.register_winner_address(&program_id, &participant, &new_wallet);
+
+    let result = client.try_batch_payout(&vec![&env, old_wallet], &vec![&env, 1_000i128]);
+    assert!(result.is_err());
+    client.batch_payout(&vec![&env, new_wallet], &vec![&env, 1_000i128]);
+}
+
+#[test]
+#[should_panic(expected = "Participant not shortlisted")]
+fn test_unlisted_participant_cannot_register() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let confirm_by = env.ledger().timestamp() + 1_000;
+    client.shortlist_winners(&vec![&env, Address::generate(&env)], &confirm_by);
+
+    client.register_winner_address(
+        &String::from_str(&env, "hack-2026"),
+        &Address::generate(&env),
+        &Address::generate(&env),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Registration window closed")]
+fn test_registration_after_deadline_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let participant = Address::generate(&env);
+    let now = env.ledger().timestamp();
+
+    client.shortlist_winners(&vec![&env, participant.clone()], &(now + 1_000));
+    env.ledger().set_timestamp(now + 1_001);
+
+    client.register_winner_address(
+        &String::from_str(&env, "hack-2026"),
+        &participant,
+        &Address::generate(&env),
+    );
+}
+
+fn finalize_winner(env: &Env, client: &ProgramEscrowContractClient, winner: &Address) {
+    let judge = Address::generate(env);
+    let hash = BytesN::from_array(env, &[7; 32]);
+    client.add_judge(&judge);
+    client.register_submission(winner, &hash);
+    client.cast_vote(&judge, &Vec::from_array(env, [hash]));
+    client.finalize_judging(&1);
+}
+
+#[test]
+fn test_winner_with_confirmed_address_paid_there() {
+    let env = Env::default();
+    let (client, token_client) = setup_program(&env, 10_000);
+    let participant = Address::generate(&env);
+    let wallet = Address::generate(&env);
+    let confirm_by = env.ledger().timestamp() + 1_000;
+
+    finalize_winner(&env, &client, &participant);
+    client.shortlist_winners(&vec![&env, participant.clone()], &confirm_by);
+    client.register_winner_address(&String::from_str(&env, "hack-2026"), &participant, &wallet);
+
+    client.single_payout(&participant, &1_000);
+    client.single_payout(&wallet, &500);
+
+    assert_eq!(token_client.balance(&wallet), 1_500);
+    assert_eq!(token_client.balance(&participant), 0);
+}
+
+#[test]
+#[should_panic(expected = "Recipient not in winning set")]
+fn test_confirmed_address_of_non_winner_rejected() {
+    let env = Env::default();
+    let (client, _token_client) = setup_program(&env, 10_000);
+    let winner = Address::generate(&env);
+    let runner_up = Address::generate(&env);
+    let wallet = Address::generate(&env);
+    let confirm_by = env.ledger().timestamp() + 1_000;
+
+    finalize_winner(&env, &client, &winner);
+    client.shortlist_winners(&vec![&env, runner_up.clone()], &confirm_by);
+    client.register_winner_address(&String::from_str(&env, "hack-2026"), &runner_up, &wallet);
+
+    client.single_payout(&wallet, &1_000);
+}
@@ -24,7 +24,7 @@ import {
 
 /** contracts/bounty_escrow/contracts/escrow/src/lib.rs — Error enum */
 const BOUNTY_ESCROW_DISCRIMINANTS: number[] = [
-  1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, /* gap at 15 */ 16, 17, 18,
+  1, 2, 3, 4, 5, 6, 7, 8, /* 9 retired */ 10, 11, 12, 13, 14, /* gap at 15 */ 16, /* 17 retired */ 18,
 ];
 
 /** contracts/grainlify-core/src/governance.rs — Error enum */
@@ -303,7 +303,7 @@ describe('Enum size regression guards', () => {
   });
 
   it('BOUNTY_ESCROW_ERROR_MAP has 17 entries', () => {
-    expect(Object.keys(BOUNTY_ESCROW_ERROR_MAP).length).toBe(19);
+    expect(Object.keys(BOUNTY_ESCROW_ERROR_MAP).length).toBe(17);
   });
 
   it('GOVERNANCE_ERROR_MAP has 14 entries', () => {
@@ -81,14 +81,14 @@ export enum ContractErrorCode {
   BOUNTY_DEADLINE_NOT_PASSED = 'BOUNTY_DEADLINE_NOT_PASSED',   // 6
   BOUNTY_UNAUTHORIZED        = 'BOUNTY_UNAUTHORIZED',          // 7
   BOUNTY_INVALID_FEE_RATE    = 'BOUNTY_INVALID_FEE_RATE',     // 8
-  BOUNTY_FEE_RECIPIENT_NOT_SET = 'BOUNTY_FEE_RECIPIENT_NOT_SET', // 9
+  BOUNTY_FEE_RECIPIENT_NOT_SET = 'BOUNTY_FEE_RECIPIENT_NOT_SET', // 9 (retired)
   BOUNTY_INVALID_BATCH_SIZE  = 'BOUNTY_INVALID_BATCH_SIZE',   // 10
   BOUNTY_BATCH_SIZE_MISMATCH = 'BOUNTY_BATCH_SIZE_MISMATCH',  // 11
   BOUNTY_DUPLICATE_ID        = 'BOUNTY_DUPLICATE_ID',          // 12
   BOUNTY_INVALID_AMOUNT      = 'BOUNTY_INVALID_AMOUNT',        // 13
   BOUNTY_INVALID_DEADLINE    = 'BOUNTY_INVALID_DEADLINE',      // 14
   BOUNTY_INSUFFICIENT_FUNDS  = 'BOUNTY_INSUFFICIENT_FUNDS',    // 16
-  BOUNTY_REFUND_NOT_APPROVED = 'BOUNTY_REFUND_NOT_APPROVED',   // 17
+  BOUNTY_REFUND_NOT_APPROVED = 'BOUNTY_REFUND_NOT_APPROVED',   // 17 (retired)
   BOUNTY_FUNDS_PAUSED        = 'BOUNTY_FUNDS_PAUSED',          // 18
 
   // ── Governance (contracts/grainlify-core/governance) ───────────────────
@@ -185,14 +185,12 @@ export const BOUNTY_ESCROW_ERROR_MAP: Record<number, ContractErrorCode> = {
   6:  ContractErrorCode.BOUNTY_DEADLINE_NOT_PASSED,
   7:  ContractErrorCode.BOUNTY_UNAUTHORIZED,
   8:  ContractErrorCode.BOUNTY_INVALID_FEE_RATE,
-  9:  ContractErrorCode.BOUNTY_FEE_RECIPIENT_NOT_SET,
   10: ContractErrorCode.BOUNTY_INVALID_BATCH_SIZE,
   11: ContractErrorCode.BOUNTY_BATCH_SIZE_MISMATCH,
   12: ContractErrorCode.BOUNTY_DUPLICATE_ID,
   13: ContractErrorCode.BOUNTY_INVALID_AMOUNT,
   14: ContractErrorCode.BOUNTY_INVALID_DEADLINE,
   16: ContractErrorCode.BOUNTY_INSUFFICIENT_FUNDS,
-  17: ContractErrorCode.BOUNTY_REFUND_NOT_APPROVED,
   18: ContractErrorCode.BOUNTY_FUNDS_PAUSED,
   19: ContractErrorCode.AMOUNT_BELOW_MIN,
   20: ContractErrorCode.AMOUNT_ABOVE_MAX,
@@ -0,0 +1,169 @@
+#![no_std]
+//! # Timelock
+//!
+//! Delayed execution of sensitive operations. grainlify-core queues its
+//! upgrades and upgrade-delay changes through it, and the treasury queues
+//! spends at or above its large spend threshold.
+//!
+//! An operation is scheduled with a payload and a delay, can be cancelled
+//! while pending, and can be executed once the delay has passed. The crate
+//! only tracks the lifecycle; the contract decides who may schedule, cancel
+//! and execute, and what executing an `action` does with its payload.
+//!
+//! ```text
+//! schedule ──► Pending ──(ready_at passed)──► execute ──► Executed
+//!                 │
+//!                 └──────────► cancel ──► Cancelled
+//! ```
+//!
+//! Operations live in persistent storage under [`TimelockKey`] and are
+//! extended with the shared TTL policy.
+
+use grainlify_common::storage;
+use soroban_sdk::{contracttype, Address, Env, IntoVal, Symbol, TryFromVal, Val};
+
+#[cfg(test)]
+mod test;
+
+/// Storage keys owned by this crate.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TimelockKey {
+    NextId,         // instance: id given to the next scheduled operation
+    Operation(u64), // persistent: id -> Operation
+    Payload(u64),   // persistent: id -> payload, removed once settled
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OperationStatus {
+    Pending,
+    Executed,
+    Cancelled,
+}
+
+/// A scheduled operation. The payload is stored separately and read with
+/// [`payload`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Operation {
+    pub id: u64,
+    /// What executing the operation does, as interpreted by the contract.
+    pub action: Symbol,
+    pub proposer: Address,
+    /// Earliest ledger timestamp at which the operation may be executed.
+    pub ready_at: u64,
+    pub status: OperationStatus,
+}
+
+/// Failure of a timelock transition. Contracts map this into their own
+/// error type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TimelockError {
+    /// No operation has this id.
+    NotFound,
+    /// The operation was already executed or cancelled.
+    NotPending,
+    /// The operation's delay has not passed yet.
+    NotReady,
+}
+
+/// Schedule `action` with `payload`, executable `delay` seconds from now.
+/// Returns the new operation.
+pub fn schedule<T>(
+    env: &Env,
+    action: Symbol,
+    payload: &T,
+    proposer: &Address,
+    delay: u64,
+) -> Operation
+where
+    T: IntoVal<Env, Val>,
+{
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&TimelockKey::NextId)
+        .unwrap_or(0);
+    env.storage()
+        .instance()
+        .set(&TimelockKey::NextId, &(id + 1));
+
+    let operation = Operation {
+        id,
+        action,
+        proposer: proposer.clone(),
+        ready_at: env.ledger().timestamp().saturating_add(delay),
+        status: OperationStatus::Pending,
+    };
+    save(env, &operation);
+    let key = TimelockKey::Payload(id);
+    env.storage().persistent().set(&key, payload);
+    storage::extend_persistent(env, &key);
+    operation
+}
+
+/// The operation with `id`, if any.
+pub fn operation(env: &Env, id: u64) -> Option<Operation> {
+    env.storage().persistent().get(&TimelockKey::Operation(id))
+}
+
+/// The payload of a pending operation. `None` once it is settled.
+pub fn payload<T>(env: &Env, id: u64) -> Option<T>
+where
+    T: TryFromVal<Env, Val>,
+{
+    env.storage().persistent().get(&TimelockKey::Payload(id))
+}
+
+/// Whether the operation is pending and its delay has passed.
+pub fn is_ready(env: &Env, id: u64) -> bool {
+    operation(env, id).is_some_and(|op| {
+        op.status == OperationStatus::Pending && env.ledger().timestamp() >= op.ready_at
+    })
+}
+
+/// Mark a ready operation executed and return it with its payload. The
+/// caller then performs the action.
+pub fn execute<T>(env: &Env, id: u64) -> Result<(Operation, T), TimelockError>
+where
+    T: TryFromVal<Env, Val>,
+{
+    let mut operation = pending(env, id)?;
+    if env.ledger().timestamp() < operation.ready_at {
+        return Err(TimelockError::NotReady);
+    }
+    let payload = payload(env, id).ok_or(TimelockError::NotFound)?;
+    operation.status = OperationStatus::Executed;
+    settle(env, &operation);
+    Ok((operation, payload))
+}
+
+/// Cancel a pending operation and return it.
+pub fn cancel(env: &Env, id: u64) -> Result<Operation, TimelockError> {
+    let mut operation = pending(env, id)?;
+    operation.status = OperationStatus::Cancelled;
+    settle(env, &operation);
+    Ok(operation)
+}
+
+fn pending(env: &Env, id: u64) -> Result<Operation, TimelockError> {
+    let operation = operation(env, id).ok_or(TimelockError::NotFound)?;
+    if operation.status != OperationStatus::Pending {
+        return Err(TimelockError::NotPending);
+    }
+    Ok(operation)
+}
+
+fn save(env: &Env, operation: &Operation) {
+    let key = TimelockKey::Operation(operation.id);
+    env.storage().persistent().set(&key, operation);
+    storage::extend_persistent(env, &key);
+}
+
+fn settle(env: &Env, operation: &Operation) {
+    save(env, operation);
+    env.storage()
+        .persistent()
+        .remove(&TimelockKey::Payload(operation.id));
+}
@@ -0,0 +1,85 @@
+use crate::{OperationStatus, TimelockError};
+use soroban_sdk::{
+    contract, symbol_short,
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+#[contract]
+struct Host;
+
+fn setup() -> (Env, Address, Address) {
+    let env = Env::default();
+    env.ledger().set_timestamp(1_000);
+    let host = env.register_contract(None, Host);
+    let proposer = Address::generate(&env);
+    (env, host, proposer)
+}
+
+#[test]
+fn test_execute_after_delay() {
+    let (env, host, proposer) = setup();
+
+    env.as_contract(&host, || {
+        let op = crate::schedule(&env, symbol_short!("fee"), &250u32, &proposer, 100);
+        assert_eq!(op.id, 0);
+        assert_eq!(op.ready_at, 1_100);
+        assert_eq!(crate::payload::<u32>(&env, op.id), Some(250));
+        assert!(!crate::is_ready(&env, op.id));
+        assert_eq!(
+            crate::execute::<u32>(&env, op.id),
+            Err(TimelockError::NotReady)
+        );
+
+        env.ledger().set_timestamp(1_100);
+        assert!(crate::is_ready(&env, op.id));
+        let (executed, payload) = crate::execute::<u32>(&env, op.id).unwrap();
+        assert_eq!(payload, 250);
+        assert_eq!(executed.status, OperationStatus::Executed);
+        assert_eq!(crate::payload::<u32>(&env, op.id), None);
+
+        // Executing twice is rejected
+        assert_eq!(
+            crate::execute::<u32>(&env, op.id),
+            Err(TimelockError::NotPending)
+        );
+    });
+}
+
+#[test]
+fn test_cancel_pending_operation() {
+    let (env, host, proposer) = setup();
+
+    env.as_contract(&host, || {
+        let op = crate::schedule(&env, symbol_short!("fee"), &250u32, &proposer, 100);
+        let cancelled = crate::cancel(&env, op.id).unwrap();
+        assert_eq!(cancelled.status, OperationStatus::Cancelled);
+        assert_eq!(
+            crate::operation(&env, op.id).unwrap().status,
+            OperationStatus::Cancelled
+        );
+
+        env.ledger().set_timestamp(2_000);
+        assert!(!crate::is_ready(&env, op.id));
+        assert_eq!(
+            crate::execute::<u32>(&env, op.id),
+            Err(TimelockError::NotPending)
+        );
+        assert_eq!(crate::cancel(&env, op.id), Err(TimelockError::NotPending));
+    });
+}
+
+#[test]
+fn test_unknown_operation() {
+    let (env, host, proposer) = setup();
+
+    env.as_contract(&host, || {
+        let first = crate::schedule(&env, symbol_short!("a"), &1u32, &proposer, 0);
+        let second = crate::schedule(&env, symbol_short!("b"), &2u32, &proposer, 0);
+        assert_eq!(second.id, first.id + 1);
+
+        assert_eq!(crate::operation(&env, 7), None);
+        assert_eq!(crate::cancel(&env, 7), Err(TimelockError::NotFound));
+        assert_eq!(crate::execute::<u32>(&env, 7), Err(TimelockError::NotFound));
+    });
+}
@@ -0,0 +1,385 @@
+#![no_std]
+//! # Treasury
+//!
+//! Holds platform fees and leftover escrow funds and pays them out under
+//! per-token spending policies. Point the escrows' fee recipient at this
+//! contract's address; tokens sent to it are simply held.
+//!
+//! ## Roles
+//! - **Admin**: sets policies, grants the spender role, cancels scheduled
+//!   spends. Transferred with `propose_admin` + `accept_admin`.
+//! - **Spender** (`"spender"` role): moves funds within the policy. A
+//!   scheduled spend only executes while its spender still holds the role.
+//!
+//! ## Spending rules
+//! A token without a [`SpendPolicy`] cannot be spent. Below the policy's
+//! `large_spend_threshold`, `spend` pays out immediately and counts against
+//! the `epoch_cap` for the current epoch (`epoch_length` seconds). Larger
+//! amounts must go through `schedule_spend`, which waits
+//! `large_spend_delay` seconds before anyone can `execute_spend` it; the
+//! delay gives the admin time to `cancel_spend`. Scheduled spends count
+//! against the cap of the epoch they execute in.
+
+use access_control::{ownable, roles, AccessError};
+use grainlify_common::{events, storage};
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, token, Address, Env, Symbol,
+};
+use timelock::TimelockError;
+
+#[cfg(test)]
+mod test;
+
+/// Role allowed to spend treasury funds.
+pub const SPENDER: Symbol = symbol_short!("spender");
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Admin,
+    Policy(Address),     // token -> SpendPolicy
+    EpochSpent(Address), // token -> EpochSpent
+}
+
+/// Spending rules for one token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SpendPolicy {
+    /// Length of a spending epoch in seconds.
+    pub epoch_length: u64,
+    /// Maximum amount `spend` may pay out per epoch.
+    pub epoch_cap: i128,
+    /// Amounts at or above this must be scheduled.
+    pub large_spend_threshold: i128,
+    /// Seconds a scheduled spend waits before it can execute.
+    pub large_spend_delay: u64,
+}
+
+/// Amount paid out by `spend` in the current epoch of a token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EpochSpent {
+    pub epoch: u64,
+    pub spent: i128,
+}
+
+/// Payload of a scheduled spend.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SpendRequest {
+    pub token: Address,
+    pub to: Address,
+    pub amount: i128,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    Unauthorized = 3,
+    InvalidAmount = 4,
+    /// Returned when a policy has a zero epoch length or delay, or a
+    /// non-positive cap or threshold
+    InvalidPolicy = 5,
+    /// Returned when spending a token that has no policy
+    PolicyNotSet = 6,
+    /// Returned when a spend would take the epoch over its cap
+    EpochCapExceeded = 7,
+    /// Returned when `spend` is used for an amount that must be scheduled
+    SpendRequiresSchedule = 8,
+    InsufficientBalance = 9,
+    SpendNotFound = 10,
+    /// Returned when a scheduled spend was already executed or cancelled
+    SpendNotPending = 11,
+    /// Returned when a scheduled spend's delay has not passed
+    SpendNotReady = 12,
+    /// Returned when `accept_admin` is called with no transfer pending
+    NoPendingAdmin = 13,
+}
+
+impl From<AccessError> for Error {
+    fn from(err: AccessError) -> Self {
+        match err {
+            AccessError::NotInitialized => Error::NotInitialized,
+            AccessError::AlreadyInitialized => Error::AlreadyInitialized,
+            AccessError::Unauthorized => Error::Unauthorized,
+            AccessError::NoPendingOwner => Error::NoPendingAdmin,
+        }
+    }
+}
+
+impl From<TimelockError> for Error {
+    fn from(err: TimelockError) -> Self {
+        match err {
+            TimelockError::NotFound => Error::SpendNotFound,
+            TimelockError::NotPending => Error::SpendNotPending,
+            TimelockError::NotReady => Error::SpendNotReady,
+        }
+    }
+}
+
+#[contract]
+pub struct TreasuryContract;
+
+#[contractimpl]
+impl TreasuryContract {
+    /// Initialize the treasury with its admin.
+    pub fn init(env: Env, admin: Address) -> Result<(), Error> {
+        ownable::init_owner(&env, &DataKey::Admin, &admin)?;
+        storage::extend_instance(&env);
+        Ok(())
+    }
+
+    // ========================================================================
+    // Admin & Roles
+    // ========================================================================
+
+    /// View: get the admin.
+    pub fn get_admin(env: Env) -> Option<Address> {
+        ownable::owner(&env, &DataKey::Admin)
+    }
+
+    /// Propose `new_admin` (admin only); it takes over once it calls
+    /// `accept_admin`.
+    pub fn propose_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        ownable::propose_owner(&env, &DataKey::Admin, &new_admin)?;
+        storage::extend_instance(&env);
+        Ok(())
+    }
+
+    /// Become admin (proposed admin only).
+    pub fn accept_admin(env: Env) -> Result<(), Error> {
+        let previous = ownable::accept_ownership(&env, &DataKey::Admin)?;
+        storage::extend_instance(&env);
+        env.events().publish(
+            (symbol_short!("admin"),),
+            (previous, Self::get_admin(env.clone())),
+        );
+        Ok(())
+    }
+
+    /// Withdraw a pending admin transfer (admin only).
+    pub fn cancel_admin_transfer(env: Env) -> Result<(), Error> {
+        ownable::cancel_ownership_transfer(&env, &DataKey::Admin)?;
+        storage::extend_instance(&env);
+        Ok(())
+    }
+
+    /// View: get the proposed admin, if a transfer is pending.
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        ownable::pending_owner(&env)
+    }
+
+    /// Grant or revoke the spender role (admin only).
+    pub fn set_spender(env: Env, account: Address, enabled: bool) -> Result<(), Error> {
+        ownable::require_owner(&env, &DataKey::Admin)?;
+        if enabled {
+            roles::grant_role(&env, &SPENDER, &account);
+        } else {
+            roles::revoke_role(&env, &SPENDER, &account);
+        }
+        storage::extend_instance(&env);
+        env.events()
+            .publish((symbol_short!("spender"), account), enabled);
+        Ok(())
+    }
+
+    /// View: whether `account` holds the spender role.
+    pub fn is_spender(env: Env, account: Address) -> bool {
+        roles::has_role(&env, &SPENDER, &account)
+    }
+
+    // ========================================================================
+    // Policies
+    // ========================================================================
+
+    /// Set the spending policy for `token` (admin only). An epoch already
+    /// under way keeps what it has spent.
+    pub fn set_policy(env: Env, token: Address, policy: SpendPolicy) -> Result<(), Error> {
+        ownable::require_owner(&env, &DataKey::Admin)?;
+        if policy.epoch_length == 0
+            || policy.epoch_cap <= 0
+            || policy.large_spend_threshold <= 0
+            || policy.large_spend_delay == 0
+        {
+            return Err(Error::InvalidPolicy);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::Policy(token.clone()), &policy);
+        storage::extend_instance(&env);
+        env.events()
+            .publish((symbol_short!("policy"), token), policy);
+        Ok(())
+    }
+
+    /// View: get the spending policy for `token`, if any.
+    pub fn get_policy(env: Env, token: Address) -> Option<SpendPolicy> {
+        env.storage().instance().get(&DataKey::Policy(token))
+    }
+
+    /// View: amount `spend` has paid out of `token` in the current epoch.
+    pub fn get_epoch_spent(env: Env, token: Address) -> i128 {
+        let Some(policy) = Self::get_policy(env.clone(), token.clone()) else {
+            return 0;
+        };
+        Self::epoch_spent(&env, &token, &policy).spent
+    }
+
+    /// View: the treasury's balance of `token`.
+    pub fn get_balance(env: Env, token: Address) -> i128 {
+        token::Client::new(&env, &token).balance(&env.current_contract_address())
+    }
+
+    // ========================================================================
+    // Spending
+    // ========================================================================
+
+    /// Pay `amount` of `token` to `to` right away (spender only).
+    ///
+    /// The amount must be below the policy's large spend threshold and fit
+    /// in what is left of the epoch cap.
+    pub fn spend(
+        env: Env,
+        spender: Address,
+        token: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        roles::require_role(&env, &SPENDER, &spender)?;
+        let policy = Self::policy_for(&env, &token, amount)?;
+        if amount >= policy.large_spend_threshold {
+            return Err(Error::SpendRequiresSchedule);
+        }
+
+        Self::charge_epoch(&env, &token, &policy, amount)?;
+        Self::transfer(&env, &token, &to, amount)?;
+        storage::extend_instance(&env);
+        env.events()
+            .publish((symbol_short!("spend"), token), (to, amount, spender));
+        Ok(())
+    }
+
+    /// Schedule a payment of `amount` of `token` to `to` (spender only),
+    /// executable after the policy's large spend delay. The amount must fit
+    /// in a whole epoch's cap.
+    ///
+    /// Returns the id to pass to `execute_spend` or `cancel_spend`.
+    pub fn schedule_spend(
+        env: Env,
+        spender: Address,
+        token: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<u64, Error> {
+        roles::require_role(&env, &SPENDER, &spender)?;
+        let policy = Self::policy_for(&env, &token, amount)?;
+        if amount > policy.epoch_cap {
+            return Err(Error::EpochCapExceeded);
+        }
+
+        let request = SpendRequest { token, to, amount };
+        let operation = timelock::schedule(
+            &env,
+            symbol_short!("spend"),
+            &request,
+            &spender,
+            policy.large_spend_delay,
+        );
+        storage::extend_instance(&env);
+        events::publish_for(
+            &env,
+            symbol_short!("spd_sched"),
+            operation.id,
+            (request, operation.ready_at),
+        );
+        Ok(operation.id)
+    }
+
+    /// Pay out a scheduled spend once its delay has passed. Callable by
+    /// anyone; the spender authorized it when scheduling and must still
+    /// hold the spender role. The amount counts against the current epoch.
+    pub fn execute_spend(env: Env, spend_id: u64) -> Result<(), Error> {
+        let (operation, request) = timelock::execute::<SpendRequest>(&env, spend_id)?;
+        if !roles::has_role(&env, &SPENDER, &operation.proposer) {
+            return Err(Error::Unauthorized);
+        }
+        let policy = Self::policy_for(&env, &request.token, request.amount)?;
+        Self::charge_epoch(&env, &request.token, &policy, request.amount)?;
+        Self::transfer(&env, &request.token, &request.to, request.amount)?;
+        storage::extend_instance(&env);
+        events::publish_for(&env, symbol_short!("spd_exec"), spend_id, request);
+        Ok(())
+    }
+
+    /// Cancel a scheduled spend (admin only).
+    pub fn cancel_spend(env: Env, spend_id: u64) -> Result<(), Error> {
+        let admin = ownable::require_owner(&env, &DataKey::Admin)?;
+        timelock::cancel(&env, spend_id)?;
+        storage::extend_instance(&env);
+        events::publish_for(&env, symbol_short!("spd_canc"), spend_id, admin);
+        Ok(())
+    }
+
+    /// View: get a scheduled spend and its request.
+    pub fn get_scheduled_spend(
+        env: Env,
+        spend_id: u64,
+    ) -> Option<(timelock::Operation, Option<SpendRequest>)> {
+        let operation = timelock::operation(&env, spend_id)?;
+        Some((operation, timelock::payload(&env, spend_id)))
+    }
+
+    fn policy_for(env: &Env, token: &Address, amount: i128) -> Result<SpendPolicy, Error> {
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        Self::get_policy(env.clone(), token.clone()).ok_or(Error::PolicyNotSet)
+    }
+
+    /// The epoch record for `token`, starting a fresh one once the stored
+    /// epoch has ended.
+    fn epoch_spent(env: &Env, token: &Address, policy: &SpendPolicy) -> EpochSpent {
+        let epoch = env.ledger().timestamp() / policy.epoch_length;
+        env.storage()
+            .instance()
+            .get(&DataKey::EpochSpent(token.clone()))
+            .filter(|spent: &EpochSpent| spent.epoch == epoch)
+            .unwrap_or(EpochSpent { epoch, spent: 0 })
+    }
+
+    /// Add `amount` to the current epoch of `token`, failing if that takes
+    /// it over the policy's cap.
+    fn charge_epoch(
+        env: &Env,
+        token: &Address,
+        policy: &SpendPolicy,
+        amount: i128,
+    ) -> Result<(), Error> {
+        let mut epoch = Self::epoch_spent(env, token, policy);
+        let spent = epoch
+            .spent
+            .checked_add(amount)
+            .ok_or(Error::InvalidAmount)?;
+        if spent > policy.epoch_cap {
+            return Err(Error::EpochCapExceeded);
+        }
+        epoch.spent = spent;
+        env.storage()
+            .instance()
+            .set(&DataKey::EpochSpent(token.clone()), &epoch);
+        Ok(())
+    }
+
+    fn transfer(env: &Env, token: &Address, to: &Address, amount: i128) -> Result<(), Error> {
+        let client = token::Client::new(env, token);
+        let treasury = env.current_contract_address();
+        if client.balance(&treasury) < amount {
+            return Err(Error::InsufficientBalance);
+        }
+        client.transfer(&treasury, to, &amount);
+        Ok(())
+    }
+}
@@ -0,0 +1,273 @@
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+};
+
+struct Setup<'a> {
+    env: Env,
+    admin: Address,
+    spender: Address,
+    recipient: Address,
+    token: TokenClient<'a>,
+    treasury: TreasuryContractClient<'a>,
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(1_000);
+
+        let admin = Address::generate(&env);
+        let spender = Address::generate(&env);
+        let recipient = Address::generate(&env);
+
+        let sac = env.register_stellar_asset_contract_v2(admin.clone());
+        let token = TokenClient::new(&env, &sac.address());
+
+        let treasury_id = env.register_contract(None, TreasuryContract);
+        let treasury = TreasuryContractClient::new(&env, &treasury_id);
+        treasury.init(&admin);
+        treasury.set_spender(&spender, &true);
+        treasury.set_policy(
+            &token.address,
+            &SpendPolicy {
+                epoch_length: 86_400,
+                epoch_cap: 1_000,
+                large_spend_threshold: 500,
+                large_spend_delay: 3_600,
+            },
+        );
+
+        // Fees arrive as plain transfers
+        StellarAssetClient::new(&env, &token.address).mint(&treasury_id, &10_000);
+
+        Self {
+            env,
+            admin,
+            spender,
+            recipient,
+            token,
+            treasury,
+        }
+    }
+
+    fn spend(&self, amount: i128) -> Result<(), Error> {
+        self.treasury
+            .try_spend(&self.spender, &self.token.address, &self.recipient, &amount)
+            .map_err(|err| err.unwrap())
+            .map(|_| ())
+    }
+}
+
+#[test]
+fn test_spend_within_epoch_cap() {
+    let s = Setup::new();
+
+    assert_eq!(s.spend(400), Ok(()));
+    assert!(s.env.auths().iter().any(|(a, _)| *a == s.spender));
+    assert_eq!(s.spend(400), Ok(()));
+    assert_eq!(s.token.balance(&s.recipient), 800);
+    assert_eq!(s.treasury.get_epoch_spent(&s.token.address), 800);
+
+    assert_eq!(s.spend(300), Err(Error::EpochCapExceeded));
+
+    // The cap resets with the next epoch
+    s.env.ledger().set_timestamp(86_400 * 2);
+    assert_eq!(s.treasury.get_epoch_spent(&s.token.address), 0);
+    assert_eq!(s.spend(300), Ok(()));
+    assert_eq!(s.token.balance(&s.recipient), 1_100);
+}
+
+#[test]
+fn test_spend_rejections() {
+    let s = Setup::new();
+
+    assert_eq!(s.spend(0), Err(Error::InvalidAmount));
+    assert_eq!(s.spend(500), Err(Error::SpendRequiresSchedule));
+
+    let outsider = Address::generate(&s.env);
+    assert_eq!(
+        s.treasury
+            .try_spend(&outsider, &s.token.address, &s.recipient, &10),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    let other_token = Address::generate(&s.env);
+    assert_eq!(
+        s.treasury
+            .try_spend(&s.spender, &other_token, &s.recipient, &10),
+        Err(Ok(Error::PolicyNotSet))
+    );
+
+    s.treasury.set_spender(&s.spender, &false);
+    assert!(!s.treasury.is_spender(&s.spender));
+    assert_eq!(s.spend(10), Err(Error::Unauthorized));
+}
+
+#[test]
+fn test_large_spend_is_timelocked() {
+    let s = Setup::new();
+
+    let id = s
+        .treasury
+        .schedule_spend(&s.spender, &s.token.address, &s.recipient, &800);
+    let (operation, request) = s.treasury.get_scheduled_spend(&id).unwrap();
+    assert_eq!(operation.ready_at, 1_000 + 3_600);
+    assert_eq!(request.unwrap().amount, 800);
+
+    assert_eq!(
+        s.treasury.try_execute_spend(&id),
+        Err(Ok(Error::SpendNotReady))
+    );
+
+    s.env.ledger().set_timestamp(operation.ready_at);
+    s.treasury.execute_spend(&id);
+    assert_eq!(s.token.balance(&s.recipient), 800);
+    assert_eq!(
+        s.treasury.try_execute_spend(&id),
+        Err(Ok(Error::SpendNotPending))
+    );
+
+    // Executed spends use up the epoch cap
+    assert_eq!(s.treasury.get_epoch_spent(&s.token.address), 800);
+    assert_eq!(s.spend(300), Err(Error::EpochCapExceeded));
+}
+
+#[test]
+fn test_scheduled_spend_respects_epoch_cap() {
+    let s = Setup::new();
+
+    assert_eq!(
+        s.treasury
+            .try_schedule_spend(&s.spender, &s.token.address, &s.recipient, &5_000),
+        Err(Ok(Error::EpochCapExceeded))
+    );
+
+    let id = s
+        .treasury
+        .schedule_spend(&s.spender, &s.token.address, &s.recipient, &800);
+    s.env.ledger().set_timestamp(1_000 + 3_600);
+    assert_eq!(s.spend(400), Ok(()));
+    assert_eq!(
+        s.treasury.try_execute_spend(&id),
+        Err(Ok(Error::EpochCapExceeded))
+    );
+
+    // Runs in the next epoch
+    s.env.ledger().set_timestamp(86_400);
+    s.treasury.execute_spend(&id);
+    assert_eq!(s.treasury.get_epoch_spent(&s.token.address), 800);
+}
+
+#[test]
+fn test_revoked_spender_cannot_execute_scheduled_spend() {
+    let s = Setup::new();
+
+    let id = s
+        .treasury
+        .schedule_spend(&s.spender, &s.token.address, &s.recipient, &800);
+    s.treasury.set_spender(&s.spender, &false);
+
+    s.env.ledger().set_timestamp(10_000);
+    assert_eq!(
+        s.treasury.try_execute_spend(&id),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(s.treasury.get_balance(&s.token.address), 10_000);
+}
+
+#[test]
+fn test_admin_cancels_scheduled_spend() {
+    let s = Setup::new();
+
+    let id = s
+        .treasury
+        .schedule_spend(&s.spender, &s.token.address, &s.recipient, &800);
+    s.treasury.cancel_spend(&id);
+    assert!(s.env.auths().iter().any(|(a, _)| *a == s.admin));
+
+    s.env.ledger().set_timestamp(10_000);
+    assert_eq!(
+        s.treasury.try_execute_spend(&id),
+        Err(Ok(Error::SpendNotPending))
+    );
+    assert_eq!(s.treasury.get_balance(&s.token.address), 10_000);
+}
+
+#[test]
+fn test_execute_spend_checks_balance() {
+    let s = Setup::new();
+    s.treasury.set_policy(
+        &s.token.address,
+        &SpendPolicy {
+            epoch_length: 86_400,
+            epoch_cap: 100_000,
+            large_spend_threshold: 500,
+            large_spend_delay: 3_600,
+        },
+    );
+
+    let id = s
+        .treasury
+        .schedule_spend(&s.spender, &s.token.address, &s.recipient, &20_000);
+    s.env.ledger().set_timestamp(10_000);
+    assert_eq!(
+        s.treasury.try_execute_spend(&id),
+        Err(Ok(Error::InsufficientBalance))
+    );
+}
+
+#[test]
+fn test_policy_validation() {
+    let s = Setup::new();
+    let policy = SpendPolicy {
+        epoch_length: 0,
+        epoch_cap: 1_000,
+        large_spend_threshold: 500,
+        large_spend_delay: 3_600,
+    };
+    assert_eq!(
+        s.treasury.try_set_policy(&s.token.address, &policy),
+        Err(Ok(Error::InvalidPolicy))
+    );
+
+    // A zero delay would let large spends skip the timelock
+    let policy = SpendPolicy {
+        epoch_length: 86_400,
+        large_spend_delay: 0,
+        ..policy
+    };
+    assert_eq!(
+        s.treasury.try_set_policy(&s.token.address, &policy),
+        Err(Ok(Error::InvalidPolicy))
+    );
+}
+
+#[test]
+fn test_two_step_admin_transfer() {
+    let s = Setup::new();
+    let next = Address::generate(&s.env);
+
+    assert_eq!(
+        s.treasury.try_accept_admin(),
+        Err(Ok(Error::NoPendingAdmin))
+    );
+    s.treasury.propose_admin(&next);
+    s.treasury.cancel_admin_transfer();
+    assert_eq!(s.treasury.get_pending_admin(), None);
+    assert_eq!(
+        s.treasury.try_accept_admin(),
+        Err(Ok(Error::NoPendingAdmin))
+    );
+
+    s.treasury.propose_admin(&next);
+    assert_eq!(s.treasury.get_pending_admin(), Some(next.clone()));
+    s.treasury.accept_admin();
+    assert_eq!(s.treasury.get_admin(), Some(next));
+    assert_eq!(
+        s.treasury.try_init(&s.admin),
+        Err(Ok(Error::AlreadyInitialized))
+    );
+}